@@ -26,6 +26,8 @@ CREATE TABLE payment_attempts_queue (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `retry_decision` LowCardinality(Nullable(String)),
+    `retry_reason` LowCardinality(Nullable(String)),
     `created_at` DateTime CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime CODEC(T64, LZ4),
@@ -64,6 +66,8 @@ CREATE TABLE payment_attempt_dist (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `retry_decision` LowCardinality(Nullable(String)),
+    `retry_reason` LowCardinality(Nullable(String)),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -111,6 +115,8 @@ CREATE MATERIALIZED VIEW kafka_parse_pa TO payment_attempt_dist (
     `error_code` Nullable(String),
     `connector_metadata` Nullable(String),
     `payment_experience` Nullable(String),
+    `retry_decision` LowCardinality(Nullable(String)),
+    `retry_reason` LowCardinality(Nullable(String)),
     `created_at` DateTime64(3),
     `capture_on` Nullable(DateTime64(3)),
     `last_synced` Nullable(DateTime64(3)),
@@ -145,6 +151,8 @@ SELECT
     error_code,
     connector_metadata,
     payment_experience,
+    retry_decision,
+    retry_reason,
     created_at,
     capture_on,
     last_synced,
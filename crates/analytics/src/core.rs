@@ -16,6 +16,11 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_refund_dimensions(),
         },
+        AnalyticsDomain::Disputes => GetInfoResponse {
+            metrics: utils::get_dispute_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_dispute_dimensions(),
+        },
         AnalyticsDomain::SdkEvents => GetInfoResponse {
             metrics: utils::get_sdk_event_metrics_info(),
             download_dimensions: None,
@@ -0,0 +1,154 @@
+use api_models::analytics::disputes::DisputeMetricsBucketValue;
+use diesel_models::enums as storage_enums;
+
+use super::metrics::DisputeMetricRow;
+#[derive(Debug, Default)]
+pub struct DisputeMetricsAccumulator {
+    pub dispute_count: CountAccumulator,
+    pub dispute_rate: DisputeRateAccumulator,
+    pub dispute_win_rate: OutcomeRateAccumulator,
+    pub dispute_loss_rate: OutcomeRateAccumulator,
+    pub amount_at_risk: SumAccumulator,
+}
+
+#[derive(Debug, Default)]
+pub struct DisputeRateAccumulator {
+    pub disputes: i64,
+    pub successful_payments: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct OutcomeRateAccumulator {
+    pub won: i64,
+    pub lost: i64,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct SumAccumulator {
+    pub total: Option<i64>,
+}
+
+pub trait DisputeMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl DisputeMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl DisputeMetricAccumulator for SumAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.total = match (
+            self.total,
+            metrics
+                .total
+                .as_ref()
+                .and_then(bigdecimal::ToPrimitive::to_i64),
+        ) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.total.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl DisputeMetricAccumulator for DisputeRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.disputes += metrics.count.unwrap_or_default();
+        self.successful_payments += metrics.successful_payment_count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.successful_payments <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.disputes).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.successful_payments).ok()?),
+            )
+        }
+    }
+}
+
+impl OutcomeRateAccumulator {
+    fn resolved(&self) -> Option<f64> {
+        u32::try_from(self.won + self.lost)
+            .ok()
+            .filter(|resolved| *resolved > 0)
+            .map(f64::from)
+    }
+
+    pub fn collect_win_rate(self) -> Option<f64> {
+        Some(f64::from(u32::try_from(self.won).ok()?) * 100.0 / self.resolved()?)
+    }
+
+    pub fn collect_loss_rate(self) -> Option<f64> {
+        Some(f64::from(u32::try_from(self.lost).ok()?) * 100.0 / self.resolved()?)
+    }
+}
+
+impl DisputeMetricAccumulator for OutcomeRateAccumulator {
+    type MetricOutput = Self;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        match metrics.dispute_status.as_ref().map(|status| status.0) {
+            Some(storage_enums::DisputeStatus::DisputeWon) => {
+                self.won += metrics.count.unwrap_or_default()
+            }
+            // Accepting a dispute or letting it expire forfeits it the same way losing does
+            Some(
+                storage_enums::DisputeStatus::DisputeLost
+                | storage_enums::DisputeStatus::DisputeAccepted
+                | storage_enums::DisputeStatus::DisputeExpired,
+            ) => self.lost += metrics.count.unwrap_or_default(),
+            _ => {}
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        self
+    }
+}
+
+impl DisputeMetricsAccumulator {
+    pub fn collect(self) -> DisputeMetricsBucketValue {
+        DisputeMetricsBucketValue {
+            dispute_count: self.dispute_count.collect(),
+            dispute_rate: self.dispute_rate.collect(),
+            dispute_win_rate: self.dispute_win_rate.collect().collect_win_rate(),
+            dispute_loss_rate: self.dispute_loss_rate.collect().collect_loss_rate(),
+            dispute_amount_at_risk: self.amount_at_risk.collect(),
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    disputes::{DisputeDimensions, DisputeFilters, DisputeMetricsBucketIdentifier},
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums::AttemptStatus;
+use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
+
+use super::DisputeMetricRow;
+use crate::{
+    query::{
+        Aggregate, GroupByClause, PostProcessingError, QueryBuilder, QueryFilter, SeriesBucket,
+        ToSql, Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+/// Disputes raised relative to successful payments.
+///
+/// Payments carry no dispute specific attributes, so both sides can only be bucketed by connector
+/// and time; grouping or filtering by any other dimension is rejected for this metric.
+#[derive(Default)]
+pub(super) struct DisputeRate {}
+
+#[async_trait::async_trait]
+impl<T> super::DisputeMetric<T> for DisputeRate
+where
+    T: AnalyticsDataSource + super::DisputeMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[DisputeDimensions],
+        merchant_id: &str,
+        filters: &DisputeFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(DisputeMetricsBucketIdentifier, DisputeMetricRow)>> {
+        let has_unsupported_dimension = dimensions
+            .iter()
+            .any(|dimension| *dimension != DisputeDimensions::Connector);
+        let has_unsupported_filter = !filters.dispute_stage.is_empty()
+            || !filters.dispute_status.is_empty()
+            || !filters.connector_reason_code.is_empty()
+            || !filters.currency.is_empty();
+        if has_unsupported_dimension || has_unsupported_filter {
+            return Err(report!(MetricsError::NotImplemented(
+                "Grouping or filtering the dispute rate by anything other than connector"
+            )));
+        }
+
+        let group_by_connector = dimensions.contains(&DisputeDimensions::Connector);
+
+        let mut disputes_query: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Dispute);
+        add_bucket_columns(
+            &mut disputes_query,
+            group_by_connector,
+            merchant_id,
+            &filters.connector,
+            granularity,
+            time_range,
+        )?;
+
+        let mut payments_query: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+        add_bucket_columns(
+            &mut payments_query,
+            group_by_connector,
+            merchant_id,
+            &filters.connector,
+            granularity,
+            time_range,
+        )?;
+        payments_query
+            .add_filter_clause("status", AttemptStatus::Charged)
+            .switch()?;
+
+        let disputes = disputes_query
+            .execute_query::<DisputeMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?;
+        let payments = payments_query
+            .execute_query::<DisputeMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?;
+
+        let mut buckets: HashMap<DisputeMetricsBucketIdentifier, DisputeMetricRow> = HashMap::new();
+        for row in disputes {
+            let id = get_bucket_identifier(&row, granularity, time_range)
+                .change_context(MetricsError::PostProcessingFailure)?;
+            buckets.insert(id, row);
+        }
+        for row in payments {
+            let id = get_bucket_identifier(&row, granularity, time_range)
+                .change_context(MetricsError::PostProcessingFailure)?;
+            let bucket = buckets.entry(id).or_insert_with(|| DisputeMetricRow {
+                connector: row.connector.clone(),
+                start_bucket: row.start_bucket,
+                end_bucket: row.end_bucket,
+                ..Default::default()
+            });
+            bucket.successful_payment_count = row.count;
+        }
+
+        Ok(buckets.into_iter().collect())
+    }
+}
+
+fn add_bucket_columns<T>(
+    query_builder: &mut QueryBuilder<T>,
+    group_by_connector: bool,
+    merchant_id: &str,
+    connectors: &[String],
+    granularity: &Option<Granularity>,
+    time_range: &TimeRange,
+) -> MetricsResult<()>
+where
+    T: AnalyticsDataSource,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+{
+    if group_by_connector {
+        query_builder
+            .add_select_column(DisputeDimensions::Connector)
+            .switch()?;
+    }
+
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    if !connectors.is_empty() {
+        query_builder
+            .add_filter_in_range_clause(DisputeDimensions::Connector, connectors)
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    time_range
+        .set_filter_clause(query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    if group_by_connector {
+        query_builder
+            .add_group_by_clause(DisputeDimensions::Connector)
+            .attach_printable("Error grouping by dimensions")
+            .switch()?;
+    }
+
+    if let Some(granularity) = granularity.as_ref() {
+        granularity
+            .set_group_by_clause(query_builder)
+            .attach_printable("Error adding granularity")
+            .switch()?;
+    }
+
+    Ok(())
+}
+
+fn get_bucket_identifier(
+    row: &DisputeMetricRow,
+    granularity: &Option<Granularity>,
+    time_range: &TimeRange,
+) -> error_stack::Result<DisputeMetricsBucketIdentifier, PostProcessingError> {
+    Ok(DisputeMetricsBucketIdentifier::new(
+        None,
+        None,
+        row.connector.clone(),
+        None,
+        None,
+        TimeRange {
+            start_time: match (granularity, row.start_bucket) {
+                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                _ => time_range.start_time,
+            },
+            end_time: granularity.as_ref().map_or_else(
+                || Ok(time_range.end_time),
+                |g| row.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+            )?,
+        },
+    ))
+}
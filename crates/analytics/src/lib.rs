@@ -1,10 +1,12 @@
 mod clickhouse;
 pub mod core;
+pub mod disputes;
 pub mod errors;
 pub mod metrics;
 pub mod payments;
 mod query;
 pub mod refunds;
+pub mod reports;
 
 pub mod api_event;
 pub mod connector_events;
@@ -14,7 +16,6 @@ mod sqlx;
 mod types;
 use api_event::metrics::{ApiEventMetric, ApiEventMetricRow};
 pub use types::AnalyticsDomain;
-pub mod lambda_utils;
 pub mod utils;
 
 use std::sync::Arc;
@@ -23,6 +24,7 @@ use api_models::analytics::{
     api_event::{
         ApiEventDimensions, ApiEventFilters, ApiEventMetrics, ApiEventMetricsBucketIdentifier,
     },
+    disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
     sdk_events::{
@@ -40,6 +42,7 @@ use router_env::{
 use storage_impl::config::Database;
 
 use self::{
+    disputes::metrics::{DisputeMetric, DisputeMetricRow},
     payments::{
         distribution::{PaymentDistribution, PaymentDistributionRow},
         metrics::{PaymentMetric, PaymentMetricRow},
@@ -391,6 +394,106 @@ impl AnalyticsProvider {
         .await
     }
 
+    pub async fn get_dispute_metrics(
+        &self,
+        metric: &DisputeMetrics,
+        dimensions: &[DisputeDimensions],
+        merchant_id: &str,
+        filters: &DisputeFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<Vec<(DisputeMetricsBucketIdentifier, DisputeMetricRow)>> {
+        // Metrics to get the fetch time for each dispute metric
+        metrics::request::record_operation_time(
+            async {
+                        match self {
+                            Self::Sqlx(pool) => {
+                                metric
+                                    .load_metrics(
+                                        dimensions,
+                                        merchant_id,
+                                        filters,
+                                        granularity,
+                                        time_range,
+                                        pool,
+                                    )
+                                    .await
+                            }
+                            Self::Clickhouse(pool) => {
+                                metric
+                                    .load_metrics(
+                                        dimensions,
+                                        merchant_id,
+                                        filters,
+                                        granularity,
+                                        time_range,
+                                        pool,
+                                    )
+                                    .await
+                            }
+                            Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                                let (ckh_result, sqlx_result) = tokio::join!(
+                                    metric.load_metrics(
+                                        dimensions,
+                                        merchant_id,
+                                        filters,
+                                        granularity,
+                                        time_range,
+                                        ckh_pool,
+                                    ),
+                                    metric.load_metrics(
+                                        dimensions,
+                                        merchant_id,
+                                        filters,
+                                        granularity,
+                                        time_range,
+                                        sqlx_pool,
+                                    )
+                                );
+                                match (&sqlx_result, &ckh_result) {
+                                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                        logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres disputes analytics metrics")
+                                    }
+                                    _ => {}
+                                };
+                                ckh_result
+                            }
+                            Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                                let (ckh_result, sqlx_result) = tokio::join!(
+                                    metric.load_metrics(
+                                        dimensions,
+                                        merchant_id,
+                                        filters,
+                                        granularity,
+                                        time_range,
+                                        ckh_pool,
+                                    ),
+                                    metric.load_metrics(
+                                        dimensions,
+                                        merchant_id,
+                                        filters,
+                                        granularity,
+                                        time_range,
+                                        sqlx_pool,
+                                    )
+                                );
+                                match (&sqlx_result, &ckh_result) {
+                                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                        logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres disputes analytics metrics")
+                                    }
+                                    _ => {}
+                                };
+                                sqlx_result
+                            }
+                        }
+                    },
+                   &metrics::METRIC_FETCH_TIME,
+       metric,
+            self,
+        )
+        .await
+    }
+
     pub async fn get_sdk_event_metrics(
         &self,
         metric: &SdkEventMetrics,
@@ -401,7 +504,10 @@ impl AnalyticsProvider {
         time_range: &TimeRange,
     ) -> types::MetricsResult<Vec<(SdkEventMetricsBucketIdentifier, SdkEventMetricRow)>> {
         match self {
-            Self::Sqlx(_pool) => Err(MetricsError::NotImplemented).into_report(),
+            Self::Sqlx(_pool) => Err(MetricsError::NotImplemented(
+                "SDK event metrics on Postgres",
+            ))
+            .into_report(),
             Self::Clickhouse(pool) => {
                 metric
                     .load_metrics(dimensions, pub_key, filters, granularity, time_range, pool)
@@ -433,7 +539,10 @@ impl AnalyticsProvider {
         time_range: &TimeRange,
     ) -> types::MetricsResult<Vec<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>> {
         match self {
-            Self::Sqlx(_pool) => Err(MetricsError::NotImplemented).into_report(),
+            Self::Sqlx(_pool) => Err(MetricsError::NotImplemented(
+                "API event metrics on Postgres",
+            ))
+            .into_report(),
             Self::Clickhouse(ckh_pool)
             | Self::CombinedCkh(_, ckh_pool)
             | Self::CombinedSqlx(_, ckh_pool) => {
@@ -502,10 +611,20 @@ impl Default for AnalyticsConfig {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, Default, serde::Serialize)]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
 pub struct ReportConfig {
-    pub payment_function: String,
-    pub refund_function: String,
-    pub dispute_function: String,
-    pub region: String,
+    /// Number of rows fetched from the analytics datasource per query
+    pub batch_size: u64,
+    /// Validity of the emailed report download link, in seconds
+    pub download_link_expiry: i64,
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 10_000,
+            download_link_expiry: 86_400,
+        }
+    }
 }
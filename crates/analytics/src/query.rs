@@ -4,6 +4,7 @@ use api_models::{
     analytics::{
         self as analytics_api,
         api_event::ApiEventDimensions,
+        disputes::DisputeDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
         refunds::{RefundDimensions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
@@ -331,6 +332,8 @@ where
     having: Option<Vec<(String, FilterTypes, String)>>,
     outer_select: Vec<String>,
     top_n: Option<TopN>,
+    order_by: Vec<String>,
+    limit: Option<u64>,
     table: AnalyticsCollection,
     distinct: bool,
     db_type: PhantomData<T>,
@@ -383,6 +386,13 @@ impl_to_sql_for_to_string!(&SdkEventDimensions, SdkEventDimensions, SdkEventName
 
 impl_to_sql_for_to_string!(&ApiEventDimensions, ApiEventDimensions);
 
+impl_to_sql_for_to_string!(
+    &DisputeDimensions,
+    DisputeDimensions,
+    storage_enums::DisputeStage,
+    storage_enums::DisputeStatus
+);
+
 #[derive(Debug)]
 pub enum FilterTypes {
     Equal,
@@ -423,6 +433,8 @@ where
             having: Default::default(),
             outer_select: Default::default(),
             top_n: Default::default(),
+            order_by: Default::default(),
+            limit: Default::default(),
             table,
             distinct: Default::default(),
             db_type: Default::default(),
@@ -552,6 +564,20 @@ where
         Ok(())
     }
 
+    pub fn add_order_by_clause(&mut self, column: impl ToSql<T>, order: Order) -> QueryResult<()> {
+        let column = column
+            .to_sql(&self.table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing order by field")?;
+        self.order_by
+            .push(format!("{} {}", column, order.to_string()));
+        Ok(())
+    }
+
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = Some(limit)
+    }
+
     pub fn add_granularity_in_mins(&mut self, granularity: &Granularity) -> QueryResult<()> {
         let interval = match granularity {
             Granularity::OneMin => "1",
@@ -583,6 +609,10 @@ where
         self.group_by.join(", ")
     }
 
+    fn get_order_by_clause(&self) -> String {
+        self.order_by.join(", ")
+    }
+
     fn get_outer_select_clause(&self) -> String {
         self.outer_select.join(", ")
     }
@@ -688,6 +718,15 @@ where
             }
         }
 
+        if !self.order_by.is_empty() {
+            query.push_str(" ORDER BY ");
+            query.push_str(&self.get_order_by_clause());
+        }
+
+        if let Some(limit) = self.limit {
+            query.push_str(format!(" LIMIT {limit}").as_str());
+        }
+
         if !self.outer_select.is_empty() {
             query.insert_str(
                 0,
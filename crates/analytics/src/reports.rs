@@ -0,0 +1,92 @@
+mod core;
+pub mod rows;
+mod writer;
+
+pub use self::core::generate_report;
+
+/// The kind of entity a report is generated for.
+#[derive(
+    Clone, Copy, Debug, serde::Serialize, serde::Deserialize, strum::Display, strum::AsRefStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportType {
+    Payments,
+    Refunds,
+    Disputes,
+}
+
+impl ReportType {
+    /// Columns written to the report, in order.
+    pub fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Payments => &[
+                "payment_id",
+                "attempt_id",
+                "status",
+                "amount",
+                "currency",
+                "connector",
+                "connector_transaction_id",
+                "payment_method",
+                "payment_method_type",
+                "authentication_type",
+                "capture_method",
+                "error_code",
+                "error_message",
+                "created_at",
+                "modified_at",
+            ],
+            Self::Refunds => &[
+                "refund_id",
+                "payment_id",
+                "attempt_id",
+                "refund_status",
+                "refund_type",
+                "total_amount",
+                "refund_amount",
+                "currency",
+                "connector",
+                "connector_refund_id",
+                "refund_reason",
+                "refund_error_code",
+                "refund_error_message",
+                "created_at",
+                "modified_at",
+            ],
+            Self::Disputes => &[
+                "dispute_id",
+                "payment_id",
+                "attempt_id",
+                "dispute_stage",
+                "dispute_status",
+                "amount",
+                "currency",
+                "connector",
+                "connector_dispute_id",
+                "connector_status",
+                "connector_reason",
+                "challenge_required_by",
+                "created_at",
+                "modified_at",
+            ],
+        }
+    }
+
+    /// Column used to break ties between rows created at the same instant while paginating.
+    pub fn id_column(&self) -> &'static str {
+        match self {
+            Self::Payments => "attempt_id",
+            Self::Refunds => "refund_id",
+            Self::Disputes => "dispute_id",
+        }
+    }
+
+    pub fn collection(&self) -> crate::types::AnalyticsCollection {
+        match self {
+            Self::Payments => crate::types::AnalyticsCollection::Payment,
+            Self::Refunds => crate::types::AnalyticsCollection::Refund,
+            Self::Disputes => crate::types::AnalyticsCollection::Dispute,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::Write;
+
+use api_models::analytics::{Granularity, ReportRequest};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::{
+    rows::{get_report_rows, ReportColumn, ReportCursor, ReportRowAnalytics},
+    writer::ReportWriter,
+    ReportType,
+};
+use crate::{
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+    AnalyticsProvider,
+};
+
+/// Generates the report for a merchant and writes it into `output`, which is returned once the
+/// report is complete.
+///
+/// Rows are fetched in pages of `batch_size`, each page starting right after the last row of the
+/// previous one, and written to `output` as they arrive, so that only a single page is held in
+/// memory.
+#[instrument(skip_all, fields(report_type = %report_type))]
+pub async fn generate_report<W: Write + Send>(
+    pool: &AnalyticsProvider,
+    report_type: ReportType,
+    merchant_id: &str,
+    request: &ReportRequest,
+    batch_size: u64,
+    output: W,
+) -> AnalyticsResult<W> {
+    match pool {
+        AnalyticsProvider::Sqlx(sqlx_pool) | AnalyticsProvider::CombinedSqlx(sqlx_pool, _) => {
+            write_report(
+                sqlx_pool,
+                report_type,
+                merchant_id,
+                request,
+                batch_size,
+                output,
+            )
+            .await
+        }
+        AnalyticsProvider::Clickhouse(ckh_pool) | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            write_report(
+                ckh_pool,
+                report_type,
+                merchant_id,
+                request,
+                batch_size,
+                output,
+            )
+            .await
+        }
+    }
+}
+
+async fn write_report<T, W: Write + Send>(
+    pool: &T,
+    report_type: ReportType,
+    merchant_id: &str,
+    request: &ReportRequest,
+    batch_size: u64,
+    output: W,
+) -> AnalyticsResult<W>
+where
+    T: AnalyticsDataSource + ReportRowAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    ReportColumn: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let batch_size = batch_size.max(1);
+    let mut writer = ReportWriter::new(request.format, report_type.columns(), output)?;
+    let mut cursor = None;
+    let mut rows_written: u64 = 0;
+    loop {
+        let rows = get_report_rows(
+            report_type,
+            merchant_id,
+            &request.time_range,
+            batch_size,
+            cursor.as_ref(),
+            pool,
+        )
+        .await
+        .switch()?;
+        writer.write_rows(&rows)?;
+
+        let fetched = u64::try_from(rows.len()).unwrap_or(batch_size);
+        rows_written += fetched;
+        if fetched < batch_size {
+            break;
+        }
+        cursor = Some(
+            rows.last()
+                .and_then(|row| ReportCursor::from_row(report_type, row))
+                .ok_or(AnalyticsError::UnknownError)
+                .into_report()
+                .attach_printable("Report row is missing the pagination columns")?,
+        );
+    }
+    logger::info!(rows_written, "Generated {report_type} report");
+    writer.finish()
+}
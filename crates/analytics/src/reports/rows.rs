@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{Granularity, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::ReportType;
+use crate::{
+    query::{
+        Aggregate, FilterTypes, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window,
+    },
+    types::{AnalyticsDataSource, FiltersError, FiltersResult, LoadRow, TableEngine},
+};
+
+pub trait ReportRowAnalytics: LoadRow<ReportRow> {}
+
+/// A column selected as text, so that every datasource yields the same row shape
+/// irrespective of the underlying column types.
+#[derive(Debug, Clone, Copy)]
+pub struct ReportColumn(pub &'static str);
+
+impl ReportColumn {
+    /// Name the text value is selected as. It differs from the column name, so that filters and
+    /// orderings on the column keep using the column rather than its text value.
+    pub fn alias(&self) -> String {
+        report_column_alias(self.0)
+    }
+}
+
+fn report_column_alias(column: &str) -> String {
+    format!("report_{column}")
+}
+
+/// A single report row keyed by column alias, with every value rendered as text.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ReportRow(pub(crate) HashMap<String, Option<String>>);
+
+impl ReportRow {
+    pub fn get(&self, column: &str) -> Option<&str> {
+        self.0
+            .get(&report_column_alias(column))
+            .and_then(|value| value.as_deref())
+    }
+}
+
+/// Position of the last row fetched, the next page starts right after it in
+/// `(created_at, id)` order.
+#[derive(Debug, Clone)]
+pub struct ReportCursor {
+    created_at: String,
+    id: String,
+}
+
+impl ReportCursor {
+    pub fn from_row(report_type: ReportType, row: &ReportRow) -> Option<Self> {
+        Some(Self {
+            created_at: row.get("created_at")?.to_string(),
+            id: row.get(report_type.id_column())?.to_string(),
+        })
+    }
+
+    fn to_filter_value(&self) -> String {
+        // The values are read back from the datasource, quotes are escaped all the same
+        format!(
+            "('{}', '{}')",
+            self.created_at.replace('\'', "''"),
+            self.id.replace('\'', "''")
+        )
+    }
+}
+
+pub async fn get_report_rows<T>(
+    report_type: ReportType,
+    merchant_id: &str,
+    time_range: &TimeRange,
+    limit: u64,
+    after: Option<&ReportCursor>,
+    pool: &T,
+) -> FiltersResult<Vec<ReportRow>>
+where
+    T: AnalyticsDataSource + ReportRowAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    ReportColumn: ToSql<T>,
+    crate::types::AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let collection = report_type.collection();
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(collection);
+
+    for column in report_type.columns() {
+        query_builder
+            .add_select_column(ReportColumn(column))
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+    time_range.set_filter_clause(&mut query_builder).switch()?;
+    if let Some(cursor) = after {
+        query_builder
+            .add_custom_filter_clause(
+                format!("(created_at, {})", report_type.id_column()),
+                cursor.to_filter_value(),
+                FilterTypes::Gt,
+            )
+            .switch()?;
+    }
+
+    // Collapsing tables hold cancelled versions of a row, grouping on every column
+    // lets the builder drop the ones that net out to zero.
+    if let TableEngine::CollapsingMergeTree { .. } = T::get_table_engine(collection) {
+        for column in report_type.columns() {
+            query_builder.add_group_by_clause(*column).switch()?;
+        }
+    }
+
+    query_builder
+        .add_order_by_clause("created_at", Order::Ascending)
+        .switch()?;
+    query_builder
+        .add_order_by_clause(report_type.id_column(), Order::Ascending)
+        .switch()?;
+    query_builder.set_limit(limit);
+
+    query_builder
+        .execute_query::<ReportRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(values: &[(&str, &str)]) -> ReportRow {
+        ReportRow(
+            values
+                .iter()
+                .map(|(column, value)| (report_column_alias(column), Some(value.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_report_row_is_read_by_column_name() {
+        let row = row(&[("payment_id", "pay_1")]);
+        assert_eq!(ReportColumn("payment_id").alias(), "report_payment_id");
+        assert_eq!(row.get("payment_id"), Some("pay_1"));
+        assert_eq!(row.get("report_payment_id"), None);
+    }
+
+    #[test]
+    fn test_report_cursor_starts_after_the_last_row() {
+        let last_row = row(&[
+            ("created_at", "2024-01-01 10:00:00"),
+            ("refund_id", "ref_1"),
+            ("attempt_id", "att_1"),
+        ]);
+        let cursor = ReportCursor::from_row(ReportType::Refunds, &last_row);
+        assert_eq!(
+            cursor.map(|cursor| cursor.to_filter_value()),
+            Some("('2024-01-01 10:00:00', 'ref_1')".to_string())
+        );
+    }
+
+    #[test]
+    fn test_report_cursor_escapes_quotes() {
+        let last_row = row(&[("created_at", "2024-01-01"), ("dispute_id", "dp_'1")]);
+        let cursor = ReportCursor::from_row(ReportType::Disputes, &last_row);
+        assert_eq!(
+            cursor.map(|cursor| cursor.to_filter_value()),
+            Some("('2024-01-01', 'dp_''1')".to_string())
+        );
+    }
+
+    #[test]
+    fn test_report_cursor_requires_the_pagination_columns() {
+        let last_row = row(&[("created_at", "2024-01-01"), ("payment_id", "pay_1")]);
+        assert!(ReportCursor::from_row(ReportType::Payments, &last_row).is_none());
+    }
+}
@@ -0,0 +1,217 @@
+use std::{io::Write, sync::Arc};
+
+use api_models::analytics::ReportFormat;
+use error_stack::{IntoReport, ResultExt};
+use parquet::{
+    data_type::{ByteArray, ByteArrayType},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+
+use super::rows::ReportRow;
+use crate::errors::{AnalyticsError, AnalyticsResult};
+
+/// Incrementally serializes report rows of the requested format into the given output.
+pub enum ReportWriter<W: Write + Send> {
+    Csv {
+        columns: &'static [&'static str],
+        writer: csv::Writer<W>,
+    },
+    Parquet {
+        columns: &'static [&'static str],
+        writer: SerializedFileWriter<W>,
+    },
+}
+
+impl<W: Write + Send> ReportWriter<W> {
+    pub fn new(
+        format: ReportFormat,
+        columns: &'static [&'static str],
+        output: W,
+    ) -> AnalyticsResult<Self> {
+        match format {
+            ReportFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(output);
+                writer
+                    .write_record(columns)
+                    .into_report()
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to write report csv header")?;
+                Ok(Self::Csv { columns, writer })
+            }
+            ReportFormat::Parquet => {
+                let message_type = format!(
+                    "message report {{ {} }}",
+                    columns
+                        .iter()
+                        .map(|column| format!("OPTIONAL BYTE_ARRAY {column} (UTF8);"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                );
+                let schema = parse_message_type(&message_type)
+                    .into_report()
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to build report parquet schema")?;
+                let writer = SerializedFileWriter::new(
+                    output,
+                    Arc::new(schema),
+                    Arc::new(WriterProperties::builder().build()),
+                )
+                .into_report()
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to create report parquet writer")?;
+                Ok(Self::Parquet { columns, writer })
+            }
+        }
+    }
+
+    /// Appends a batch of rows. For parquet every batch is written as a separate row group.
+    pub fn write_rows(&mut self, rows: &[ReportRow]) -> AnalyticsResult<()> {
+        match self {
+            Self::Csv { columns, writer } => {
+                for row in rows {
+                    writer
+                        .write_record(columns.iter().map(|column| row.get(column).unwrap_or("")))
+                        .into_report()
+                        .change_context(AnalyticsError::UnknownError)
+                        .attach_printable("Failed to write report csv record")?;
+                }
+                Ok(())
+            }
+            Self::Parquet { columns, writer } => {
+                if rows.is_empty() {
+                    return Ok(());
+                }
+                let mut row_group = writer
+                    .next_row_group()
+                    .into_report()
+                    .change_context(AnalyticsError::UnknownError)?;
+                for column in columns.iter() {
+                    let mut values = Vec::with_capacity(rows.len());
+                    let mut definition_levels = Vec::with_capacity(rows.len());
+                    for row in rows {
+                        match row.get(column) {
+                            Some(value) => {
+                                values.push(ByteArray::from(value));
+                                definition_levels.push(1);
+                            }
+                            None => definition_levels.push(0),
+                        }
+                    }
+                    if let Some(mut column_writer) = row_group
+                        .next_column()
+                        .into_report()
+                        .change_context(AnalyticsError::UnknownError)?
+                    {
+                        column_writer
+                            .typed::<ByteArrayType>()
+                            .write_batch(&values, Some(&definition_levels), None)
+                            .into_report()
+                            .change_context(AnalyticsError::UnknownError)
+                            .attach_printable_lazy(|| {
+                                format!("Failed to write report parquet column {column}")
+                            })?;
+                        column_writer
+                            .close()
+                            .into_report()
+                            .change_context(AnalyticsError::UnknownError)?;
+                    }
+                }
+                row_group
+                    .close()
+                    .into_report()
+                    .change_context(AnalyticsError::UnknownError)
+                    .map(|_| ())
+            }
+        }
+    }
+
+    /// Flushes the remaining rows and returns the output the report was written into.
+    pub fn finish(self) -> AnalyticsResult<W> {
+        match self {
+            Self::Csv { writer, .. } => writer
+                .into_inner()
+                .map_err(|error| error.into_error())
+                .into_report()
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to flush report csv"),
+            Self::Parquet { writer, .. } => writer
+                .into_inner()
+                .into_report()
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to flush report parquet"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{collections::HashMap, fs::File};
+
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    use super::*;
+
+    const COLUMNS: &[&str] = &["payment_id", "error_code"];
+
+    fn rows(values: &[(&str, Option<&str>)]) -> Vec<ReportRow> {
+        values
+            .iter()
+            .map(|(payment_id, error_code)| {
+                ReportRow(HashMap::from([
+                    (
+                        "report_payment_id".to_string(),
+                        Some(payment_id.to_string()),
+                    ),
+                    (
+                        "report_error_code".to_string(),
+                        error_code.map(ToString::to_string),
+                    ),
+                ]))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_csv_report() {
+        let mut writer = ReportWriter::new(ReportFormat::Csv, COLUMNS, Vec::new()).unwrap();
+        writer
+            .write_rows(&rows(&[("pay_1", None), ("pay_2", Some("card_declined"))]))
+            .unwrap();
+        writer.write_rows(&rows(&[("pay,3", None)])).unwrap();
+
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(
+            output,
+            "payment_id,error_code\npay_1,\npay_2,card_declined\n\"pay,3\",\n"
+        );
+    }
+
+    #[test]
+    fn test_parquet_report() {
+        let path = std::env::temp_dir().join("analytics_test_parquet_report.parquet");
+        let mut writer =
+            ReportWriter::new(ReportFormat::Parquet, COLUMNS, File::create(&path).unwrap())
+                .unwrap();
+        writer
+            .write_rows(&rows(&[("pay_1", None), ("pay_2", Some("card_declined"))]))
+            .unwrap();
+        // Empty batches don't add row groups
+        writer.write_rows(&[]).unwrap();
+        writer.write_rows(&rows(&[("pay_3", None)])).unwrap();
+        writer.finish().unwrap();
+
+        let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        let metadata = reader.metadata();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(metadata.file_metadata().num_rows(), 3);
+        assert_eq!(metadata.num_row_groups(), 2);
+        let schema = metadata.file_metadata().schema_descr();
+        assert_eq!(schema.num_columns(), 2);
+        assert_eq!(schema.column(0).name(), "payment_id");
+        assert_eq!(schema.column(1).name(), "error_code");
+    }
+}
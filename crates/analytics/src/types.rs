@@ -15,6 +15,7 @@ use crate::errors::AnalyticsError;
 pub enum AnalyticsDomain {
     Payments,
     Refunds,
+    Disputes,
     SdkEvents,
     ApiEvents,
 }
@@ -28,6 +29,7 @@ pub enum AnalyticsCollection {
     PaymentIntent,
     ConnectorEvents,
     OutgoingWebhookEvent,
+    Dispute,
 }
 
 #[allow(dead_code)]
@@ -97,9 +99,19 @@ pub enum MetricsError {
     QueryExecutionFailure,
     #[error("Error processing query results")]
     PostProcessingFailure,
-    #[allow(dead_code)]
-    #[error("Not Implemented")]
-    NotImplemented,
+    #[error("Not Implemented: {0}")]
+    NotImplemented(&'static str),
+}
+
+impl ErrorSwitch<AnalyticsError> for MetricsError {
+    fn switch(&self) -> AnalyticsError {
+        match self {
+            Self::QueryBuildingError
+            | Self::QueryExecutionFailure
+            | Self::PostProcessingFailure => AnalyticsError::UnknownError,
+            Self::NotImplemented(a) => AnalyticsError::NotImplemented(a),
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,179 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+use crate::enums::{DisputeStage, DisputeStatus};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct DisputeFilters {
+    #[serde(default)]
+    pub dispute_stage: Vec<DisputeStage>,
+    #[serde(default)]
+    pub dispute_status: Vec<DisputeStatus>,
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub connector_reason_code: Vec<String>,
+    #[serde(default)]
+    pub currency: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeDimensions {
+    DisputeStage,
+    DisputeStatus,
+    Connector,
+    ConnectorReasonCode,
+    Currency,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeMetrics {
+    DisputeCount,
+    DisputeRate,
+    DisputeWinRate,
+    DisputeLossRate,
+    DisputeAmountAtRisk,
+}
+
+pub mod metric_behaviour {
+    pub struct DisputeCount;
+    pub struct DisputeRate;
+    pub struct DisputeWinRate;
+    pub struct DisputeLossRate;
+    pub struct DisputeAmountAtRisk;
+}
+
+impl From<DisputeMetrics> for NameDescription {
+    fn from(value: DisputeMetrics) -> Self {
+        let desc = match value {
+            DisputeMetrics::DisputeCount => "Number of disputes raised",
+            DisputeMetrics::DisputeRate => {
+                "Disputes raised as a percentage of successful payments, split by connector only"
+            }
+            DisputeMetrics::DisputeWinRate => "Percentage of resolved disputes won",
+            DisputeMetrics::DisputeLossRate => {
+                "Percentage of resolved disputes lost, accepted or expired"
+            }
+            DisputeMetrics::DisputeAmountAtRisk => {
+                "Sum of amounts under disputes that are open or being challenged"
+            }
+        };
+        Self {
+            name: value.to_string(),
+            desc: desc.to_string(),
+        }
+    }
+}
+
+impl From<DisputeDimensions> for NameDescription {
+    fn from(value: DisputeDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct DisputeMetricsBucketIdentifier {
+    pub dispute_stage: Option<DisputeStage>,
+    pub dispute_status: Option<DisputeStatus>,
+    pub connector: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub currency: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for DisputeMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dispute_stage.hash(state);
+        self.dispute_status.hash(state);
+        self.connector.hash(state);
+        self.connector_reason_code.hash(state);
+        self.currency.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for DisputeMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl DisputeMetricsBucketIdentifier {
+    pub fn new(
+        dispute_stage: Option<DisputeStage>,
+        dispute_status: Option<DisputeStatus>,
+        connector: Option<String>,
+        connector_reason_code: Option<String>,
+        currency: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            dispute_stage,
+            dispute_status,
+            connector,
+            connector_reason_code,
+            currency,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DisputeMetricsBucketValue {
+    pub dispute_count: Option<u64>,
+    pub dispute_rate: Option<f64>,
+    pub dispute_win_rate: Option<f64>,
+    pub dispute_loss_rate: Option<f64>,
+    pub dispute_amount_at_risk: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DisputeMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: DisputeMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: DisputeMetricsBucketIdentifier,
+}
@@ -8,9 +8,35 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    /// Blocks a single customer email address
+    Email(String),
+    /// Blocks every email address under the given domain, e.g. `example.com`
+    EmailDomain(String),
+    /// Blocks a single IPv4 or IPv6 address
+    IpAddress(String),
+    /// Blocks an IPv4 or IPv6 network in CIDR notation, e.g. `192.168.0.0/16`
+    IpRange(String),
+    /// Blocks a phone number in international format, e.g. `+14155552671`
+    PhoneNumber(String),
+    /// Blocks a range of card BINs given as 6 to 8 digits where `*` matches any digit,
+    /// e.g. `4242**`
+    CardBinRange(String),
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AddToBlocklistRequest {
+    #[serde(flatten)]
+    pub data: BlocklistRequest,
+    /// Time after which the entry stops blocking payments and is purged, the entry is
+    /// permanent when absent
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2024-06-30T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    /// Why the data is being blocked
+    #[schema(max_length = 255, example = "Chargeback fraud")]
+    pub reason: Option<String>,
 }
 
-pub type AddToBlocklistRequest = BlocklistRequest;
 pub type DeleteFromBlocklistRequest = BlocklistRequest;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -20,6 +46,11 @@ pub struct BlocklistResponse {
     pub data_kind: enums::BlocklistDataKind,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub reason: Option<String>,
+    /// Id of the API key or the user which added the entry
+    pub created_by: Option<String>,
 }
 
 pub type AddToBlocklistResponse = BlocklistResponse;
@@ -39,6 +70,43 @@ fn default_list_limit() -> u16 {
     10
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BlocklistExportQuery {
+    /// Only exports the entries of this kind, all the entries are exported when absent
+    #[schema(value_type = Option<BlocklistDataKind>)]
+    pub data_kind: Option<enums::BlocklistDataKind>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistImportRequest {
+    /// The identifier of a CSV file uploaded with the `blocklist_import` purpose. Each row has
+    /// the columns `type`, `data`, `expires_at` and `reason`, the entries are added on behalf
+    /// of the API key or the user which imports the file
+    #[schema(example = "file_3LWJ3dYPBd1dQT0mCGIW6Esc")]
+    pub file_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BlocklistImportResponse {
+    /// Entries which were added to the blocklist
+    pub imported: Vec<BlocklistResponse>,
+    /// Rows which could not be imported
+    pub failed: Vec<BlocklistImportError>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct BlocklistImportError {
+    /// Line of the row in the CSV file, the header being line 1
+    pub line: u64,
+    pub data: Option<String>,
+    pub error_message: String,
+}
+
 impl ApiEventMetric for BlocklistRequest {}
+impl ApiEventMetric for AddToBlocklistRequest {}
 impl ApiEventMetric for BlocklistResponse {}
 impl ApiEventMetric for ListBlocklistQuery {}
+impl ApiEventMetric for BlocklistExportQuery {}
+impl ApiEventMetric for BlocklistImportRequest {}
+impl ApiEventMetric for BlocklistImportResponse {}
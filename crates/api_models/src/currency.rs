@@ -7,14 +7,27 @@ pub struct CurrencyConversionParams {
     pub amount: i64,
     pub to_currency: String,
     pub from_currency: String,
+    /// Converts at the rates that applied on this date instead of the latest rates
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub transaction_date: Option<time::PrimitiveDateTime>,
+    /// Rounding applied to the converted amount, defaults to half-even
+    #[serde(default)]
+    pub rounding_mode: Option<crate::enums::RoundingMode>,
+    /// Markup quoted on top of the mid-market rate, in basis points
+    #[serde(default)]
+    pub markup_basis_points: Option<u32>,
 }
 
 /// Response to be send for convert currency route
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct CurrencyConversionResponse {
+    /// Converted amount in the minor unit of `currency`
     pub converted_amount: String,
     pub currency: String,
+    /// Date of the rates used, present when converting at a transaction date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_date: Option<String>,
 }
 
 impl ApiEventMetric for CurrencyConversionResponse {}
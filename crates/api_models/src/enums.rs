@@ -172,6 +172,19 @@ pub enum PayoutConnectors {
     Wise,
 }
 
+#[cfg(feature = "payouts")]
+impl PayoutConnectors {
+    /// Whether the connector can be polled for the status of a payout. Adyen's payout API has no
+    /// endpoint to look up a payout, its payouts are only updated through the connector's
+    /// payout notifications, so no status sync is scheduled for them
+    pub fn supports_payout_sync(&self) -> bool {
+        match self {
+            Self::Wise => true,
+            Self::Adyen => false,
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl From<PayoutConnectors> for RoutableConnectors {
     fn from(value: PayoutConnectors) -> Self {
@@ -11,6 +11,7 @@ pub mod refund;
 pub mod routing;
 pub mod user;
 pub mod user_role;
+pub mod webhook_events;
 
 use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
@@ -44,6 +45,7 @@ impl_misc_api_event_type!(
     RetrievePaymentLinkResponse,
     MandateListConstraints,
     CreateFileResponse,
+    FileDownloadLinkResponse,
     DisputeResponse,
     SubmitEvidenceRequest,
     MerchantConnectorResponse,
@@ -89,6 +91,9 @@ impl_misc_api_event_type!(
     PaymentFiltersResponse,
     GetRefundFilterRequest,
     RefundFiltersResponse,
+    GetDisputeMetricRequest,
+    GetDisputeFilterRequest,
+    DisputeFiltersResponse,
     GetSdkEventFiltersRequest,
     SdkEventFiltersResponse,
     ApiLogsRequest,
@@ -0,0 +1,29 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::webhook_events::{
+    EventListRequestInternal, EventResponse, WebhookDeliveryRetryRequestInternal,
+};
+
+impl ApiEventMetric for EventListRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id_or_profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for WebhookDeliveryRetryRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id_or_profile_id: self.event_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for EventResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Events {
+            merchant_id_or_profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -19,3 +19,12 @@ pub struct FileMetadataResponse {
     /// File availability
     pub available: bool,
 }
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct FileDownloadLinkResponse {
+    /// Link which downloads the file without authentication until it expires
+    pub url: String,
+    /// Time at which the link expires
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
@@ -15,6 +15,7 @@ pub struct GsmCreateRequest {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub error_category: Option<GsmErrorCategory>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -48,6 +49,93 @@ pub enum GsmDecision {
     DoDefault,
 }
 
+/// The category of the error of a failed payment attempt, which retry policies use to decide
+/// whether the payment is retried.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmErrorCategory {
+    /// Declines that may succeed on another attempt, such as insufficient funds
+    SoftDecline,
+    /// Declines that fail on every attempt, such as a stolen or expired card
+    HardDecline,
+    /// The connector or the issuer could not be reached
+    ProcessorDowntime,
+    /// Errors in the request sent to the connector or in the integration with it
+    TechnicalError,
+}
+
+/// Policy for retrying failed payments on the next eligible connector. A policy can be
+/// configured for a merchant, under the config key `retry_policy_{merchant_id}`, or for a
+/// business profile, under `retry_policy_{merchant_id}_{profile_id}`. The policy of the business
+/// profile takes precedence over the policy of the merchant.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts for a payment, including the first attempt
+    pub max_attempts: u16,
+    /// Error categories for which a failed payment is retried. If not set, a failed payment is
+    /// retried whenever the decision of the matching GSM rule is to retry
+    pub allowed_error_categories: Option<Vec<GsmErrorCategory>>,
+    /// Whether only errors categorized as soft declines are retried
+    #[serde(default)]
+    pub soft_declines_only: bool,
+    /// Time in milliseconds, since the attempt that failed first was created, after which no more
+    /// retries are made
+    pub latency_budget_ms: Option<u64>,
+}
+
+/// The decision taken after an attempt of a payment failed, which is stored on the attempt.
+#[derive(
+    Clone, Copy, Debug, strum::Display, PartialEq, Eq, serde::Serialize, strum::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RetryDecision {
+    Retry,
+    StepUp,
+    DoNotRetry,
+}
+
+/// The reason for a [`RetryDecision`], which is stored on the attempt.
+#[derive(
+    Clone, Copy, Debug, strum::Display, PartialEq, Eq, serde::Serialize, strum::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RetryReason {
+    /// The error allows stepping up to 3DS, and step up is enabled for the connector
+    StepUpPossible,
+    /// The decision of the matching GSM rule is to retry
+    GsmRetry,
+    /// The decision of the matching GSM rule is not to retry, or no GSM rule matched
+    GsmDoDefault,
+    /// The error category is one of the categories allowed by the retry policy
+    ErrorCategoryAllowed,
+    /// The error category isn't one of the categories allowed by the retry policy
+    ErrorCategoryNotAllowed,
+    /// The retry policy only allows soft declines to be retried
+    NotSoftDecline,
+    /// The payment has as many attempts as the retry policy allows
+    MaxAttemptsReached,
+    /// The time spent on the payment exceeds the latency budget of the retry policy
+    LatencyBudgetExceeded,
+    /// The merchant has no configured number of retries, or has used all of them
+    RetriesExhausted,
+    /// There are no more eligible connectors to retry the payment on
+    ConnectorsExhausted,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GsmUpdateRequest {
     pub connector: String,
@@ -61,6 +149,7 @@ pub struct GsmUpdateRequest {
     pub step_up_possible: Option<bool>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub error_category: Option<GsmErrorCategory>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -94,4 +183,5 @@ pub struct GsmResponse {
     pub step_up_possible: bool,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub error_category: Option<GsmErrorCategory>,
 }
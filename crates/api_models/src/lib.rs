@@ -30,9 +30,11 @@ pub mod pm_auth;
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_configs;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_events;
 pub mod webhooks;
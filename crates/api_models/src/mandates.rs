@@ -43,6 +43,10 @@ pub struct MandateResponse {
     /// Details about the customer’s acceptance
     #[schema(value_type = Option<CustomerAcceptance>)]
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
+    /// The amount which can still be debited from the mandate, absent when the mandate has no
+    /// amount limit
+    #[schema(example = 6540)]
+    pub remaining_amount: Option<i64>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone)]
@@ -610,7 +610,8 @@ pub struct SingleUseMandate {
 
 #[derive(Clone, Eq, PartialEq, Debug, Default, ToSchema, serde::Serialize, serde::Deserialize)]
 pub struct MandateAmountData {
-    /// The maximum amount to be debited for the mandate transaction
+    /// The maximum amount to be debited for the mandate transaction. For multi use mandates this
+    /// is the total amount which can be debited over all the transactions
     #[schema(example = 6540)]
     pub amount: i64,
     /// The currency for the transaction
@@ -629,6 +630,9 @@ pub struct MandateAmountData {
         "frequency": "DAILY"
     }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The maximum amount which can be debited in a single transaction of a multi use mandate
+    #[schema(example = 1000)]
+    pub max_transaction_amount: Option<i64>,
 }
 
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -3344,6 +3348,17 @@ pub mod amount {
     {
         deserializer.deserialize_option(OptionalAmountVisitor)
     }
+
+    /// Serializes the amount as the integer it is deserialized from
+    pub(crate) fn serialize_option<S>(
+        amount: &Option<Amount>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&amount.map(i64::from), serializer)
+    }
 }
 
 #[cfg(test)]
@@ -5,6 +5,7 @@ use common_utils::{
 };
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use crate::{admin, enums as api_enums, payments};
@@ -36,7 +37,11 @@ pub struct PayoutCreateRequest {
 
     /// The payout amount. Amount for the payout in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.,
     #[schema(value_type = i64, example = 1000)]
-    #[serde(default, deserialize_with = "payments::amount::deserialize_option")]
+    #[serde(
+        default,
+        deserialize_with = "payments::amount::deserialize_option",
+        serialize_with = "payments::amount::serialize_option"
+    )]
     pub amount: Option<payments::Amount>,
 
     /// The currency of the payout request can be specified here
@@ -406,6 +411,10 @@ pub struct PayoutRetrieveRequest {
     /// (defaults to false)
     #[schema(value_type = Option<bool>, default = false, example = true)]
     pub force_sync: Option<bool>,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: Option<String>,
 }
 
 #[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -420,3 +429,215 @@ pub struct PayoutActionRequest {
     )]
     pub payout_id: String,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutListConstraints {
+    /// The identifier for customer
+    #[schema(example = "cus_meowuwunwiuwiwqw")]
+    pub customer_id: Option<String>,
+
+    /// A cursor for use in pagination, fetch the next list after some object
+    #[schema(example = "payout_fafa124123")]
+    pub starting_after: Option<String>,
+
+    /// A cursor for use in pagination, fetch the previous list before some object
+    #[schema(example = "payout_fafa124123")]
+    pub ending_before: Option<String>,
+
+    /// limit on the number of objects to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+
+    /// The time at which payout is created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created: Option<PrimitiveDateTime>,
+
+    /// Time less than the payout created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lt"
+    )]
+    pub created_lt: Option<PrimitiveDateTime>,
+
+    /// Time greater than the payout created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.gt"
+    )]
+    pub created_gt: Option<PrimitiveDateTime>,
+
+    /// Time less than or equals to the payout created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lte"
+    )]
+    pub created_lte: Option<PrimitiveDateTime>,
+
+    /// Time greater than or equals to the payout created time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "created.gte")]
+    pub created_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PayoutListFilterConstraints {
+    /// The identifier for payout
+    pub payout_id: Option<String>,
+    /// The identifier for business profile
+    pub profile_id: Option<String>,
+    /// The identifier for customer
+    pub customer_id: Option<String>,
+    /// The limit on the number of objects. The default limit is 10 and max limit is 20
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+    /// The starting point within a list of objects
+    pub offset: Option<u32>,
+    /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
+    #[serde(flatten)]
+    pub time_range: Option<payments::TimeRange>,
+    /// The list of connectors to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutConnectors>>)]
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+    /// The list of currencies to filter payouts list
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currency: Option<Vec<api_enums::Currency>>,
+    /// The list of payout status to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutStatus>>)]
+    pub status: Option<Vec<api_enums::PayoutStatus>>,
+    /// The list of payout methods to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutType>>)]
+    pub payout_method: Option<Vec<api_enums::PayoutType>>,
+    /// The list of entity types to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutEntityType>>)]
+    pub entity_type: Option<Vec<api_enums::PayoutEntityType>>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list
+    pub size: usize,
+    /// The list of payouts response objects
+    pub data: Vec<PayoutCreateResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutListResponseV2 {
+    /// The number of payouts included in the list for given constraints
+    pub count: usize,
+    /// The total number of available payouts for given constraints
+    pub total_count: i64,
+    /// The list of payouts response objects
+    pub data: Vec<PayoutCreateResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutListFilters {
+    /// The list of available connector filters
+    pub connector: Vec<String>,
+    /// The list of available currency filters
+    #[schema(value_type = Vec<Currency>)]
+    pub currency: Vec<api_enums::Currency>,
+    /// The list of available payout status filters
+    #[schema(value_type = Vec<PayoutStatus>)]
+    pub status: Vec<api_enums::PayoutStatus>,
+    /// The list of available payout method filters
+    #[schema(value_type = Vec<PayoutType>)]
+    pub payout_method: Vec<api_enums::PayoutType>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The list of payouts to be created in the batch. Either this or `file_id` must be provided
+    pub payouts: Option<Vec<PayoutCreateRequest>>,
+
+    /// The identifier of a CSV file uploaded with the `payout_batch` purpose, containing one payout per row
+    #[schema(example = "file_3LWJ3dYPBd1dQT0mCGIW6Esc")]
+    pub file_id: Option<String>,
+
+    /// The business profile to be used for the payouts of the batch
+    pub profile_id: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct PayoutBatchRetrieveRequest {
+    /// Unique identifier for the payout batch
+    #[schema(example = "payout_batch_mbabizu24mvu3mela5njyhpit4")]
+    pub batch_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// Unique identifier for the payout batch
+    #[schema(example = "payout_batch_mbabizu24mvu3mela5njyhpit4")]
+    pub batch_id: String,
+
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The business profile used for the payouts of the batch
+    pub profile_id: Option<String>,
+
+    /// The aggregate status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// The number of payouts in the batch
+    pub total_count: i32,
+
+    /// The number of payouts which were created successfully
+    pub succeeded_count: i32,
+
+    /// The number of payouts which could not be created
+    pub failed_count: i32,
+
+    /// The identifier of the CSV file the batch was created from
+    pub file_id: Option<String>,
+
+    /// Time when the batch was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The per-item results of the batch, in the order they were submitted
+    pub items: Vec<PayoutBatchItemResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// The position of the item in the submitted batch, starting from 0
+    pub item_index: i32,
+
+    /// The identifier of the payout created for the item
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+
+    /// The processing status of the item
+    #[schema(value_type = PayoutBatchItemStatus, example = "succeeded")]
+    pub status: api_enums::PayoutBatchItemStatus,
+
+    /// The status of the payout created for the item
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+
+    /// The error code if the item could not be processed
+    pub error_code: Option<String>,
+
+    /// The error message if the item could not be processed
+    pub error_message: Option<String>,
+}
+
+fn default_limit() -> u32 {
+    10
+}
@@ -1,14 +1,19 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 
 use common_utils::errors::ParsingError;
 use error_stack::IntoReport;
 use euclid::{
     dssa::types::EuclidAnalysable,
     frontend::{
-        ast,
+        ast::{
+            self,
+            parser::{self as ast_parser, EuclidParsable, ParseResult},
+            printer::{EuclidPrintable, PrintError},
+        },
         dir::{DirKeyKind, EuclidDirFilter},
     },
 };
+use nom::{branch, bytes::complete, combinator, error, multi, sequence};
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{self, RoutableConnectors};
@@ -31,6 +36,141 @@ impl ConnectorSelection {
     }
 }
 
+/// The textual form of a connector selection is a list of connectors, optionally followed by a
+/// label in parentheses. A volume split has the percentage of each connector after a colon.
+///
+/// ```text
+/// [stripe, adyen("eu")]
+/// [stripe: 70%, adyen("eu"): 30%]
+/// ```
+impl EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        // An empty list is parsed as a priority list
+        let volume_split = combinator::map(
+            combinator::verify(
+                connector_list(combinator::map(
+                    sequence::pair(
+                        routable_connector_choice,
+                        sequence::preceded(
+                            ast_parser::skip_ws(complete::tag(":")),
+                            ast_parser::skip_ws(split_percentage),
+                        ),
+                    ),
+                    |(connector, split)| ConnectorVolumeSplit { connector, split },
+                )),
+                |splits: &Vec<ConnectorVolumeSplit>| !splits.is_empty(),
+            ),
+            Self::VolumeSplit,
+        );
+        let priority = combinator::map(connector_list(routable_connector_choice), Self::Priority);
+
+        error::context("connector_selection", branch::alt((volume_split, priority)))(input)
+    }
+}
+
+impl EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> Result<String, PrintError> {
+        let connectors = match self {
+            Self::Priority(connectors) => connectors
+                .iter()
+                .map(print_routable_connector_choice)
+                .collect::<Result<Vec<_>, _>>()?,
+            Self::VolumeSplit(splits) if splits.is_empty() => {
+                Err(PrintError::Empty("volume split"))?
+            }
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| {
+                    print_routable_connector_choice(&split.connector)
+                        .map(|connector| format!("{connector}: {}%", split.split))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+
+        Ok(format!("[{}]", connectors.join(", ")))
+    }
+}
+
+fn connector_list<'a, O>(
+    element: impl FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
+) -> impl FnMut(&'a str) -> ParseResult<&'a str, Vec<O>> {
+    sequence::delimited(
+        ast_parser::skip_ws(complete::tag("[")),
+        multi::separated_list0(
+            ast_parser::skip_ws(complete::tag(",")),
+            ast_parser::skip_ws(element),
+        ),
+        ast_parser::skip_ws(complete::tag("]")),
+    )
+}
+
+fn split_percentage(input: &str) -> ParseResult<&str, u8> {
+    error::context(
+        "volume_split_percentage",
+        combinator::verify(
+            sequence::terminated(
+                combinator::map_res(
+                    complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
+                    u8::from_str,
+                ),
+                complete::tag("%"),
+            ),
+            |split: &u8| *split <= 100,
+        ),
+    )(input)
+}
+
+fn routable_connector_choice(input: &str) -> ParseResult<&str, RoutableConnectorChoice> {
+    let connector = combinator::map_res(
+        complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+        RoutableConnectors::from_str,
+    );
+    let label = sequence::delimited(
+        ast_parser::skip_ws(complete::tag("(")),
+        ast_parser::skip_ws(sequence::delimited(
+            complete::tag("\""),
+            complete::take_while(|c: char| c != '"'),
+            complete::tag("\""),
+        )),
+        ast_parser::skip_ws(complete::tag(")")),
+    );
+
+    error::context(
+        "connector",
+        combinator::map(
+            sequence::pair(connector, combinator::opt(label)),
+            |(connector, label): (RoutableConnectors, Option<&str>)| RoutableConnectorChoice {
+                #[cfg(feature = "connector_choice_bcompat")]
+                choice_kind: if label.is_some() {
+                    RoutableChoiceKind::FullStruct
+                } else {
+                    RoutableChoiceKind::OnlyConnector
+                },
+                connector,
+                #[cfg(feature = "connector_choice_mca_id")]
+                merchant_connector_id: label.map(str::to_string),
+                #[cfg(not(feature = "connector_choice_mca_id"))]
+                sub_label: label.map(str::to_string),
+            },
+        ),
+    )(input)
+}
+
+fn print_routable_connector_choice(choice: &RoutableConnectorChoice) -> Result<String, PrintError> {
+    #[cfg(feature = "connector_choice_mca_id")]
+    let label = choice.merchant_connector_id.as_ref();
+    #[cfg(not(feature = "connector_choice_mca_id"))]
+    let label = choice.sub_label.as_ref();
+
+    match label {
+        Some(label) if label.contains('"') => Err(PrintError::InvalidOutput(format!(
+            "connector label '{label}' contains a double quote"
+        ))),
+        Some(label) => Ok(format!("{}(\"{label}\")", choice.connector)),
+        None => Ok(choice.connector.to_string()),
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RoutingConfigRequest {
     pub name: Option<String>,
@@ -113,6 +253,9 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::VoucherType,
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
+        DirKeyKind::TransactionHour,
+        DirKeyKind::TransactionWeekday,
+        DirKeyKind::TransactionDate,
     ];
 }
 
@@ -159,6 +302,72 @@ pub struct ConnectorVolumeSplit {
     pub split: u8,
 }
 
+/// Routes payments to the connector with the highest authorization success rate in a recent
+/// window, among payments with the same payment method, card network and currency.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SuccessRateRoutingConfig {
+    /// Connectors to rank, in the order in which they are tried when their success rates are not
+    /// known yet
+    pub connectors: Vec<RoutableConnectorChoice>,
+    /// Duration (in seconds) of the sliding window over which success rates are computed
+    #[serde(default = "SuccessRateRoutingConfig::default_window_in_seconds")]
+    pub window_in_seconds: u32,
+    /// Minimum number of payments a connector must have processed in the window for its success
+    /// rate to be considered
+    #[serde(default = "SuccessRateRoutingConfig::default_min_volume")]
+    pub min_volume: u32,
+    /// Percentage of payments routed to a randomly chosen connector instead of the connector with
+    /// the highest success rate, so that a connector that has recovered receives traffic again
+    #[serde(default = "SuccessRateRoutingConfig::default_exploration_percent")]
+    pub exploration_percent: u8,
+}
+
+impl SuccessRateRoutingConfig {
+    /// Duration (in seconds) of the buckets in which success rate counters are maintained
+    pub const BUCKET_SIZE_IN_SECONDS: u32 = 300;
+    /// Maximum duration (in seconds) of the window over which success rates can be computed
+    pub const MAX_WINDOW_IN_SECONDS: u32 = 86400;
+
+    fn default_window_in_seconds() -> u32 {
+        3600
+    }
+
+    fn default_min_volume() -> u32 {
+        10
+    }
+
+    fn default_exploration_percent() -> u8 {
+        5
+    }
+
+    fn validate(&self) -> Result<(), error_stack::Report<ParsingError>> {
+        if self.connectors.is_empty() {
+            Err(ParsingError::StructParseFailure(
+                "Connectors list can't be empty for Success rate Algorithm",
+            ))
+            .into_report()?
+        }
+
+        if !(Self::BUCKET_SIZE_IN_SECONDS..=Self::MAX_WINDOW_IN_SECONDS)
+            .contains(&self.window_in_seconds)
+        {
+            Err(ParsingError::StructParseFailure(
+                "Window for Success rate Algorithm must be between 300 and 86400 seconds",
+            ))
+            .into_report()?
+        }
+
+        if self.exploration_percent > 100 {
+            Err(ParsingError::StructParseFailure(
+                "Exploration percent for Success rate Algorithm can't be greater than 100",
+            ))
+            .into_report()?
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "connector_choice_bcompat")]
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub enum RoutableChoiceKind {
@@ -330,6 +539,7 @@ pub enum RoutingAlgorithmKind {
     Priority,
     VolumeSplit,
     Advanced,
+    SuccessRate,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -351,6 +561,7 @@ pub enum RoutingAlgorithm {
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(euclid::frontend::ast::Program<ConnectorSelection>),
+    SuccessRate(SuccessRateRoutingConfig),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -360,6 +571,9 @@ pub enum RoutingAlgorithmSerde {
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(euclid::frontend::ast::Program<ConnectorSelection>),
+    /// The textual form of an advanced program
+    AdvancedText(String),
+    SuccessRate(SuccessRateRoutingConfig),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
@@ -379,6 +593,7 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
                 ))
                 .into_report()?
             }
+            RoutingAlgorithmSerde::SuccessRate(config) => config.validate()?,
             _ => {}
         };
         Ok(match value {
@@ -386,6 +601,17 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
             RoutingAlgorithmSerde::Priority(i) => Self::Priority(i),
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
+            RoutingAlgorithmSerde::AdvancedText(text) => Self::Advanced(
+                ast_parser::parse_program(&text)
+                    .map_err(|error| ParsingError::SyntaxError {
+                        name: "routing program",
+                        line: error.line,
+                        column: error.column,
+                        message: error.message,
+                    })
+                    .into_report()?,
+            ),
+            RoutingAlgorithmSerde::SuccessRate(i) => Self::SuccessRate(i),
         })
     }
 }
@@ -487,6 +713,7 @@ impl RoutingAlgorithm {
             Self::Priority(_) => RoutingAlgorithmKind::Priority,
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
+            Self::SuccessRate(_) => RoutingAlgorithmKind::SuccessRate,
         }
     }
 }
@@ -547,3 +774,127 @@ pub enum RoutingKind {
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(transparent)]
 pub struct RoutingAlgorithmId(pub String);
+
+/// The form in which a routing algorithm is returned. Only advanced algorithms have a textual
+/// form, which is accepted back as an `advanced_text` algorithm.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingAlgorithmFormat {
+    #[default]
+    Json,
+    Text,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct RoutingRetrieveFormatQuery {
+    #[serde(default)]
+    pub format: RoutingAlgorithmFormat,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingRetrieveConfigRequest {
+    pub algorithm_id: RoutingAlgorithmId,
+    pub format: RoutingAlgorithmFormat,
+}
+
+/// Maximum number of historical payments that a single routing simulation replays.
+pub const ROUTING_SIMULATION_MAX_LIMIT: u32 = 10000;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingSimulationRequest {
+    /// The candidate algorithm, which must be either a single connector, a priority list, a
+    /// volume split or an advanced program
+    pub algorithm: RoutingAlgorithm,
+    /// The time range of the payments to replay through the algorithm
+    pub time_range: crate::payments::TimeRange,
+    pub profile_id: Option<String>,
+    /// The maximum number of payments to replay, starting from the most recent ones
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingSimulationResponse {
+    /// Number of payments in the time range that were replayed
+    pub total_payments: usize,
+    /// Number of payments for which the algorithm selected a different connector than the one
+    /// that actually processed the payment
+    pub changed_payments: usize,
+    /// Number of payments that could not be replayed, because they were never routed to a
+    /// connector or their stored inputs could not be evaluated
+    pub skipped_payments: usize,
+    pub connectors: Vec<RoutingSimulationConnectorDistribution>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RoutingSimulationConnectorDistribution {
+    pub connector: String,
+    /// Number of payments that were actually processed by the connector
+    pub actual_count: usize,
+    /// Number of payments that the algorithm would have routed to the connector
+    pub simulated_count: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingCoverageRequest {
+    /// The candidate algorithm, which must be an advanced program
+    pub algorithm: RoutingAlgorithm,
+    pub profile_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingCoverageResponse {
+    pub profile_id: Option<String>,
+    /// Rules that can never be selected, rules shadowed by earlier rules, and values of the
+    /// payment that fall through to the default selection
+    #[serde(flatten)]
+    pub coverage: euclid::dssa::types::CoverageReport,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::panic)]
+
+    use euclid::frontend::ast::printer;
+
+    use super::*;
+
+    #[test]
+    fn test_advanced_text_round_trip() {
+        let program_str = r#"default: [stripe, adyen("eu")]
+
+rule_1: [stripe: 70%, adyen("eu"): 30%] {
+    payment_method = card & amount > 100
+}
+
+rule_2: [checkout] {
+    currency = (USD, EUR)
+}
+"#;
+
+        let algorithm: RoutingAlgorithm = serde_json::from_value(serde_json::json!({
+            "type": "advanced_text",
+            "data": program_str,
+        }))
+        .expect("Routing algorithm");
+
+        let RoutingAlgorithm::Advanced(program) = algorithm else {
+            panic!("Expected an advanced routing algorithm");
+        };
+        assert_eq!(
+            printer::print_program(&program).expect("Printed program"),
+            program_str
+        );
+    }
+
+    #[test]
+    fn test_advanced_text_parse_error() {
+        let error = serde_json::from_value::<RoutingAlgorithm>(serde_json::json!({
+            "type": "advanced_text",
+            "data": "default: [stripe]\n\nrule_1: [unknown_connector] {\n    amount > 100\n}\n",
+        }))
+        .err()
+        .expect("Parse error");
+
+        assert!(error.to_string().contains("line 3"));
+    }
+}
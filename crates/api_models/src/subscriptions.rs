@@ -0,0 +1,119 @@
+use common_utils::{events::ApiEventMetric, pii};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The customer who is billed, the mandate should belong to this customer
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The multi use mandate which is charged off-session at every billing cycle
+    #[schema(max_length = 64, example = "man_Q9JJ2EtBGcMUhfiOYUqy")]
+    pub mandate_id: String,
+    /// The amount charged at every billing cycle, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+    /// Number of intervals between two billing cycles, e.g. an `interval` of `month` with an
+    /// `interval_count` of 3 bills the customer every quarter
+    #[schema(minimum = 1, example = 1)]
+    #[serde(default = "default_interval_count")]
+    pub interval_count: u16,
+    /// The date from which the billing cycles are counted, defaults to the creation time.
+    /// Monthly and yearly cycles which fall on a day the month does not have are billed on the
+    /// last day of that month
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2024-02-01T00:00:00Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub anchor_date: Option<PrimitiveDateTime>,
+    /// Number of days after the anchor date before the first billing cycle is charged
+    #[schema(example = 14)]
+    #[serde(default)]
+    pub trial_days: u16,
+    #[serde(default)]
+    pub end_condition: SubscriptionEndCondition,
+    /// The business profile the renewal payments are made under
+    pub profile_id: Option<String>,
+    /// Description of the subscription, used as the description of the renewal payments
+    #[schema(max_length = 255, example = "Premium plan")]
+    pub description: Option<String>,
+    #[schema(value_type = Option<Object>, example = r#"{ "plan": "premium" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+fn default_interval_count() -> u16 {
+    1
+}
+
+/// When a subscription stops billing the customer
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum SubscriptionEndCondition {
+    /// The subscription is billed until it is cancelled
+    #[default]
+    Never,
+    /// The subscription completes after the given number of paid billing cycles
+    AfterCycles { cycles: u32 },
+    /// The subscription completes once the next billing cycle would fall after this date
+    OnDate {
+        #[schema(value_type = PrimitiveDateTime, example = "2025-01-31T23:59:59Z")]
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        date: PrimitiveDateTime,
+    },
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SubscriptionResponse {
+    #[schema(example = "sub_pNhJ4pu6UZCNe8KvdFQv")]
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: api_enums::SubscriptionStatus,
+    pub amount: i64,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    #[schema(value_type = SubscriptionInterval, example = "month")]
+    pub interval: api_enums::SubscriptionInterval,
+    pub interval_count: u16,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub anchor_date: PrimitiveDateTime,
+    pub trial_days: u16,
+    pub end_condition: SubscriptionEndCondition,
+    /// Number of billing cycles which were paid
+    pub completed_cycles: u32,
+    /// Number of failed payment attempts of the current billing cycle
+    pub failed_attempts: u32,
+    /// When the next payment is attempted, absent once the subscription has ended
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    /// The payment made for the latest billing cycle attempt
+    pub last_payment_id: Option<String>,
+    pub description: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    #[schema(value_type = PrimitiveDateTime)]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionId {
+    pub subscription_id: String,
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {}
+impl ApiEventMetric for SubscriptionResponse {}
+impl ApiEventMetric for SubscriptionId {}
@@ -0,0 +1,108 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::webhooks;
+
+/// The constraints to apply when listing the events delivered to a business profile.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// Filter events created after the specified time.
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter events created before the specified time.
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of events to include in the response.
+    pub limit: Option<i64>,
+
+    /// The number of events to skip when retrieving the list of events.
+    pub offset: Option<i64>,
+}
+
+/// A single delivery attempt of an outgoing webhook, including the request sent and the response
+/// received from the merchant.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventResponse {
+    /// The identifier for the event (delivery attempt).
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The identifier for the merchant account.
+    #[schema(example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the business profile.
+    #[schema(example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The identifier for the object (Payment Intent ID, Refund ID, etc.)
+    #[schema(example = "QHrfd5LUDdZaKtAjdJmMu0dMa1")]
+    pub object_id: String,
+
+    /// Specifies the type of event, which includes the object and its status.
+    #[schema(value_type = EventType)]
+    pub event_type: common_enums::EventType,
+
+    /// Specifies the class of event (the type of object: Payment, Refund, etc.)
+    #[schema(value_type = EventClass)]
+    pub event_class: common_enums::EventClass,
+
+    /// Indicates whether the merchant acknowledged receipt of the webhook.
+    pub is_delivery_successful: bool,
+
+    /// The identifier of the initial delivery attempt. This will be the same as `event_id` for
+    /// the initial delivery attempt.
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub initial_attempt_id: String,
+
+    /// Specifies whether the attempt was the initial attempt, an automatic retry or a manual
+    /// retry.
+    #[schema(value_type = Option<WebhookDeliveryAttempt>)]
+    pub delivery_attempt: Option<common_enums::WebhookDeliveryAttempt>,
+
+    /// Time at which the event was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The request information (headers and body) sent in the webhook.
+    pub request: Option<webhooks::OutgoingWebhookRequestContent>,
+
+    /// The response information (status code, body and latency) received for the webhook.
+    pub response: Option<OutgoingWebhookResponseContent>,
+}
+
+/// The response information received for the webhook.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct OutgoingWebhookResponseContent {
+    /// The HTTP status code returned by the merchant, if a response was received.
+    #[schema(value_type = Option<u16>, example = 200)]
+    pub status_code: Option<u16>,
+
+    /// The response body received from the merchant, truncated to the first 1024 characters.
+    pub body: Option<String>,
+
+    /// The time taken by the merchant to respond, in milliseconds.
+    #[schema(example = 120)]
+    pub latency_ms: Option<i64>,
+}
+
+/// The path parameters for listing the events delivered to a business profile.
+#[derive(Debug, Serialize)]
+pub struct EventListRequestInternal {
+    pub profile_id: String,
+    pub constraints: EventListConstraints,
+}
+
+/// The path parameters for redelivering an event.
+#[derive(Debug, Serialize)]
+pub struct WebhookDeliveryRetryRequestInternal {
+    pub event_id: String,
+}
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+#[cfg(feature = "payouts")]
+use crate::payouts;
+use crate::{disputes, enums as api_enums, mandates, payments, refunds, subscriptions};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -177,6 +179,26 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse)]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutCreateResponse)]
+    PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = SubscriptionResponse)]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
+}
+
+/// The signed request sent to the merchant for an outgoing webhook
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OutgoingWebhookRequestContent {
+    /// The serialized webhook payload, exactly as it was signed
+    #[schema(value_type = String)]
+    pub body: masking::Secret<String>,
+
+    /// The headers sent along with the payload, including the webhook signature
+    #[schema(
+        value_type = Vec<(String, String)>,
+        example = json!([["content-type", "application/json"]])
+    )]
+    pub headers: Vec<(String, masking::Secret<String>)>,
 }
 
 #[derive(Debug, Clone, Serialize)]
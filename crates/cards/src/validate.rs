@@ -93,6 +93,10 @@ where
     fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let val_str: &str = val.as_ref();
 
+        if let Some(result) = masking::apply_masking_policy("card_number", val_str, f) {
+            return result;
+        }
+
         if val_str.len() < 15 || val_str.len() > 19 {
             return WithType::fmt(val, f);
         }
@@ -9,10 +9,10 @@ pub mod diesel_exports {
         DbBlocklistDataKind as BlocklistDataKind, DbCaptureMethod as CaptureMethod,
         DbCaptureStatus as CaptureStatus, DbConnectorType as ConnectorType,
         DbCountryAlpha2 as CountryAlpha2, DbCurrency as Currency, DbDisputeStage as DisputeStage,
-        DbDisputeStatus as DisputeStatus, DbEventType as EventType, DbFutureUsage as FutureUsage,
-        DbIntentStatus as IntentStatus, DbMandateStatus as MandateStatus,
-        DbPaymentMethodIssuerCode as PaymentMethodIssuerCode, DbPaymentType as PaymentType,
-        DbRefundStatus as RefundStatus,
+        DbDisputeStatus as DisputeStatus, DbEventClass as EventClass, DbEventType as EventType,
+        DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
+        DbMandateStatus as MandateStatus, DbPaymentMethodIssuerCode as PaymentMethodIssuerCode,
+        DbPaymentType as PaymentType, DbRefundStatus as RefundStatus,
         DbRequestIncrementalAuthorization as RequestIncrementalAuthorization,
     };
 }
@@ -295,6 +295,12 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
+    EmailDomain,
+    IpAddress,
+    IpRange,
+    PhoneNumber,
+    CardBinRange,
 }
 
 #[derive(
@@ -963,6 +969,61 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    PayoutSuccess,
+    PayoutFailed,
+    PayoutProcessing,
+    PayoutReversed,
+    SubscriptionRenewed,
+    SubscriptionRenewalFailed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventClass {
+    Payments,
+    Refunds,
+    Disputes,
+    Mandates,
+    Payouts,
+    Subscriptions,
+}
+
+/// Describes how an outgoing webhook delivery attempt was triggered
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryAttempt {
+    /// The first attempt made right after the event was created
+    InitialAttempt,
+    /// A retry scheduled by the outgoing webhook retry workflow
+    AutomaticRetry,
+    /// A redelivery explicitly requested by the merchant
+    ManualRetry,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -1882,6 +1943,118 @@ pub enum PayoutStatus {
     RequiresCreation,
     RequiresPayoutMethodData,
     RequiresFulfillment,
+    Reversed,
+}
+
+/// The aggregate status of a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch has been accepted and is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// Items of the batch are being processed
+    Processing,
+    /// All the items of the batch were processed successfully
+    Completed,
+    /// Some of the items of the batch failed
+    PartiallyCompleted,
+    /// All the items of the batch failed, or the batch could not be processed after retrying
+    Failed,
+}
+
+/// The status of a single item in a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The item is waiting to be processed
+    #[default]
+    Pending,
+    /// A payout was created for the item
+    Succeeded,
+    /// The item failed validation or the payout could not be created
+    Failed,
+}
+
+/// The status of a subscription
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is in its trial period and has not been charged yet
+    Trialing,
+    /// The last billing cycle of the subscription was paid
+    Active,
+    /// The payment of the current billing cycle failed and is being retried
+    PastDue,
+    /// The subscription was cancelled by the merchant or after all the retries of a billing
+    /// cycle failed
+    Cancelled,
+    /// The end condition of the subscription was reached
+    Completed,
+}
+
+/// The unit of the interval at which a subscription is billed
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionInterval {
+    Day,
+    Week,
+    Month,
+    Year,
 }
 
 #[derive(
@@ -2056,3 +2229,32 @@ pub enum ConnectorStatus {
     Inactive,
     Active,
 }
+
+/// Rounding applied when a converted amount is brought down to the minor unit of a currency
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round to the nearest value, ties go to the even neighbour (banker's rounding)
+    #[default]
+    HalfEven,
+    /// Round to the nearest value, ties go away from zero
+    HalfUp,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceiling,
+}
@@ -24,6 +24,11 @@ pub const PAYMENTS_LIST_MAX_LIMIT_V1: u32 = 100;
 /// Maximum limit for payments list post api with filters
 pub const PAYMENTS_LIST_MAX_LIMIT_V2: u32 = 20;
 
+/// Maximum limit for payouts list get api
+pub const PAYOUTS_LIST_MAX_LIMIT_V1: u32 = 100;
+/// Maximum limit for payouts list post api with filters
+pub const PAYOUTS_LIST_MAX_LIMIT_V2: u32 = 20;
+
 /// Maximum limit for payment link list get api
 pub const PAYMENTS_LINK_LIST_LIMIT: u32 = 100;
 
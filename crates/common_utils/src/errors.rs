@@ -32,6 +32,18 @@ pub enum ParsingError {
     /// Failed to parse phone number
     #[error("Failed to parse phone number")]
     PhoneNumberParsingError,
+    /// Failed to parse the textual form of a program
+    #[error("Failed to parse {name} at line {line}, column {column}: {message}")]
+    SyntaxError {
+        /// Name of the program being parsed
+        name: &'static str,
+        /// Line at which the error was encountered, counted from 1
+        line: usize,
+        /// Column at which the error was encountered, counted from 1
+        column: usize,
+        /// Description of the error
+        message: String,
+    },
 }
 
 /// Validation errors.
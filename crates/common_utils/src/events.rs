@@ -40,6 +40,9 @@ pub enum ApiEventsType {
     },
     Routing,
     ResourceListAPI,
+    Events {
+        merchant_id_or_profile_id: String,
+    },
     PaymentRedirectionResponse {
         connector: Option<String>,
         payment_id: Option<String>,
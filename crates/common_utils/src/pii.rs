@@ -42,6 +42,9 @@ where
 {
     fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let val_str: &str = val.as_ref();
+        if let Some(result) = masking::apply_masking_policy("phone_number", val_str, f) {
+            return result;
+        }
 
         if let Some(val_str) = val_str.get(val_str.len() - 4..) {
             // masks everything but the last 4 digits
@@ -210,6 +213,9 @@ where
 {
     fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let val_str: &str = val.as_ref();
+        if let Some(result) = masking::apply_masking_policy("email", val_str, f) {
+            return result;
+        }
         match val_str.split_once('@') {
             Some((a, b)) => write!(f, "{}@{}", "*".repeat(a.len()), b),
             None => WithType::fmt(val, f),
@@ -320,6 +326,9 @@ where
 {
     fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let val_str: &str = val.as_ref();
+        if let Some(result) = masking::apply_masking_policy("ip_address", val_str, f) {
+            return result;
+        }
         let segments: Vec<&str> = val_str.split('.').collect();
 
         if segments.len() != 4 {
@@ -353,6 +362,9 @@ where
 {
     fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let vpa_str: &str = val.as_ref();
+        if let Some(result) = masking::apply_masking_policy("upi_vpa", vpa_str, f) {
+            return result;
+        }
         if let Some((user_identifier, bank_or_psp)) = vpa_str.split_once('@') {
             let masked_user_identifier = "*".repeat(user_identifier.len());
             write!(f, "{masked_user_identifier}@{bank_or_psp}")
@@ -1,37 +1,85 @@
 use common_enums::Currency;
-use rust_decimal::Decimal;
-use rusty_money::Money;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 
 use crate::{
     error::CurrencyConversionError,
-    types::{currency_match, ExchangeRates},
+    types::{currency_exponent, rounding_strategy, ConversionOptions, ExchangeRates},
 };
 
+/// Converts `amount`, given in the minor unit of `from_currency`, to the minor unit of
+/// `to_currency`, applying the markup and rounding mode from `options`.
 pub fn convert(
     ex_rates: &ExchangeRates,
     from_currency: Currency,
     to_currency: Currency,
     amount: i64,
+    options: &ConversionOptions,
+) -> Result<i64, CurrencyConversionError> {
+    let converted = convert_major_units(ex_rates, from_currency, to_currency, amount)?;
+    let quoted = apply_markup(converted, options.markup)?;
+    to_minor_units(quoted, to_currency, options)
+}
+
+/// Converts `amount`, given in the minor unit of `from_currency`, to an unrounded amount in the
+/// major unit of `to_currency`.
+pub fn convert_major_units(
+    ex_rates: &ExchangeRates,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
 ) -> Result<Decimal, CurrencyConversionError> {
-    let money_minor = Money::from_minor(amount, currency_match(from_currency));
+    let amount = Decimal::new(amount, currency_exponent(from_currency));
     let base_currency = ex_rates.base_currency;
     if to_currency == base_currency {
-        ex_rates.forward_conversion(*money_minor.amount(), from_currency)
+        ex_rates.forward_conversion(amount, from_currency)
     } else if from_currency == base_currency {
-        ex_rates.backward_conversion(*money_minor.amount(), to_currency)
+        ex_rates.backward_conversion(amount, to_currency)
     } else {
-        let base_conversion_amt =
-            ex_rates.forward_conversion(*money_minor.amount(), from_currency)?;
+        let base_conversion_amt = ex_rates.forward_conversion(amount, from_currency)?;
         ex_rates.backward_conversion(base_conversion_amt, to_currency)
     }
 }
 
+fn apply_markup(
+    amount: Decimal,
+    markup: Option<Decimal>,
+) -> Result<Decimal, CurrencyConversionError> {
+    match markup {
+        None => Ok(amount),
+        Some(markup) if markup < Decimal::ZERO => {
+            Err(CurrencyConversionError::InvalidMarkup(markup.to_string()))
+        }
+        Some(markup) => Decimal::ONE
+            .checked_add(markup)
+            .and_then(|factor| amount.checked_mul(factor))
+            .ok_or(CurrencyConversionError::DecimalMultiplicationFailed),
+    }
+}
+
+fn to_minor_units(
+    amount: Decimal,
+    currency: Currency,
+    options: &ConversionOptions,
+) -> Result<i64, CurrencyConversionError> {
+    let scale = 10_i64
+        .checked_pow(currency_exponent(currency))
+        .ok_or(CurrencyConversionError::AmountOutOfRange)?;
+    amount
+        .checked_mul(Decimal::from(scale))
+        .ok_or(CurrencyConversionError::DecimalMultiplicationFailed)?
+        .round_dp_with_strategy(0, rounding_strategy(options.rounding_mode))
+        .to_i64()
+        .ok_or(CurrencyConversionError::AmountOutOfRange)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
     use std::collections::HashMap;
 
-    use crate::types::CurrencyFactors;
+    use common_enums::Currency;
+
+    use crate::types::{CurrencyFactors, ExchangeRates};
     #[test]
     fn currency_to_currency_conversion() {
         use super::*;
@@ -47,8 +95,8 @@ mod tests {
         conversion.insert(convert_from, inr_conversion_rates);
         conversion.insert(convert_to, szl_conversion_rates);
         let sample_rate = ExchangeRates::new(base_currency, conversion);
-        let res =
-            convert(&sample_rate, convert_from, convert_to, amount).expect("converted_currency");
+        let res = convert_major_units(&sample_rate, convert_from, convert_to, amount)
+            .expect("converted_currency");
         println!(
             "The conversion from {} {} to {} is {:?}",
             amount, convert_from, convert_to, res
@@ -69,8 +117,8 @@ mod tests {
         conversion.insert(convert_from, inr_conversion_rates);
         conversion.insert(convert_to, usd_conversion_rates);
         let sample_rate = ExchangeRates::new(base_currency, conversion);
-        let res =
-            convert(&sample_rate, convert_from, convert_to, amount).expect("converted_currency");
+        let res = convert_major_units(&sample_rate, convert_from, convert_to, amount)
+            .expect("converted_currency");
         println!(
             "The conversion from {} {} to {} is {:?}",
             amount, convert_from, convert_to, res
@@ -91,11 +139,166 @@ mod tests {
         conversion.insert(convert_from, usd_conversion_rates);
         conversion.insert(convert_to, inr_conversion_rates);
         let sample_rate = ExchangeRates::new(base_currency, conversion);
-        let res =
-            convert(&sample_rate, convert_from, convert_to, amount).expect("converted_currency");
+        let res = convert_major_units(&sample_rate, convert_from, convert_to, amount)
+            .expect("converted_currency");
         println!(
             "The conversion from {} {} to {} is {:?}",
             amount, convert_from, convert_to, res
         );
     }
+
+    fn usd_rates(factors: Vec<(Currency, CurrencyFactors)>) -> ExchangeRates {
+        ExchangeRates::new(Currency::USD, factors.into_iter().collect())
+    }
+
+    #[test]
+    fn conversion_rounds_to_minor_units() {
+        use super::*;
+        use common_enums::RoundingMode;
+        // 1 USD = 0.925 EUR, so 1.01 USD = 0.93425 EUR = 93.425 EUR minor units
+        let rates = usd_rates(vec![
+            (
+                Currency::USD,
+                CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+            ),
+            (
+                Currency::EUR,
+                CurrencyFactors::new(Decimal::new(925, 3), Decimal::ONE),
+            ),
+        ]);
+        let expectations = [
+            (RoundingMode::HalfEven, 93),
+            (RoundingMode::HalfUp, 93),
+            (RoundingMode::Floor, 93),
+            (RoundingMode::Ceiling, 94),
+        ];
+        for (rounding_mode, expected) in expectations {
+            let options = ConversionOptions::new(rounding_mode, None);
+            let res = convert(&rates, Currency::USD, Currency::EUR, 101, &options)
+                .expect("converted_currency");
+            assert_eq!(res, expected, "rounding mode {rounding_mode}");
+        }
+    }
+
+    #[test]
+    fn conversion_breaks_ties_by_rounding_mode() {
+        use super::*;
+        use common_enums::RoundingMode;
+        // 1 USD = 0.5 EUR, so 0.05 USD = 2.5 EUR minor units
+        let rates = usd_rates(vec![
+            (
+                Currency::USD,
+                CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+            ),
+            (
+                Currency::EUR,
+                CurrencyFactors::new(Decimal::new(5, 1), Decimal::new(2, 0)),
+            ),
+        ]);
+        let half_even = convert(
+            &rates,
+            Currency::USD,
+            Currency::EUR,
+            5,
+            &ConversionOptions::new(RoundingMode::HalfEven, None),
+        )
+        .expect("converted_currency");
+        let half_up = convert(
+            &rates,
+            Currency::USD,
+            Currency::EUR,
+            5,
+            &ConversionOptions::new(RoundingMode::HalfUp, None),
+        )
+        .expect("converted_currency");
+        assert_eq!(half_even, 2);
+        assert_eq!(half_up, 3);
+    }
+
+    #[test]
+    fn conversion_uses_iso_exponents() {
+        use super::*;
+        let rates = usd_rates(vec![
+            (
+                Currency::USD,
+                CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+            ),
+            (
+                Currency::JPY,
+                CurrencyFactors::new(
+                    Decimal::new(150, 0),
+                    Decimal::new(1, 0) / Decimal::new(150, 0),
+                ),
+            ),
+            (
+                Currency::KWD,
+                CurrencyFactors::new(
+                    Decimal::new(3075, 4),
+                    Decimal::new(1, 0) / Decimal::new(3075, 4),
+                ),
+            ),
+        ]);
+        let options = ConversionOptions::default();
+        // 10.00 USD -> 1500 JPY (zero decimal)
+        let jpy = convert(&rates, Currency::USD, Currency::JPY, 1000, &options)
+            .expect("converted_currency");
+        assert_eq!(jpy, 1500);
+        // 10.00 USD -> 3.075 KWD (three decimal)
+        let kwd = convert(&rates, Currency::USD, Currency::KWD, 1000, &options)
+            .expect("converted_currency");
+        assert_eq!(kwd, 3075);
+    }
+
+    #[test]
+    fn conversion_round_trips_through_minor_units() {
+        use super::*;
+        // MGA has an ISO 4217 exponent of 2 while being a zero decimal currency for connectors,
+        // the amount has to be read and written with the same exponent
+        let rates = usd_rates(vec![
+            (
+                Currency::USD,
+                CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+            ),
+            (
+                Currency::MGA,
+                CurrencyFactors::new(
+                    Decimal::new(4500, 0),
+                    Decimal::new(1, 0) / Decimal::new(4500, 0),
+                ),
+            ),
+        ]);
+        let options = ConversionOptions::default();
+        let mga = convert(&rates, Currency::USD, Currency::MGA, 1000, &options)
+            .expect("converted_currency");
+        let usd = convert(&rates, Currency::MGA, Currency::USD, mga, &options)
+            .expect("converted_currency");
+        assert_eq!(usd, 1000);
+    }
+
+    #[test]
+    fn conversion_applies_markup() {
+        use super::*;
+        use common_enums::RoundingMode;
+        let rates = usd_rates(vec![
+            (
+                Currency::USD,
+                CurrencyFactors::new(Decimal::ONE, Decimal::ONE),
+            ),
+            (
+                Currency::EUR,
+                CurrencyFactors::new(Decimal::new(9, 1), Decimal::ONE),
+            ),
+        ]);
+        // 100.00 USD -> 90.00 EUR, plus a 2.5% markup -> 92.25 EUR
+        let options = ConversionOptions::new(RoundingMode::HalfEven, Some(Decimal::new(25, 3)));
+        let res = convert(&rates, Currency::USD, Currency::EUR, 10000, &options)
+            .expect("converted_currency");
+        assert_eq!(res, 9225);
+
+        let negative = ConversionOptions::new(RoundingMode::HalfEven, Some(Decimal::new(-1, 2)));
+        assert!(matches!(
+            convert(&rates, Currency::USD, Currency::EUR, 10000, &negative),
+            Err(CurrencyConversionError::InvalidMarkup(_))
+        ));
+    }
 }
@@ -5,4 +5,8 @@ pub enum CurrencyConversionError {
     DecimalMultiplicationFailed,
     #[error("Currency not supported: '{0}'")]
     ConversionNotSupported(String),
+    #[error("Markup must not be negative: '{0}'")]
+    InvalidMarkup(String),
+    #[error("Converted amount does not fit in the minor unit range")]
+    AmountOutOfRange,
 }
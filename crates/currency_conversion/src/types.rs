@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
-use common_enums::Currency;
-use rust_decimal::Decimal;
+use common_enums::{Currency, RoundingMode};
+use rust_decimal::{Decimal, RoundingStrategy};
 use rusty_money::iso;
 
 use crate::error::CurrencyConversionError;
@@ -31,6 +31,42 @@ impl CurrencyFactors {
     }
 }
 
+/// Controls how a converted amount is settled into the minor unit of the target currency
+#[derive(Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConversionOptions {
+    /// Rounding applied when bringing the converted amount down to the minor unit
+    #[serde(default)]
+    pub rounding_mode: RoundingMode,
+    /// Optional markup applied on top of the converted amount, expressed as a fraction
+    /// (`0.025` quotes the amount 2.5% above the mid-market rate)
+    #[serde(default)]
+    pub markup: Option<Decimal>,
+}
+
+impl ConversionOptions {
+    pub fn new(rounding_mode: RoundingMode, markup: Option<Decimal>) -> Self {
+        Self {
+            rounding_mode,
+            markup,
+        }
+    }
+}
+
+pub fn rounding_strategy(rounding_mode: RoundingMode) -> RoundingStrategy {
+    match rounding_mode {
+        RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+        RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+        RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+        RoundingMode::Ceiling => RoundingStrategy::ToPositiveInfinity,
+    }
+}
+
+/// Number of digits after the decimal point in the minor unit of the currency, as per ISO 4217.
+/// Amounts are read and written in minor units with this exponent.
+pub fn currency_exponent(currency: Currency) -> u32 {
+    currency_match(currency).exponent
+}
+
 impl ExchangeRates {
     pub fn new(base_currency: Currency, conversion: HashMap<Currency, CurrencyFactors>) -> Self {
         Self {
@@ -23,6 +23,8 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(default)]
+    pub max_transaction_amount: Option<i64>,
 }
 
 // The fields on this struct are optional, as we want to allow the merchant to provide partial
@@ -81,6 +83,7 @@ impl From<ApiMandateAmountData> for MandateAmountData {
             start_date: value.start_date,
             end_date: value.end_date,
             metadata: value.metadata,
+            max_transaction_amount: value.max_transaction_amount,
         }
     }
 }
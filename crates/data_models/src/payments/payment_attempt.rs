@@ -155,6 +155,8 @@ pub struct PaymentAttempt {
     pub merchant_connector_id: Option<String>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub retry_decision: Option<String>,
+    pub retry_reason: Option<String>,
 }
 
 impl PaymentAttempt {
@@ -232,6 +234,8 @@ pub struct PaymentAttemptNew {
     pub merchant_connector_id: Option<String>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub retry_decision: Option<String>,
+    pub retry_reason: Option<String>,
 }
 
 impl PaymentAttemptNew {
@@ -296,6 +300,7 @@ pub enum PaymentAttemptUpdate {
         straight_through_algorithm: Option<serde_json::Value>,
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
+        error_reason: Option<Option<String>>,
         amount_capturable: Option<i64>,
         updated_by: String,
         surcharge_amount: Option<i64>,
@@ -390,6 +395,11 @@ pub enum PaymentAttemptUpdate {
         amount: i64,
         amount_capturable: i64,
     },
+    RetryDecisionUpdate {
+        retry_decision: String,
+        retry_reason: String,
+        updated_by: String,
+    },
 }
 
 impl ForeignIDRef for PaymentAttempt {
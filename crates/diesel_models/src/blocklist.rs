@@ -11,6 +11,9 @@ pub struct BlocklistNew {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub reason: Option<String>,
+    pub created_by: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
@@ -23,4 +26,14 @@ pub struct Blocklist {
     pub data_kind: common_enums::BlocklistDataKind,
     pub metadata: Option<serde_json::Value>,
     pub created_at: time::PrimitiveDateTime,
+    pub expires_at: Option<time::PrimitiveDateTime>,
+    pub reason: Option<String>,
+    pub created_by: Option<String>,
+}
+
+/// Tracking data of the process tracker task which purges a blocklist entry once it expires
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct BlocklistExpiryWorkflow {
+    pub merchant_id: String,
+    pub fingerprint_id: String,
 }
@@ -8,6 +8,8 @@ use crate::schema::blocklist_lookup;
 pub struct BlocklistLookupNew {
     pub merchant_id: String,
     pub fingerprint: String,
+    /// Fingerprint id of the blocklist entry the lookup belongs to
+    pub fingerprint_id: Option<String>,
 }
 
 #[derive(Default, Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Deserialize, Serialize)]
@@ -17,4 +19,5 @@ pub struct BlocklistLookup {
     pub id: i32,
     pub merchant_id: String,
     pub fingerprint: String,
+    pub fingerprint_id: Option<String>,
 }
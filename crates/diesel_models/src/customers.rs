@@ -1,10 +1,11 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{encryption::Encryption, schema::customers};
 
-#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
 #[diesel(table_name = customers)]
 pub struct CustomerNew {
     pub customer_id: String,
@@ -21,7 +22,7 @@ pub struct CustomerNew {
     pub address_id: Option<String>,
 }
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = customers)]
 pub struct Customer {
     pub id: i32,
@@ -39,7 +40,9 @@ pub struct Customer {
     pub address_id: Option<String>,
 }
 
-#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[derive(
+    Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
 #[diesel(table_name = customers)]
 pub struct CustomerUpdateInternal {
     pub name: Option<Encryption>,
@@ -52,3 +55,20 @@ pub struct CustomerUpdateInternal {
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
 }
+
+impl CustomerUpdateInternal {
+    pub fn apply_changeset(self, source: Customer) -> Customer {
+        Customer {
+            name: self.name.or(source.name),
+            email: self.email.or(source.email),
+            phone: self.phone.or(source.phone),
+            description: self.description.or(source.description),
+            phone_country_code: self.phone_country_code.or(source.phone_country_code),
+            metadata: self.metadata.or(source.metadata),
+            modified_at: self.modified_at.unwrap_or(source.modified_at),
+            connector_customer: self.connector_customer.or(source.connector_customer),
+            address_id: self.address_id.or(source.address_id),
+            ..source
+        }
+    }
+}
@@ -13,11 +13,14 @@ pub mod diesel_exports {
         DbMandateStatus as MandateStatus, DbMandateType as MandateType,
         DbMerchantStorageScheme as MerchantStorageScheme,
         DbPaymentMethodIssuerCode as PaymentMethodIssuerCode, DbPaymentSource as PaymentSource,
-        DbPaymentType as PaymentType, DbPayoutStatus as PayoutStatus, DbPayoutType as PayoutType,
-        DbProcessTrackerStatus as ProcessTrackerStatus, DbReconStatus as ReconStatus,
-        DbRefundStatus as RefundStatus, DbRefundType as RefundType,
+        DbPaymentType as PaymentType, DbPayoutBatchItemStatus as PayoutBatchItemStatus,
+        DbPayoutBatchStatus as PayoutBatchStatus, DbPayoutStatus as PayoutStatus,
+        DbPayoutType as PayoutType, DbProcessTrackerStatus as ProcessTrackerStatus,
+        DbReconStatus as ReconStatus, DbRefundStatus as RefundStatus, DbRefundType as RefundType,
         DbRequestIncrementalAuthorization as RequestIncrementalAuthorization,
-        DbRoutingAlgorithmKind as RoutingAlgorithmKind, DbUserStatus as UserStatus,
+        DbRoutingAlgorithmKind as RoutingAlgorithmKind,
+        DbSubscriptionInterval as SubscriptionInterval, DbSubscriptionStatus as SubscriptionStatus,
+        DbUserStatus as UserStatus,
     };
 }
 pub use common_enums::*;
@@ -45,27 +48,7 @@ pub enum RoutingAlgorithmKind {
     Priority,
     VolumeSplit,
     Advanced,
-}
-
-#[derive(
-    Clone,
-    Copy,
-    Debug,
-    Eq,
-    PartialEq,
-    serde::Deserialize,
-    serde::Serialize,
-    strum::Display,
-    strum::EnumString,
-)]
-#[diesel_enum(storage_type = "db_enum")]
-#[serde(rename_all = "snake_case")]
-#[strum(serialize_all = "snake_case")]
-pub enum EventClass {
-    Payments,
-    Refunds,
-    Disputes,
-    Mandates,
+    SuccessRate,
 }
 
 #[derive(
@@ -87,6 +70,8 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    PayoutDetails,
+    SubscriptionDetails,
 }
 
 #[derive(
@@ -206,6 +191,8 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(default)]
+    pub max_transaction_amount: Option<i64>,
 }
 
 #[derive(
@@ -1,4 +1,4 @@
-use common_utils::custom_serde;
+use common_utils::{custom_serde, pii};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -16,17 +16,34 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub merchant_id: Option<String>,
+    pub business_profile_id: Option<String>,
+    pub initial_attempt_id: Option<String>,
+    pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
 }
 
 #[derive(Debug)]
 pub enum EventUpdate {
-    UpdateWebhookNotified { is_webhook_notified: Option<bool> },
+    UpdateWebhookNotified {
+        is_webhook_notified: Option<bool>,
+    },
+    UpdateDeliveryDetails {
+        is_webhook_notified: bool,
+        request: pii::SecretSerdeValue,
+        response_status_code: Option<i16>,
+        response_body: Option<String>,
+        delivery_latency_ms: i64,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = events)]
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
+    pub request: Option<pii::SecretSerdeValue>,
+    pub response_status_code: Option<i16>,
+    pub response_body: Option<String>,
+    pub delivery_latency_ms: Option<i64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -43,6 +60,14 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub merchant_id: Option<String>,
+    pub business_profile_id: Option<String>,
+    pub initial_attempt_id: Option<String>,
+    pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub request: Option<pii::SecretSerdeValue>,
+    pub response_status_code: Option<i16>,
+    pub response_body: Option<String>,
+    pub delivery_latency_ms: Option<i64>,
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -52,6 +77,20 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified,
             } => Self {
                 is_webhook_notified,
+                ..Default::default()
+            },
+            EventUpdate::UpdateDeliveryDetails {
+                is_webhook_notified,
+                request,
+                response_status_code,
+                response_body,
+                delivery_latency_ms,
+            } => Self {
+                is_webhook_notified: Some(is_webhook_notified),
+                request: Some(request),
+                response_status_code,
+                response_body,
+                delivery_latency_ms: Some(delivery_latency_ms),
             },
         }
     }
@@ -0,0 +1,27 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::{Date, PrimitiveDateTime};
+
+use crate::{enums as storage_enums, schema::forex_rate_snapshot};
+
+/// Exchange rates a provider published for a single day, kept so that conversions can be
+/// reproduced later.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = forex_rate_snapshot)]
+#[diesel(primary_key(provider, rate_date))]
+pub struct ForexRateSnapshot {
+    pub rate_date: Date,
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = forex_rate_snapshot)]
+pub struct ForexRateSnapshotNew {
+    pub rate_date: Date,
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+}
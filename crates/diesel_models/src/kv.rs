@@ -3,9 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
+    customers::{Customer, CustomerNew, CustomerUpdateInternal},
     errors,
+    mandate::{Mandate, MandateNew, MandateUpdate},
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntentNew, PaymentIntentUpdate},
+    payment_method::{PaymentMethod, PaymentMethodNew, PaymentMethodUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
     PaymentIntent, PgPooledConn,
@@ -16,6 +19,7 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
+    Delete { deletable: Deletable },
 }
 
 impl DBOperation {
@@ -23,6 +27,7 @@ impl DBOperation {
         match self {
             Self::Insert { .. } => "insert",
             Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
         }
     }
     pub fn table<'a>(&self) -> &'a str {
@@ -33,12 +38,21 @@ impl DBOperation {
                 Insertable::Refund(_) => "refund",
                 Insertable::Address(_) => "address",
                 Insertable::ReverseLookUp(_) => "reverse_lookup",
+                Insertable::Customer(_) => "customers",
+                Insertable::PaymentMethod(_) => "payment_methods",
+                Insertable::Mandate(_) => "mandate",
             },
             Self::Update { updatable } => match updatable {
                 Updateable::PaymentIntentUpdate(_) => "payment_intent",
                 Updateable::PaymentAttemptUpdate(_) => "payment_attempt",
                 Updateable::RefundUpdate(_) => "refund",
                 Updateable::AddressUpdate(_) => "address",
+                Updateable::CustomerUpdate(_) => "customers",
+                Updateable::PaymentMethodUpdate(_) => "payment_methods",
+                Updateable::MandateUpdate(_) => "mandate",
+            },
+            Self::Delete { deletable } => match deletable {
+                Deletable::PaymentMethod(_) => "payment_methods",
             },
         }
     }
@@ -51,6 +65,9 @@ pub enum DBResult {
     Refund(Box<Refund>),
     Address(Box<Address>),
     ReverseLookUp(Box<ReverseLookup>),
+    Customer(Box<Customer>),
+    PaymentMethod(Box<PaymentMethod>),
+    Mandate(Box<Mandate>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -74,6 +91,13 @@ impl DBOperation {
                 Insertable::ReverseLookUp(rev) => {
                     DBResult::ReverseLookUp(Box::new(rev.insert(conn).await?))
                 }
+                Insertable::Customer(cust) => {
+                    DBResult::Customer(Box::new(cust.insert(conn).await?))
+                }
+                Insertable::PaymentMethod(pm) => {
+                    DBResult::PaymentMethod(Box::new(pm.insert(conn).await?))
+                }
+                Insertable::Mandate(m) => DBResult::Mandate(Box::new(m.insert(conn).await?)),
             },
             Self::Update { updatable } => match updatable {
                 Updateable::PaymentIntentUpdate(a) => {
@@ -88,6 +112,39 @@ impl DBOperation {
                 Updateable::AddressUpdate(a) => {
                     DBResult::Address(Box::new(a.orig.update(conn, a.update_data).await?))
                 }
+                Updateable::CustomerUpdate(cust) => DBResult::Customer(Box::new(
+                    Customer::update_by_customer_id_merchant_id(
+                        conn,
+                        cust.orig.customer_id,
+                        cust.orig.merchant_id,
+                        cust.update_data,
+                    )
+                    .await?,
+                )),
+                Updateable::PaymentMethodUpdate(pm) => DBResult::PaymentMethod(Box::new(
+                    pm.orig
+                        .update_with_payment_method_id(conn, pm.update_data)
+                        .await?,
+                )),
+                Updateable::MandateUpdate(m) => DBResult::Mandate(Box::new(
+                    Mandate::update_by_merchant_id_mandate_id(
+                        conn,
+                        &m.orig.merchant_id,
+                        &m.orig.mandate_id,
+                        m.update_data,
+                    )
+                    .await?,
+                )),
+            },
+            Self::Delete { deletable } => match deletable {
+                Deletable::PaymentMethod(pm) => DBResult::PaymentMethod(Box::new(
+                    PaymentMethod::delete_by_merchant_id_payment_method_id(
+                        conn,
+                        &pm.merchant_id,
+                        &pm.payment_method_id,
+                    )
+                    .await?,
+                )),
             },
         })
     }
@@ -123,6 +180,99 @@ pub enum Insertable {
     Refund(RefundNew),
     Address(Box<AddressNew>),
     ReverseLookUp(ReverseLookupNew),
+    Customer(CustomerNew),
+    PaymentMethod(PaymentMethodNew),
+    Mandate(MandateNew),
+}
+
+impl Insertable {
+    /// Whether both the values belong to the same table and can be inserted in the same
+    /// multi-row statement
+    pub fn is_batchable_with(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Inserts all the values in a single multi-row statement. All the values must belong to
+    /// the same table
+    pub async fn insert_batch(batch: Vec<Self>, conn: &PgPooledConn) -> crate::StorageResult<()> {
+        let Some(first) = batch.first() else {
+            return Ok(());
+        };
+
+        match first {
+            Self::PaymentIntent(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::PaymentIntent(a) => Some(a),
+                    _ => None,
+                })?;
+                PaymentIntentNew::batch_insert(values, conn).await
+            }
+            Self::PaymentAttempt(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::PaymentAttempt(a) => Some(a),
+                    _ => None,
+                })?;
+                PaymentAttemptNew::batch_insert(values, conn).await
+            }
+            Self::Refund(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::Refund(a) => Some(a),
+                    _ => None,
+                })?;
+                RefundNew::batch_insert(values, conn).await
+            }
+            Self::Address(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::Address(addr) => Some(*addr),
+                    _ => None,
+                })?;
+                AddressNew::batch_insert(values, conn).await
+            }
+            Self::ReverseLookUp(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::ReverseLookUp(rev) => Some(rev),
+                    _ => None,
+                })?;
+                ReverseLookupNew::batch_insert(values, conn).await
+            }
+            Self::Customer(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::Customer(cust) => Some(cust),
+                    _ => None,
+                })?;
+                CustomerNew::batch_insert(values, conn).await
+            }
+            Self::PaymentMethod(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::PaymentMethod(pm) => Some(pm),
+                    _ => None,
+                })?;
+                PaymentMethodNew::batch_insert(values, conn).await
+            }
+            Self::Mandate(_) => {
+                let values = collect_batch(batch, |insertable| match insertable {
+                    Self::Mandate(m) => Some(m),
+                    _ => None,
+                })?;
+                MandateNew::batch_insert(values, conn).await
+            }
+        }
+    }
+}
+
+fn collect_batch<T>(
+    batch: Vec<Insertable>,
+    extract: impl Fn(Insertable) -> Option<T>,
+) -> crate::StorageResult<Vec<T>> {
+    batch
+        .into_iter()
+        .map(|insertable| {
+            extract(insertable)
+                .ok_or(errors::DatabaseError::QueryGenerationFailed)
+                .into_report()
+                .attach_printable("All the values of a batch must belong to the same table")
+        })
+        .collect()
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -132,6 +282,15 @@ pub enum Updateable {
     PaymentAttemptUpdate(PaymentAttemptUpdateMems),
     RefundUpdate(RefundUpdateMems),
     AddressUpdate(Box<AddressUpdateMems>),
+    CustomerUpdate(CustomerUpdateMems),
+    PaymentMethodUpdate(PaymentMethodUpdateMems),
+    MandateUpdate(MandateUpdateMems),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    PaymentMethod(PaymentMethod),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -157,3 +316,21 @@ pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerUpdateMems {
+    pub orig: Customer,
+    pub update_data: CustomerUpdateInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaymentMethodUpdateMems {
+    pub orig: PaymentMethod,
+    pub update_data: PaymentMethodUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MandateUpdateMems {
+    pub orig: Mandate,
+    pub update_data: MandateUpdate,
+}
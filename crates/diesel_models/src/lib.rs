@@ -17,6 +17,7 @@ pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 #[allow(unused)]
 pub mod fraud_check;
 pub mod gsm;
@@ -25,6 +26,7 @@ pub mod kv;
 pub mod locker_mock_up;
 pub mod macros;
 pub mod mandate;
+pub mod mandate_usage;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
@@ -34,6 +36,7 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
@@ -42,6 +45,7 @@ pub mod reverse_lookup;
 pub mod routing_algorithm;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
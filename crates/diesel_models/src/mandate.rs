@@ -1,11 +1,12 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::mandate};
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = mandate)]
 pub struct Mandate {
     pub id: i32,
@@ -32,10 +33,47 @@ pub struct Mandate {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub max_transaction_amount: Option<i64>,
+}
+
+impl Mandate {
+    /// The maximum amount which can be debited in a single transaction, `None` when the mandate
+    /// has no per transaction limit
+    pub fn get_transaction_limit(&self) -> Option<i64> {
+        match self.mandate_type {
+            storage_enums::MandateType::SingleUse => self.mandate_amount,
+            storage_enums::MandateType::MultiUse => self.max_transaction_amount,
+        }
+    }
+
+    /// The amount which can still be debited from the mandate, `None` when the mandate has no
+    /// amount limit
+    pub fn get_remaining_amount(&self) -> Option<i64> {
+        self.mandate_amount.map(
+            |mandate_amount| match (self.mandate_status, self.mandate_type) {
+                (storage_enums::MandateStatus::Active, storage_enums::MandateType::SingleUse) => {
+                    mandate_amount
+                }
+                (storage_enums::MandateStatus::Active, storage_enums::MandateType::MultiUse) => {
+                    mandate_amount
+                        .saturating_sub(self.amount_captured.unwrap_or(0))
+                        .max(0)
+                }
+                _ => 0,
+            },
+        )
+    }
 }
 
 #[derive(
-    router_derive::Setter, Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay,
+    router_derive::Setter,
+    Clone,
+    Debug,
+    Default,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
 )]
 #[diesel(table_name = mandate)]
 pub struct MandateNew {
@@ -62,9 +100,10 @@ pub struct MandateNew {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub max_transaction_amount: Option<i64>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MandateUpdate {
     StatusUpdate {
         mandate_status: storage_enums::MandateStatus,
@@ -91,6 +130,17 @@ pub struct MandateUpdateInternal {
     connector_mandate_ids: Option<pii::SecretSerdeValue>,
 }
 
+impl MandateUpdateInternal {
+    pub fn apply_changeset(self, source: Mandate) -> Mandate {
+        Mandate {
+            mandate_status: self.mandate_status.unwrap_or(source.mandate_status),
+            amount_captured: self.amount_captured.or(source.amount_captured),
+            connector_mandate_ids: self.connector_mandate_ids.or(source.connector_mandate_ids),
+            ..source
+        }
+    }
+}
+
 impl From<MandateUpdate> for MandateUpdateInternal {
     fn from(mandate_update: MandateUpdate) -> Self {
         match mandate_update {
@@ -0,0 +1,31 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::mandate_usage};
+
+/// A debit made against a mandate, recorded once per payment attempt
+#[derive(Clone, Debug, Eq, Insertable, PartialEq, Serialize, Deserialize)]
+#[diesel(table_name = mandate_usage)]
+pub struct MandateUsageNew {
+    pub mandate_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Queryable, Identifiable, Deserialize, Serialize)]
+#[diesel(table_name = mandate_usage)]
+pub struct MandateUsage {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub mandate_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub created_at: time::PrimitiveDateTime,
+}
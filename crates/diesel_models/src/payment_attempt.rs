@@ -64,6 +64,8 @@ pub struct PaymentAttempt {
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
     pub net_amount: Option<i64>,
+    pub retry_decision: Option<String>,
+    pub retry_reason: Option<String>,
 }
 
 impl PaymentAttempt {
@@ -138,6 +140,8 @@ pub struct PaymentAttemptNew {
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
     pub net_amount: Option<i64>,
+    pub retry_decision: Option<String>,
+    pub retry_reason: Option<String>,
 }
 
 impl PaymentAttemptNew {
@@ -207,6 +211,7 @@ pub enum PaymentAttemptUpdate {
         straight_through_algorithm: Option<serde_json::Value>,
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
+        error_reason: Option<Option<String>>,
         amount_capturable: Option<i64>,
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
@@ -301,6 +306,11 @@ pub enum PaymentAttemptUpdate {
         amount: i64,
         amount_capturable: i64,
     },
+    RetryDecisionUpdate {
+        retry_decision: String,
+        retry_reason: String,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -343,6 +353,8 @@ pub struct PaymentAttemptUpdateInternal {
     encoded_data: Option<String>,
     unified_code: Option<Option<String>>,
     unified_message: Option<Option<String>>,
+    retry_decision: Option<String>,
+    retry_reason: Option<String>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -403,6 +415,8 @@ impl PaymentAttemptUpdate {
             encoded_data,
             unified_code,
             unified_message,
+            retry_decision,
+            retry_reason,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -444,6 +458,8 @@ impl PaymentAttemptUpdate {
             encoded_data: encoded_data.or(source.encoded_data),
             unified_code: unified_code.unwrap_or(source.unified_code),
             unified_message: unified_message.unwrap_or(source.unified_message),
+            retry_decision: retry_decision.or(source.retry_decision),
+            retry_reason: retry_reason.or(source.retry_reason),
             ..source
         }
     }
@@ -514,6 +530,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 straight_through_algorithm,
                 error_code,
                 error_message,
+                error_reason,
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
@@ -536,6 +553,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 straight_through_algorithm,
                 error_code,
                 error_message,
+                error_reason,
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
@@ -740,6 +758,17 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable: Some(amount_capturable),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::RetryDecisionUpdate {
+                retry_decision,
+                retry_reason,
+                updated_by,
+            } => Self {
+                retry_decision: Some(retry_decision),
+                retry_reason: Some(retry_reason),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
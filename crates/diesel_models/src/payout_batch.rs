@@ -0,0 +1,163 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    encryption::Encryption,
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch)]
+#[diesel(primary_key(batch_id))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub file_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub succeeded_count: i32,
+    pub failed_count: i32,
+    pub file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+    ResultUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        succeeded_count: i32,
+        failed_count: i32,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: storage_enums::PayoutBatchStatus,
+    pub succeeded_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(update: PayoutBatchUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match update {
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status,
+                succeeded_count: None,
+                failed_count: None,
+                last_modified_at,
+            },
+            PayoutBatchUpdate::ResultUpdate {
+                status,
+                succeeded_count,
+                failed_count,
+            } => Self {
+                status,
+                succeeded_count: Some(succeeded_count),
+                failed_count: Some(failed_count),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch_item)]
+#[diesel(primary_key(batch_id, item_index))]
+pub struct PayoutBatchItem {
+    pub batch_id: String,
+    pub item_index: i32,
+    pub merchant_id: String,
+    pub payout_id: Option<String>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    /// The encrypted `PayoutCreateRequest` of the item
+    pub request: Encryption,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub batch_id: String,
+    pub item_index: i32,
+    pub merchant_id: String,
+    pub payout_id: Option<String>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub request: Encryption,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct PayoutBatchItemUpdate {
+    pub payout_id: Option<String>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    pub payout_id: Option<String>,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(update: PayoutBatchItemUpdate) -> Self {
+        Self {
+            payout_id: update.payout_id,
+            status: update.status,
+            payout_status: update.payout_status,
+            error_code: update.error_code,
+            error_message: update.error_message,
+            last_modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which processes the items of a payout batch
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PayoutBatchWorkflow {
+    pub batch_id: String,
+    pub merchant_id: String,
+}
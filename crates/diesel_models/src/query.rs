@@ -14,11 +14,13 @@ pub mod dashboard_metadata;
 pub mod dispute;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod generics;
 pub mod gsm;
 pub mod locker_mock_up;
 pub mod mandate;
+pub mod mandate_usage;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
@@ -28,10 +30,12 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
@@ -14,6 +14,12 @@ impl AddressNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Address> {
         generics::generic_insert(conn, self).await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(values: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Address>(conn, values).await?;
+        Ok(())
+    }
 }
 
 impl Address {
@@ -51,6 +51,24 @@ impl Blocklist {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id_data_kinds(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        data_kinds: Vec<common_enums::BlocklistDataKind>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::data_kind.eq_any(data_kinds)),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn list_by_merchant_id(
         conn: &PgPooledConn,
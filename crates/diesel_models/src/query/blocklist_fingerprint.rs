@@ -30,4 +30,19 @@ impl BlocklistFingerprint {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+        )
+        .await
+    }
 }
@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -16,6 +16,37 @@ impl EventNew {
 }
 
 impl Event {
+    #[instrument(skip(conn))]
+    pub async fn find_by_event_id(conn: &PgPooledConn, event_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::event_id.eq(event_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_business_profile_id_constraints(
+        conn: &PgPooledConn,
+        business_profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::business_profile_id
+                .eq(business_profile_id.to_owned())
+                .and(dsl::created_at.ge(created_after))
+                .and(dsl::created_at.le(created_before)),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn update(
         conn: &PgPooledConn,
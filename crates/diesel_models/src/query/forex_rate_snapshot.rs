@@ -0,0 +1,45 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::report;
+use router_env::{instrument, tracing};
+use time::Date;
+
+use super::generics;
+use crate::{
+    errors,
+    forex_rate_snapshot::{ForexRateSnapshot, ForexRateSnapshotNew},
+    schema::forex_rate_snapshot::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ForexRateSnapshotNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ForexRateSnapshot> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ForexRateSnapshot {
+    /// Finds the most recent snapshot of `provider` published on or before `rate_date`, so that
+    /// days on which no rates were published (weekends, holidays) resolve to the previous
+    /// business day.
+    #[instrument(skip(conn))]
+    pub async fn find_latest_on_or_before(
+        conn: &PgPooledConn,
+        provider: &str,
+        rate_date: Date,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::provider
+                .eq(provider.to_owned())
+                .and(dsl::rate_date.le(rate_date)),
+            Some(1),
+            None,
+            Some(dsl::rate_date.desc()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(report!(errors::DatabaseError::NotFound))
+    }
+}
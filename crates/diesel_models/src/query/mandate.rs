@@ -1,4 +1,9 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use diesel::{
+    associations::HasTable,
+    dsl::sql,
+    sql_types::{BigInt, Bool, Nullable},
+    BoolExpressionMethods, ExpressionMethods, Table,
+};
 use error_stack::report;
 use router_env::{instrument, tracing};
 
@@ -10,6 +15,12 @@ impl MandateNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Mandate> {
         generics::generic_insert(conn, self).await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(values: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Mandate>(conn, values).await?;
+        Ok(())
+    }
 }
 
 impl Mandate {
@@ -63,6 +74,31 @@ impl Mandate {
         .await
     }
 
+    /// Adds `amount` to the captured amount of the mandate in a single statement, provided that the
+    /// captured amount stays within the mandate amount. Returns `None` if no mandate was updated.
+    pub async fn increment_amount_captured_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                .and(
+                    sql::<Bool>("(mandate_amount IS NULL OR COALESCE(amount_captured, 0) + ")
+                        .bind::<BigInt, _>(amount)
+                        .sql(" <= mandate_amount)"),
+                ),
+            dsl::amount_captured.eq(sql::<Nullable<BigInt>>("COALESCE(amount_captured, 0) + ")
+                .bind::<BigInt, _>(amount)),
+        )
+        .await
+        .map(|mandates| mandates.first().cloned())
+    }
+
     pub async fn update_by_merchant_id_mandate_id(
         conn: &PgPooledConn,
         merchant_id: &str,
@@ -0,0 +1,14 @@
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    mandate_usage::{MandateUsage, MandateUsageNew},
+    PgPooledConn, StorageResult,
+};
+
+impl MandateUsageNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MandateUsage> {
+        generics::generic_insert(conn, self).await
+    }
+}
@@ -25,6 +25,16 @@ impl PaymentAttemptNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentAttempt> {
         generics::generic_insert(conn, self.populate_derived_fields()).await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(values: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        let values: Vec<Self> = values
+            .into_iter()
+            .map(Self::populate_derived_fields)
+            .collect();
+        generics::generic_insert::<_, _, PaymentAttempt>(conn, values).await?;
+        Ok(())
+    }
 }
 
 impl PaymentAttempt {
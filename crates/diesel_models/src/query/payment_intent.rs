@@ -16,6 +16,12 @@ impl PaymentIntentNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentIntent> {
         generics::generic_insert(conn, self).await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(values: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentIntent>(conn, values).await?;
+        Ok(())
+    }
 }
 
 impl PaymentIntent {
@@ -14,6 +14,12 @@ impl PaymentMethodNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethod> {
         generics::generic_insert(conn, self).await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(values: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentMethod>(conn, values).await?;
+        Ok(())
+    }
 }
 
 impl PaymentMethod {
@@ -0,0 +1,135 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        batch_id: &str,
+        batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            PayoutBatchUpdateInternal::from(batch_update),
+        )
+        .await
+    }
+}
+
+impl PayoutBatchItem {
+    #[instrument(skip_all)]
+    pub async fn insert_batch(
+        conn: &PgPooledConn,
+        items: Vec<PayoutBatchItemNew>,
+    ) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self as HasTable>::table()).values(items);
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .execute_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while inserting payout batch items")
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(item_dsl::batch_id.eq(batch_id.to_owned())),
+            None,
+            None,
+            Some(item_dsl::item_index.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_batch_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(item_dsl::batch_id.eq(batch_id.to_owned()))
+                .and(item_dsl::status.eq(status)),
+            Some(limit),
+            None,
+            Some(item_dsl::item_index.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_batch_id_item_index(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        item_index: i32,
+        item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            (batch_id.to_owned(), item_index),
+            PayoutBatchItemUpdateInternal::from(item_update),
+        )
+        .await
+    }
+}
@@ -14,6 +14,12 @@ impl RefundNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Refund> {
         generics::generic_insert(conn, self).await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(values: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, Refund>(conn, values).await?;
+        Ok(())
+    }
 }
 
 impl Refund {
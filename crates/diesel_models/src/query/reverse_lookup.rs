@@ -13,6 +13,7 @@ impl ReverseLookupNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReverseLookup> {
         generics::generic_insert(conn, self).await
     }
+
     #[instrument(skip(conn))]
     pub async fn batch_insert(
         reverse_lookups: Vec<Self>,
@@ -0,0 +1,62 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::subscription::dsl,
+    subscription::{Subscription, SubscriptionNew, SubscriptionUpdate, SubscriptionUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Subscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Subscription {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Subscriptions which have been cancelled or completed are not updated, `NotFound` is
+    /// returned for them
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription_update: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned()))
+                .and(dsl::status.ne_all(vec![
+                    storage_enums::SubscriptionStatus::Cancelled,
+                    storage_enums::SubscriptionStatus::Completed,
+                ])),
+            SubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+    }
+}
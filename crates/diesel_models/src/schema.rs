@@ -65,11 +65,16 @@ diesel::table! {
         id -> Int4,
         #[max_length = 64]
         merchant_id -> Varchar,
-        #[max_length = 64]
+        #[max_length = 255]
         fingerprint_id -> Varchar,
         data_kind -> BlocklistDataKind,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        #[max_length = 64]
+        created_by -> Nullable<Varchar>,
     }
 }
 
@@ -98,6 +103,8 @@ diesel::table! {
         #[max_length = 64]
         merchant_id -> Varchar,
         fingerprint -> Text,
+        #[max_length = 255]
+        fingerprint_id -> Nullable<Varchar>,
     }
 }
 
@@ -292,6 +299,8 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        purpose -> Nullable<Varchar>,
     }
 }
 
@@ -312,6 +321,18 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        business_profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        initial_attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        delivery_attempt -> Nullable<Varchar>,
+        request -> Nullable<Jsonb>,
+        response_status_code -> Nullable<Int2>,
+        response_body -> Nullable<Text>,
+        delivery_latency_ms -> Nullable<Int8>,
     }
 }
 
@@ -344,6 +365,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    forex_rate_snapshot (provider, rate_date) {
+        rate_date -> Date,
+        #[max_length = 64]
+        provider -> Varchar,
+        base_currency -> Currency,
+        rates -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -404,6 +439,8 @@ diesel::table! {
         unified_code -> Nullable<Varchar>,
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
+        #[max_length = 64]
+        error_category -> Nullable<Varchar>,
     }
 }
 
@@ -510,6 +547,27 @@ diesel::table! {
         original_payment_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        max_transaction_amount -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    mandate_usage (id) {
+        id -> Int4,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
     }
 }
 
@@ -688,6 +746,10 @@ diesel::table! {
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
         net_amount -> Nullable<Int8>,
+        #[max_length = 32]
+        retry_decision -> Nullable<Varchar>,
+        #[max_length = 64]
+        retry_reason -> Nullable<Varchar>,
     }
 }
 
@@ -866,6 +928,51 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        status -> PayoutBatchStatus,
+        total_count -> Int4,
+        succeeded_count -> Int4,
+        failed_count -> Int4,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (batch_id, item_index) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        item_index -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        status -> PayoutBatchItemStatus,
+        payout_status -> Nullable<PayoutStatus>,
+        request -> Bytea,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1015,6 +1122,44 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        status -> SubscriptionStatus,
+        amount -> Int8,
+        currency -> Currency,
+        interval -> SubscriptionInterval,
+        interval_count -> Int4,
+        anchor_date -> Timestamp,
+        trial_days -> Int4,
+        end_after_cycles -> Nullable<Int4>,
+        ends_at -> Nullable<Timestamp>,
+        completed_cycles -> Int4,
+        failed_attempts -> Int4,
+        next_billing_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        last_payment_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        cancelled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1076,11 +1221,13 @@ diesel::allow_tables_to_appear_in_same_query!(
     dispute,
     events,
     file_metadata,
+    forex_rate_snapshot,
     fraud_check,
     gateway_status_map,
     incremental_authorization,
     locker_mock_up,
     mandate,
+    mandate_usage,
     merchant_account,
     merchant_connector_account,
     merchant_key_store,
@@ -1090,11 +1237,14 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
     refund,
     reverse_lookup,
     routing_algorithm,
+    subscription,
     user_roles,
     users,
 );
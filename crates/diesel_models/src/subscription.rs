@@ -0,0 +1,159 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::subscription};
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Serialize, Deserialize)]
+#[diesel(table_name = subscription)]
+#[diesel(primary_key(subscription_id))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub anchor_date: PrimitiveDateTime,
+    pub trial_days: i32,
+    pub end_after_cycles: Option<i32>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub ends_at: Option<PrimitiveDateTime>,
+    /// Number of billing cycles which were paid
+    pub completed_cycles: i32,
+    /// Number of failed payment attempts of the current billing cycle
+    pub failed_attempts: i32,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    pub last_payment_id: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub profile_id: Option<String>,
+    pub status: storage_enums::SubscriptionStatus,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub interval: storage_enums::SubscriptionInterval,
+    pub interval_count: i32,
+    pub anchor_date: PrimitiveDateTime,
+    pub trial_days: i32,
+    pub end_after_cycles: Option<i32>,
+    pub ends_at: Option<PrimitiveDateTime>,
+    pub completed_cycles: i32,
+    pub failed_attempts: i32,
+    pub next_billing_at: Option<PrimitiveDateTime>,
+    pub last_payment_id: Option<String>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    /// The payment of the current billing cycle went through
+    CyclePaid {
+        status: storage_enums::SubscriptionStatus,
+        completed_cycles: i32,
+        next_billing_at: Option<PrimitiveDateTime>,
+        last_payment_id: String,
+    },
+    /// The payment of the current billing cycle failed
+    CycleFailed {
+        status: storage_enums::SubscriptionStatus,
+        failed_attempts: i32,
+        next_billing_at: Option<PrimitiveDateTime>,
+        last_payment_id: String,
+        /// Set when all the retries of the billing cycle failed
+        cancelled_at: Option<PrimitiveDateTime>,
+    },
+    Cancel {
+        cancelled_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    pub status: storage_enums::SubscriptionStatus,
+    pub completed_cycles: Option<i32>,
+    pub failed_attempts: Option<i32>,
+    pub next_billing_at: Option<Option<PrimitiveDateTime>>,
+    pub last_payment_id: Option<String>,
+    pub cancelled_at: Option<PrimitiveDateTime>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(update: SubscriptionUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match update {
+            SubscriptionUpdate::CyclePaid {
+                status,
+                completed_cycles,
+                next_billing_at,
+                last_payment_id,
+            } => Self {
+                status,
+                completed_cycles: Some(completed_cycles),
+                failed_attempts: Some(0),
+                next_billing_at: Some(next_billing_at),
+                last_payment_id: Some(last_payment_id),
+                cancelled_at: None,
+                last_modified_at,
+            },
+            SubscriptionUpdate::CycleFailed {
+                status,
+                failed_attempts,
+                next_billing_at,
+                last_payment_id,
+                cancelled_at,
+            } => Self {
+                status,
+                completed_cycles: None,
+                failed_attempts: Some(failed_attempts),
+                next_billing_at: Some(next_billing_at),
+                last_payment_id: Some(last_payment_id),
+                cancelled_at,
+                last_modified_at,
+            },
+            SubscriptionUpdate::Cancel { cancelled_at } => Self {
+                status: storage_enums::SubscriptionStatus::Cancelled,
+                completed_cycles: None,
+                failed_attempts: None,
+                next_billing_at: Some(None),
+                last_payment_id: None,
+                cancelled_at: Some(cancelled_at),
+                last_modified_at,
+            },
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which charges a subscription at every billing cycle
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct SubscriptionWorkflow {
+    pub subscription_id: String,
+    pub merchant_id: String,
+}
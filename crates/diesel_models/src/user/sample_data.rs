@@ -63,6 +63,8 @@ pub struct PaymentAttemptBatchNew {
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
     pub net_amount: Option<i64>,
+    pub retry_decision: Option<String>,
+    pub retry_reason: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -116,6 +118,8 @@ impl PaymentAttemptBatchNew {
             unified_code: self.unified_code,
             unified_message: self.unified_message,
             net_amount: self.net_amount,
+            retry_decision: self.retry_decision,
+            retry_reason: self.retry_reason,
         }
     }
 }
@@ -0,0 +1,280 @@
+//! Grouping of consecutive inserts into the same table, so that the entries of a stream are
+//! drained with multi-row statements instead of one statement per entry.
+
+use std::{collections::HashMap, time::SystemTime};
+
+use tokio::time::Duration;
+
+use crate::kv;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BatchConfig {
+    pub max_batch_size: usize,
+    pub flush_interval: Duration,
+}
+
+/// Consecutive insert entries of a stream, all of them into the same table
+#[derive(Default)]
+pub struct InsertBatch {
+    table: &'static str,
+    entries: Vec<(String, HashMap<String, String>)>,
+    insertables: Vec<kv::Insertable>,
+    pushed_at: Vec<i64>,
+}
+
+impl InsertBatch {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn table(&self) -> &'static str {
+        self.table
+    }
+
+    /// Whether the operation can be added to the batch without exceeding the maximum batch size
+    pub fn accepts(&self, operation: &kv::DBOperation, max_batch_size: usize) -> bool {
+        match operation {
+            kv::DBOperation::Insert { insertable } => {
+                self.len() < max_batch_size
+                    && self
+                        .insertables
+                        .first()
+                        .map_or(true, |first| first.is_batchable_with(insertable))
+            }
+            kv::DBOperation::Update { .. } | kv::DBOperation::Delete { .. } => false,
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        (entry_id, entry): (String, HashMap<String, String>),
+        table: &'static str,
+        insertable: kv::Insertable,
+        pushed_at: i64,
+    ) {
+        self.table = table;
+        self.entries.push((entry_id, entry));
+        self.insertables.push(insertable);
+        self.pushed_at.push(pushed_at);
+    }
+
+    /// Whether the batch has to be executed now rather than waiting for more entries to be added
+    /// to the stream. The age of the batch is that of its oldest entry, the id of a stream entry
+    /// being prefixed with the time at which it was added in milliseconds
+    pub fn is_due(&self, config: &BatchConfig) -> bool {
+        if self.len() >= config.max_batch_size || config.flush_interval.is_zero() {
+            return true;
+        }
+
+        let added_at = self
+            .entries
+            .first()
+            .and_then(|(entry_id, _)| entry_id.split('-').next())
+            .and_then(|millis| millis.parse::<u64>().ok());
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .ok()
+            .and_then(|now| u64::try_from(now.as_millis()).ok());
+
+        match added_at.zip(now) {
+            Some((added_at, now)) => {
+                Duration::from_millis(now.saturating_sub(added_at)) >= config.flush_interval
+            }
+            // The age can't be determined, don't hold the entries back
+            None => true,
+        }
+    }
+
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<(String, HashMap<String, String>)>,
+        Vec<kv::Insertable>,
+        Vec<i64>,
+    ) {
+        (self.entries, self.insertables, self.pushed_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use diesel_models::{customers, mandate, reverse_lookup};
+
+    use super::*;
+
+    fn reverse_lookup_insert() -> kv::DBOperation {
+        kv::DBOperation::Insert {
+            insertable: kv::Insertable::ReverseLookUp(reverse_lookup::ReverseLookupNew {
+                lookup_id: "lookup_id".to_string(),
+                pk_id: "pk_id".to_string(),
+                sk_id: "sk_id".to_string(),
+                source: "payment_attempt".to_string(),
+                updated_by: "redis_kv".to_string(),
+            }),
+        }
+    }
+
+    fn mandate_insert() -> kv::DBOperation {
+        kv::DBOperation::Insert {
+            insertable: kv::Insertable::Mandate(mandate::MandateNew::default()),
+        }
+    }
+
+    fn customer_update() -> kv::DBOperation {
+        let now = common_utils::date_time::now();
+        kv::DBOperation::Update {
+            updatable: kv::Updateable::CustomerUpdate(kv::CustomerUpdateMems {
+                orig: customers::Customer {
+                    id: 1,
+                    customer_id: "customer_id".to_string(),
+                    merchant_id: "merchant_id".to_string(),
+                    name: None,
+                    email: None,
+                    phone: None,
+                    phone_country_code: None,
+                    description: None,
+                    created_at: now,
+                    metadata: None,
+                    connector_customer: None,
+                    modified_at: now,
+                    address_id: None,
+                },
+                update_data: customers::CustomerUpdateInternal::default(),
+            }),
+        }
+    }
+
+    fn push(batch: &mut InsertBatch, entry_id: &str, operation: kv::DBOperation) {
+        let table = operation.table();
+        if let kv::DBOperation::Insert { insertable } = operation {
+            batch.push((entry_id.to_string(), HashMap::new()), table, insertable, 0)
+        }
+    }
+
+    fn entry_id_added_ago(age: Duration) -> String {
+        let added_at = SystemTime::now()
+            .checked_sub(age)
+            .unwrap()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        format!("{added_at}-0")
+    }
+
+    #[test]
+    fn test_empty_batch_accepts_any_insert() {
+        let batch = InsertBatch::default();
+        assert!(batch.accepts(&reverse_lookup_insert(), 10));
+        assert!(batch.accepts(&mandate_insert(), 10));
+    }
+
+    #[test]
+    fn test_batch_rejects_insert_into_another_table() {
+        let mut batch = InsertBatch::default();
+        push(&mut batch, "1-0", reverse_lookup_insert());
+
+        assert!(batch.accepts(&reverse_lookup_insert(), 10));
+        assert!(!batch.accepts(&mandate_insert(), 10));
+        assert_eq!(batch.table(), "reverse_lookup");
+    }
+
+    #[test]
+    fn test_batch_rejects_insert_beyond_max_batch_size() {
+        let mut batch = InsertBatch::default();
+        push(&mut batch, "1-0", reverse_lookup_insert());
+        push(&mut batch, "2-0", reverse_lookup_insert());
+
+        assert!(batch.accepts(&reverse_lookup_insert(), 3));
+        assert!(!batch.accepts(&reverse_lookup_insert(), 2));
+    }
+
+    #[test]
+    fn test_update_between_inserts_is_not_batched() {
+        let mut batch = InsertBatch::default();
+        push(&mut batch, "1-0", reverse_lookup_insert());
+
+        // The update is not accepted, so the batch is executed before it and the inserts after
+        // the update start a new batch
+        assert!(!batch.accepts(&customer_update(), 10));
+        assert!(!InsertBatch::default().accepts(&customer_update(), 10));
+    }
+
+    #[test]
+    fn test_batch_is_due_when_full() {
+        let config = BatchConfig {
+            max_batch_size: 2,
+            flush_interval: Duration::from_secs(3600),
+        };
+        let mut batch = InsertBatch::default();
+        push(
+            &mut batch,
+            &entry_id_added_ago(Duration::ZERO),
+            reverse_lookup_insert(),
+        );
+        assert!(!batch.is_due(&config));
+
+        push(
+            &mut batch,
+            &entry_id_added_ago(Duration::ZERO),
+            reverse_lookup_insert(),
+        );
+        assert!(batch.is_due(&config));
+    }
+
+    #[test]
+    fn test_batch_is_deferred_until_flush_interval_elapses() {
+        let config = BatchConfig {
+            max_batch_size: 10,
+            flush_interval: Duration::from_secs(60),
+        };
+
+        let mut recent_batch = InsertBatch::default();
+        push(
+            &mut recent_batch,
+            &entry_id_added_ago(Duration::from_secs(1)),
+            reverse_lookup_insert(),
+        );
+        assert!(!recent_batch.is_due(&config));
+
+        // The age of a batch is that of its oldest entry
+        let mut old_batch = InsertBatch::default();
+        push(
+            &mut old_batch,
+            &entry_id_added_ago(Duration::from_secs(120)),
+            reverse_lookup_insert(),
+        );
+        push(
+            &mut old_batch,
+            &entry_id_added_ago(Duration::ZERO),
+            reverse_lookup_insert(),
+        );
+        assert!(old_batch.is_due(&config));
+    }
+
+    #[test]
+    fn test_batch_is_due_without_flush_interval_or_age() {
+        let mut batch = InsertBatch::default();
+        push(
+            &mut batch,
+            &entry_id_added_ago(Duration::ZERO),
+            reverse_lookup_insert(),
+        );
+        assert!(batch.is_due(&BatchConfig {
+            max_batch_size: 10,
+            flush_interval: Duration::ZERO,
+        }));
+
+        let mut batch = InsertBatch::default();
+        push(&mut batch, "invalid-entry-id", reverse_lookup_insert());
+        assert!(batch.is_due(&BatchConfig {
+            max_batch_size: 10,
+            flush_interval: Duration::from_secs(60),
+        }));
+    }
+}
@@ -1,5 +1,8 @@
 use bb8::PooledConnection;
+use common_utils::errors::CustomResult;
 use diesel::PgConnection;
+use diesel_models::errors::DatabaseError;
+use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "hashicorp-vault")]
 use external_services::hashicorp_vault::{self, decrypt::VaultFetch, Kv2};
 #[cfg(feature = "kms")]
@@ -61,11 +64,15 @@ pub async fn diesel_make_pg_pool(
         .expect("Failed to create PostgreSQL connection pool")
 }
 
-#[allow(clippy::expect_used)]
 pub async fn pg_connection(
     pool: &PgPool,
-) -> PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>> {
+) -> CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    DatabaseError,
+> {
     pool.get()
         .await
-        .expect("Couldn't retrieve PostgreSQL connection")
+        .into_report()
+        .change_context(DatabaseError::DatabaseConnectionError)
+        .attach_printable("Couldn't retrieve PostgreSQL connection")
 }
@@ -0,0 +1,192 @@
+//! Tooling to inspect, retry or discard the entries which the drainer moved to the dead-letter
+//! streams after their queries failed.
+
+use std::{collections::HashMap, sync::Arc};
+
+use error_stack::IntoReport;
+
+use crate::{
+    errors,
+    query::ExecuteQuery,
+    settings::DeadLetterAction,
+    stream::{DEAD_LETTER_ERROR, DEAD_LETTER_FAILED_AT, DEAD_LETTER_SOURCE_ENTRY_ID},
+    Store, StreamData,
+};
+
+/// Number of entries read at once while retrying all the entries of a stream
+const RETRY_BATCH_SIZE: u64 = 100;
+
+pub async fn run(store: Arc<Store>, action: DeadLetterAction) -> errors::DrainerResult<()> {
+    match action {
+        DeadLetterAction::List { partition, count } => {
+            let stream_name = get_stream_name(&store, partition)?;
+            let entries = read_entries(&store, &stream_name, "-", "+", Some(count)).await?;
+            if entries.is_empty() {
+                println!("No entries in the dead-letter stream {stream_name}");
+            }
+            for (entry_id, entry) in entries {
+                println!("{}", describe_entry(&entry_id, entry));
+            }
+            Ok(())
+        }
+        DeadLetterAction::Retry {
+            partition,
+            entry_id: Some(entry_id),
+            ..
+        } => {
+            let stream_name = get_stream_name(&store, partition)?;
+            let entries = read_entries(&store, &stream_name, &entry_id, &entry_id, Some(1)).await?;
+            match entries.into_iter().next() {
+                Some((entry_id, entry)) => {
+                    retry_entry(&store, &stream_name, &entry_id, entry).await?;
+                    Ok(())
+                }
+                None => Err(errors::DrainerError::InvalidCommand(format!(
+                    "entry {entry_id} does not exist in the dead-letter stream {stream_name}"
+                )))
+                .into_report(),
+            }
+        }
+        DeadLetterAction::Retry {
+            partition,
+            entry_id: None,
+            ..
+        } => {
+            let stream_name = get_stream_name(&store, partition)?;
+            // Entries are retried in the order they were drained, later entries may depend on the
+            // earlier ones so the retry stops at the first entry which fails again
+            loop {
+                let entries =
+                    read_entries(&store, &stream_name, "-", "+", Some(RETRY_BATCH_SIZE)).await?;
+                if entries.is_empty() {
+                    println!(
+                        "All the entries of the dead-letter stream {stream_name} were retried"
+                    );
+                    return Ok(());
+                }
+                for (entry_id, entry) in entries {
+                    if !retry_entry(&store, &stream_name, &entry_id, entry).await? {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        DeadLetterAction::Discard {
+            partition,
+            entry_id,
+        } => {
+            let stream_name = get_stream_name(&store, partition)?;
+            let deleted = delete_entry(&store, &stream_name, &entry_id).await?;
+            if deleted == 0 {
+                Err(errors::DrainerError::InvalidCommand(format!(
+                    "entry {entry_id} does not exist in the dead-letter stream {stream_name}"
+                )))
+                .into_report()
+            } else {
+                println!("Discarded entry {entry_id}");
+                Ok(())
+            }
+        }
+    }
+}
+
+fn get_stream_name(store: &Store, partition: u8) -> errors::DrainerResult<String> {
+    if partition >= store.config.drainer_num_partitions {
+        return Err(errors::DrainerError::InvalidCommand(format!(
+            "partition should be less than {}",
+            store.config.drainer_num_partitions
+        )))
+        .into_report();
+    }
+    Ok(store.get_dead_letter_stream_name(partition))
+}
+
+async fn read_entries(
+    store: &Store,
+    stream_name: &str,
+    start: &str,
+    end: &str,
+    count: Option<u64>,
+) -> errors::DrainerResult<Vec<(String, HashMap<String, String>)>> {
+    store
+        .redis_conn
+        .stream_read_range(stream_name, start, end, count)
+        .await
+        .map_err(errors::DrainerError::from)
+        .into_report()
+}
+
+async fn delete_entry(
+    store: &Store,
+    stream_name: &str,
+    entry_id: &str,
+) -> errors::DrainerResult<usize> {
+    store
+        .redis_conn
+        .stream_delete_entries(stream_name, entry_id)
+        .await
+        .map_err(errors::DrainerError::from)
+        .into_report()
+}
+
+/// Executes the query of the entry again and removes the entry if it succeeds, returns whether
+/// the entry was removed
+async fn retry_entry(
+    store: &Arc<Store>,
+    stream_name: &str,
+    entry_id: &str,
+    entry: HashMap<String, String>,
+) -> errors::DrainerResult<bool> {
+    let data = match StreamData::from_hashmap(entry) {
+        Ok(data) => data,
+        Err(err) => {
+            println!("Entry {entry_id} could not be parsed: {err:?}");
+            return Ok(false);
+        }
+    };
+
+    // The entry is no longer delayed by the drainer, the time it was pushed at is not used so
+    // that the drainer delay metric is not skewed
+    let retried_at = common_utils::date_time::now_unix_timestamp();
+    match data.typed_sql.execute_query(store, retried_at).await {
+        Ok(()) => {}
+        // The drainer treats unique violations as already applied, the entry is removed the same
+        Err(err)
+            if matches!(
+                err.current_context(),
+                diesel_models::errors::DatabaseError::UniqueViolation
+            ) => {}
+        Err(err) => {
+            println!("Retry of entry {entry_id} failed: {err:?}");
+            return Ok(false);
+        }
+    }
+
+    delete_entry(store, stream_name, entry_id).await?;
+    println!("Retried entry {entry_id}");
+    Ok(true)
+}
+
+fn describe_entry(entry_id: &str, mut entry: HashMap<String, String>) -> serde_json::Value {
+    let source_entry_id = entry.remove(DEAD_LETTER_SOURCE_ENTRY_ID);
+    let error = entry.remove(DEAD_LETTER_ERROR);
+    let failed_at = entry.remove(DEAD_LETTER_FAILED_AT);
+    let request_id = entry.get("request_id").cloned();
+    let global_id = entry.get("global_id").cloned();
+    let (operation, table) = StreamData::from_hashmap(entry)
+        .map(|data| (data.typed_sql.operation(), data.typed_sql.table()))
+        .map_or((None, None), |(operation, table)| {
+            (Some(operation), Some(table))
+        });
+
+    serde_json::json!({
+        "entry_id": entry_id,
+        "source_entry_id": source_entry_id,
+        "request_id": request_id,
+        "global_id": global_id,
+        "operation": operation,
+        "table": table,
+        "failed_at": failed_at,
+        "error": error,
+    })
+}
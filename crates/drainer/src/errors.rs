@@ -15,6 +15,8 @@ pub enum DrainerError {
     ParsingError(error_stack::Report<common_utils::errors::ParsingError>),
     #[error("Unexpected error occurred: {0}")]
     UnexpectedError(String),
+    #[error("Invalid command: {0}")]
+    InvalidCommand(String),
 }
 
 pub type DrainerResult<T> = error_stack::Result<T, DrainerError>;
@@ -1,4 +1,7 @@
-use std::sync::{atomic, Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic, Arc},
+};
 
 use tokio::{
     sync::{mpsc, oneshot},
@@ -6,8 +9,10 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    batch::{BatchConfig, InsertBatch},
+    errors, instrument, kv, logger, metrics,
+    query::{self, ExecuteQuery},
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -28,6 +33,7 @@ impl std::ops::Deref for Handler {
 pub struct HandlerInner {
     shutdown_interval: Duration,
     loop_interval: Duration,
+    batch_config: BatchConfig,
     active_tasks: Arc<atomic::AtomicU64>,
     conf: DrainerSettings,
     store: Arc<Store>,
@@ -38,6 +44,10 @@ impl Handler {
     pub fn from_conf(conf: DrainerSettings, store: Arc<Store>) -> Self {
         let shutdown_interval = Duration::from_millis(conf.shutdown_interval.into());
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
+        let batch_config = BatchConfig {
+            max_batch_size: usize::try_from(conf.max_batch_size).unwrap_or(usize::MAX),
+            flush_interval: Duration::from_millis(conf.batch_flush_interval.into()),
+        };
 
         let active_tasks = Arc::new(atomic::AtomicU64::new(0));
 
@@ -46,6 +56,7 @@ impl Handler {
         let handler = HandlerInner {
             shutdown_interval,
             loop_interval,
+            batch_config,
             active_tasks,
             conf,
             store,
@@ -72,6 +83,7 @@ impl Handler {
                     self.store.clone(),
                     stream_index,
                     self.conf.max_read_count,
+                    self.batch_config,
                     self.active_tasks.clone(),
                     jobs_picked.clone(),
                 ));
@@ -144,17 +156,21 @@ async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
     max_read_count: u64,
+    batch_config: BatchConfig,
     active_tasks: Arc<atomic::AtomicU64>,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = store.get_drainer_stream_name(stream_index);
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(stream_index);
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
+        batch_config,
         stream_name.as_str(),
+        dead_letter_stream_name.as_str(),
         jobs_picked,
     ))
     .await;
@@ -177,7 +193,9 @@ async fn drainer_handler(
 async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
+    batch_config: BatchConfig,
     stream_name: &str,
+    dead_letter_stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
     let stream_read = match store.read_from_stream(stream_name, max_read_count).await {
@@ -215,45 +233,88 @@ async fn drainer(
     );
 
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
+    tracing::Span::current().record("session_id", &session_id);
 
     let mut last_processed_id = String::new();
+    let mut interrupted = false;
+
+    // Consecutive inserts into the same table are grouped and executed together. A batch is
+    // always executed before the entry which follows it, so the entries of a stream (and hence
+    // of a partition) are drained in the order they were added
+    let mut batch = InsertBatch::default();
 
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
-            Err(err) => {
-                logger::error!(operation = "deserialization", err=?err);
-                metrics::STREAM_PARSE_FAIL.add(
-                    &metrics::CONTEXT,
-                    1,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: "deserialization".into(),
-                    }],
-                );
-
-                // break from the loop in case of a deser error
-                break;
+        let data = StreamData::from_hashmap(entry.clone());
+
+        if let Ok(data) = &data {
+            tracing::Span::current().record("request_id", data.request_id.as_str());
+            tracing::Span::current().record("global_id", data.global_id.as_str());
+        }
+
+        let fits_in_batch = data.as_ref().map_or(false, |data| {
+            batch.accepts(&data.typed_sql, batch_config.max_batch_size)
+        });
+
+        if !batch.is_empty()
+            && !fits_in_batch
+            && !drain_batch(
+                &store,
+                dead_letter_stream_name,
+                std::mem::take(&mut batch),
+                &mut last_processed_id,
+            )
+            .await
+        {
+            interrupted = true;
+            break;
+        }
+
+        let data = match data {
+            Ok(data) => {
+                let table = data.typed_sql.table();
+                match data.typed_sql {
+                    kv::DBOperation::Insert { insertable } => {
+                        batch.push((entry_id, entry), table, insertable, data.pushed_at);
+                        continue;
+                    }
+                    typed_sql @ (kv::DBOperation::Update { .. }
+                    | kv::DBOperation::Delete { .. }) => Ok(StreamData { typed_sql, ..data }),
+                }
             }
+            Err(err) => Err(err),
         };
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-        tracing::Span::current().record("session_id", &session_id);
+        if drain_entry(&store, dead_letter_stream_name, &entry_id, entry, data).await {
+            last_processed_id = entry_id;
+        } else {
+            interrupted = true;
+            break;
+        }
+    }
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
-            }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
-                }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+    // The inserts at the end of the stream are held back until the batch is full or its oldest
+    // entry is older than the flush interval, more inserts into the same table may be added to
+    // the stream by then
+    let mut deferred_count = 0;
+    if !interrupted && !batch.is_empty() {
+        if batch.is_due(&batch_config) {
+            drain_batch(
+                &store,
+                dead_letter_stream_name,
+                batch,
+                &mut last_processed_id,
+            )
+            .await;
+        } else {
+            deferred_count = batch.len();
+            metrics::BATCHES_DEFERRED.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue {
+                    key: "table".into(),
+                    value: batch.table().into(),
+                }],
+            );
         }
     }
 
@@ -261,17 +322,241 @@ async fn drainer(
         let entries_trimmed = store
             .trim_from_stream(stream_name, &last_processed_id)
             .await?;
-        if read_count != entries_trimmed {
+        if read_count.saturating_sub(deferred_count) != entries_trimmed {
             logger::error!(
                 read_entries = %read_count,
+                deferred_entries = %deferred_count,
                 trimmed_entries = %entries_trimmed,
                 ?entries,
                 "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
             );
         }
-    } else {
+    } else if deferred_count != read_count {
         logger::error!(read_entries = %read_count,?entries,"No streams were processed in this session");
     }
 
     Ok(())
 }
+
+/// Executes the inserts of a batch as a single multi-row statement. Returns whether all the
+/// entries of the batch were drained, draining has to stop otherwise so that the entries are
+/// retried later in the same order
+async fn drain_batch(
+    store: &Arc<Store>,
+    dead_letter_stream_name: &str,
+    batch: InsertBatch,
+    last_processed_id: &mut String,
+) -> bool {
+    let table = batch.table();
+    let (mut entries, insertables, pushed_at) = batch.into_parts();
+
+    let err = match query::execute_batch_query(store, table, insertables, &pushed_at).await {
+        Ok(()) => {
+            if let Some((entry_id, _)) = entries.pop() {
+                *last_processed_id = entry_id;
+            }
+            return true;
+        }
+        Err(err) => err,
+    };
+
+    if is_transient_error(&err) {
+        return false;
+    }
+
+    if entries.len() == 1 {
+        if let Some((entry_id, entry)) = entries.pop() {
+            let drained =
+                handle_query_error(store, dead_letter_stream_name, &entry_id, entry, err).await;
+            if drained {
+                *last_processed_id = entry_id;
+            }
+            return drained;
+        }
+    }
+
+    // The whole statement fails because of a single insert, execute the entries one at a time
+    // so that only the failing ones are skipped or moved to the dead-letter stream
+    metrics::BATCH_FALLBACKS.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue {
+            key: "table".into(),
+            value: table.into(),
+        }],
+    );
+
+    for (entry_id, entry) in entries {
+        let data = StreamData::from_hashmap(entry.clone());
+        if drain_entry(store, dead_letter_stream_name, &entry_id, entry, data).await {
+            *last_processed_id = entry_id;
+        } else {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Executes the query of a single entry. Returns whether the entry was drained, draining has to
+/// stop otherwise so that the entry is retried later
+async fn drain_entry(
+    store: &Arc<Store>,
+    dead_letter_stream_name: &str,
+    entry_id: &str,
+    entry: HashMap<String, String>,
+    data: common_utils::errors::CustomResult<StreamData, common_utils::errors::ParsingError>,
+) -> bool {
+    let data = match data {
+        Ok(data) => data,
+        Err(err) => {
+            logger::error!(operation = "deserialization", err=?err);
+            metrics::STREAM_PARSE_FAIL.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: "deserialization".into(),
+                }],
+            );
+
+            // An entry which can't be parsed would block the stream forever, move it to the
+            // dead-letter stream
+            return move_to_dead_letter_stream(
+                store,
+                dead_letter_stream_name,
+                entry_id,
+                entry,
+                format!("{err:?}"),
+            )
+            .await;
+        }
+    };
+
+    match data.typed_sql.execute_query(store, data.pushed_at).await {
+        Ok(_) => true,
+        Err(err) => handle_query_error(store, dead_letter_stream_name, entry_id, entry, err).await,
+    }
+}
+
+async fn handle_query_error(
+    store: &Arc<Store>,
+    dead_letter_stream_name: &str,
+    entry_id: &str,
+    entry: HashMap<String, String>,
+    err: error_stack::Report<diesel_models::errors::DatabaseError>,
+) -> bool {
+    match err.current_context() {
+        // In case of Uniqueviolation we can't really do anything to fix it so just clear
+        // it from the stream
+        diesel_models::errors::DatabaseError::UniqueViolation => true,
+        // The query may succeed later, stop draining so that the entry and the ones behind it
+        // are retried in order. Skipping it would let a later retry overwrite newer state
+        _ if is_transient_error(&err) => false,
+        // The query would fail again on every retry, move the entry to the dead-letter stream
+        // so that the entries behind it are drained
+        _ => {
+            move_to_dead_letter_stream(
+                store,
+                dead_letter_stream_name,
+                entry_id,
+                entry,
+                format!("{err:?}"),
+            )
+            .await
+        }
+    }
+}
+
+/// Errors which are not caused by the query itself: pool errors, closed connections and
+/// serialization failures. Every other database error is reported by diesel as `Others` as well,
+/// so the underlying diesel error is inspected rather than the context
+fn is_transient_error(error: &error_stack::Report<diesel_models::errors::DatabaseError>) -> bool {
+    match error.current_context() {
+        diesel_models::errors::DatabaseError::DatabaseConnectionError => true,
+        diesel_models::errors::DatabaseError::Others => error
+            .downcast_ref::<diesel::result::Error>()
+            .map_or(false, |error| {
+                matches!(
+                    error,
+                    diesel::result::Error::DatabaseError(
+                        diesel::result::DatabaseErrorKind::ClosedConnection
+                            | diesel::result::DatabaseErrorKind::UnableToSendCommand
+                            | diesel::result::DatabaseErrorKind::SerializationFailure,
+                        _,
+                    ) | diesel::result::Error::BrokenTransactionManager
+                )
+            }),
+        diesel_models::errors::DatabaseError::NotFound
+        | diesel_models::errors::DatabaseError::UniqueViolation
+        | diesel_models::errors::DatabaseError::NoFieldsToUpdate
+        | diesel_models::errors::DatabaseError::QueryGenerationFailed => false,
+    }
+}
+
+/// Returns whether the entry was moved, it is retried later otherwise
+async fn move_to_dead_letter_stream(
+    store: &Arc<Store>,
+    dead_letter_stream_name: &str,
+    entry_id: &str,
+    entry: HashMap<String, String>,
+    error: String,
+) -> bool {
+    match store
+        .push_to_dead_letter_stream(dead_letter_stream_name, entry_id, entry, error)
+        .await
+    {
+        Ok(()) => true,
+        Err(error) => {
+            logger::error!(operation = "dead_letter", err=?error);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+    use diesel_models::errors::DatabaseError;
+    use error_stack::report;
+
+    use super::*;
+
+    fn query_error(kind: DatabaseErrorKind) -> error_stack::Report<DatabaseError> {
+        report!(DieselError::DatabaseError(
+            kind,
+            Box::new("error".to_string())
+        ))
+        .change_context(DatabaseError::Others)
+    }
+
+    #[test]
+    fn test_connection_and_serialization_errors_are_transient() {
+        assert!(is_transient_error(&report!(
+            DatabaseError::DatabaseConnectionError
+        )));
+        assert!(is_transient_error(&query_error(
+            DatabaseErrorKind::ClosedConnection
+        )));
+        assert!(is_transient_error(&query_error(
+            DatabaseErrorKind::SerializationFailure
+        )));
+    }
+
+    #[test]
+    fn test_constraint_violations_are_not_transient() {
+        assert!(!is_transient_error(&query_error(
+            DatabaseErrorKind::ForeignKeyViolation
+        )));
+        assert!(!is_transient_error(&query_error(
+            DatabaseErrorKind::NotNullViolation
+        )));
+        assert!(!is_transient_error(&query_error(
+            DatabaseErrorKind::CheckViolation
+        )));
+        assert!(!is_transient_error(&query_error(
+            DatabaseErrorKind::Unknown
+        )));
+        assert!(!is_transient_error(&report!(DatabaseError::Others)));
+    }
+}
@@ -1,4 +1,6 @@
+mod batch;
 mod connection;
+pub mod dead_letter;
 pub mod errors;
 mod handler;
 pub mod logger;
@@ -1,4 +1,6 @@
-use drainer::{errors::DrainerResult, logger::logger, services, settings, start_drainer};
+use drainer::{
+    dead_letter, errors::DrainerResult, logger::logger, services, settings, start_drainer,
+};
 
 #[tokio::main]
 async fn main() -> DrainerResult<()> {
@@ -24,6 +26,10 @@ async fn main() -> DrainerResult<()> {
         [router_env::service_name!()],
     );
 
+    if let Some(settings::Subcommand::DeadLetter { action }) = cmd_line.subcommand {
+        return dead_letter::run(store, action).await;
+    }
+
     logger::debug!(startup_config=?conf);
     logger::info!("Drainer started [{:?}] [{:?}]", conf.drainer, conf.log);
 
@@ -21,7 +21,7 @@ impl ExecuteQuery for kv::DBOperation {
         store: &Arc<Store>,
         pushed_at: i64,
     ) -> CustomResult<(), DatabaseError> {
-        let conn = pg_connection(&store.master_pool).await;
+        let conn = pg_connection(&store.master_pool).await?;
         let operation = self.operation();
         let table = self.table();
 
@@ -57,6 +57,54 @@ impl ExecuteQuery for kv::DBOperation {
     }
 }
 
+/// Executes consecutive inserts into the same table as a single multi-row statement
+pub async fn execute_batch_query(
+    store: &Arc<Store>,
+    table: &str,
+    insertables: Vec<kv::Insertable>,
+    pushed_at: &[i64],
+) -> CustomResult<(), DatabaseError> {
+    let conn = pg_connection(&store.master_pool).await?;
+    let operation = "batch_insert";
+    let batch_size = u64::try_from(insertables.len()).unwrap_or(u64::MAX);
+
+    let tags: &[metrics::KeyValue] = &[
+        metrics::KeyValue {
+            key: "operation".into(),
+            value: operation.into(),
+        },
+        metrics::KeyValue {
+            key: "table".into(),
+            value: table.to_owned().into(),
+        },
+    ];
+
+    let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+        kv::Insertable::insert_batch(insertables, &conn)
+    }))
+    .await;
+
+    for pushed_at in pushed_at {
+        push_drainer_delay(*pushed_at, operation, table, tags);
+    }
+    metrics::QUERY_EXECUTION_TIME.record(&metrics::CONTEXT, execution_time, tags);
+    metrics::BATCH_SIZE.record(&metrics::CONTEXT, batch_size, tags);
+
+    match result {
+        Ok(()) => {
+            logger::info!(operation = operation, table = table, batch_size);
+            metrics::SUCCESSFUL_QUERY_EXECUTION.add(&metrics::CONTEXT, 1, tags);
+            metrics::BATCHED_ENTRIES.add(&metrics::CONTEXT, batch_size, tags);
+            Ok(())
+        }
+        Err(err) => {
+            logger::error!(operation = operation, table = table, ?err);
+            metrics::ERRORS_WHILE_QUERY_EXECUTION.add(&metrics::CONTEXT, 1, tags);
+            Err(err)
+        }
+    }
+}
+
 #[inline(always)]
 fn push_drainer_delay(pushed_at: i64, operation: &str, table: &str, tags: &[metrics::KeyValue]) {
     let drained_at = common_utils::date_time::now_unix_timestamp();
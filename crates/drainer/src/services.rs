@@ -13,6 +13,7 @@ pub struct Store {
 #[derive(Clone)]
 pub struct StoreConfig {
     pub drainer_stream_name: String,
+    pub drainer_dead_letter_stream_name: String,
     pub drainer_num_partitions: u8,
 }
 
@@ -39,6 +40,7 @@ impl Store {
             redis_conn: Arc::new(crate::connection::redis_connection(config).await),
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
+                drainer_dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
             },
             request_id: None,
@@ -25,6 +25,51 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+    #[command(subcommand)]
+    pub subcommand: Option<Subcommand>,
+}
+
+#[derive(clap::Subcommand)]
+pub enum Subcommand {
+    /// Inspect, retry or discard the entries in the dead-letter stream of a partition
+    DeadLetter {
+        #[command(subcommand)]
+        action: DeadLetterAction,
+    },
+}
+
+#[derive(clap::Subcommand)]
+pub enum DeadLetterAction {
+    /// List the entries in the dead-letter stream, oldest first
+    List {
+        /// The partition whose dead-letter stream is read
+        #[arg(long)]
+        partition: u8,
+        /// Maximum number of entries to list
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+    },
+    /// Execute the queries of dead-lettered entries again, removing the entries which succeed
+    Retry {
+        /// The partition whose dead-letter stream is read
+        #[arg(long)]
+        partition: u8,
+        /// Id of the entry to retry
+        #[arg(long, required_unless_present = "all")]
+        entry_id: Option<String>,
+        /// Retry all the entries in order, stopping at the first one which fails again
+        #[arg(long, conflicts_with = "entry_id")]
+        all: bool,
+    },
+    /// Remove an entry from the dead-letter stream without executing its query
+    Discard {
+        /// The partition whose dead-letter stream is read
+        #[arg(long)]
+        partition: u8,
+        /// Id of the entry to discard
+        #[arg(long)]
+        entry_id: String,
+    },
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -65,10 +110,13 @@ pub struct Database {
 #[serde(default)]
 pub struct DrainerSettings {
     pub stream_name: String,
+    pub dead_letter_stream_name: String,
     pub num_partitions: u8,
     pub max_read_count: u64,
-    pub shutdown_interval: u32, // in milliseconds
-    pub loop_interval: u32,     // in milliseconds
+    pub max_batch_size: u32,
+    pub batch_flush_interval: u32, // in milliseconds
+    pub shutdown_interval: u32,    // in milliseconds
+    pub loop_interval: u32,        // in milliseconds
 }
 
 impl Default for CacheSettings {
@@ -99,10 +147,13 @@ impl Default for DrainerSettings {
     fn default() -> Self {
         Self {
             stream_name: "DRAINER_STREAM".into(),
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
             num_partitions: 64,
             max_read_count: 100,
-            shutdown_interval: 1000, // in milliseconds
-            loop_interval: 100,      // in milliseconds
+            max_batch_size: 50,
+            batch_flush_interval: 100, // in milliseconds
+            shutdown_interval: 1000,   // in milliseconds
+            loop_interval: 100,        // in milliseconds
         }
     }
 }
@@ -139,10 +190,30 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead-letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.dead_letter_stream_name == self.stream_name, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead-letter stream name must differ from the stream name".into(),
+            ))
+        })?;
+
+        when(self.max_batch_size == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max batch size must be greater than zero".into(),
+            ))
         })
     }
 }
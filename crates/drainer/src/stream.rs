@@ -9,6 +9,11 @@ use crate::{errors, metrics, Store};
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
+/// Fields added to an entry when it is moved to the dead-letter stream
+pub const DEAD_LETTER_SOURCE_ENTRY_ID: &str = "source_entry_id";
+pub const DEAD_LETTER_ERROR: &str = "error";
+pub const DEAD_LETTER_FAILED_AT: &str = "failed_at";
+
 impl Store {
     #[inline(always)]
     pub fn drainer_stream(&self, shard_key: &str) -> String {
@@ -16,6 +21,21 @@ impl Store {
         format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
     }
 
+    #[inline(always)]
+    pub fn dead_letter_stream(&self, shard_key: &str) -> String {
+        // Example: {shard_5}_drainer_dead_letter_stream
+        // The shard key is hashed the same as the drainer stream, keeping both in the same slot
+        format!(
+            "{{{}}}_{}",
+            shard_key, self.config.drainer_dead_letter_stream_name,
+        )
+    }
+
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_index: u8) -> String {
+        self.dead_letter_stream(format!("shard_{stream_index}").as_str())
+    }
+
     #[inline(always)]
     pub(crate) fn get_stream_key_flag(&self, stream_index: u8) -> String {
         format!("{}_in_use", self.get_drainer_stream_name(stream_index))
@@ -78,6 +98,48 @@ impl Store {
 
         output
     }
+
+    /// Moves an entry whose query could not be executed to the dead-letter stream, along with the
+    /// error and the id it had in the drainer stream
+    pub async fn push_to_dead_letter_stream(
+        &self,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        error: String,
+    ) -> errors::DrainerResult<()> {
+        let mut fields: Vec<(String, String)> = entry.into_iter().collect();
+        fields.extend([
+            (DEAD_LETTER_SOURCE_ENTRY_ID.to_owned(), entry_id.to_owned()),
+            (DEAD_LETTER_ERROR.to_owned(), error),
+            (
+                DEAD_LETTER_FAILED_AT.to_owned(),
+                common_utils::date_time::now_unix_timestamp().to_string(),
+            ),
+        ]);
+
+        self.redis_conn
+            .stream_append_entry(
+                dead_letter_stream_name,
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)
+            .into_report()?;
+
+        metrics::DEAD_LETTERED_ENTRIES.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::KeyValue::new(
+                "stream",
+                dead_letter_stream_name.to_owned(),
+            )],
+        );
+
+        Ok(())
+    }
+
     pub async fn trim_from_stream(
         &self,
         stream_name: &str,
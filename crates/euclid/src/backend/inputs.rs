@@ -1,7 +1,9 @@
+use chrono::{Datelike, NaiveDateTime, TimeZone, Timelike};
+use chrono_tz::Tz;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
-use crate::enums;
+use crate::{enums, types};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MandateData {
@@ -28,6 +30,35 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    /// Unix timestamp, in seconds, at which the payment was made
+    #[serde(default)]
+    pub transaction_time: Option<i64>,
+}
+
+/// The local time of a payment, in the timezone of the program it is evaluated against.
+#[derive(Debug, Clone)]
+pub struct LocalTransactionTime {
+    pub hour: i64,
+    pub weekday: enums::Weekday,
+    pub date: i64,
+}
+
+impl PaymentInput {
+    pub fn get_local_transaction_time(
+        &self,
+        timezone: Option<&Tz>,
+    ) -> Option<LocalTransactionTime> {
+        let utc_time = NaiveDateTime::from_timestamp_opt(self.transaction_time?, 0)?;
+        let local_time = timezone
+            .unwrap_or(&chrono_tz::UTC)
+            .from_utc_datetime(&utc_time);
+
+        Some(LocalTransactionTime {
+            hour: i64::from(local_time.hour()),
+            weekday: local_time.weekday().into(),
+            date: types::date_to_number(local_time.date_naive()),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,12 +1,16 @@
 pub mod types;
 
+use chrono_tz::Tz;
+
 use crate::{
     backend::{self, inputs, EuclidBackend},
     frontend::ast,
+    types as euclid_types,
 };
 
 pub struct InterpreterBackend<O> {
     program: ast::Program<O>,
+    timezone: Option<Tz>,
 }
 
 impl<O> InterpreterBackend<O>
@@ -170,11 +174,22 @@ where
     type Error = types::InterpreterError;
 
     fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
-        Ok(Self { program })
+        let timezone = program
+            .timezone
+            .as_deref()
+            .map(|timezone| {
+                euclid_types::parse_timezone(timezone).ok_or_else(|| types::InterpreterError {
+                    error_type: types::InterpreterErrorType::InvalidTimezone(timezone.to_string()),
+                    metadata: program.metadata.clone(),
+                })
+            })
+            .transpose()?;
+
+        Ok(Self { program, timezone })
     }
 
     fn execute(&self, input: inputs::BackendInput) -> Result<super::BackendOutput<O>, Self::Error> {
-        let ctx: types::Context = input.into();
+        let ctx = types::Context::from_input(input, self.timezone.as_ref());
         Self::eval_program(&self.program, &ctx)
     }
 }
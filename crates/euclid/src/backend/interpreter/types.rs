@@ -1,5 +1,6 @@
 use std::{collections::HashMap, fmt, ops::Deref, string::ToString};
 
+use chrono_tz::Tz;
 use serde::Serialize;
 
 use crate::{backend::inputs, frontend::ast::ValueType, types::EuclidKey};
@@ -11,6 +12,8 @@ pub enum InterpreterErrorType {
     InvalidKey(String),
     #[error("Invalid Comparison")]
     InvalidComparison,
+    #[error("Invalid timezone '{0}'")]
+    InvalidTimezone(String),
 }
 
 #[derive(Debug, Clone, Serialize, thiserror::Error)]
@@ -35,8 +38,9 @@ impl Deref for Context {
     }
 }
 
-impl From<inputs::BackendInput> for Context {
-    fn from(input: inputs::BackendInput) -> Self {
+impl Context {
+    pub fn from_input(input: inputs::BackendInput, timezone: Option<&Tz>) -> Self {
+        let transaction_time = input.payment.get_local_transaction_time(timezone);
         let ctx = HashMap::<String, Option<ValueType>>::from_iter([
             (
                 EuclidKey::PaymentMethod.to_string(),
@@ -74,6 +78,22 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::TransactionHour.to_string(),
+                transaction_time
+                    .as_ref()
+                    .map(|time| ValueType::Number(time.hour)),
+            ),
+            (
+                EuclidKey::TransactionWeekday.to_string(),
+                transaction_time
+                    .as_ref()
+                    .map(|time| ValueType::EnumVariant(time.weekday.to_string())),
+            ),
+            (
+                EuclidKey::TransactionDate.to_string(),
+                transaction_time.map(|time| ValueType::Number(time.date)),
+            ),
         ]);
 
         Self(ctx)
@@ -93,7 +93,7 @@ where
         &self,
         input: inputs::BackendInput,
     ) -> Result<backend::BackendOutput<O>, Self::Error> {
-        let ctx = types::Context::from_input(input);
+        let ctx = types::Context::from_input(input, self.program.timezone.as_ref());
         Ok(Self::eval_program(&self.program, &ctx))
     }
 }
@@ -134,6 +134,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -174,6 +175,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -215,6 +217,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -256,6 +259,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -297,6 +301,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -338,6 +343,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -379,6 +385,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -420,6 +427,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -463,6 +471,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -504,6 +513,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -554,6 +564,7 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                transaction_time: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
@@ -580,4 +591,75 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_transaction_time_conditions() {
+        let program_str = r#"
+        timezone: "Asia/Kolkata"
+        default: ["stripe"]
+
+        holiday: ["checkout"]
+        {
+            transaction_date >= 20261225 & transaction_date <= 20261226
+        }
+
+        overnight: ["adyen"]
+        {
+            transaction_hour = (>=22, <6)
+        }
+
+        weekend: ["paypal"]
+        {
+            transaction_weekday = (saturday, sunday)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: 120,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+                transaction_time: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: None,
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+
+        // Times are in UTC, and are 5:30 hours behind the timezone of the program
+        let cases = [
+            // 2026-12-24 20:00, which is past midnight on 2026-12-25 in the program's timezone
+            (Some(1798142400), Some("holiday")),
+            // Saturday, 2026-10-17 18:00
+            (Some(1792260000), Some("overnight")),
+            // Saturday, 2026-10-17 08:00
+            (Some(1792224000), Some("weekend")),
+            // Monday, 2026-10-19 08:00
+            (Some(1792396800), None),
+            (None, None),
+        ];
+
+        for (transaction_time, expected_rule) in cases {
+            let mut inp = inp.clone();
+            inp.payment.transaction_time = transaction_time;
+            let result = backend.execute(inp).expect("Execution");
+            assert_eq!(result.rule_name.as_deref(), expected_rule);
+        }
+    }
 }
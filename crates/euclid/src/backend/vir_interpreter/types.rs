@@ -1,3 +1,4 @@
+use chrono_tz::Tz;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -51,7 +52,8 @@ impl Context {
         }
     }
 
-    pub fn from_input(input: BackendInput) -> Self {
+    pub fn from_input(input: BackendInput, timezone: Option<&Tz>) -> Self {
+        let transaction_time = input.payment.get_local_transaction_time(timezone);
         let payment = input.payment;
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
@@ -114,7 +116,7 @@ impl Context {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -122,6 +124,24 @@ impl Context {
             }),
         )]);
 
+        if let Some(transaction_time) = transaction_time {
+            enum_values.insert(EuclidValue::TransactionWeekday(transaction_time.weekday));
+            numeric_values.insert(
+                EuclidKey::TransactionHour,
+                EuclidValue::TransactionHour(types::NumValue {
+                    number: transaction_time.hour,
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::TransactionDate,
+                EuclidValue::TransactionDate(types::NumValue {
+                    number: transaction_time.date,
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
@@ -1,5 +1,6 @@
 //! Domain Specific Static Analyzer
 pub mod analyzer;
+pub mod coverage;
 pub mod graph;
 pub mod state_machine;
 pub mod truth;
@@ -14,7 +14,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValue, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -136,7 +136,71 @@ fn analyze_negated_assertions(
     Ok(())
 }
 
-fn perform_condition_analyses(
+/// Narrows down the range of values that a number key can take to the bounds implied by the
+/// given values. Inequalities are left out, since they cannot be represented as a single range.
+/// The returned lower bound is greater than the upper bound if no value satisfies all of them.
+pub fn get_num_bounds(
+    key: &dir::DirKeyKind,
+    values: impl IntoIterator<Item = NumValue>,
+) -> (i64, i64) {
+    let (mut lower, mut upper) = key
+        .get_num_range()
+        .map_or((i64::MIN, i64::MAX), |range| (*range.start(), *range.end()));
+
+    for num_value in values {
+        let number = num_value.number;
+
+        match num_value.refinement {
+            None => {
+                lower = lower.max(number);
+                upper = upper.min(number);
+            }
+            Some(NumValueRefinement::GreaterThan) => lower = lower.max(number.saturating_add(1)),
+            Some(NumValueRefinement::GreaterThanEqual) => lower = lower.max(number),
+            Some(NumValueRefinement::LessThan) => upper = upper.min(number.saturating_sub(1)),
+            Some(NumValueRefinement::LessThanEqual) => upper = upper.min(number),
+            Some(NumValueRefinement::NotEqual) => {}
+        }
+    }
+
+    (lower, upper)
+}
+
+/// Analyses assertions on a number key in a conjunctive context that leave no value for the key.
+///
+/// For example,
+/// ```notrust
+/// transaction_hour >= 22 && ... && transaction_hour < 6
+/// ```notrust
+/// This is a condition that will never evaluate to `true`, since no hour of the day is both at
+/// or after 22 and before 6. The range of values that the key can take, such as 0 to 23 for
+/// `transaction_hour`, is taken into account, so that `transaction_hour > 23` is caught as well.
+pub fn analyze_unsatisfiable_ranges(
+    keywise_number_assertions: &FxHashMap<dir::DirKey, Vec<(&dir::DirValue, &Metadata)>>,
+) -> Result<(), types::AnalysisError> {
+    for (key, assertions) in keywise_number_assertions {
+        let (lower, upper) = get_num_bounds(
+            &key.kind,
+            assertions.iter().filter_map(|(val, _)| val.get_num_value()),
+        );
+
+        if lower > upper {
+            Err(types::AnalysisError {
+                error_type: types::AnalysisErrorType::UnsatisfiableRange {
+                    key: key.clone(),
+                    metadata: assertions
+                        .iter()
+                        .map(|(_, metadata)| (*metadata).clone())
+                        .collect(),
+                },
+                metadata: Default::default(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn perform_condition_analyses(
     context: &types::ConjunctiveContext<'_>,
 ) -> Result<(), types::AnalysisError> {
     let mut assertion_metadata: FxHashMap<&dir::DirValue, &Metadata> = FxHashMap::default();
@@ -147,6 +211,8 @@ fn perform_condition_analyses(
         FxHashMap::default();
     let mut keywise_negations: FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>> =
         FxHashMap::default();
+    let mut keywise_number_assertions: FxHashMap<dir::DirKey, Vec<(&dir::DirValue, &Metadata)>> =
+        FxHashMap::default();
 
     for ctx_val in context {
         let key = if let Some(k) = ctx_val.value.get_key() {
@@ -159,6 +225,15 @@ fn perform_condition_analyses(
             continue;
         }
 
+        if matches!(key.kind.get_type(), DataType::Number) {
+            if let types::CtxValueKind::Assertion(val) = ctx_val.value {
+                keywise_number_assertions
+                    .entry(key.clone())
+                    .or_default()
+                    .push((val, ctx_val.metadata));
+            }
+        }
+
         if !matches!(key.kind.get_type(), DataType::EnumVariant) {
             continue;
         }
@@ -197,6 +272,7 @@ fn perform_condition_analyses(
         &keywise_negations,
         &negation_metadata,
     )?;
+    analyze_unsatisfiable_ranges(&keywise_number_assertions)?;
 
     Ok(())
 }
@@ -366,6 +442,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unsatisfiable_range_detection() {
+        let program_strs = [
+            r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                transaction_hour = (>=22, <6) {
+                    transaction_weekday = saturday & transaction_hour > 5 & transaction_hour < 22
+                }
+            }
+            "#,
+            r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                transaction_hour > 23
+            }
+            "#,
+        ];
+
+        for program_str in program_strs {
+            let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+            let analysis_result = analyze(program, None);
+
+            if let Err(types::AnalysisError {
+                error_type: types::AnalysisErrorType::UnsatisfiableRange { key, .. },
+                ..
+            }) = analysis_result
+            {
+                assert!(
+                    matches!(key.kind, dir::DirKeyKind::TransactionHour),
+                    "Expected key to be transaction_hour"
+                );
+            } else {
+                panic!("Expected unsatisfiable range error");
+            }
+        }
+
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                transaction_hour = (>=22, <6) & transaction_weekday = (saturday, sunday)
+                transaction_date >= 20261224 & transaction_date <= 20261226
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        assert!(analyze(program, None).is_ok(), "Expected analysis to pass");
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {crate
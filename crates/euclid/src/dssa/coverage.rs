@@ -0,0 +1,504 @@
+//! Coverage Analysis for the Euclid Rule DSL
+//!
+//! Reports, for a whole program, the rules that can never be selected, the rules that are
+//! shadowed by earlier rules, and the values of the input that fall through to the default
+//! selection of the program. Unlike the [`analyzer`](super::analyzer), the findings are collected
+//! into a report instead of failing the analysis, since a program with such findings is still a
+//! valid program.
+//!
+//! A rule is made up of one conjunctive context for every path through its statements. A rule is
+//! shadowed if each of its contexts is covered by a context of an earlier rule, since the earlier
+//! rules are evaluated first and are selected for every input that the shadowed rule would match.
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{analyzer, graph::Memoization, types::EuclidAnalysable};
+use crate::{
+    dssa::{graph, state_machine, truth, types},
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+    },
+    types::{DataType, Metadata},
+};
+
+/// The assertions and negations of a conjunctive context, grouped by key.
+struct KeywiseContext<'a> {
+    assertions: FxHashMap<dir::DirKey, Vec<&'a dir::DirValue>>,
+    negations: FxHashMap<dir::DirKey, Vec<&'a dir::DirValue>>,
+}
+
+impl<'a> KeywiseContext<'a> {
+    fn new(context: &types::ConjunctiveContext<'a>) -> Self {
+        let mut assertions: FxHashMap<dir::DirKey, Vec<&'a dir::DirValue>> = FxHashMap::default();
+        let mut negations: FxHashMap<dir::DirKey, Vec<&'a dir::DirValue>> = FxHashMap::default();
+
+        for ctx_val in context {
+            match ctx_val.value {
+                types::CtxValueKind::Assertion(val) => {
+                    assertions.entry(val.get_key()).or_default().push(val);
+                }
+                types::CtxValueKind::Negation(vals) => {
+                    for val in vals {
+                        negations.entry(val.get_key()).or_default().push(val);
+                    }
+                }
+            }
+        }
+
+        Self {
+            assertions,
+            negations,
+        }
+    }
+
+    fn get_assertions(&self, key: &dir::DirKey) -> &[&'a dir::DirValue] {
+        self.assertions.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    fn get_negations(&self, key: &dir::DirKey) -> &[&'a dir::DirValue] {
+        self.negations.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// The bounds of a number key in this context, ignoring the numbers it is negated for.
+    fn get_num_bounds(&self, key: &dir::DirKey) -> (i64, i64) {
+        analyzer::get_num_bounds(
+            &key.kind,
+            self.get_assertions(key)
+                .iter()
+                .filter_map(|val| val.get_num_value()),
+        )
+    }
+
+    /// The numbers that a number key is negated for in this context.
+    fn get_negated_numbers(&self, key: &dir::DirKey) -> Vec<i64> {
+        let mut numbers: Vec<i64> = self
+            .get_negations(key)
+            .iter()
+            .filter_map(|val| val.get_num_value())
+            .map(|num_value| num_value.number)
+            .collect();
+
+        numbers.sort_unstable();
+        numbers.dedup();
+        numbers
+    }
+
+    /// Whether the value holds for every input matching this context.
+    fn implies_assertion(&self, value: &dir::DirValue) -> bool {
+        let key = value.get_key();
+
+        match value.get_num_value() {
+            Some(num_value) => {
+                let (lower, upper) = self.get_num_bounds(&key);
+                let (value_lower, value_upper) =
+                    analyzer::get_num_bounds(&key.kind, std::iter::once(num_value));
+
+                value_lower <= lower && upper <= value_upper
+            }
+            None => self.get_assertions(&key).contains(&value),
+        }
+    }
+
+    /// Whether the value holds for no input matching this context.
+    fn implies_negation(&self, value: &dir::DirValue) -> bool {
+        let key = value.get_key();
+
+        if self.get_negations(&key).contains(&value) {
+            return true;
+        }
+
+        match value.get_num_value() {
+            Some(num_value) => {
+                let (lower, upper) = self.get_num_bounds(&key);
+                num_value.number < lower || num_value.number > upper
+            }
+            None => self
+                .get_assertions(&key)
+                .iter()
+                .any(|asserted| *asserted != value),
+        }
+    }
+
+    /// Whether every input matching this context also matches the given context.
+    fn is_covered_by(&self, context: &types::ConjunctiveContext<'_>) -> bool {
+        context.iter().all(|ctx_val| match ctx_val.value {
+            types::CtxValueKind::Assertion(val) => self.implies_assertion(val),
+            types::CtxValueKind::Negation(vals) => {
+                vals.iter().all(|val| self.implies_negation(val))
+            }
+        })
+    }
+
+    /// The values of an enum key that inputs matching this context can take.
+    fn get_covered_variants(
+        &self,
+        key: &dir::DirKey,
+        value_set: &[dir::DirValue],
+    ) -> Vec<dir::DirValue> {
+        let assertions = self.get_assertions(key);
+        let negations = self.get_negations(key);
+
+        value_set
+            .iter()
+            .filter(|val| {
+                (assertions.is_empty() || assertions.contains(val)) && !negations.contains(val)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// The ranges of a number key that inputs matching this context can take.
+    fn get_covered_ranges(&self, key: &dir::DirKey) -> Vec<(i64, i64)> {
+        let (lower, upper) = self.get_num_bounds(key);
+        let mut ranges = Vec::new();
+        let mut start = Some(lower);
+
+        for number in self.get_negated_numbers(key) {
+            match start {
+                Some(range_start) if range_start <= number && number <= upper => {
+                    if number > range_start {
+                        ranges.push((range_start, number - 1));
+                    }
+                    start = (number < upper).then(|| number + 1);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(range_start) = start {
+            if range_start <= upper {
+                ranges.push((range_start, upper));
+            }
+        }
+
+        ranges
+    }
+}
+
+/// Subtracts the covered ranges from the range of values that the key can take.
+fn get_uncovered_ranges(
+    key: &dir::DirKeyKind,
+    mut covered: Vec<(i64, i64)>,
+) -> Vec<types::NumRange> {
+    let (domain_start, domain_end) = key
+        .get_num_range()
+        .map_or((i64::MIN, i64::MAX), |range| (*range.start(), *range.end()));
+
+    covered.sort_unstable();
+
+    let mut uncovered = Vec::new();
+    // The smallest number that isn't known to be covered yet, if any
+    let mut next = Some(domain_start);
+
+    for (start, end) in covered {
+        let Some(next_uncovered) = next else {
+            break;
+        };
+
+        if start > next_uncovered {
+            uncovered.push(types::NumRange {
+                start: next_uncovered,
+                end: start - 1,
+            });
+        }
+
+        if end >= next_uncovered {
+            next = (end < domain_end).then(|| end + 1);
+        }
+    }
+
+    if let Some(next_uncovered) = next {
+        uncovered.push(types::NumRange {
+            start: next_uncovered,
+            end: domain_end,
+        });
+    }
+
+    uncovered
+}
+
+/// Finds the values of each key referenced in the program for which no rule is selected.
+///
+/// Each key is considered on its own, so a value is only reported if no rule is selected for it
+/// regardless of the values of the other keys. Inputs that fall through to the default selection
+/// only for a combination of values of several keys are not reported.
+fn find_fall_through_regions(
+    keys: &[dir::DirKey],
+    contexts: &[KeywiseContext<'_>],
+) -> Vec<types::FallThroughRegion> {
+    keys.iter()
+        .filter_map(|key| {
+            let uncovered = match key.kind.get_type() {
+                DataType::EnumVariant => {
+                    let value_set = key.kind.get_value_set()?;
+                    let covered: FxHashSet<dir::DirValue> = contexts
+                        .iter()
+                        .flat_map(|ctx| ctx.get_covered_variants(key, &value_set))
+                        .collect();
+
+                    let variants: Vec<dir::DirValue> = value_set
+                        .into_iter()
+                        .filter(|val| !covered.contains(val))
+                        .collect();
+
+                    (!variants.is_empty()).then_some(types::UncoveredValues::Variants(variants))
+                }
+                DataType::Number => {
+                    let covered = contexts
+                        .iter()
+                        .flat_map(|ctx| ctx.get_covered_ranges(key))
+                        .collect();
+                    let ranges = get_uncovered_ranges(&key.kind, covered);
+
+                    (!ranges.is_empty()).then_some(types::UncoveredValues::Ranges(ranges))
+                }
+                DataType::MetadataValue | DataType::StrValue => None,
+            }?;
+
+            Some(types::FallThroughRegion {
+                key: key.clone(),
+                uncovered,
+            })
+        })
+        .collect()
+}
+
+fn collect_rule_contexts<'a, O>(
+    rule: &'a dir::DirRule<O>,
+) -> Result<Vec<types::ConjunctiveContext<'a>>, types::AnalysisError> {
+    let mut ctx_manager = state_machine::RuleContextManager::new(rule, &[]);
+    let mut contexts = Vec::new();
+
+    while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
+        metadata: Default::default(),
+        error_type: types::AnalysisErrorType::StateMachine(err),
+    })? {
+        contexts.push(ctx.clone());
+    }
+
+    Ok(contexts)
+}
+
+fn is_satisfiable(
+    context: &types::ConjunctiveContext<'_>,
+    knowledge_graph: &graph::KnowledgeGraph<'_>,
+) -> bool {
+    analyzer::perform_condition_analyses(context).is_ok()
+        && knowledge_graph
+            .perform_context_analysis(context, &mut Memoization::new())
+            .is_ok()
+}
+
+/// Whether at least one of the connectors of the rule can process a payment matching the
+/// context, as per the knowledge graph.
+fn has_eligible_connector<'a>(
+    context: &types::ConjunctiveContext<'a>,
+    connectors: &'a [(dir::DirValue, Metadata)],
+    knowledge_graph: &graph::KnowledgeGraph<'_>,
+) -> bool {
+    connectors.is_empty()
+        || connectors.iter().any(|(connector, metadata)| {
+            let mut context = context.clone();
+            context.push(types::ContextValue::assertion(connector, metadata));
+
+            knowledge_graph
+                .perform_context_analysis(&context, &mut Memoization::new())
+                .is_ok()
+        })
+}
+
+/// Analyses the coverage of the rules of the program.
+///
+/// The knowledge graph is used both to discard conditions that can never hold, and to find
+/// rules whose connectors cannot process any payment matching their conditions. A graph built
+/// from the merchant's connector accounts with
+/// [`kgraph_utils::mca::make_mca_graph`](../../../kgraph_utils/mca/fn.make_mca_graph.html),
+/// combined with [`truth::ANALYSIS_GRAPH`], reports rules that can never fire for that
+/// merchant. [`truth::ANALYSIS_GRAPH`] is used if no graph is given.
+pub fn analyze_coverage<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
+) -> Result<types::CoverageReport, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    let knowledge_graph = knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH);
+    let selection_data = state_machine::make_connector_selection_data(&dir_program);
+
+    let mut report = types::CoverageReport::default();
+    let mut keys: Vec<dir::DirKey> = Vec::new();
+    let mut seen_keys: FxHashSet<dir::DirKey> = FxHashSet::default();
+    // The satisfiable contexts of each rule, which select the rule whenever they hold
+    let mut rule_contexts: Vec<(&str, Vec<types::ConjunctiveContext<'_>>)> = Vec::new();
+    let mut all_contexts: Vec<KeywiseContext<'_>> = Vec::new();
+
+    for (rule, connectors) in dir_program.rules.iter().zip(selection_data.iter()) {
+        let contexts = collect_rule_contexts(rule)?;
+
+        for key in contexts
+            .iter()
+            .flatten()
+            .filter_map(|ctx_val| ctx_val.value.get_key())
+        {
+            if seen_keys.insert(key.clone()) {
+                keys.push(key);
+            }
+        }
+
+        let satisfiable: Vec<types::ConjunctiveContext<'_>> = contexts
+            .into_iter()
+            .filter(|ctx| is_satisfiable(ctx, knowledge_graph))
+            .collect();
+
+        if satisfiable.is_empty() {
+            report.unreachable_rules.push(types::UnreachableRule {
+                rule_name: rule.name.clone(),
+                reason: types::UnreachableReason::UnsatisfiableConditions,
+            });
+            continue;
+        }
+
+        if !satisfiable
+            .iter()
+            .any(|ctx| has_eligible_connector(ctx, connectors, knowledge_graph))
+        {
+            report.unreachable_rules.push(types::UnreachableRule {
+                rule_name: rule.name.clone(),
+                reason: types::UnreachableReason::NoEligibleConnector,
+            });
+        }
+
+        let mut shadowed_by: Vec<String> = Vec::new();
+        let is_shadowed = satisfiable.iter().all(|ctx| {
+            let keywise_ctx = KeywiseContext::new(ctx);
+            let covering_rule = rule_contexts.iter().find(|(_, earlier_contexts)| {
+                earlier_contexts
+                    .iter()
+                    .any(|earlier| keywise_ctx.is_covered_by(earlier))
+            });
+
+            covering_rule.map_or(false, |(name, _)| {
+                if !shadowed_by.iter().any(|shadowing| shadowing == name) {
+                    shadowed_by.push(name.to_string());
+                }
+                true
+            })
+        });
+
+        if is_shadowed {
+            report.shadowed_rules.push(types::ShadowedRule {
+                rule_name: rule.name.clone(),
+                shadowed_by,
+            });
+        }
+
+        all_contexts.extend(satisfiable.iter().map(KeywiseContext::new));
+        rule_contexts.push((rule.name.as_str(), satisfiable));
+    }
+
+    report.fall_through_regions = find_fall_through_regions(&keys, &all_contexts);
+
+    Ok(report)
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::panic, clippy::expect_used)]
+
+    use super::*;
+    use crate::{dirval, types::DummyOutput};
+
+    #[test]
+    fn test_shadowed_and_unsatisfiable_rules() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            cards: ["stripe"]
+            {
+                payment_method = card
+            }
+
+            usd_cards: ["adyen"]
+            {
+                payment_method = card & currency = USD
+            }
+
+            impossible: ["adyen"]
+            {
+                amount > 500 & amount < 100
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let report = analyze_coverage(program, None).expect("Coverage report");
+
+        assert_eq!(report.unreachable_rules.len(), 1);
+        let unreachable = report.unreachable_rules.first().expect("Unreachable rule");
+        assert_eq!(unreachable.rule_name, "impossible");
+        assert_eq!(
+            unreachable.reason,
+            types::UnreachableReason::UnsatisfiableConditions
+        );
+
+        assert_eq!(report.shadowed_rules.len(), 1);
+        let shadowed = report.shadowed_rules.first().expect("Shadowed rule");
+        assert_eq!(shadowed.rule_name, "usd_cards");
+        assert_eq!(shadowed.shadowed_by, vec!["cards".to_string()]);
+
+        let payment_method_region = report
+            .fall_through_regions
+            .iter()
+            .find(|region| matches!(region.key.kind, dir::DirKeyKind::PaymentMethod))
+            .expect("Payment method region");
+        match &payment_method_region.uncovered {
+            types::UncoveredValues::Variants(variants) => {
+                assert!(!variants.contains(&dirval!(PaymentMethod = Card)));
+                assert!(variants.contains(&dirval!(PaymentMethod = Wallet)));
+            }
+            types::UncoveredValues::Ranges(_) => {
+                panic!("Expected uncovered payment methods")
+            }
+        }
+    }
+
+    #[test]
+    fn test_number_fall_through_regions() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            small: ["stripe"]
+            {
+                amount < 100
+            }
+
+            large: ["adyen"]
+            {
+                amount >= 1000 & amount /= 5000
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let report = analyze_coverage(program, None).expect("Coverage report");
+
+        assert!(report.unreachable_rules.is_empty());
+        assert!(report.shadowed_rules.is_empty());
+        assert_eq!(report.fall_through_regions.len(), 1);
+
+        let region = report.fall_through_regions.first().expect("Amount region");
+        assert!(matches!(region.key.kind, dir::DirKeyKind::PaymentAmount));
+        match &region.uncovered {
+            types::UncoveredValues::Ranges(ranges) => assert_eq!(
+                ranges,
+                &vec![
+                    types::NumRange {
+                        start: 100,
+                        end: 999
+                    },
+                    types::NumRange {
+                        start: 5000,
+                        end: 5000
+                    },
+                ]
+            ),
+            types::UncoveredValues::Variants(_) => panic!("Expected uncovered amount ranges"),
+        }
+    }
+}
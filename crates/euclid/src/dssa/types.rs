@@ -149,6 +149,13 @@ pub enum AnalysisErrorType {
     NotImplemented,
     #[error("The payment method type is not supported under the payment method")]
     NotSupported,
+    #[error("Invalid timezone '{0}'")]
+    InvalidTimezone(String),
+    #[error("Conditions on key '{}' can never be satisfied together", .key.kind)]
+    UnsatisfiableRange {
+        key: dir::DirKey,
+        metadata: Vec<Metadata>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -156,3 +163,56 @@ pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
     Number,
 }
+
+/// The reason for which a rule can never be selected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnreachableReason {
+    /// None of the conditions of the rule can evaluate to `true`
+    UnsatisfiableConditions,
+    /// None of the connectors of the rule can process a payment matching its conditions
+    NoEligibleConnector,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnreachableRule {
+    pub rule_name: String,
+    pub reason: UnreachableReason,
+}
+
+/// A rule whose conditions are all covered by the conditions of earlier rules, which are
+/// evaluated first.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShadowedRule {
+    pub rule_name: String,
+    pub shadowed_by: Vec<String>,
+}
+
+/// An inclusive range of numbers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NumRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum UncoveredValues {
+    Variants(Vec<dir::DirValue>),
+    Ranges(Vec<NumRange>),
+}
+
+/// Values of a key for which no rule is selected, regardless of the values of the other keys,
+/// and which fall through to the default selection of the program.
+#[derive(Debug, Clone, Serialize)]
+pub struct FallThroughRegion {
+    pub key: dir::DirKey,
+    pub uncovered: UncoveredValues,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CoverageReport {
+    pub unreachable_rules: Vec<UnreachableRule>,
+    pub shadowed_rules: Vec<ShadowedRule>,
+    pub fall_through_regions: Vec<FallThroughRegion>,
+}
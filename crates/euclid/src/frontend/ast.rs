@@ -1,6 +1,8 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+#[cfg(feature = "ast_parser")]
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use serde::{Deserialize, Serialize};
@@ -151,4 +153,8 @@ pub struct Program<O> {
     pub default_selection: O,
     pub rules: Vec<Rule<O>>,
     pub metadata: Metadata,
+    /// IANA name of the timezone in which conditions on the transaction time are evaluated,
+    /// defaults to UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
@@ -72,16 +72,20 @@ macro_rules! lower_enum {
 /// use case
 
 macro_rules! lower_number {
-    ($key:ident, $value:ident, $comp:ident) => {
+    ($key:ident, $value:ident, $comp:ident $(, $validation_closure:expr)?) => {
         match $value {
-            ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
-                number: num,
-                refinement: $comp.into(),
-            })]),
+            ast::ValueType::Number(num) => {
+                $($validation_closure(num)?;)?
+                Ok(vec![dir::DirValue::$key(types::NumValue {
+                    number: num,
+                    refinement: $comp.into(),
+                })])
+            }
 
             ast::ValueType::NumberArray(na) => na
                 .into_iter()
                 .map(|num| {
+                    $($validation_closure(num)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
                         refinement: $comp.clone().into(),
@@ -92,6 +96,7 @@ macro_rules! lower_number {
             ast::ValueType::NumberComparisonArray(nca) => nca
                 .into_iter()
                 .map(|nc| {
+                    $($validation_closure(nc.number)?;)?
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: nc.number,
                         refinement: nc.comparison_type.into(),
@@ -293,6 +298,25 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::TransactionHour => lower_number!(TransactionHour, value, comparison),
+
+        dir::DirKeyKind::TransactionWeekday => lower_enum!(TransactionWeekday, value),
+
+        dir::DirKeyKind::TransactionDate => {
+            let validation_closure = |date: i64| -> Result<(), AnalysisErrorType> {
+                if types::date_from_number(date).is_some() {
+                    Ok(())
+                } else {
+                    Err(AnalysisErrorType::InvalidValue {
+                        key: dir::DirKeyKind::TransactionDate,
+                        value: date.to_string(),
+                        message: Some("Expected a date in the YYYYMMDD format".to_string()),
+                    })
+                }
+            };
+            lower_number!(TransactionDate, value, comparison, validation_closure)
+        }
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
@@ -365,6 +389,17 @@ pub fn lower_rule<O: EuclidDirFilter>(
 pub fn lower_program<O: EuclidDirFilter>(
     program: ast::Program<O>,
 ) -> Result<dir::DirProgram<O>, AnalysisError> {
+    let timezone = program
+        .timezone
+        .as_deref()
+        .map(|timezone| {
+            types::parse_timezone(timezone).ok_or_else(|| AnalysisError {
+                error_type: AnalysisErrorType::InvalidTimezone(timezone.to_string()),
+                metadata: program.metadata.clone(),
+            })
+        })
+        .transpose()?;
+
     Ok(dir::DirProgram {
         default_selection: program.default_selection,
         rules: program
@@ -373,5 +408,6 @@ pub fn lower_program<O: EuclidDirFilter>(
             .map(lower_rule)
             .collect::<Result<_, _>>()?,
         metadata: program.metadata,
+        timezone,
     })
 }
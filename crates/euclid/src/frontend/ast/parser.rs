@@ -5,6 +5,67 @@ use nom::{
 use crate::{frontend::ast, types::DummyOutput};
 pub type ParseResult<T, U> = nom::IResult<T, U, nom::error::VerboseError<T>>;
 
+/// An error encountered while parsing the textual form of a program, along with the position in
+/// the text at which it was encountered. Lines and columns are counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("line {line}, column {column}: {message}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(input: &str, remaining: &str, message: String) -> Self {
+        // Point at the token that couldn't be parsed rather than at the whitespace preceding it
+        let remaining = remaining.trim_start();
+        let offset = input.len().saturating_sub(remaining.len());
+        let consumed = input.get(..offset).unwrap_or(input);
+
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed
+                .rsplit('\n')
+                .next()
+                .map_or(0, |line| line.chars().count())
+                + 1,
+            message,
+        }
+    }
+
+    fn from_verbose_error(input: &str, error: &error::VerboseError<&str>) -> Self {
+        // The errors are ordered from the innermost parser outwards, so the first context is
+        // the most specific description of what was being parsed
+        let context = error.errors.iter().find_map(|(_, kind)| match kind {
+            error::VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        });
+
+        match error.errors.first() {
+            Some((remaining, kind)) => {
+                let message = match (kind, context) {
+                    (error::VerboseErrorKind::Char(expected), _) => {
+                        format!("expected '{expected}'")
+                    }
+                    (error::VerboseErrorKind::Nom(error::ErrorKind::Eof), _) => {
+                        "unexpected input".to_string()
+                    }
+                    (_, Some(context)) => format!("invalid {context}"),
+                    (error::VerboseErrorKind::Nom(kind), None) => {
+                        format!("unexpected input ({})", kind.description())
+                    }
+                    (error::VerboseErrorKind::Context(context), None) => {
+                        format!("invalid {context}")
+                    }
+                };
+
+                Self::new(input, remaining, message)
+            }
+            None => Self::new(input, input, "invalid program".to_string()),
+        }
+    }
+}
+
 pub enum EuclidError {
     InvalidPercentage(String),
     InvalidConnector(String),
@@ -331,7 +392,10 @@ pub fn arbitrary_comparison(input: &str) -> ParseResult<&str, ast::Comparison> {
 pub fn comparison_array(input: &str) -> ParseResult<&str, Vec<ast::Comparison>> {
     let many_with_ampersand = error::context(
         "many_with_amp",
-        multi::many0(sequence::preceded(skip_ws(complete::tag("&")), comparison)),
+        multi::many0(sequence::preceded(
+            skip_ws(complete::tag("&")),
+            skip_ws(branch::alt((comparison, arbitrary_comparison))),
+        )),
     );
 
     let full_sequence = sequence::pair(
@@ -426,16 +490,75 @@ pub fn default_output<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&
     )(input)
 }
 
+pub fn timezone(input: &str) -> ParseResult<&str, String> {
+    error::context(
+        "timezone",
+        sequence::preceded(
+            sequence::pair(
+                skip_ws(complete::tag("timezone")),
+                skip_ws(pchar::char(':')),
+            ),
+            skip_ws(string_str),
+        ),
+    )(input)
+}
+
 pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, ast::Program<O>> {
     error::context(
         "program",
         combinator::map(
-            sequence::pair(default_output, multi::many1(skip_ws(rule::<O>))),
-            |tup: (O, Vec<ast::Rule<O>>)| ast::Program {
-                default_selection: tup.0,
-                rules: tup.1,
+            sequence::tuple((
+                combinator::opt(timezone),
+                default_output,
+                multi::many1(skip_ws(rule::<O>)),
+            )),
+            |tup: (Option<String>, O, Vec<ast::Rule<O>>)| ast::Program {
+                default_selection: tup.1,
+                rules: tup.2,
                 metadata: std::collections::HashMap::new(),
+                timezone: tup.0,
             },
         ),
     )(input)
 }
+
+/// Parses the complete textual form of a program, reporting the line and column of the first
+/// error encountered.
+pub fn parse_program<O: EuclidParsable + 'static>(
+    input: &str,
+) -> Result<ast::Program<O>, ParseError> {
+    let result =
+        combinator::all_consuming(sequence::terminated(program::<O>, pchar::multispace0))(input);
+
+    match result {
+        Ok((_, program)) => Ok(program),
+
+        Err(nom::Err::Error(verbose_error) | nom::Err::Failure(verbose_error)) => {
+            // The rules are parsed until one fails, so input left over after the last parsed rule
+            // is most likely an invalid rule, which is parsed again to report the actual error
+            let invalid_rule_error = verbose_error
+                .errors
+                .first()
+                .filter(|(_, kind)| {
+                    matches!(kind, error::VerboseErrorKind::Nom(error::ErrorKind::Eof))
+                })
+                .and_then(|&(remaining, _)| match skip_ws(rule::<O>)(remaining) {
+                    Err(nom::Err::Error(rule_error) | nom::Err::Failure(rule_error)) => {
+                        Some(rule_error)
+                    }
+                    _ => None,
+                });
+
+            Err(ParseError::from_verbose_error(
+                input,
+                invalid_rule_error.as_ref().unwrap_or(&verbose_error),
+            ))
+        }
+
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(
+            input,
+            "",
+            "unexpected end of input".to_string(),
+        )),
+    }
+}
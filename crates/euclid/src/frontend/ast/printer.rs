@@ -0,0 +1,350 @@
+//! Pretty-printer for the textual form of a program.
+//!
+//! The printed text is accepted by [`super::parser::parse_program`], and parses back to the same
+//! program. The metadata of the program and of its comparisons has no textual form, and is left
+//! out of the printed text.
+
+use std::fmt::Write;
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+/// The reasons for which a program cannot be represented in its textual form.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PrintError {
+    #[error("'{0}' is not a valid identifier")]
+    InvalidIdentifier(String),
+    #[error("'{0}' is not a valid left hand side of a comparison")]
+    InvalidLhs(String),
+    #[error("string '{0}' is either empty or contains a double quote")]
+    InvalidString(String),
+    #[error("negative number '{0}' is not supported")]
+    NegativeNumber(i64),
+    #[error("comparison '{0:?}' is not supported in a number comparison array")]
+    InvalidNumberComparison(ast::ComparisonType),
+    #[error("metadata comparisons must have 'metadata' as the left hand side, found '{0}'")]
+    InvalidMetadataComparison(String),
+    #[error("{0} cannot be empty")]
+    Empty(&'static str),
+    #[error("invalid output: {0}")]
+    InvalidOutput(String),
+}
+
+pub trait EuclidPrintable {
+    /// Prints the output in the form accepted by
+    /// [`EuclidParsable::parse_output`](super::parser::EuclidParsable::parse_output).
+    fn print_output(&self) -> Result<String, PrintError>;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> Result<String, PrintError> {
+        if self.outputs.is_empty() {
+            return Err(PrintError::Empty("outputs"));
+        }
+
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                if output.contains('"') {
+                    Err(PrintError::InvalidString(output.clone()))
+                } else {
+                    Ok(format!("\"{output}\""))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(format!("[{}]", outputs.join(", ")))
+    }
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn check_identifier(value: &str) -> Result<&str, PrintError> {
+    if is_identifier(value) {
+        Ok(value)
+    } else {
+        Err(PrintError::InvalidIdentifier(value.to_string()))
+    }
+}
+
+fn check_lhs(lhs: &str) -> Result<&str, PrintError> {
+    if !lhs.is_empty()
+        && lhs
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '.' || c == '_')
+    {
+        Ok(lhs)
+    } else {
+        Err(PrintError::InvalidLhs(lhs.to_string()))
+    }
+}
+
+fn print_string(value: &str) -> Result<String, PrintError> {
+    if value.is_empty() || value.contains('"') {
+        Err(PrintError::InvalidString(value.to_string()))
+    } else {
+        Ok(format!("\"{value}\""))
+    }
+}
+
+fn print_number(number: i64) -> Result<String, PrintError> {
+    if number < 0 {
+        Err(PrintError::NegativeNumber(number))
+    } else {
+        Ok(number.to_string())
+    }
+}
+
+fn print_comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn print_array<T>(
+    values: &[T],
+    name: &'static str,
+    print_value: impl Fn(&T) -> Result<String, PrintError>,
+) -> Result<String, PrintError> {
+    if values.is_empty() {
+        return Err(PrintError::Empty(name));
+    }
+
+    let values = values
+        .iter()
+        .map(print_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(format!("({})", values.join(", ")))
+}
+
+fn print_value(value: &ast::ValueType) -> Result<String, PrintError> {
+    match value {
+        ast::ValueType::Number(number) => print_number(*number),
+        ast::ValueType::EnumVariant(variant) => check_identifier(variant).map(str::to_string),
+        ast::ValueType::StrValue(value) => print_string(value),
+        ast::ValueType::NumberArray(numbers) => {
+            print_array(numbers, "number array", |number| print_number(*number))
+        }
+        ast::ValueType::EnumVariantArray(variants) => {
+            print_array(variants, "enum variant array", |variant| {
+                check_identifier(variant).map(str::to_string)
+            })
+        }
+        ast::ValueType::NumberComparisonArray(comparisons) => print_array(
+            comparisons,
+            "number comparison array",
+            |comparison| match comparison.comparison_type {
+                ast::ComparisonType::LessThan
+                | ast::ComparisonType::LessThanEqual
+                | ast::ComparisonType::GreaterThan
+                | ast::ComparisonType::GreaterThanEqual => Ok(format!(
+                    "{}{}",
+                    print_comparison_type(&comparison.comparison_type),
+                    print_number(comparison.number)?
+                )),
+                ast::ComparisonType::Equal | ast::ComparisonType::NotEqual => Err(
+                    PrintError::InvalidNumberComparison(comparison.comparison_type.clone()),
+                ),
+            },
+        ),
+        // Metadata comparisons are printed as a whole by `print_comparison`
+        ast::ValueType::MetadataVariant(metadata) => print_string(&metadata.value),
+    }
+}
+
+fn print_comparison(comparison: &ast::Comparison) -> Result<String, PrintError> {
+    let operator = print_comparison_type(&comparison.comparison);
+
+    match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => {
+            if comparison.lhs != "metadata" {
+                return Err(PrintError::InvalidMetadataComparison(
+                    comparison.lhs.clone(),
+                ));
+            }
+
+            Ok(format!(
+                "{} {} {}",
+                print_string(&metadata.key)?,
+                operator,
+                print_string(&metadata.value)?
+            ))
+        }
+        value => Ok(format!(
+            "{} {} {}",
+            check_lhs(&comparison.lhs)?,
+            operator,
+            print_value(value)?
+        )),
+    }
+}
+
+fn print_statements(
+    out: &mut String,
+    statements: &[ast::IfStatement],
+    depth: usize,
+) -> Result<(), PrintError> {
+    for statement in statements {
+        if statement.condition.is_empty() {
+            return Err(PrintError::Empty("condition"));
+        }
+
+        let condition = statement
+            .condition
+            .iter()
+            .map(print_comparison)
+            .collect::<Result<Vec<_>, _>>()?
+            .join(" & ");
+
+        let indent = INDENT.repeat(depth);
+        out.push_str(&indent);
+        out.push_str(&condition);
+
+        match &statement.nested {
+            Some(nested) => {
+                out.push_str(" {\n");
+                print_statements(out, nested, depth + 1)?;
+                out.push_str(&indent);
+                out.push_str("}\n");
+            }
+            None => out.push('\n'),
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the program in its textual form. Each rule is separated by an empty line, and nested
+/// statements are indented by four spaces. The timezone of the program, if any, is printed on the
+/// first line.
+///
+/// ```text
+/// timezone: "Europe/Berlin"
+/// default: ["stripe", "adyen"]
+///
+/// rule_1: ["stripe"] {
+///     payment_method = card & amount > 100 {
+///         card_network = (visa, mastercard)
+///     }
+///     currency = USD
+/// }
+/// ```
+pub fn print_program<O: EuclidPrintable>(program: &ast::Program<O>) -> Result<String, PrintError> {
+    if program.rules.is_empty() {
+        return Err(PrintError::Empty("rules"));
+    }
+
+    let mut out = String::new();
+    // Writing to a `String` never fails
+    if let Some(timezone) = &program.timezone {
+        let _ = writeln!(out, "timezone: {}", print_string(timezone)?);
+    }
+    let _ = writeln!(
+        out,
+        "default: {}",
+        program.default_selection.print_output()?
+    );
+
+    for rule in &program.rules {
+        let _ = writeln!(
+            out,
+            "\n{}: {} {{",
+            check_identifier(&rule.name)?,
+            rule.connector_selection.print_output()?
+        );
+
+        if rule.statements.is_empty() {
+            return Err(PrintError::Empty("statements"));
+        }
+        print_statements(&mut out, &rule.statements, 1)?;
+        out.push_str("}\n");
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_round_trip() {
+        let program_str = r#"timezone: "Asia/Kolkata"
+default: ["stripe", "adyen"]
+
+rule_1: ["stripe"] {
+    payment_method = card & amount > 100 {
+        card_network = (Visa, Mastercard) & amount = (>500, <=1000)
+    }
+    currency /= USD
+}
+
+night: ["adyen"] {
+    transaction_hour = (>=22, <6) & transaction_weekday = (saturday, sunday)
+}
+
+rule_2: ["adyen", "checkout"] {
+    "merchant_tier" = "gold" & amount = (10, 20) & business_label = "electronics"
+    payment_method = pay_later {
+        pay_later = klarna {
+            "region" = "eu"
+        }
+    }
+}
+"#;
+
+        let program = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        let printed = print_program(&program).expect("Printed program");
+        assert_eq!(printed, program_str);
+
+        let reparsed = parser::parse_program::<DummyOutput>(&printed).expect("Reparsed program");
+        assert_eq!(reparsed.timezone.as_deref(), Some("Asia/Kolkata"));
+        assert_eq!(
+            serde_json::to_value(&program.rules).expect("Rules"),
+            serde_json::to_value(&reparsed.rules).expect("Reparsed rules")
+        );
+    }
+
+    #[test]
+    fn test_unprintable_program() {
+        let program_str = r#"
+        default: ["stripe"]
+        rule_1: ["stripe"] { amount > 100 }
+        "#;
+
+        let mut program = parser::parse_program::<DummyOutput>(program_str).expect("Program");
+        program.rules.get_mut(0).unwrap().name = "rule 1".to_string();
+
+        assert_eq!(
+            print_program(&program),
+            Err(PrintError::InvalidIdentifier("rule 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let program_str = "default: [\"stripe\"]\n\nrule_1: [\"stripe\"] {\n    amount > 100\n    currency = \n}\n";
+
+        let error = parser::parse_program::<DummyOutput>(program_str)
+            .err()
+            .expect("Parse error");
+        assert_eq!((error.line, error.column), (5, 5));
+    }
+}
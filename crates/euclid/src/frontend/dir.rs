@@ -3,6 +3,9 @@ pub mod enums;
 pub mod lowering;
 pub mod transformers;
 
+use std::ops::RangeInclusive;
+
+use chrono_tz::Tz;
 use strum::IntoEnumIterator;
 
 use crate::{enums as euclid_enums, frontend::ast, types};
@@ -312,6 +315,27 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_redirect")]
     CardRedirectType,
+    #[strum(
+        serialize = "transaction_hour",
+        detailed_message = "Hour of the day, from 0 to 23, at which the payment was made in the timezone of the program",
+        props(Category = "Transaction Time")
+    )]
+    #[serde(rename = "transaction_hour")]
+    TransactionHour,
+    #[strum(
+        serialize = "transaction_weekday",
+        detailed_message = "Day of the week on which the payment was made in the timezone of the program",
+        props(Category = "Transaction Time")
+    )]
+    #[serde(rename = "transaction_weekday")]
+    TransactionWeekday,
+    #[strum(
+        serialize = "transaction_date",
+        detailed_message = "Date, in the YYYYMMDD format, on which the payment was made in the timezone of the program",
+        props(Category = "Transaction Time")
+    )]
+    #[serde(rename = "transaction_date")]
+    TransactionDate,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -359,6 +383,9 @@ impl DirKeyKind {
             Self::BusinessLabel => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
             Self::CardRedirectType => types::DataType::EnumVariant,
+            Self::TransactionHour => types::DataType::Number,
+            Self::TransactionWeekday => types::DataType::EnumVariant,
+            Self::TransactionDate => types::DataType::Number,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -485,6 +512,23 @@ impl DirKeyKind {
                     .map(DirValue::CardRedirectType)
                     .collect(),
             ),
+            Self::TransactionHour => None,
+            Self::TransactionWeekday => Some(
+                enums::TransactionWeekday::iter()
+                    .map(DirValue::TransactionWeekday)
+                    .collect(),
+            ),
+            Self::TransactionDate => None,
+        }
+    }
+
+    /// The range of values that a number key can take, for keys whose values are bounded.
+    pub fn get_num_range(&self) -> Option<RangeInclusive<i64>> {
+        match self {
+            Self::PaymentAmount => Some(0..=i64::MAX),
+            Self::TransactionHour => Some(0..=23),
+            Self::TransactionDate => Some(10000101..=99991231),
+            _ => None,
         }
     }
 }
@@ -550,6 +594,12 @@ pub enum DirValue {
     SetupFutureUsage(enums::SetupFutureUsage),
     #[serde(rename = "card_redirect")]
     CardRedirectType(enums::CardRedirectType),
+    #[serde(rename = "transaction_hour")]
+    TransactionHour(types::NumValue),
+    #[serde(rename = "transaction_weekday")]
+    TransactionWeekday(enums::TransactionWeekday),
+    #[serde(rename = "transaction_date")]
+    TransactionDate(types::NumValue),
 }
 
 impl DirValue {
@@ -583,6 +633,9 @@ impl DirValue {
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
+            Self::TransactionHour(_) => (DirKeyKind::TransactionHour, None),
+            Self::TransactionWeekday(_) => (DirKeyKind::TransactionWeekday, None),
+            Self::TransactionDate(_) => (DirKeyKind::TransactionDate, None),
         };
 
         DirKey::new(kind, data)
@@ -617,6 +670,9 @@ impl DirValue {
             Self::BusinessLabel(_) => None,
             Self::SetupFutureUsage(_) => None,
             Self::CardRedirectType(_) => None,
+            Self::TransactionHour(_) => None,
+            Self::TransactionWeekday(_) => None,
+            Self::TransactionDate(_) => None,
         }
     }
 
@@ -629,7 +685,9 @@ impl DirValue {
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::TransactionHour(val) | Self::TransactionDate(val) => {
+                Some(val.clone())
+            }
             _ => None,
         }
     }
@@ -662,6 +720,7 @@ impl DirValue {
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
             (Self::CardRedirectType(crt1), Self::CardRedirectType(crt2)) => crt1 == crt2,
+            (Self::TransactionWeekday(tw1), Self::TransactionWeekday(tw2)) => tw1 == tw2,
             _ => false,
         }
     }
@@ -700,6 +759,7 @@ pub struct DirProgram<O> {
     pub default_selection: O,
     pub rules: Vec<DirRule<O>>,
     pub metadata: types::Metadata,
+    pub timezone: Option<Tz>,
 }
 
 #[cfg(test)]
@@ -747,6 +807,9 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(TransactionHour = 22),
+            dirval!(TransactionWeekday = Saturday),
+            dirval!(TransactionDate = 20261127),
         ];
 
         for val in values {
@@ -5,6 +5,7 @@ pub use crate::enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Country, Country as BusinessCountry,
     Country as BillingCountry, Currency as PaymentCurrency, MandateAcceptanceType, MandateType,
     PaymentMethod, PaymentType, RoutableConnectors, SetupFutureUsage,
+    Weekday as TransactionWeekday,
 };
 
 #[derive(
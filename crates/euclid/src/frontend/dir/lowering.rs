@@ -221,6 +221,9 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::RewardType(rt) => EuclidValue::PaymentMethodType(rt.into()),
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
+        dir::DirValue::TransactionHour(th) => EuclidValue::TransactionHour(th),
+        dir::DirValue::TransactionWeekday(tw) => EuclidValue::TransactionWeekday(tw),
+        dir::DirValue::TransactionDate(td) => EuclidValue::TransactionDate(td),
     })
 }
 
@@ -292,5 +295,6 @@ pub fn lower_program<O>(
                 metadata: Default::default(),
             })?,
         metadata: dir_program.metadata,
+        timezone: dir_program.timezone,
     })
 }
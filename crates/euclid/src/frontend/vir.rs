@@ -1,4 +1,6 @@
 //! Valued Intermediate Representation
+use chrono_tz::Tz;
+
 use crate::types::{EuclidValue, Metadata};
 
 #[derive(Debug, Clone)]
@@ -34,4 +36,5 @@ pub struct ValuedProgram<O> {
     pub default_selection: O,
     pub rules: Vec<ValuedRule<O>>,
     pub metadata: Metadata,
+    pub timezone: Option<Tz>,
 }
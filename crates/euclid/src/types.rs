@@ -1,5 +1,7 @@
 pub mod transformers;
 
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
 use euclid_macros::EnumNums;
 use serde::Serialize;
 use strum::VariantNames;
@@ -59,6 +61,12 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "transaction_hour")]
+    TransactionHour,
+    #[strum(serialize = "transaction_weekday")]
+    TransactionWeekday,
+    #[strum(serialize = "transaction_date")]
+    TransactionDate,
 }
 impl EuclidDirFilter for DummyOutput {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -76,6 +84,9 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::TransactionHour,
+        DirKeyKind::TransactionWeekday,
+        DirKeyKind::TransactionDate,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -137,6 +148,9 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::TransactionHour => DataType::Number,
+            Self::TransactionWeekday => DataType::EnumVariant,
+            Self::TransactionDate => DataType::Number,
         }
     }
 }
@@ -252,12 +266,17 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    TransactionHour(NumValue),
+    TransactionWeekday(enums::Weekday),
+    TransactionDate(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::TransactionHour(val) | Self::TransactionDate(val) => {
+                Some(val.clone())
+            }
             _ => None,
         }
     }
@@ -280,10 +299,33 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::TransactionHour(_) => EuclidKey::TransactionHour,
+            Self::TransactionWeekday(_) => EuclidKey::TransactionWeekday,
+            Self::TransactionDate(_) => EuclidKey::TransactionDate,
         }
     }
 }
 
+/// Parses the IANA name of a timezone, such as `Europe/Berlin`.
+pub fn parse_timezone(timezone: &str) -> Option<Tz> {
+    timezone.parse().ok()
+}
+
+/// Converts a date written as a number in the YYYYMMDD format into a calendar date, if the number
+/// denotes a valid date.
+pub fn date_from_number(date: i64) -> Option<NaiveDate> {
+    let year = i32::try_from(date / 10000).ok()?;
+    let month = u32::try_from(date / 100 % 100).ok()?;
+    let day = u32::try_from(date % 100).ok()?;
+
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Converts a calendar date into a number in the YYYYMMDD format.
+pub fn date_to_number(date: NaiveDate) -> i64 {
+    i64::from(date.year()) * 10000 + i64::from(date.month()) * 100 + i64::from(date.day())
+}
+
 #[cfg(test)]
 mod global_type_tests {
     use super::*;
@@ -315,4 +357,14 @@ mod global_type_tests {
 
         assert!(val1.fits(&val2));
     }
+
+    #[test]
+    fn test_date_number_conversion() {
+        let date = date_from_number(20240229).map(date_to_number);
+        assert_eq!(date, Some(20240229));
+
+        assert!(date_from_number(20230229).is_none());
+        assert!(date_from_number(20241301).is_none());
+        assert!(date_from_number(-20240101).is_none());
+    }
 }
@@ -61,18 +61,30 @@ pub fn seed_forex(forex: JsValue) -> JsResult {
 
 /// This function can be used to perform currency_conversion on the input amount, from_currency,
 /// to_currency which are all expected to be one of currencies we already have in our Currency
-/// enum.
+/// enum. The optional conversion options carry the rounding mode and markup, and the result is
+/// returned in the minor unit of to_currency.
 #[wasm_bindgen(js_name = convertCurrency)]
-pub fn convert_forex_value(amount: i64, from_currency: JsValue, to_currency: JsValue) -> JsResult {
+pub fn convert_forex_value(
+    amount: i64,
+    from_currency: JsValue,
+    to_currency: JsValue,
+    options: JsValue,
+) -> JsResult {
     let forex_data = SEED_FOREX
         .get()
         .ok_or("Forex Data not seeded")
         .err_to_js()?;
     let from_currency: common_enums::Currency = serde_wasm_bindgen::from_value(from_currency)?;
     let to_currency: common_enums::Currency = serde_wasm_bindgen::from_value(to_currency)?;
-    let converted_amount = convert_currency(forex_data, from_currency, to_currency, amount)
-        .map_err(|_| "conversion not possible for provided values")
-        .err_to_js()?;
+    let options: currency_conversion_types::ConversionOptions = if options.is_undefined() {
+        currency_conversion_types::ConversionOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+    let converted_amount =
+        convert_currency(forex_data, from_currency, to_currency, amount, &options)
+            .map_err(|_| "conversion not possible for provided values")
+            .err_to_js()?;
 
     Ok(serde_wasm_bindgen::to_value(&converted_amount)?)
 }
@@ -175,6 +187,18 @@ pub fn analyze_program(js_program: JsValue) -> JsResult {
     Ok(JsValue::NULL)
 }
 
+/// Reports the rules of the program that can never be selected or are shadowed by earlier rules,
+/// and the values of the input that fall through to the default selection. The merchant's
+/// connector accounts are taken into account if the knowledge graph has been seeded.
+#[wasm_bindgen(js_name = analyzeProgramCoverage)]
+pub fn analyze_program_coverage(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    let report = dssa::coverage::analyze_coverage(program, SEED_DATA.get().map(|sd| &sd.kgraph))
+        .err_to_js()?;
+
+    Ok(serde_wasm_bindgen::to_value(&report)?)
+}
+
 #[wasm_bindgen(js_name = runProgram)]
 pub fn run_program(program: JsValue, input: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(program)?;
@@ -256,11 +280,14 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::CardRedirectType => dir_enums::CardRedirectType::VARIANTS,
         dir::DirKeyKind::GiftCardType => dir_enums::GiftCardType::VARIANTS,
         dir::DirKeyKind::VoucherType => dir_enums::VoucherType::VARIANTS,
+        dir::DirKeyKind::TransactionWeekday => dir_enums::TransactionWeekday::VARIANTS,
         dir::DirKeyKind::PaymentAmount
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
-        | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::MetaData
+        | dir::DirKeyKind::TransactionHour
+        | dir::DirKeyKind::TransactionDate => Err("Key does not have variants".to_string())?,
         dir::DirKeyKind::BankDebitType => dir_enums::BankDebitType::VARIANTS,
     };
 
@@ -734,4 +734,42 @@ mod tests {
         );
         assert!(result.is_err(), "bluesnap validation failed");
     }
+
+    #[test]
+    fn test_coverage_with_mca_graph() {
+        use euclid::{
+            dssa::{coverage, truth, types::UnreachableReason},
+            frontend::ast::parser,
+        };
+
+        let mca_graph = build_test_data();
+        let graph = graph::KnowledgeGraph::combine(&mca_graph, &truth::ANALYSIS_GRAPH)
+            .expect("Failed graph combination");
+
+        let program_str = r#"
+            default: [stripe]
+
+            credit_cards: [stripe] {
+                payment_method = card & card_type = credit & card_network = Visa & currency = USD & amount = 100
+            }
+
+            wallets: [stripe] {
+                payment_method = wallet
+            }
+        "#;
+
+        let program = parser::parse_program::<api_models::routing::ConnectorSelection>(program_str)
+            .expect("Program");
+        let report = coverage::analyze_coverage(program, Some(&graph)).expect("Coverage report");
+
+        let unreachable = report
+            .unreachable_rules
+            .iter()
+            .map(|rule| (rule.rule_name.as_str(), rule.reason.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unreachable,
+            vec![("wallets", UnreachableReason::NoEligibleConnector)]
+        );
+    }
 }
@@ -15,6 +15,9 @@ pub use zeroize::{self, DefaultIsZeroes, Zeroize as ZeroizableSecret};
 mod strategy;
 
 pub use strategy::{Strategy, WithType, WithoutType};
+
+pub mod policy;
+pub use policy::{apply_masking_policy, set_masking_policy, MaskingPolicy, MaskingRule};
 mod abs;
 pub use abs::{ExposeInterface, ExposeOptionInterface, PeekInterface, SwitchStrategy};
 
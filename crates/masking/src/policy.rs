@@ -0,0 +1,146 @@
+//!
+//! Runtime masking policy.
+//!
+//! Masking strategies which have a name consult the policy before applying their own masking
+//! rule, so that the amount of information shown in logs can be tuned without code changes.
+//!
+
+use std::{collections::HashMap, fmt, sync::RwLock};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{PeekInterface, Secret};
+
+static MASKING_POLICY: RwLock<Option<MaskingPolicy>> = RwLock::new(None);
+
+/// How the values masked by a strategy are displayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(tag = "rule", rename_all = "snake_case")
+)]
+pub enum MaskingRule {
+    /// Show the first characters of the value and mask the rest
+    ShowFirst {
+        /// Number of characters shown
+        characters: usize,
+    },
+    /// Show the last characters of the value and mask the rest
+    ShowLast {
+        /// Number of characters shown
+        characters: usize,
+    },
+    /// Show the HMAC-SHA256 of the value keyed with the `hash_key` of the policy, which keeps
+    /// equal values correlatable. Values are redacted when no key is configured.
+    Hash,
+    /// Mask the whole value
+    Redact,
+}
+
+/// Masking rules of the named strategies, like `card_number`, `email` or `phone_number`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(default))]
+pub struct MaskingPolicy {
+    /// Fail whenever a secret would be serialized unmasked while masked serialization was
+    /// requested, meant to be enabled in tests
+    pub strict: bool,
+    /// Rules overriding the built-in masking of a strategy, keyed by the strategy name
+    pub strategies: HashMap<String, MaskingRule>,
+    /// Key of the HMAC shown by the `hash` rule, so that masked values cannot be recovered by
+    /// hashing candidate values
+    pub hash_key: Secret<String>,
+}
+
+/// Installs the masking policy used by the whole process
+pub fn set_masking_policy(policy: MaskingPolicy) {
+    match MASKING_POLICY.write() {
+        Ok(mut guard) => *guard = Some(policy),
+        Err(poisoned) => *poisoned.into_inner() = Some(policy),
+    }
+}
+
+/// Whether the installed masking policy is strict
+pub fn is_strict_masking() -> bool {
+    MASKING_POLICY
+        .read()
+        .map(|policy| policy.as_ref().map_or(false, |policy| policy.strict))
+        .unwrap_or(false)
+}
+
+/// Formats the value with the rule configured for the strategy, returns `None` when no rule is
+/// configured so that the strategy falls back to its built-in masking
+pub fn apply_masking_policy(
+    strategy: &str,
+    value: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> Option<fmt::Result> {
+    let guard = MASKING_POLICY.read().ok()?;
+    let policy = guard.as_ref()?;
+    let rule = policy.strategies.get(strategy)?;
+    Some(write!(f, "{}", rule.apply(value, policy.hash_key.peek())))
+}
+
+impl MaskingRule {
+    /// Masks the value according to the rule, `hash_key` is the key of the HMAC shown by the
+    /// `hash` rule
+    pub fn apply(&self, value: &str, hash_key: &str) -> String {
+        let length = value.chars().count();
+        match self {
+            // Values which are not longer than the shown part are masked entirely
+            Self::ShowFirst { characters } if *characters < length => value
+                .chars()
+                .take(*characters)
+                .chain(std::iter::repeat('*').take(length - characters))
+                .collect(),
+            Self::ShowLast { characters } if *characters < length => std::iter::repeat('*')
+                .take(length - characters)
+                .chain(value.chars().skip(length - characters))
+                .collect(),
+            Self::ShowFirst { .. } | Self::ShowLast { .. } => "*".repeat(length),
+            Self::Hash if !hash_key.is_empty() => {
+                match Hmac::<Sha256>::new_from_slice(hash_key.as_bytes()) {
+                    Ok(mut mac) => {
+                        mac.update(value.as_bytes());
+                        mac.finalize()
+                            .into_bytes()
+                            .iter()
+                            .map(|byte| format!("{byte:02x}"))
+                            .collect()
+                    }
+                    Err(_) => Self::Redact.apply(value, hash_key),
+                }
+            }
+            Self::Hash | Self::Redact => "*** ***".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masking_rules() {
+        let value = "4242424242424242";
+        assert_eq!(
+            MaskingRule::ShowFirst { characters: 6 }.apply(value, ""),
+            "424242**********"
+        );
+        assert_eq!(
+            MaskingRule::ShowLast { characters: 4 }.apply(value, ""),
+            "************4242"
+        );
+        assert_eq!(
+            MaskingRule::ShowLast { characters: 4 }.apply("424", ""),
+            "***"
+        );
+        assert_eq!(MaskingRule::Redact.apply(value, ""), "*** ***");
+        assert_eq!(
+            MaskingRule::Hash.apply("abc", "key"),
+            "9c196e32dc0175f86f4b1cb89289d6619de6bee699e4c378e68309ed97a1a6ab"
+        );
+        assert_eq!(MaskingRule::Hash.apply("abc", ""), "*** ***");
+    }
+}
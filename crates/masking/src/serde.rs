@@ -87,6 +87,7 @@ where
 /// for storing the secret to database or sending it over the network requires the secret to be exposed
 /// This method allows to serialize the secret in masked format if needed for logs or other insecure exposures
 pub fn masked_serialize<T: Serialize>(value: &T) -> Result<Value, serde_json::Error> {
+    let _guard = pii_serializer::MaskedSerializationGuard::enter();
     value.serialize(PIISerializer {
         inner: JsonValueSerializer,
     })
@@ -132,7 +133,31 @@ impl<'a> Serialize for dyn ErasedMaskSerialize + 'a + Send {
 use pii_serializer::PIISerializer;
 
 mod pii_serializer {
-    use std::fmt::Display;
+    use std::{cell::Cell, fmt::Display};
+
+    thread_local! {
+        static MASKED_SERIALIZATION_DEPTH: Cell<usize> = Cell::new(0);
+    }
+
+    /// Marks the current thread as performing a masked serialization while it is alive
+    pub(super) struct MaskedSerializationGuard;
+
+    impl MaskedSerializationGuard {
+        pub(super) fn enter() -> Self {
+            MASKED_SERIALIZATION_DEPTH.with(|depth| depth.set(depth.get() + 1));
+            Self
+        }
+    }
+
+    impl Drop for MaskedSerializationGuard {
+        fn drop(&mut self) {
+            MASKED_SERIALIZATION_DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+        }
+    }
+
+    fn is_masked_serialization() -> bool {
+        MASKED_SERIALIZATION_DEPTH.with(|depth| depth.get() > 0)
+    }
 
     pub(super) fn pii_serialize<
         V: Serialize,
@@ -152,6 +177,12 @@ mod pii_serializer {
             >()
         {
             std::collections::HashMap::<String, String>::from([]).serialize(serializer)
+        } else if is_masked_serialization() && crate::policy::is_strict_masking() {
+            // Serde wrapped the masking serializer in one of its own, the secret would be exposed
+            Err(serde::ser::Error::custom(format!(
+                "secret of type {} would be serialized unmasked",
+                std::any::type_name::<T>()
+            )))
         } else {
             value.peek().serialize(serializer)
         }
@@ -0,0 +1,57 @@
+#![allow(clippy::unwrap_used)]
+#![cfg(feature = "serde")]
+
+use std::fmt;
+
+use masking::{MaskingPolicy, MaskingRule, Secret, Strategy};
+use serde::Serialize;
+
+enum AccountNumberStrategy {}
+
+impl<T> Strategy<T> for AccountNumberStrategy
+where
+    T: AsRef<str>,
+{
+    fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(result) = masking::apply_masking_policy("account_number", val.as_ref(), f) {
+            return result;
+        }
+        f.write_str("*** ***")
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum Metadata {
+    Connector(Secret<serde_json::Value>),
+}
+
+// The policy is global, so a single test exercises it to keep the assertions independent of
+// the order tests run in
+#[test]
+fn masking_policy() {
+    let account_number = Secret::<String, AccountNumberStrategy>::new("1234567890".to_string());
+    let metadata = Metadata::Connector(Secret::new(serde_json::json!({ "key": "value" })));
+
+    assert_eq!(format!("{account_number:?}"), "*** ***");
+    // Internally tagged enums wrap the masking serializer, which exposes the secret
+    assert!(masking::masked_serialize(&metadata).is_ok());
+
+    masking::set_masking_policy(MaskingPolicy {
+        strict: true,
+        strategies: [(
+            "account_number".to_string(),
+            MaskingRule::ShowLast { characters: 4 },
+        )]
+        .into_iter()
+        .collect(),
+        hash_key: Secret::new("key".to_string()),
+    });
+
+    assert_eq!(format!("{account_number:?}"), "******7890");
+    assert!(masking::masked_serialize(&metadata).is_err());
+    assert_eq!(
+        serde_json::to_value(&metadata).unwrap(),
+        serde_json::json!({ "type": "Connector", "key": "value" })
+    );
+}
@@ -6,7 +6,7 @@
 //!
 //!
 
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use common_utils::{
     errors::CustomResult,
@@ -15,11 +15,11 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
+        MultipleValues, RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
     },
 };
 use futures::StreamExt;
@@ -230,6 +230,15 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expire_at(
         &self,
@@ -405,6 +414,67 @@ impl super::RedisConnectionPool {
             .await)
     }
 
+    /// Returns the keys matching the pattern, scanning every node of a cluster. This walks the
+    /// whole keyspace, so it is meant for infrequent listing requests only
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn scan(
+        &self,
+        pattern: &str,
+        count: Option<u32>,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        let pages = self
+            .pool
+            .next()
+            .scan_cluster(pattern, count, None)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut keys = Vec::new();
+        for page in pages {
+            let mut page = page
+                .into_report()
+                .change_context(errors::RedisError::GetFailed)?;
+            keys.extend(
+                page.take_results()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|key| key.as_str().map(ToOwned::to_owned)),
+            );
+        }
+        Ok(keys)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn add_set_members_with_expiry<V>(
+        &self,
+        key: &str,
+        members: V,
+        seconds: i64,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<MultipleValues> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.pool
+            .sadd::<(), _, _>(key, members)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetAddMembersFailed)?;
+        self.set_expiry(key, seconds).await
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_set_members(
+        &self,
+        key: &str,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .smembers(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetSetMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan_and_deserialize<T>(
         &self,
@@ -462,6 +532,19 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+    ) -> CustomResult<DelReply, errors::RedisError> {
+        self.pool
+            .hdel(key, field)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -570,6 +653,22 @@ impl super::RedisConnectionPool {
             })
     }
 
+    /// Reads the entries of a stream whose ids lie between `start` and `end`, both inclusive
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &str,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<(String, HashMap<String, String>)>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream, start, end, count)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
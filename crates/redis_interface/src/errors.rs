@@ -18,6 +18,8 @@ pub enum RedisError {
     GetFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to append entry to Redis stream")]
     StreamAppendFailed,
     #[error("Failed to read entries from Redis stream")]
@@ -52,6 +54,10 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to add members to set in Redis")]
+    SetAddMembersFailed,
+    #[error("Failed to get set members in Redis")]
+    GetSetMembersFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -1,23 +1,26 @@
 pub use analytics::*;
 
+pub mod reports;
+
 pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
         api_event::api_events_core, connector_events::connector_events_core,
-        errors::AnalyticsError, lambda_utils::invoke_lambda,
-        outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
+        errors::AnalyticsError, outgoing_webhook_event::outgoing_webhook_events_core,
+        reports::ReportType, sdk_events::sdk_events_core,
     };
     use api_models::analytics::{
-        GenerateReportRequest, GetApiEventFiltersRequest, GetApiEventMetricRequest,
-        GetPaymentFiltersRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetApiEventFiltersRequest, GetApiEventMetricRequest, GetDisputeFilterRequest,
+        GetDisputeMetricRequest, GetPaymentFiltersRequest, GetPaymentMetricRequest,
+        GetRefundFilterRequest, GetRefundMetricRequest, GetSdkEventFiltersRequest,
+        GetSdkEventMetricRequest, ReportRequest,
     };
     use error_stack::ResultExt;
     use router_env::AnalyticsFlow;
 
+    use super::reports;
     use crate::{
         core::api_locking,
-        db::user::UserInterface,
         routes::AppState,
         services::{
             api,
@@ -25,7 +28,6 @@ pub mod routes {
             authorization::permissions::Permission,
             ApplicationResponse,
         },
-        types::domain::UserEmail,
     };
 
     pub struct Analytics;
@@ -42,6 +44,10 @@ pub mod routes {
                     .service(
                         web::resource("metrics/refunds").route(web::post().to(get_refunds_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/disputes")
+                            .route(web::post().to(get_dispute_metrics)),
+                    )
                     .service(
                         web::resource("filters/payments")
                             .route(web::post().to(get_payment_filters)),
@@ -49,6 +55,10 @@ pub mod routes {
                     .service(
                         web::resource("filters/refunds").route(web::post().to(get_refund_filters)),
                     )
+                    .service(
+                        web::resource("filters/disputes")
+                            .route(web::post().to(get_dispute_filters)),
+                    )
                     .service(web::resource("{domain}/info").route(web::get().to(get_info)))
                     .service(
                         web::resource("report/dispute")
@@ -187,6 +197,42 @@ pub mod routes {
         .await
     }
 
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetDisputeMetricRequest` element.
+    pub async fn get_dispute_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetDisputeMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetDisputeMetricRequest");
+        let flow = AnalyticsFlow::GetDisputeMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req| async move {
+                analytics::disputes::get_metrics(
+                    &state.pool,
+                    &auth.merchant_account.merchant_id,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetSdkEventMetricRequest` element.
@@ -275,6 +321,32 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_dispute_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetDisputeFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetDisputeFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetDisputeFilterRequest| async move {
+                analytics::disputes::get_filters(
+                    &state.pool,
+                    req,
+                    &auth.merchant_account.merchant_id,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_sdk_event_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -395,29 +467,14 @@ pub mod routes {
                     .change_context(AnalyticsError::UnknownError)?
                     .user_id;
 
-                let user = UserInterface::find_user_by_id(&*state.store, &user_id)
-                    .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: auth.merchant_account.merchant_id.to_string(),
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                reports::generate_report_core(
+                    state,
+                    ReportType::Refunds,
+                    auth.merchant_account.merchant_id,
+                    &user_id,
+                    payload,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth(Permission::Analytics),
             api_locking::LockAction::NotApplicable,
@@ -447,29 +504,14 @@ pub mod routes {
                     .change_context(AnalyticsError::UnknownError)?
                     .user_id;
 
-                let user = UserInterface::find_user_by_id(&*state.store, &user_id)
-                    .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: auth.merchant_account.merchant_id.to_string(),
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                reports::generate_report_core(
+                    state,
+                    ReportType::Disputes,
+                    auth.merchant_account.merchant_id,
+                    &user_id,
+                    payload,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth(Permission::Analytics),
             api_locking::LockAction::NotApplicable,
@@ -499,29 +541,14 @@ pub mod routes {
                     .change_context(AnalyticsError::UnknownError)?
                     .user_id;
 
-                let user = UserInterface::find_user_by_id(&*state.store, &user_id)
-                    .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: auth.merchant_account.merchant_id.to_string(),
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                reports::generate_report_core(
+                    state,
+                    ReportType::Payments,
+                    auth.merchant_account.merchant_id,
+                    &user_id,
+                    payload,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth(Permission::Analytics),
             api_locking::LockAction::NotApplicable,
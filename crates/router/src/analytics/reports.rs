@@ -0,0 +1,237 @@
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::Path,
+};
+
+use analytics::{
+    errors::{AnalyticsError, AnalyticsResult},
+    reports::{generate_report, ReportType},
+};
+use api_models::analytics::{GenerateReportRequest, ReportFormat, ReportRequest};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::files::helpers as file_helpers,
+    db::user::UserInterface,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, ProcessTrackerExt},
+    },
+};
+
+/// Data required by the report workflow to generate a report and email it to the user who
+/// requested it
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ReportTrackingData {
+    pub report_type: ReportType,
+    pub merchant_id: String,
+    pub user_id: String,
+    pub request: ReportRequest,
+}
+
+/// Accepts a report request on behalf of the dashboard user and schedules the generation of the
+/// report. The user is emailed a download link once the report has been stored.
+pub async fn generate_report_core(
+    state: AppState,
+    report_type: ReportType,
+    merchant_id: String,
+    user_id: &str,
+    request: ReportRequest,
+) -> AnalyticsResult<ApplicationResponse<()>> {
+    // Report generation scans the whole time range, so it is run by the scheduler rather than
+    // being tied to the request lifecycle
+    let report_id = common_utils::generate_id(consts::ID_LENGTH, "report");
+    let runner = "REPORT_WORKFLOW";
+    let task = "GENERATE_REPORT";
+    let process_tracker_id =
+        scheduler::utils::get_process_tracker_id(runner, task, &report_id, &merchant_id);
+    let tracking_data = ReportTrackingData {
+        report_type,
+        merchant_id,
+        user_id: user_id.to_owned(),
+        request,
+    };
+    let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .into_report()
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to construct report task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to schedule report task")?;
+
+    Ok(ApplicationResponse::Json(()))
+}
+
+/// Generates the report of a report task and emails its download link to the user who requested
+/// it
+#[instrument(skip_all, fields(merchant_id = %tracking_data.merchant_id))]
+pub async fn generate_and_send_report(
+    state: &AppState,
+    tracking_data: &ReportTrackingData,
+) -> AnalyticsResult<()> {
+    let report_type = tracking_data.report_type;
+    let user = UserInterface::find_user_by_id(&*state.store, &tracking_data.user_id)
+        .await
+        .change_context(AnalyticsError::UnknownError)?;
+
+    let user_email = domain::UserEmail::from_pii_email(user.email)
+        .change_context(AnalyticsError::UnknownError)?
+        .get_secret();
+
+    let report_request = GenerateReportRequest {
+        request: tracking_data.request.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        email: user_email,
+    };
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    // The report is spooled to disk as it is generated and uploaded from there, so that it is
+    // never held in memory as a whole
+    let report_path = std::env::temp_dir().join(&file_id);
+    let stored_report =
+        generate_and_store_report(state, report_type, &report_request, &file_id, &report_path)
+            .await;
+    if let Err(error) = fs::remove_file(&report_path) {
+        logger::warn!(?error, "Failed to remove the spooled {report_type} report");
+    }
+    let file_metadata = stored_report?;
+
+    let (link, _) = file_helpers::create_file_download_link(
+        state,
+        &file_metadata,
+        state.conf.report_download_config.download_link_expiry,
+    )
+    .await
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to create report download link")?;
+    send_report_email(state, report_type, &report_request, link).await
+}
+
+async fn generate_and_store_report(
+    state: &AppState,
+    report_type: ReportType,
+    report_request: &GenerateReportRequest,
+    file_id: &str,
+    report_path: &Path,
+) -> AnalyticsResult<diesel_models::file::FileMetadata> {
+    let merchant_id = &report_request.merchant_id;
+    let format = report_request.request.format;
+
+    let report_file = File::create(report_path)
+        .into_report()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to create the report file")?;
+    generate_report(
+        &state.pool,
+        report_type,
+        merchant_id,
+        &report_request.request,
+        state.conf.report_download_config.batch_size,
+        BufWriter::new(report_file),
+    )
+    .await?
+    .into_inner()
+    .map_err(|error| error.into_error())
+    .into_report()
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to flush the report file")?;
+
+    let file_size = fs::metadata(report_path)
+        .into_report()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to read the report file size")
+        .and_then(|metadata| {
+            i32::try_from(metadata.len())
+                .into_report()
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Report exceeds the maximum storable file size")
+        })?;
+    let file_type = match format {
+        ReportFormat::Csv => mime::TEXT_CSV,
+        ReportFormat::Parquet => mime::APPLICATION_OCTET_STREAM,
+    };
+
+    let file_key = file_helpers::get_file_key(merchant_id, file_id);
+    file_helpers::upload_file_from_path(state, file_key.clone(), report_path)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to upload report")?;
+
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id: file_id.to_string(),
+        merchant_id: merchant_id.to_string(),
+        file_name: Some(format!("{report_type}_report_{file_id}.{format}")),
+        file_size,
+        file_type: file_type.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(diesel_models::enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: None,
+        merchant_connector_id: None,
+        purpose: None,
+    };
+    state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Unable to insert report file_metadata")
+}
+
+#[cfg(feature = "email")]
+async fn send_report_email(
+    state: &AppState,
+    report_type: ReportType,
+    report_request: &GenerateReportRequest,
+    link: String,
+) -> AnalyticsResult<()> {
+    use crate::services::email::types as email_types;
+
+    let email_contents = email_types::ReportDownload {
+        recipient_email: domain::UserEmail::new(report_request.email.clone())
+            .change_context(AnalyticsError::UnknownError)?,
+        report_name: report_type.to_string(),
+        merchant_id: report_request.merchant_id.clone(),
+        link,
+        expiry_in_hours: state.conf.report_download_config.download_link_expiry / 3600,
+        settings: state.conf.clone(),
+        subject: format!("Your {report_type} report is ready"),
+    };
+
+    state
+        .email_client
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to send report download email")
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_report_email(
+    _state: &AppState,
+    report_type: ReportType,
+    _report_request: &GenerateReportRequest,
+    link: String,
+) -> AnalyticsResult<()> {
+    logger::info!(%link, "Email is disabled, {report_type} report is available for download");
+    Ok(())
+}
@@ -83,6 +83,15 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    OutgoingWebhookRetryWorkflow,
+    BlocklistExpiryWorkflow,
+    SubscriptionWorkflow,
+    #[cfg(feature = "payouts")]
+    PayoutsSyncWorkflow,
+    #[cfg(feature = "payouts")]
+    PayoutBatchWorkflow,
+    #[cfg(feature = "olap")]
+    ReportWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -107,6 +116,25 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            Some(PTRunner::OutgoingWebhookRetryWorkflow) => {
+                Box::new(workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow)
+            }
+            Some(PTRunner::BlocklistExpiryWorkflow) => {
+                Box::new(workflows::blocklist_expiry::BlocklistExpiryWorkflow)
+            }
+            Some(PTRunner::SubscriptionWorkflow) => {
+                Box::new(workflows::subscription::SubscriptionWorkflow)
+            }
+            #[cfg(feature = "payouts")]
+            Some(PTRunner::PayoutsSyncWorkflow) => {
+                Box::new(workflows::payout_sync::PayoutsSyncWorkflow)
+            }
+            #[cfg(feature = "payouts")]
+            Some(PTRunner::PayoutBatchWorkflow) => {
+                Box::new(workflows::payout_batch::PayoutBatchWorkflow)
+            }
+            #[cfg(feature = "olap")]
+            Some(PTRunner::ReportWorkflow) => Box::new(workflows::report::ReportWorkflow),
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
@@ -120,6 +120,12 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
     PayoutNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout batch")]
+    PayoutBatchNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such subscription")]
+    SubscriptionNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "Duplicate payout request")]
     DuplicatePayout { payout_id: String },
 
@@ -243,6 +249,12 @@ pub enum StripeErrorCode {
     InvalidConnectorConfiguration { config: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "HE_01", message = "Failed to convert currency to minor unit")]
     CurrencyConversionFailed,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "Keys for idempotent requests can only be used with the same parameters they were first used with. Try using a key other than '{idempotency_key}' if you meant to execute a different request.")]
+    IdempotencyKeyReused { idempotency_key: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this Idempotent Key (that probably means you submitted twice, and the other request is still going through): {idempotency_key}. Please try again later.")]
+    IdempotentRequestInProgress { idempotency_key: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly. Retry after {retry_after_seconds} seconds.")]
+    RateLimitExceeded { retry_after_seconds: u64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -507,9 +519,31 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
             errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::PayoutBatchNotFound => Self::PayoutBatchNotFound,
+            errors::ApiErrorResponse::SubscriptionNotFound => Self::SubscriptionNotFound,
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
+            errors::ApiErrorResponse::MandateTransactionLimitExceeded { limit } => {
+                Self::PaymentIntentMandateInvalid {
+                    message: format!(
+                        "The amount exceeds the per transaction limit of {limit} of the mandate"
+                    ),
+                }
+            }
+            errors::ApiErrorResponse::MandateCumulativeLimitExceeded { remaining_amount } => {
+                Self::PaymentIntentMandateInvalid {
+                    message: format!(
+                        "The amount exceeds the remaining allowance of {remaining_amount} of the mandate"
+                    ),
+                }
+            }
+            errors::ApiErrorResponse::MandateNotYetValid => Self::PaymentIntentMandateInvalid {
+                message: "The mandate is not valid before its start date".to_string(),
+            },
+            errors::ApiErrorResponse::MandateExpired => Self::PaymentIntentMandateInvalid {
+                message: "The mandate has expired".to_string(),
+            },
             errors::ApiErrorResponse::ReturnUrlUnavailable => Self::ReturnUrlUnavailable,
             errors::ApiErrorResponse::DuplicateMerchantAccount => Self::DuplicateMerchantAccount,
             errors::ApiErrorResponse::DuplicateMerchantConnectorAccount {
@@ -599,6 +633,17 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::InvalidConnectorConfiguration { config }
             }
             errors::ApiErrorResponse::CurrencyConversionFailed => Self::CurrencyConversionFailed,
+            errors::ApiErrorResponse::IdempotencyKeyReused { idempotency_key } => {
+                Self::IdempotencyKeyReused { idempotency_key }
+            }
+            errors::ApiErrorResponse::IdempotentRequestInProgress { idempotency_key } => {
+                Self::IdempotentRequestInProgress { idempotency_key }
+            }
+            errors::ApiErrorResponse::TooManyRequests {
+                retry_after_seconds,
+            } => Self::RateLimitExceeded {
+                retry_after_seconds,
+            },
         }
     }
 }
@@ -634,6 +679,8 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MandateNotFound
             | Self::ApiKeyNotFound
             | Self::PayoutNotFound
+            | Self::PayoutBatchNotFound
+            | Self::SubscriptionNotFound
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount { .. }
             | Self::DuplicatePaymentMethod
@@ -667,7 +714,8 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::DuplicateCustomer
             | Self::PaymentMethodUnactivated
             | Self::InvalidConnectorConfiguration { .. }
-            | Self::CurrencyConversionFailed => StatusCode::BAD_REQUEST,
+            | Self::CurrencyConversionFailed
+            | Self::IdempotencyKeyReused { .. } => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
             | Self::PaymentLinkNotFound
@@ -680,6 +728,8 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::IdempotentRequestInProgress { .. } => StatusCode::CONFLICT,
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
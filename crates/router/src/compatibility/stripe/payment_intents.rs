@@ -2,11 +2,12 @@ pub mod types;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::payments as payment_types;
 use error_stack::report;
+use futures::TryFutureExt;
 use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
-    core::{api_locking::GetLockingInput, payment_methods::Oss, payments},
+    core::{api_locking::GetLockingInput, idempotency, payment_methods::Oss, payments},
     routes,
     services::{api, authentication as auth},
     types::api as api_types,
@@ -32,6 +33,19 @@ pub async fn payment_intents_create(
         Err(err) => return api::log_and_return_error_response(err),
     };
 
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        &create_payment_req,
+        routes::lock_utils::ApiIdentifier::Payments,
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(
+                err.current_context().clone()
+            )))
+        }
+    };
+
     let flow = Flow::PaymentsCreate;
     let locking_action = create_payment_req.get_locking_input(flow.clone());
     Box::pin(wrap::compatibility_api_wrap::<
@@ -41,7 +55,7 @@ pub async fn payment_intents_create(
         _,
         _,
         _,
-        types::StripePaymentIntentResponse,
+        idempotency::IdempotentResponse<types::StripePaymentIntentResponse>,
         errors::StripeErrorCode,
         _,
     >(
@@ -51,8 +65,18 @@ pub async fn payment_intents_create(
         create_payment_req,
         |state, auth, req| {
             let eligible_connectors = req.connector.clone();
-            payments::payments_core::<api_types::Authorize, api_types::PaymentsResponse, _, _, _,Oss>(
-                state,
+            let merchant_id = auth.merchant_account.merchant_id.clone();
+            // The response is converted before it is stored, so that replayed responses are
+            // returned in the same format as the original response
+            let payment_response = payments::payments_core::<
+                api_types::Authorize,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                Oss,
+            >(
+                state.clone(),
                 auth.merchant_account,
                 auth.key_store,
                 payments::PaymentCreate,
@@ -62,6 +86,13 @@ pub async fn payment_intents_create(
                 eligible_connectors,
                 api_types::HeaderPayload::default(),
             )
+            .map_ok(|response| response.map(types::StripePaymentIntentResponse::from));
+            idempotency::perform_idempotent_request(
+                state,
+                idempotency_input.clone(),
+                merchant_id,
+                payment_response,
+            )
         },
         &auth::ApiKeyAuth,
         locking_action,
@@ -736,6 +736,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            max_transaction_amount: None,
                         },
                     )),
                     StripeMandateType::MultiUse => Some(payments::MandateType::MultiUse(None)),
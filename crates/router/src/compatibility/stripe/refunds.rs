@@ -1,11 +1,12 @@
 pub mod types;
 use actix_web::{web, HttpRequest, HttpResponse};
 use error_stack::report;
+use futures::TryFutureExt;
 use router_env::{instrument, tracing, Flow};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
-    core::{api_locking, refunds},
+    core::{api_locking, idempotency, refunds},
     routes,
     services::{api, authentication as auth},
     types::api::refunds as refund_types,
@@ -27,6 +28,18 @@ pub async fn refund_create(
     };
 
     let create_refund_req: refund_types::RefundRequest = payload.into();
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        &create_refund_req,
+        routes::lock_utils::ApiIdentifier::Refunds,
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(
+                err.current_context().clone()
+            )))
+        }
+    };
 
     let flow = Flow::RefundsCreate;
 
@@ -37,7 +50,7 @@ pub async fn refund_create(
         _,
         _,
         _,
-        types::StripeRefundResponse,
+        idempotency::IdempotentResponse<types::StripeRefundResponse>,
         errors::StripeErrorCode,
         _,
     >(
@@ -46,7 +59,20 @@ pub async fn refund_create(
         &req,
         create_refund_req,
         |state, auth, req| {
-            refunds::refund_create_core(state, auth.merchant_account, auth.key_store, req)
+            let merchant_id = auth.merchant_account.merchant_id.clone();
+            let refund_response = refunds::refund_create_core(
+                state.clone(),
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+            .map_ok(|response| response.map(types::StripeRefundResponse::from));
+            idempotency::perform_idempotent_request(
+                state,
+                idempotency_input.clone(),
+                merchant_id,
+                refund_response,
+            )
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
@@ -74,6 +74,8 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    Payout(Box<api_models::payouts::PayoutCreateResponse>),
+    Subscription(Box<api_models::subscriptions::SubscriptionResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -183,6 +185,12 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::PayoutSuccess => "payout.paid",
+        api_models::enums::EventType::PayoutFailed => "payout.failed",
+        api_models::enums::EventType::PayoutProcessing => "payout.processing",
+        api_models::enums::EventType::PayoutReversed => "payout.reversed",
+        api_models::enums::EventType::SubscriptionRenewed => "invoice.paid",
+        api_models::enums::EventType::SubscriptionRenewalFailed => "invoice.payment_failed",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -230,6 +238,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout),
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription(subscription)
+            }
         }
     }
 }
@@ -95,6 +95,30 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            // 24 hours
+            response_expiry_seconds: 86400,
+            // 3 minutes
+            in_progress_expiry_seconds: 180,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_seconds: 60,
+            default_limit: 1000,
+            route_group_limits: HashMap::new(),
+            // 5 minutes
+            identity_cache_expiry_seconds: 300,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
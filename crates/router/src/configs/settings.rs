@@ -28,6 +28,7 @@ use crate::{
     core::errors::{ApplicationError, ApplicationResult},
     env::{self, logger, Env},
     events::EventsConfig,
+    services::file_storage::FileStorageConfig,
 };
 #[cfg(feature = "kms")]
 pub type Password = kms::KmsValue;
@@ -92,8 +93,7 @@ pub struct Settings {
     pub kms: kms::KmsConfig,
     #[cfg(feature = "hashicorp-vault")]
     pub hc_vault: hashicorp_vault::HashiCorpVaultConfig,
-    #[cfg(feature = "aws_s3")]
-    pub file_upload_config: FileUploadConfig,
+    pub file_storage: FileStorageConfig,
     pub tokenization: TokenizationConfig,
     pub connector_customer: ConnectorCustomer,
     #[cfg(feature = "dummy_connector")]
@@ -112,6 +112,8 @@ pub struct Settings {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: ApplepayMerchantConfigs,
     pub lock_settings: LockSettings,
+    pub idempotency: IdempotencyConfig,
+    pub rate_limit: RateLimitConfig,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub payment_link: PaymentLink,
     #[cfg(feature = "olap")]
@@ -143,7 +145,7 @@ pub struct PaymentLink {
     pub sdk_url: String,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ForexApi {
     pub local_fetch_retry_count: u64,
@@ -157,6 +159,58 @@ pub struct ForexApi {
     pub api_timeout: u64,
     /// in ms
     pub redis_lock_timeout: u64,
+    /// Provider queried for rates, authenticated with `api_key` where required
+    pub provider: ForexProviderConfig,
+    /// Provider queried when the primary one fails, authenticated with `fallback_api_key`
+    pub fallback_provider: ForexProviderConfig,
+    /// Maximum number of days between a transaction date and the snapshot whose rates are used
+    /// for it
+    pub max_snapshot_age_days: u16,
+}
+
+impl Default for ForexApi {
+    fn default() -> Self {
+        Self {
+            local_fetch_retry_count: Default::default(),
+            api_key: Default::default(),
+            fallback_api_key: Default::default(),
+            call_delay: Default::default(),
+            local_fetch_retry_delay: Default::default(),
+            api_timeout: Default::default(),
+            redis_lock_timeout: Default::default(),
+            provider: ForexProviderConfig::OpenExchangeRates,
+            fallback_provider: ForexProviderConfig::ApiLayer,
+            max_snapshot_age_days: 7,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ForexProviderConfig {
+    #[default]
+    OpenExchangeRates,
+    ApiLayer,
+    /// Rates read from a JSON file in the `DefaultExchangeRates` format
+    StaticFile {
+        path: String,
+    },
+    /// Daily reference rates in the ECB `eurofxref` XML format, which can also be served locally
+    EcbXml {
+        url: String,
+    },
+}
+
+impl ForexProviderConfig {
+    /// Name recorded against the rate snapshots taken from this provider
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::OpenExchangeRates => "open_exchange_rates",
+            Self::ApiLayer => "api_layer",
+            Self::StaticFile { .. } => "static_file",
+            Self::EcbXml { .. } => "ecb_xml",
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -721,16 +775,6 @@ pub struct ApiKeys {
     pub expiry_reminder_days: Vec<u8>,
 }
 
-#[cfg(feature = "aws_s3")]
-#[derive(Debug, Deserialize, Clone, Default)]
-#[serde(default)]
-pub struct FileUploadConfig {
-    /// The AWS region to send file uploads
-    pub region: String,
-    /// The AWS s3 bucket to send file uploads
-    pub bucket_name: String,
-}
-
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DelayedSessionConfig {
     #[serde(deserialize_with = "deser_to_get_connectors")]
@@ -853,9 +897,10 @@ impl Settings {
         self.kms
             .validate()
             .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
-        #[cfg(feature = "aws_s3")]
-        self.file_upload_config.validate()?;
+        self.file_storage.validate()?;
         self.lock_settings.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
         self.events.validate()?;
         Ok(())
     }
@@ -883,6 +928,8 @@ mod payment_method_deserialization_test {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Payouts {
     pub payout_eligibility: bool,
+    /// Number of items of a payout batch which are processed concurrently
+    pub batch_item_concurrency: usize,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -914,6 +961,31 @@ impl<'de> Deserialize<'de> for LockSettings {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct IdempotencyConfig {
+    /// Time (in seconds) for which the response of an idempotent request is replayed
+    pub response_expiry_seconds: i64,
+    /// Time (in seconds) after which a request that is still being processed no longer blocks
+    /// other requests made with the same idempotency key
+    pub in_progress_expiry_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    /// Whether requests made with an API key are rate limited
+    pub enabled: bool,
+    /// Length (in seconds) of the window over which requests are counted
+    pub window_in_seconds: i64,
+    /// Maximum number of requests allowed per API key and route group in a window
+    pub default_limit: u64,
+    /// Limits for specific route groups, keyed by the first segment of the request path
+    pub route_group_limits: HashMap<String, u64>,
+    /// Time (in seconds) for which the limits resolved for an API key are cached
+    pub identity_cache_expiry_seconds: i64,
+}
+
 #[cfg(feature = "olap")]
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
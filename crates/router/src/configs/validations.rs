@@ -127,20 +127,31 @@ impl super::settings::DrainerSettings {
     }
 }
 
-#[cfg(feature = "aws_s3")]
-impl super::settings::FileUploadConfig {
+impl crate::services::file_storage::FileStorageConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(self.region.is_default_or_empty(), || {
-            Err(ApplicationError::InvalidConfigurationValueError(
-                "s3 region must not be empty".into(),
-            ))
-        })?;
+        #[cfg(feature = "aws_s3")]
+        if matches!(
+            self.backend,
+            crate::services::file_storage::FileStorageBackend::AwsS3
+        ) {
+            when(self.aws_s3.region.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "s3 region must not be empty".into(),
+                ))
+            })?;
 
-        when(self.bucket_name.is_default_or_empty(), || {
+            when(self.aws_s3.bucket_name.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "s3 bucket name must not be empty".into(),
+                ))
+            })?;
+        }
+
+        when(self.download_link_expiry <= 0, || {
             Err(ApplicationError::InvalidConfigurationValueError(
-                "s3 bucket name must not be empty".into(),
+                "file download link expiry must be positive".into(),
             ))
         })
     }
@@ -193,3 +204,39 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::IdempotencyConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.response_expiry_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency response_expiry_seconds must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.in_progress_expiry_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency in_progress_expiry_seconds must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window_in_seconds must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.identity_cache_expiry_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit identity_cache_expiry_seconds must be greater than 0".into(),
+            ))
+        })
+    }
+}
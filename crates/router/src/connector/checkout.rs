@@ -921,6 +921,11 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch | api::FilePurpose::BlocklistImport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "only dispute evidence files are uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
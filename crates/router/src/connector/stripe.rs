@@ -1418,6 +1418,11 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch | api::FilePurpose::BlocklistImport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "only dispute evidence files are uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
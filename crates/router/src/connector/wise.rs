@@ -230,6 +230,8 @@ impl api::PayoutQuote for Wise {}
 impl api::PayoutRecipient for Wise {}
 #[cfg(feature = "payouts")]
 impl api::PayoutFulfill for Wise {}
+#[cfg(feature = "payouts")]
+impl api::PayoutSync for Wise {}
 
 #[cfg(feature = "payouts")]
 impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
@@ -688,6 +690,74 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
     }
 }
 
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Wise
+{
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let transfer_id = req.request.connector_payout_id.to_owned().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "transfer_id",
+            },
+        )?;
+        Ok(format!(
+            "{}v1/transfers/{}",
+            connectors.wise.base_url, transfer_id
+        ))
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutSyncType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutSyncType::get_headers(self, req, connectors)?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoSync>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoSync>, errors::ConnectorError> {
+        let response: wise::WisePayoutSyncResponse = res
+            .response
+            .parse_struct("WisePayoutSyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 impl api::Refund for Wise {}
 impl api::RefundExecute for Wise {}
 impl api::RefundSync for Wise {}
@@ -291,6 +291,35 @@ pub enum WiseStatus {
 
     #[serde(rename = "incoming_payment_waiting")]
     IncomingPaymentWaiting,
+
+    #[serde(rename = "incoming_payment_initiated")]
+    IncomingPaymentInitiated,
+
+    #[serde(rename = "funds_converted")]
+    FundsConverted,
+
+    #[serde(rename = "outgoing_payment_sent")]
+    OutgoingPaymentSent,
+
+    #[serde(rename = "funds_refunded")]
+    FundsRefunded,
+
+    #[serde(rename = "bounced_back")]
+    BouncedBack,
+
+    #[serde(rename = "charged_back")]
+    ChargedBack,
+
+    #[serde(rename = "unknown")]
+    Unknown,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WisePayoutSyncResponse {
+    id: i64,
+    status: WiseStatus,
 }
 
 #[cfg(feature = "payouts")]
@@ -519,6 +548,28 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutResponse>>
     }
 }
 
+// Payouts sync response transform
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutSyncResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, WisePayoutSyncResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response: WisePayoutSyncResponse = item.response;
+
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(storage_enums::PayoutStatus::foreign_from(response.status)),
+                connector_payout_id: response.id.to_string(),
+                payout_eligible: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 // Payouts fulfill request transform
 #[cfg(feature = "payouts")]
 impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutFulfillRequest {
@@ -562,12 +613,18 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseFulfillResponse>>
 impl ForeignFrom<WiseStatus> for storage_enums::PayoutStatus {
     fn foreign_from(wise_status: WiseStatus) -> Self {
         match wise_status {
-            WiseStatus::Completed => Self::Success,
+            WiseStatus::Completed | WiseStatus::OutgoingPaymentSent => Self::Success,
             WiseStatus::Rejected => Self::Failed,
             WiseStatus::Cancelled => Self::Cancelled,
-            WiseStatus::Pending | WiseStatus::Processing | WiseStatus::IncomingPaymentWaiting => {
-                Self::Pending
+            WiseStatus::FundsRefunded | WiseStatus::BouncedBack | WiseStatus::ChargedBack => {
+                Self::Reversed
             }
+            WiseStatus::Pending
+            | WiseStatus::Processing
+            | WiseStatus::IncomingPaymentWaiting
+            | WiseStatus::IncomingPaymentInitiated
+            | WiseStatus::FundsConverted
+            | WiseStatus::Unknown => Self::Pending,
         }
     }
 }
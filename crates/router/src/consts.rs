@@ -30,6 +30,9 @@ pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
+/// Error code recorded on payment attempts blocked by a blocklist rule
+pub const BLOCKLIST_ERROR_CODE: &str = "HE_03";
+
 // String literals
 pub(crate) const NO_ERROR_MESSAGE: &str = "No error message";
 pub(crate) const NO_ERROR_CODE: &str = "No error code";
@@ -87,3 +90,16 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
+
+/// Max size of a CSV file consumed by the router, like payout batches and blocklist imports,
+/// 5 Megabytes (MB)
+pub const MAX_CSV_FILE_SIZE: i32 = 5000000;
+
+/// Max number of payouts which can be submitted in a single payout batch
+pub const MAX_PAYOUT_BATCH_SIZE: usize = 10000;
+
+/// Number of payout batch items inserted in a single query
+pub const PAYOUT_BATCH_ITEM_INSERT_CHUNK_SIZE: usize = 500;
+
+/// Max number of entries which can be imported from a single blocklist CSV file
+pub const MAX_BLOCKLIST_IMPORT_SIZE: usize = 10000;
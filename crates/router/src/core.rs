@@ -17,6 +17,7 @@ pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
 pub mod gsm;
+pub mod idempotency;
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
@@ -28,6 +29,7 @@ pub mod payouts;
 pub mod pm_auth;
 pub mod refunds;
 pub mod routing;
+pub mod subscriptions;
 pub mod surcharge_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
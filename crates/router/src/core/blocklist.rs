@@ -1,3 +1,4 @@
+pub mod bulk;
 pub mod transformers;
 pub mod utils;
 
@@ -14,8 +15,9 @@ pub async fn add_entry_to_blocklist(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     body: api_blocklist::AddToBlocklistRequest,
+    created_by: Option<String>,
 ) -> RouterResponse<api_blocklist::AddToBlocklistResponse> {
-    utils::insert_entry_into_blocklist(&state, merchant_account.merchant_id, body)
+    utils::insert_entry_into_blocklist(&state, merchant_account.merchant_id, body, created_by)
         .await
         .map(services::ApplicationResponse::Json)
 }
@@ -39,3 +41,24 @@ pub async fn list_blocklist_entries(
         .await
         .map(services::ApplicationResponse::Json)
 }
+
+pub async fn import_blocklist(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    body: api_blocklist::BlocklistImportRequest,
+    created_by: Option<String>,
+) -> RouterResponse<api_blocklist::BlocklistImportResponse> {
+    bulk::import_blocklist_entries(&state, &merchant_account, body, created_by)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn export_blocklist(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    query: api_blocklist::BlocklistExportQuery,
+) -> RouterResponse<Vec<u8>> {
+    bulk::export_blocklist_entries(&state, &merchant_account.merchant_id, query)
+        .await
+        .map(|csv| services::ApplicationResponse::FileData((csv, mime::TEXT_CSV)))
+}
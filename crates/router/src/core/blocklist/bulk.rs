@@ -0,0 +1,341 @@
+use api_models::blocklist as api_blocklist;
+use common_utils::errors::ErrorSwitch;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::utils;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        files,
+    },
+    routes::AppState,
+    types::{api, domain, storage},
+};
+
+/// A single row of a blocklist CSV file, used both for imports and exports
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct BlocklistCsvRecord {
+    #[serde(rename = "type")]
+    data_type: String,
+    data: String,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    expires_at: Option<time::PrimitiveDateTime>,
+    reason: Option<String>,
+    /// Exported for reference only, imported entries are created by the importer
+    #[serde(default)]
+    created_by: Option<String>,
+}
+
+impl BlocklistCsvRecord {
+    fn into_request(self) -> RouterResult<api_blocklist::AddToBlocklistRequest> {
+        let data = serde_json::from_value::<api_blocklist::BlocklistRequest>(serde_json::json!({
+            "type": self.data_type,
+            "data": self.data,
+        }))
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue { field_name: "type" })?;
+
+        Ok(api_blocklist::AddToBlocklistRequest {
+            data,
+            expires_at: self.expires_at,
+            reason: self.reason,
+        })
+    }
+}
+
+impl From<storage::Blocklist> for BlocklistCsvRecord {
+    fn from(entry: storage::Blocklist) -> Self {
+        // Blocked payment methods are added by their fingerprint, other kinds share their name
+        // with the request type
+        let data_type = match entry.data_kind {
+            common_enums::BlocklistDataKind::PaymentMethod => "fingerprint".to_string(),
+            data_kind => data_kind.to_string(),
+        };
+
+        Self {
+            data_type,
+            data: entry.fingerprint_id,
+            expires_at: entry.expires_at,
+            reason: entry.reason,
+            created_by: entry.created_by,
+        }
+    }
+}
+
+/// Reads the header and the rows of a blocklist CSV file
+fn read_blocklist_csv(file: &[u8]) -> RouterResult<(csv::StringRecord, Vec<csv::StringRecord>)> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(file);
+    let headers = reader
+        .headers()
+        .cloned()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("failed to parse the blocklist file: {error}"),
+        })
+        .into_report()?;
+    let records = reader
+        .records()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("failed to parse the blocklist file: {error}"),
+        })
+        .into_report()?;
+
+    Ok((headers, records))
+}
+
+fn parse_blocklist_record(
+    record: &csv::StringRecord,
+    headers: &csv::StringRecord,
+) -> RouterResult<api_blocklist::AddToBlocklistRequest> {
+    record
+        .deserialize::<BlocklistCsvRecord>(Some(headers))
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("failed to parse the row: {error}"),
+        })
+        .into_report()?
+        .into_request()
+}
+
+/// Whether a blocklist entry is part of an export, expired entries and entries that only store
+/// the hash of personal data can't be imported back and are left out
+fn is_exportable_entry(
+    entry: &storage::Blocklist,
+    data_kind: Option<&common_enums::BlocklistDataKind>,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    data_kind.map_or(true, |data_kind| &entry.data_kind == data_kind)
+        && !utils::is_blocklist_entry_expired(entry, now)
+        && !utils::stores_hashed_value(entry.data_kind)
+}
+
+fn get_error_message(error: &error_stack::Report<errors::ApiErrorResponse>) -> String {
+    let mut api_error: api_models::errors::types::ApiErrorResponse =
+        error.current_context().switch();
+    api_error.get_internal_error_mut().error_message.clone()
+}
+
+#[instrument(skip_all)]
+pub async fn import_blocklist_entries(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    req: api_blocklist::BlocklistImportRequest,
+    created_by: Option<String>,
+) -> RouterResult<api_blocklist::BlocklistImportResponse> {
+    let file = files::helpers::retrieve_file_for_purpose(
+        state,
+        merchant_account,
+        &req.file_id,
+        api::FilePurpose::BlocklistImport,
+    )
+    .await?;
+
+    let (headers, records) = read_blocklist_csv(&file)?;
+
+    if records.len() > consts::MAX_BLOCKLIST_IMPORT_SIZE {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "at most {} entries can be imported at once",
+                consts::MAX_BLOCKLIST_IMPORT_SIZE
+            ),
+        })
+        .into_report();
+    }
+
+    let mut imported = Vec::new();
+    let mut failed = Vec::new();
+    let data_column = headers.iter().position(|header| header == "data");
+    for record in records {
+        let line = record.position().map_or(0, |position| position.line());
+        let data = data_column
+            .and_then(|column| record.get(column))
+            .map(ToString::to_string);
+
+        let result = match parse_blocklist_record(&record, &headers) {
+            Ok(to_block) => {
+                utils::insert_entry_into_blocklist(
+                    state,
+                    merchant_account.merchant_id.clone(),
+                    to_block,
+                    created_by.clone(),
+                )
+                .await
+            }
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(entry) => imported.push(entry),
+            Err(error) => {
+                logger::warn!(blocklist_import_error=?error, line);
+                failed.push(api_blocklist::BlocklistImportError {
+                    line,
+                    data,
+                    error_message: get_error_message(&error),
+                });
+            }
+        }
+    }
+
+    Ok(api_blocklist::BlocklistImportResponse { imported, failed })
+}
+
+#[instrument(skip_all)]
+pub async fn export_blocklist_entries(
+    state: &AppState,
+    merchant_id: &str,
+    query: api_blocklist::BlocklistExportQuery,
+) -> RouterResult<Vec<u8>> {
+    let entries = state
+        .store
+        .list_blocklist_entries_by_merchant_id(merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "no blocklist records found".to_string(),
+        })?;
+
+    let now = common_utils::date_time::now();
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for entry in entries
+        .into_iter()
+        .filter(|entry| is_exportable_entry(entry, query.data_kind.as_ref(), now))
+    {
+        writer
+            .serialize(BlocklistCsvRecord::from(entry))
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to write blocklist entry to csv")?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|error| error.into_error())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to flush blocklist csv")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn blocklist_entry(
+        data_kind: common_enums::BlocklistDataKind,
+        fingerprint_id: &str,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::Blocklist {
+        storage::Blocklist {
+            id: 1,
+            merchant_id: "merchant_1".to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
+            data_kind,
+            metadata: None,
+            created_at: common_utils::date_time::now(),
+            expires_at,
+            reason: Some("chargebacks".to_string()),
+            created_by: Some("user_1".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_blocklist_csv_parsing() {
+        let file = b"type,data,expires_at,reason\n\
+            card_bin, 424242 ,,fraud\n\
+            ip_range,192.168.0.0/16,2030-01-01T00:00:00.000Z,\n\
+            unknown,value,,\n";
+        let (headers, records) = read_blocklist_csv(file).unwrap();
+        assert_eq!(records.len(), 3);
+
+        let card_bin = parse_blocklist_record(&records[0], &headers).unwrap();
+        assert!(matches!(
+            card_bin.data,
+            api_blocklist::BlocklistRequest::CardBin(ref bin) if bin == "424242"
+        ));
+        assert_eq!(card_bin.reason.as_deref(), Some("fraud"));
+        assert!(card_bin.expires_at.is_none());
+
+        let ip_range = parse_blocklist_record(&records[1], &headers).unwrap();
+        assert!(matches!(
+            ip_range.data,
+            api_blocklist::BlocklistRequest::IpRange(ref range) if range == "192.168.0.0/16"
+        ));
+        assert_eq!(
+            ip_range.expires_at,
+            Some(time::macros::datetime!(2030-01-01 00:00:00))
+        );
+        assert!(ip_range.reason.is_none());
+
+        assert!(parse_blocklist_record(&records[2], &headers).is_err());
+        assert!(read_blocklist_csv(b"type,data\ncard_bin\n").is_err());
+    }
+
+    #[test]
+    fn test_blocklist_csv_record_round_trip() {
+        let expires_at = time::macros::datetime!(2030-01-01 00:00:00);
+        let entries = [
+            blocklist_entry(
+                common_enums::BlocklistDataKind::PaymentMethod,
+                "fingerprint_123",
+                Some(expires_at),
+            ),
+            blocklist_entry(common_enums::BlocklistDataKind::CardBin, "424242", None),
+        ];
+
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for entry in entries {
+            writer.serialize(BlocklistCsvRecord::from(entry)).unwrap();
+        }
+        let file = writer.into_inner().unwrap();
+
+        let (headers, records) = read_blocklist_csv(&file).unwrap();
+        assert_eq!(
+            headers.iter().collect::<Vec<_>>(),
+            ["type", "data", "expires_at", "reason", "created_by"]
+        );
+
+        let fingerprint = parse_blocklist_record(&records[0], &headers).unwrap();
+        assert!(matches!(
+            fingerprint.data,
+            api_blocklist::BlocklistRequest::Fingerprint(ref id) if id == "fingerprint_123"
+        ));
+        assert_eq!(fingerprint.expires_at, Some(expires_at));
+        assert_eq!(fingerprint.reason.as_deref(), Some("chargebacks"));
+
+        let card_bin = parse_blocklist_record(&records[1], &headers).unwrap();
+        assert!(matches!(
+            card_bin.data,
+            api_blocklist::BlocklistRequest::CardBin(ref bin) if bin == "424242"
+        ));
+        assert!(card_bin.expires_at.is_none());
+    }
+
+    #[test]
+    fn test_blocklist_export_filtering() {
+        let now = time::macros::datetime!(2024-01-01 00:00:00);
+        let card_bin = blocklist_entry(common_enums::BlocklistDataKind::CardBin, "424242", None);
+        let expired = blocklist_entry(
+            common_enums::BlocklistDataKind::CardBin,
+            "424243",
+            Some(time::macros::datetime!(2023-12-31 00:00:00)),
+        );
+        let email = blocklist_entry(common_enums::BlocklistDataKind::Email, "hash", None);
+
+        assert!(is_exportable_entry(&card_bin, None, now));
+        assert!(is_exportable_entry(
+            &card_bin,
+            Some(&common_enums::BlocklistDataKind::CardBin),
+            now
+        ));
+        assert!(!is_exportable_entry(
+            &card_bin,
+            Some(&common_enums::BlocklistDataKind::IpRange),
+            now
+        ));
+        assert!(!is_exportable_entry(&expired, None, now));
+        assert!(!is_exportable_entry(&email, None, now));
+    }
+}
@@ -1,17 +1,28 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
 use api_models::blocklist as api_blocklist;
 use common_utils::crypto::{self, SignMessage};
 use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms;
+use router_env::logger;
+use scheduler::{db::process_tracker::ProcessTrackerExt, errors as sch_errors, utils as pt_utils};
 
 use super::{errors, AppState};
 use crate::{
     consts,
-    core::errors::{RouterResult, StorageErrorExt},
+    core::errors::{CustomResult, RouterResult, StorageErrorExt},
+    db::StorageInterface,
     types::{storage, transformers::ForeignInto},
     utils,
 };
 
+const BLOCKLIST_EXPIRY_RUNNER: &str = "BLOCKLIST_EXPIRY_WORKFLOW";
+const BLOCKLIST_EXPIRY_TASK: &str = "BLOCKLIST_EXPIRY";
+
 pub async fn delete_entry_from_blocklist(
     state: &AppState,
     merchant_id: String,
@@ -19,11 +30,77 @@ pub async fn delete_entry_from_blocklist(
 ) -> RouterResult<api_blocklist::DeleteFromBlocklistResponse> {
     let blocklist_entry = match request {
         api_blocklist::DeleteFromBlocklistRequest::CardBin(bin) => {
-            delete_card_bin_blocklist_entry(state, &bin, &merchant_id).await?
+            delete_hashed_blocklist_entry(
+                state,
+                &bin,
+                &merchant_id,
+                common_enums::BlocklistDataKind::CardBin,
+            )
+            .await?
         }
 
         api_blocklist::DeleteFromBlocklistRequest::ExtendedCardBin(xbin) => {
-            delete_card_bin_blocklist_entry(state, &xbin, &merchant_id).await?
+            delete_hashed_blocklist_entry(
+                state,
+                &xbin,
+                &merchant_id,
+                common_enums::BlocklistDataKind::ExtendedCardBin,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::Email(email) => {
+            delete_hashed_blocklist_entry(
+                state,
+                &normalize_email(&email)?,
+                &merchant_id,
+                common_enums::BlocklistDataKind::Email,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::EmailDomain(domain) => {
+            delete_hashed_blocklist_entry(
+                state,
+                &normalize_email_domain(&domain)?,
+                &merchant_id,
+                common_enums::BlocklistDataKind::EmailDomain,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IpAddress(ip_address) => {
+            delete_hashed_blocklist_entry(
+                state,
+                &normalize_ip_address(&ip_address)?,
+                &merchant_id,
+                common_enums::BlocklistDataKind::IpAddress,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::PhoneNumber(phone_number) => {
+            delete_hashed_blocklist_entry(
+                state,
+                &normalize_phone_number(&phone_number)?,
+                &merchant_id,
+                common_enums::BlocklistDataKind::PhoneNumber,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IpRange(ip_range) => {
+            delete_range_blocklist_entry(state, &normalize_ip_range(&ip_range)?, &merchant_id)
+                .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::CardBinRange(bin_range) => {
+            delete_range_blocklist_entry(
+                state,
+                &normalize_card_bin_range(&bin_range)?,
+                &merchant_id,
+            )
+            .await?
         }
 
         api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => {
@@ -121,35 +198,311 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+fn invalid_blocklist_data(expected_format: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::InvalidDataFormat {
+        field_name: "data".to_string(),
+        expected_format: expected_format.to_string(),
+    }
+}
+
+fn normalize_email(email: &str) -> RouterResult<String> {
+    let email = email.trim().to_lowercase();
+    match email.split_once('@') {
+        Some((local, domain)) if !local.is_empty() && normalize_email_domain(domain).is_ok() => {
+            Ok(email)
+        }
+        _ => Err(invalid_blocklist_data("a valid email address")).into_report(),
+    }
+}
+
+fn normalize_email_domain(domain: &str) -> RouterResult<String> {
+    let domain = domain.trim().trim_start_matches('@').to_lowercase();
+    let is_valid = domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+
+    if is_valid {
+        Ok(domain)
+    } else {
+        Err(invalid_blocklist_data("a domain name such as example.com")).into_report()
+    }
+}
+
+fn normalize_ip_address(ip_address: &str) -> RouterResult<String> {
+    IpAddr::from_str(ip_address.trim())
+        .map(|ip_address| ip_address.to_string())
+        .map_err(|_| invalid_blocklist_data("an IPv4 or IPv6 address"))
+        .into_report()
+}
+
+fn parse_ip_range(ip_range: &str) -> Option<(IpAddr, u8)> {
+    let (network, prefix_length) = ip_range.trim().split_once('/')?;
+    let network = IpAddr::from_str(network).ok()?;
+    let prefix_length = prefix_length.parse::<u8>().ok()?;
+    let max_prefix_length = match network {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+
+    (prefix_length <= max_prefix_length).then_some((network, prefix_length))
+}
+
+fn mask_ipv4(ip_address: Ipv4Addr, prefix_length: u8) -> u32 {
+    let mask = u32::MAX
+        .checked_shl(32 - u32::from(prefix_length))
+        .unwrap_or(0);
+    u32::from(ip_address) & mask
+}
+
+fn mask_ipv6(ip_address: Ipv6Addr, prefix_length: u8) -> u128 {
+    let mask = u128::MAX
+        .checked_shl(128 - u32::from(prefix_length))
+        .unwrap_or(0);
+    u128::from(ip_address) & mask
+}
+
+/// Normalizes a CIDR range so that the stored network address has its host bits cleared
+fn normalize_ip_range(ip_range: &str) -> RouterResult<String> {
+    let (network, prefix_length) = parse_ip_range(ip_range).ok_or_else(|| {
+        invalid_blocklist_data("an IPv4 or IPv6 network in CIDR notation, e.g. 10.0.0.0/8")
+    })?;
+
+    let network = match network {
+        IpAddr::V4(network) => IpAddr::V4(Ipv4Addr::from(mask_ipv4(network, prefix_length))),
+        IpAddr::V6(network) => IpAddr::V6(Ipv6Addr::from(mask_ipv6(network, prefix_length))),
+    };
+
+    Ok(format!("{network}/{prefix_length}"))
+}
+
+fn ip_range_contains(ip_range: &str, ip_address: IpAddr) -> bool {
+    let Some((network, prefix_length)) = parse_ip_range(ip_range) else {
+        return false;
+    };
+
+    match (network, ip_address) {
+        (IpAddr::V4(network), IpAddr::V4(ip_address)) => {
+            mask_ipv4(network, prefix_length) == mask_ipv4(ip_address, prefix_length)
+        }
+        (IpAddr::V4(network), IpAddr::V6(ip_address)) => {
+            ip_address.to_ipv4_mapped().map_or(false, |ip_address| {
+                mask_ipv4(network, prefix_length) == mask_ipv4(ip_address, prefix_length)
+            })
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip_address)) => {
+            mask_ipv6(network, prefix_length) == mask_ipv6(ip_address, prefix_length)
+        }
+        (IpAddr::V6(_), IpAddr::V4(_)) => false,
+    }
+}
+
+/// Normalizes a phone number to `+` followed by the country code and subscriber number
+fn normalize_phone_number(phone_number: &str) -> RouterResult<String> {
+    let phone_number = phone_number
+        .chars()
+        .filter(|c| !(c.is_whitespace() || matches!(c, '-' | '(' | ')' | '.')))
+        .collect::<String>();
+
+    match phone_number.strip_prefix('+') {
+        Some(digits)
+            if (8..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            Ok(phone_number)
+        }
+        _ => Err(invalid_blocklist_data(
+            "a phone number with country code, e.g. +14155552671",
+        ))
+        .into_report(),
+    }
+}
+
+/// Joins the country code and number the way phone numbers are stored in the blocklist
+pub fn phone_number_with_country_code(country_code: Option<&str>, number: &str) -> String {
+    match country_code {
+        Some(country_code) => format!("+{}{}", country_code.trim_start_matches('+'), number),
+        None => number.to_string(),
+    }
+}
+
+fn normalize_card_bin_range(bin_range: &str) -> RouterResult<String> {
+    let bin_range = bin_range.trim();
+    let is_valid = (6..=8).contains(&bin_range.len())
+        && bin_range.starts_with(|c: char| c.is_ascii_digit())
+        && bin_range.chars().all(|c| c.is_ascii_digit() || c == '*');
+
+    if is_valid {
+        Ok(bin_range.to_string())
+    } else {
+        Err(invalid_blocklist_data(
+            "6 to 8 characters of digits or * starting with a digit, e.g. 4242**",
+        ))
+        .into_report()
+    }
+}
+
+fn card_bin_range_matches(bin_range: &str, card_number: &str) -> bool {
+    card_number.len() >= bin_range.len()
+        && bin_range
+            .chars()
+            .zip(card_number.chars())
+            .all(|(pattern, digit)| pattern == '*' || pattern == digit)
+}
+
+fn validate_blocklist_entry_details(
+    to_block: &api_blocklist::AddToBlocklistRequest,
+) -> RouterResult<()> {
+    if let Some(expires_at) = to_block.expires_at {
+        if expires_at <= common_utils::date_time::now() {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "expires_at must be in the future".to_string(),
+            })
+            .into_report();
+        }
+    }
+
+    if to_block
+        .reason
+        .as_ref()
+        .map_or(false, |reason| reason.len() > 255)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "reason must be at most 255 characters long".to_string(),
+        })
+        .into_report();
+    }
+
+    Ok(())
+}
+
+fn new_blocklist_entry(
+    merchant_id: &str,
+    fingerprint_id: &str,
+    data_kind: common_enums::BlocklistDataKind,
+    to_block: &api_blocklist::AddToBlocklistRequest,
+    created_by: Option<&str>,
+) -> storage::BlocklistNew {
+    storage::BlocklistNew {
+        merchant_id: merchant_id.to_string(),
+        fingerprint_id: fingerprint_id.to_string(),
+        data_kind,
+        metadata: None,
+        created_at: common_utils::date_time::now(),
+        expires_at: to_block.expires_at,
+        reason: to_block.reason.clone(),
+        created_by: created_by.map(ToString::to_string),
+    }
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &AppState,
     merchant_id: String,
     to_block: api_blocklist::AddToBlocklistRequest,
+    created_by: Option<String>,
 ) -> RouterResult<api_blocklist::AddToBlocklistResponse> {
-    let blocklist_entry = match &to_block {
-        api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
+    let created_by = created_by.as_deref();
+    validate_blocklist_entry_details(&to_block)?;
+
+    let blocklist_entry = match &to_block.data {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_hashed_entry(
                 bin,
                 state,
                 &merchant_id,
                 common_enums::BlocklistDataKind::CardBin,
+                &to_block,
+                created_by,
             )
             .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_hashed_entry(
                 bin,
                 state,
                 &merchant_id,
                 common_enums::BlocklistDataKind::ExtendedCardBin,
+                &to_block,
+                created_by,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::Email(email) => {
+            duplicate_check_insert_hashed_entry(
+                &normalize_email(email)?,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::Email,
+                &to_block,
+                created_by,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::EmailDomain(domain) => {
+            duplicate_check_insert_hashed_entry(
+                &normalize_email_domain(domain)?,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::EmailDomain,
+                &to_block,
+                created_by,
             )
             .await?
         }
 
-        api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
+        api_blocklist::BlocklistRequest::IpAddress(ip_address) => {
+            duplicate_check_insert_hashed_entry(
+                &normalize_ip_address(ip_address)?,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::IpAddress,
+                &to_block,
+                created_by,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::PhoneNumber(phone_number) => {
+            duplicate_check_insert_hashed_entry(
+                &normalize_phone_number(phone_number)?,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::PhoneNumber,
+                &to_block,
+                created_by,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::IpRange(ip_range) => {
+            duplicate_check_insert_range(
+                &normalize_ip_range(ip_range)?,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::IpRange,
+                &to_block,
+                created_by,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::CardBinRange(bin_range) => {
+            duplicate_check_insert_range(
+                &normalize_card_bin_range(bin_range)?,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::CardBinRange,
+                &to_block,
+                created_by,
+            )
+            .await?
+        }
+
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => {
             let blocklist_entry_result = state
                 .store
                 .find_blocklist_entry_by_merchant_id_fingerprint_id(&merchant_id, fingerprint_id)
@@ -201,6 +554,7 @@ pub async fn insert_entry_into_blocklist(
                     diesel_models::blocklist_lookup::BlocklistLookupNew {
                         merchant_id: merchant_id.clone(),
                         fingerprint: decrypted_fingerprint,
+                        fingerprint_id: Some(fingerprint_id.clone()),
                     },
                 )
                 .await
@@ -211,19 +565,27 @@ pub async fn insert_entry_into_blocklist(
 
             state
                 .store
-                .insert_blocklist_entry(storage::BlocklistNew {
-                    merchant_id: merchant_id.clone(),
-                    fingerprint_id: fingerprint_id.clone(),
-                    data_kind: blocklist_fingerprint.data_kind,
-                    metadata: None,
-                    created_at: common_utils::date_time::now(),
-                })
+                .insert_blocklist_entry(new_blocklist_entry(
+                    &merchant_id,
+                    fingerprint_id,
+                    blocklist_fingerprint.data_kind,
+                    &to_block,
+                    created_by,
+                ))
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("failed to add fingerprint to pm blocklist")?
         }
     };
 
+    if let Some(expires_at) = blocklist_entry.expires_at {
+        add_blocklist_expiry_task(&*state.store, &blocklist_entry, expires_at)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("failed to add blocklist expiry task to process tracker")?;
+    }
+
     Ok(blocklist_entry.foreign_into())
 }
 
@@ -265,32 +627,52 @@ pub fn get_merchant_fingerprint_secret_key(merchant_id: &str) -> String {
     format!("fingerprint_secret_{merchant_id}")
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+/// Hashes a value the same way it is stored in the blocklist lookup table
+pub fn generate_blocklist_fingerprint(merchant_secret: &str, data: &str) -> RouterResult<String> {
+    crypto::HmacSha512
+        .sign_message(merchant_secret.as_bytes(), data.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error in blocklist data hash creation")
+}
+
+/// Whether the blocklist entries of this kind hold personal data, such entries store the hash of
+/// the blocked value as their fingerprint id instead of the value itself
+pub fn stores_hashed_value(data_kind: common_enums::BlocklistDataKind) -> bool {
+    matches!(
+        data_kind,
+        common_enums::BlocklistDataKind::Email
+            | common_enums::BlocklistDataKind::IpAddress
+            | common_enums::BlocklistDataKind::PhoneNumber
+    )
+}
+
+/// Blocks a value that is matched exactly, by storing its hash in the blocklist lookup table
+async fn duplicate_check_insert_hashed_entry(
+    data: &str,
     state: &AppState,
     merchant_id: &str,
     data_kind: common_enums::BlocklistDataKind,
+    to_block: &api_blocklist::AddToBlocklistRequest,
+    created_by: Option<&str>,
 ) -> RouterResult<storage::Blocklist> {
     let merchant_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
-    let bin_fingerprint = crypto::HmacSha512::sign_message(
-        &crypto::HmacSha512,
-        merchant_secret.clone().as_bytes(),
-        bin.as_bytes(),
-    )
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("error in bin hash creation")?;
-
-    let encoded_fingerprint = hex::encode(bin_fingerprint.clone());
+    let encoded_fingerprint = generate_blocklist_fingerprint(&merchant_secret, data)?;
+    let fingerprint_id = if stores_hashed_value(data_kind) {
+        encoded_fingerprint.as_str()
+    } else {
+        data
+    };
 
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
         Ok(_) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: format!("provided {data_kind} is already blocked"),
             })
             .into_report();
         }
@@ -310,6 +692,7 @@ async fn duplicate_check_insert_bin(
         .insert_blocklist_lookup_entry(diesel_models::blocklist_lookup::BlocklistLookupNew {
             merchant_id: merchant_id.to_string(),
             fingerprint: encoded_fingerprint.clone(),
+            fingerprint_id: Some(fingerprint_id.to_string()),
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -317,43 +700,503 @@ async fn duplicate_check_insert_bin(
 
     state
         .store
-        .insert_blocklist_entry(storage::BlocklistNew {
-            merchant_id: merchant_id.to_string(),
-            fingerprint_id: bin.to_string(),
+        .insert_blocklist_entry(new_blocklist_entry(
+            merchant_id,
+            fingerprint_id,
             data_kind,
-            metadata: None,
-            created_at: common_utils::date_time::now(),
-        })
+            to_block,
+            created_by,
+        ))
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("error inserting pm blocklist item")
 }
 
-async fn delete_card_bin_blocklist_entry(
+async fn delete_hashed_blocklist_entry(
     state: &AppState,
-    bin: &str,
+    data: &str,
     merchant_id: &str,
+    data_kind: common_enums::BlocklistDataKind,
 ) -> RouterResult<storage::Blocklist> {
     let merchant_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
-    let bin_fingerprint = crypto::HmacSha512
-        .sign_message(merchant_secret.as_bytes(), bin.as_bytes())
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error when hashing card bin")?;
-    let encoded_fingerprint = hex::encode(bin_fingerprint);
+    let encoded_fingerprint = generate_blocklist_fingerprint(&merchant_secret, data)?;
+    let fingerprint_id = if stores_hashed_value(data_kind) {
+        encoded_fingerprint.as_str()
+    } else {
+        data
+    };
 
     state
         .store
         .delete_blocklist_lookup_entry_by_merchant_id_fingerprint(merchant_id, &encoded_fingerprint)
         .await
         .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "could not find a blocklist entry for the given bin".to_string(),
+            message: "could not find a blocklist entry for the given data".to_string(),
         })?;
 
     state
         .store
-        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "could not find a blocklist entry for the given bin".to_string(),
+            message: "could not find a blocklist entry for the given data".to_string(),
+        })
+}
+
+/// Blocks a range of values. Ranges cannot be looked up by hash, so they are only stored in the
+/// blocklist table and matched against the payment data during confirmation.
+async fn duplicate_check_insert_range(
+    range: &str,
+    state: &AppState,
+    merchant_id: &str,
+    data_kind: common_enums::BlocklistDataKind,
+    to_block: &api_blocklist::AddToBlocklistRequest,
+    created_by: Option<&str>,
+) -> RouterResult<storage::Blocklist> {
+    state
+        .store
+        .insert_blocklist_entry(new_blocklist_entry(
+            merchant_id,
+            range,
+            data_kind,
+            to_block,
+            created_by,
+        ))
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("provided {data_kind} is already blocked"),
+        })
+        .attach_printable("error inserting blocklist range entry")
+}
+
+async fn delete_range_blocklist_entry(
+    state: &AppState,
+    range: &str,
+    merchant_id: &str,
+) -> RouterResult<storage::Blocklist> {
+    state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, range)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "could not find a blocklist entry for the given range".to_string(),
+        })
+}
+
+/// Schedules the removal of a blocklist entry at the time it expires
+pub async fn add_blocklist_expiry_task(
+    db: &dyn StorageInterface,
+    blocklist_entry: &storage::Blocklist,
+    expires_at: time::PrimitiveDateTime,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let tracking_data = storage::BlocklistExpiryWorkflow {
+        merchant_id: blocklist_entry.merchant_id.clone(),
+        fingerprint_id: blocklist_entry.fingerprint_id.clone(),
+    };
+    // The same data can be blocked again once unblocked, so every entry gets a task of its own
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        BLOCKLIST_EXPIRY_RUNNER,
+        BLOCKLIST_EXPIRY_TASK,
+        &utils::generate_id(consts::ID_LENGTH, "blocklist"),
+        &blocklist_entry.merchant_id,
+    );
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        BLOCKLIST_EXPIRY_TASK,
+        BLOCKLIST_EXPIRY_RUNNER,
+        tracking_data,
+        expires_at,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub fn is_blocklist_entry_expired(
+    blocklist_entry: &storage::Blocklist,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    blocklist_entry
+        .expires_at
+        .map_or(false, |expires_at| expires_at <= now)
+}
+
+fn ignore_not_found<T>(
+    result: CustomResult<T, errors::StorageError>,
+    message: &'static str,
+) -> RouterResult<()> {
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(message),
+    }
+}
+
+/// Removes an expired entry from the blocklist together with its `blocklist_lookup` row and,
+/// for blocked payment methods, its `blocklist_fingerprint` row
+pub async fn purge_expired_blocklist_entry(
+    state: &AppState,
+    blocklist_entry: &storage::Blocklist,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = blocklist_entry.merchant_id.as_str();
+    let fingerprint_id = blocklist_entry.fingerprint_id.as_str();
+
+    let lookup_fingerprint = match blocklist_entry.data_kind {
+        common_enums::BlocklistDataKind::PaymentMethod => {
+            let blocklist_fingerprint = db
+                .find_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+                    merchant_id,
+                    fingerprint_id,
+                )
+                .await;
+
+            match blocklist_fingerprint {
+                Ok(blocklist_fingerprint) => {
+                    #[cfg(feature = "kms")]
+                    let decrypted_fingerprint = kms::get_kms_client(&state.conf.kms)
+                        .await
+                        .decrypt(blocklist_fingerprint.encrypted_fingerprint)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("failed to kms decrypt fingerprint")?;
+
+                    #[cfg(not(feature = "kms"))]
+                    let decrypted_fingerprint = blocklist_fingerprint.encrypted_fingerprint;
+
+                    ignore_not_found(
+                        db.delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+                            merchant_id,
+                            fingerprint_id,
+                        )
+                        .await,
+                        "failed to delete blocklist fingerprint",
+                    )?;
+
+                    Some(decrypted_fingerprint)
+                }
+                Err(error) if error.current_context().is_db_not_found() => None,
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("failed to fetch blocklist fingerprint")?,
+            }
+        }
+        common_enums::BlocklistDataKind::CardBinRange
+        | common_enums::BlocklistDataKind::IpRange => None,
+        common_enums::BlocklistDataKind::Email
+        | common_enums::BlocklistDataKind::IpAddress
+        | common_enums::BlocklistDataKind::PhoneNumber => Some(fingerprint_id.to_string()),
+        common_enums::BlocklistDataKind::CardBin
+        | common_enums::BlocklistDataKind::ExtendedCardBin
+        | common_enums::BlocklistDataKind::EmailDomain => {
+            let merchant_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+            Some(generate_blocklist_fingerprint(
+                &merchant_secret,
+                fingerprint_id,
+            )?)
+        }
+    };
+
+    if let Some(lookup_fingerprint) = lookup_fingerprint {
+        ignore_not_found(
+            db.delete_blocklist_lookup_entry_by_merchant_id_fingerprint(
+                merchant_id,
+                &lookup_fingerprint,
+            )
+            .await,
+            "failed to delete blocklist lookup entry",
+        )?;
+    }
+
+    ignore_not_found(
+        db.delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+            .await,
+        "failed to delete blocklist entry",
+    )
+}
+
+/// Payment data that is checked against the merchant's blocklist during confirmation
+#[derive(Debug, Default)]
+pub struct BlocklistCheckData {
+    /// Hash of the card number, as stored in the blocklist lookup table
+    pub card_number_fingerprint: Option<String>,
+    pub card_number: Option<cards::CardNumber>,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    pub ip_address: Option<IpAddr>,
+}
+
+/// The blocklist rule that caused a payment to be blocked
+#[derive(Debug, Clone)]
+pub struct BlocklistMatch {
+    pub data_kind: common_enums::BlocklistDataKind,
+    /// Id of the matched blocklist entry, absent when the entry could not be fetched
+    pub blocklist_entry_id: Option<i32>,
+}
+
+impl BlocklistMatch {
+    /// The reason stored on the blocked payment attempt. It only names the kind of the rule, the
+    /// blocked value is returned to clients and must not be part of it
+    pub fn reason(&self) -> String {
+        format!("blocked by {} rule", self.data_kind)
+    }
+
+    /// The error reason stored on the blocked payment attempt, it records which blocklist entry
+    /// matched so that the block can be traced back to the rule
+    pub fn error_reason(&self) -> Option<String> {
+        self.blocklist_entry_id
+            .map(|blocklist_entry_id| format!("blocklist_entry_id: {blocklist_entry_id}"))
+    }
+}
+
+/// Returns the first blocklist rule of the merchant that matches the payment data, exact
+/// matches are looked up by hash before ranges are checked.
+pub async fn find_blocklist_match(
+    state: &AppState,
+    merchant_id: &str,
+    merchant_fingerprint_secret: &str,
+    data: BlocklistCheckData,
+) -> Option<BlocklistMatch> {
+    let card_number = data
+        .card_number
+        .map(|card_number| card_number.get_card_no());
+    let email = data.email.and_then(|email| normalize_email(&email).ok());
+    let email_domain = email
+        .as_ref()
+        .and_then(|email| email.split_once('@'))
+        .map(|(_, domain)| domain.to_string());
+
+    let mut candidates = Vec::new();
+    if let Some(card_number_fingerprint) = data.card_number_fingerprint {
+        candidates.push((
+            common_enums::BlocklistDataKind::PaymentMethod,
+            None,
+            card_number_fingerprint,
+        ));
+    }
+
+    let values = [
+        (
+            common_enums::BlocklistDataKind::CardBin,
+            card_number
+                .as_ref()
+                .map(|card_number| card_number.chars().take(6).collect::<String>()),
+        ),
+        (
+            common_enums::BlocklistDataKind::ExtendedCardBin,
+            card_number
+                .as_ref()
+                .map(|card_number| card_number.chars().take(8).collect::<String>()),
+        ),
+        (common_enums::BlocklistDataKind::Email, email),
+        (common_enums::BlocklistDataKind::EmailDomain, email_domain),
+        (
+            common_enums::BlocklistDataKind::PhoneNumber,
+            data.phone_number
+                .and_then(|phone_number| normalize_phone_number(&phone_number).ok()),
+        ),
+        (
+            common_enums::BlocklistDataKind::IpAddress,
+            data.ip_address.map(|ip_address| ip_address.to_string()),
+        ),
+    ];
+
+    for (data_kind, value) in values {
+        let Some(value) = value else { continue };
+        match generate_blocklist_fingerprint(merchant_fingerprint_secret, &value) {
+            Ok(fingerprint) => {
+                let fingerprint_id = if stores_hashed_value(data_kind) {
+                    fingerprint.clone()
+                } else {
+                    value
+                };
+                candidates.push((data_kind, Some(fingerprint_id), fingerprint))
+            }
+            Err(error) => logger::error!(?error, %data_kind, "failed to hash blocklist data"),
+        }
+    }
+
+    let lookups = futures::future::join_all(candidates.iter().map(|(_, _, fingerprint)| {
+        state
+            .store
+            .find_blocklist_lookup_entry_by_merchant_id_fingerprint(merchant_id, fingerprint)
+    }))
+    .await;
+
+    let now = common_utils::date_time::now();
+    // The fingerprint id of a blocked payment method is only known from its lookup entry, older
+    // lookup entries don't record it
+    let exact_matches = candidates.into_iter().zip(lookups).filter_map(
+        |((data_kind, fingerprint_id, _), lookup)| {
+            lookup
+                .ok()
+                .map(|lookup| (data_kind, lookup.fingerprint_id.or(fingerprint_id)))
+        },
+    );
+
+    // Entries stay in the lookup table until their expiry task purges them, so the expiry of a
+    // matched entry of any kind is checked before blocking the payment
+    for (data_kind, fingerprint_id) in exact_matches {
+        let Some(fingerprint_id) = fingerprint_id else {
+            return Some(BlocklistMatch {
+                data_kind,
+                blocklist_entry_id: None,
+            });
+        };
+
+        match state
+            .store
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
+            .await
+        {
+            Ok(entry) if is_blocklist_entry_expired(&entry, now) => {}
+            entry => {
+                return Some(BlocklistMatch {
+                    data_kind,
+                    blocklist_entry_id: entry.ok().map(|entry| entry.id),
+                })
+            }
+        }
+    }
+
+    if card_number.is_none() && data.ip_address.is_none() {
+        return None;
+    }
+
+    let ranges = state
+        .store
+        .list_blocklist_range_entries_by_merchant_id(merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "failed to fetch blocklist ranges"))
+        .ok()?;
+
+    ranges
+        .into_iter()
+        .filter(|entry| !is_blocklist_entry_expired(entry, now))
+        .find(|entry| match entry.data_kind {
+            common_enums::BlocklistDataKind::CardBinRange => {
+                card_number.as_ref().map_or(false, |card_number| {
+                    card_bin_range_matches(&entry.fingerprint_id, card_number)
+                })
+            }
+            common_enums::BlocklistDataKind::IpRange => {
+                data.ip_address.map_or(false, |ip_address| {
+                    ip_range_contains(&entry.fingerprint_id, ip_address)
+                })
+            }
+            _ => false,
+        })
+        .map(|entry| BlocklistMatch {
+            data_kind: entry.data_kind,
+            blocklist_entry_id: Some(entry.id),
         })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_ip_range_matching() {
+        let range = normalize_ip_range("192.168.10.20/16").unwrap();
+        assert_eq!(range, "192.168.0.0/16");
+        assert!(ip_range_contains(
+            &range,
+            IpAddr::from_str("192.168.255.1").unwrap()
+        ));
+        assert!(!ip_range_contains(
+            &range,
+            IpAddr::from_str("192.169.0.1").unwrap()
+        ));
+        assert!(ip_range_contains(
+            &range,
+            IpAddr::from_str("::ffff:192.168.1.1").unwrap()
+        ));
+
+        let range = normalize_ip_range("2001:db8::/32").unwrap();
+        assert!(ip_range_contains(
+            &range,
+            IpAddr::from_str("2001:db8:1::1").unwrap()
+        ));
+        assert!(!ip_range_contains(
+            &range,
+            IpAddr::from_str("2001:db9::1").unwrap()
+        ));
+
+        assert!(ip_range_contains(
+            "0.0.0.0/0",
+            IpAddr::from_str("8.8.8.8").unwrap()
+        ));
+        assert!(normalize_ip_range("10.0.0.0/33").is_err());
+        assert!(normalize_ip_range("10.0.0.0").is_err());
+    }
+
+    #[test]
+    fn test_card_bin_range_matching() {
+        let range = normalize_card_bin_range("4242**").unwrap();
+        assert!(card_bin_range_matches(&range, "4242424242424242"));
+        assert!(card_bin_range_matches(&range, "4242004242424242"));
+        assert!(!card_bin_range_matches(&range, "4243424242424242"));
+        assert!(card_bin_range_matches("4*4*4*42", "4242424242424242"));
+
+        assert!(normalize_card_bin_range("*24242").is_err());
+        assert!(normalize_card_bin_range("42424").is_err());
+        assert!(normalize_card_bin_range("4242abcd").is_err());
+    }
+
+    #[test]
+    fn test_contact_normalization() {
+        assert_eq!(
+            normalize_email(" John.Doe@Example.COM ").unwrap(),
+            "john.doe@example.com"
+        );
+        assert!(normalize_email("john.doe").is_err());
+        assert_eq!(
+            normalize_email_domain("@Example.com").unwrap(),
+            "example.com"
+        );
+        assert!(normalize_email_domain("localhost").is_err());
+
+        assert_eq!(
+            normalize_phone_number("+1 (415) 555-2671").unwrap(),
+            "+14155552671"
+        );
+        assert!(normalize_phone_number("4155552671").is_err());
+        assert_eq!(
+            phone_number_with_country_code(Some("+1"), "4155552671"),
+            "+14155552671"
+        );
+
+        assert_eq!(normalize_ip_address(" 10.0.0.1").unwrap(), "10.0.0.1");
+        assert!(normalize_ip_address("10.0.0.256").is_err());
+    }
+
+    #[test]
+    fn test_blocklist_entry_expiry() {
+        let now = time::macros::datetime!(2024-01-01 00:00:00);
+        let mut entry = storage::Blocklist {
+            id: 1,
+            merchant_id: "merchant_1".to_string(),
+            fingerprint_id: "424242".to_string(),
+            data_kind: common_enums::BlocklistDataKind::CardBin,
+            metadata: None,
+            created_at: now,
+            expires_at: None,
+            reason: None,
+            created_by: None,
+        };
+        assert!(!is_blocklist_entry_expired(&entry, now));
+
+        entry.expires_at = Some(time::macros::datetime!(2024-01-01 00:00:01));
+        assert!(!is_blocklist_entry_expired(&entry, now));
+
+        entry.expires_at = Some(now);
+        assert!(is_blocklist_entry_expired(&entry, now));
+
+        entry.expires_at = Some(time::macros::datetime!(2023-12-31 23:59:59));
+        assert!(is_blocklist_entry_expired(&entry, now));
+    }
+}
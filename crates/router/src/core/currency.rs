@@ -34,6 +34,9 @@ pub async fn convert_forex(
     amount: i64,
     to_currency: String,
     from_currency: String,
+    transaction_date: Option<time::PrimitiveDateTime>,
+    rounding_mode: api_models::enums::RoundingMode,
+    markup_basis_points: Option<u32>,
 ) -> CustomResult<
     ApplicationResponse<api_models::currency::CurrencyConversionResponse>,
     ApiErrorResponse,
@@ -44,12 +47,29 @@ pub async fn convert_forex(
             amount,
             to_currency,
             from_currency,
+            transaction_date,
+            currency_conversion::types::ConversionOptions::new(
+                rounding_mode,
+                markup_basis_points.map(|bps| rust_decimal::Decimal::new(bps.into(), 4)),
+            ),
             #[cfg(feature = "kms")]
             &state.conf.kms,
             #[cfg(feature = "hashicorp-vault")]
             &state.conf.hc_vault,
         ))
         .await
-        .change_context(ApiErrorResponse::InternalServerError)?,
+        .map_err(|error| match error.current_context() {
+            currency::ForexCacheError::HistoricalRatesNotFound => {
+                error.change_context(ApiErrorResponse::GenericNotFoundError {
+                    message: "Forex rates not available for the requested date".to_string(),
+                })
+            }
+            currency::ForexCacheError::FutureRateDate => {
+                error.change_context(ApiErrorResponse::InvalidRequestData {
+                    message: "transaction_date cannot be in the future".to_string(),
+                })
+            }
+            _ => error.change_context(ApiErrorResponse::InternalServerError),
+        })?,
     ))
 }
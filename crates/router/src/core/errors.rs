@@ -290,6 +290,17 @@ impl ConnectorError {
     }
 }
 
+impl WebhooksFlowError {
+    /// Whether the outgoing webhook could not be delivered to the merchant, in which case the
+    /// delivery can be attempted again later
+    pub fn is_webhook_delivery_retryable_error(&self) -> bool {
+        matches!(
+            self,
+            Self::CallToMerchantFailed | Self::NotReceivedByMerchant
+        )
+    }
+}
+
 #[cfg(feature = "detailed_errors")]
 pub mod error_stack_parsing {
 
@@ -372,8 +383,14 @@ pub enum RoutingError {
     InvalidRoutingAlgorithmStructure,
     #[error("Volume split failed")]
     VolumeSplitFailed,
+    #[error("Failed to fetch success rates of connectors")]
+    SuccessRateFetchFailed,
+    #[error("Failed to update success rate of connector")]
+    SuccessRateUpdateFailed,
     #[error("Unable to parse metadata")]
     MetadataParsingError,
+    #[error("Unable to parse payment method data")]
+    PaymentMethodDataParsingError,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -172,6 +172,10 @@ pub enum ApiErrorResponse {
     ApiKeyNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
     PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout batch does not exist in our records")]
+    PayoutBatchNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Subscription does not exist in our records")]
+    SubscriptionNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Invalid mandate id passed from connector")]
     MandateSerializationFailed,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Unable to parse the mandate identifier passed from connector")]
@@ -240,6 +244,20 @@ pub enum ApiErrorResponse {
     CurrencyNotSupported { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_25", message = "Keys for idempotent requests can only be used with the same parameters they were first used with. Try using a key other than '{idempotency_key}' if you meant to execute a different request")]
+    IdempotencyKeyReused { idempotency_key: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "IR_26", message = "There is currently another in-progress request using the idempotency key '{idempotency_key}'. Please try again later")]
+    IdempotentRequestInProgress { idempotency_key: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_27", message = "Too many requests, retry after {retry_after_seconds} seconds")]
+    TooManyRequests { retry_after_seconds: u64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_28", message = "The amount exceeds the per transaction limit of {limit} of the mandate")]
+    MandateTransactionLimitExceeded { limit: i64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_29", message = "The amount exceeds the remaining allowance of {remaining_amount} of the mandate")]
+    MandateCumulativeLimitExceeded { remaining_amount: i64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_30", message = "The mandate is not valid before its start date")]
+    MandateNotYetValid,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_31", message = "The mandate has expired")]
+    MandateExpired,
     #[error(error_type = ErrorType::ValidationError, code = "HE_01", message = "Failed to convert currency to minor unit")]
     CurrencyConversionFailed,
 }
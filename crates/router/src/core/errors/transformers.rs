@@ -179,6 +179,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Payout does not exist in our records", None))
             }
+            Self::PayoutBatchNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payout batch does not exist in our records", None))
+            }
+            Self::SubscriptionNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Subscription does not exist in our records", None))
+            }
             Self::ReturnUrlUnavailable => AER::NotFound(ApiError::new("HE", 3, "Return URL is not configured and not passed in payments request", None)),
             Self::RefundNotPossible { connector } => {
                 AER::BadRequest(ApiError::new("HE", 3, format!("This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard"), None))
@@ -271,6 +277,25 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InvalidConnectorConfiguration {config} => {
                 AER::BadRequest(ApiError::new("IR", 24, format!("Merchant connector account is configured with invalid {config}"), None))
             }
+            Self::IdempotencyKeyReused { idempotency_key } => {
+                AER::BadRequest(ApiError::new("IR", 25, format!("Keys for idempotent requests can only be used with the same parameters they were first used with. Try using a key other than '{idempotency_key}' if you meant to execute a different request"), None))
+            }
+            Self::IdempotentRequestInProgress { idempotency_key } => {
+                AER::Conflict(ApiError::new("IR", 26, format!("There is currently another in-progress request using the idempotency key '{idempotency_key}'. Please try again later"), None))
+            }
+            Self::TooManyRequests { retry_after_seconds } => {
+                AER::TooManyRequests(ApiError::new("IR", 27, format!("Too many requests, retry after {retry_after_seconds} seconds"), None))
+            }
+            Self::MandateTransactionLimitExceeded { limit } => {
+                AER::BadRequest(ApiError::new("IR", 28, format!("The amount exceeds the per transaction limit of {limit} of the mandate"), None))
+            }
+            Self::MandateCumulativeLimitExceeded { remaining_amount } => {
+                AER::BadRequest(ApiError::new("IR", 29, format!("The amount exceeds the remaining allowance of {remaining_amount} of the mandate"), None))
+            }
+            Self::MandateNotYetValid => {
+                AER::BadRequest(ApiError::new("IR", 30, "The mandate is not valid before its start date", None))
+            }
+            Self::MandateExpired => AER::BadRequest(ApiError::new("IR", 31, "The mandate has expired", None)),
             Self::CurrencyConversionFailed => {
                 AER::Unprocessable(ApiError::new("HE", 2, "Failed to convert currency to minor unit", None))
             }
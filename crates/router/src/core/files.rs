@@ -1,9 +1,4 @@
 pub mod helpers;
-#[cfg(feature = "aws_s3")]
-pub mod s3_utils;
-
-#[cfg(not(feature = "aws_s3"))]
-pub mod fs_utils;
 
 use api_models::files;
 use error_stack::{IntoReport, ResultExt};
@@ -29,10 +24,7 @@ pub async fn files_create_core(
     )
     .await?;
     let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
-    #[cfg(feature = "aws_s3")]
-    let file_key = format!("{}/{}", merchant_account.merchant_id, file_id);
-    #[cfg(not(feature = "aws_s3"))]
-    let file_key = format!("{}_{}", merchant_account.merchant_id, file_id);
+    let file_key = helpers::get_file_key(&merchant_account.merchant_id, &file_id);
     let file_new = diesel_models::file::FileMetadataNew {
         file_id: file_id.clone(),
         merchant_id: merchant_account.merchant_id.clone(),
@@ -45,6 +37,7 @@ pub async fn files_create_core(
         connector_label: None,
         profile_id: None,
         merchant_connector_id: None,
+        purpose: Some(create_file_request.purpose.to_string()),
     };
 
     let file_metadata_object = state
@@ -137,3 +130,152 @@ pub async fn files_retrieve_core(
         content_type,
     )))
 }
+
+pub async fn files_download_link_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: api::FileId,
+) -> RouterResponse<files::FileDownloadLinkResponse> {
+    let file_metadata_object = state
+        .store
+        .as_ref()
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &req.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Unable to retrieve file_metadata")?;
+    let (url, expires_at) = helpers::create_file_download_link(
+        &state,
+        &file_metadata_object,
+        state.conf.file_storage.download_link_expiry,
+    )
+    .await?;
+    Ok(ApplicationResponse::Json(files::FileDownloadLinkResponse {
+        url,
+        expires_at,
+    }))
+}
+
+pub async fn files_download_core(
+    state: AppState,
+    token: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata_object =
+        helpers::find_file_metadata_by_download_token(&state, &token).await?;
+    let file_key = file_metadata_object
+        .provider_file_id
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .into_report()
+        .attach_printable("File key not found")?;
+    let content_type = file_metadata_object
+        .file_type
+        .parse::<mime::Mime>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse file content type")?;
+    let file = helpers::retrieve_file(&state, file_key).await?;
+    Ok(ApplicationResponse::FileData((file, content_type)))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{
+        configs::settings::Settings, db::StorageImpl, services::file_storage::FileStorageBackend,
+    };
+
+    async fn state_with_in_memory_storage() -> AppState {
+        let mut conf = Settings::new().expect("invalid settings");
+        conf.file_storage.backend = FileStorageBackend::InMemory;
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        )
+        .await
+    }
+
+    fn file_metadata_new(
+        file_id: &str,
+        file_upload_provider: diesel_models::enums::FileUploadProvider,
+    ) -> diesel_models::file::FileMetadataNew {
+        diesel_models::file::FileMetadataNew {
+            file_id: file_id.to_string(),
+            merchant_id: "merchant_1".to_string(),
+            file_name: Some("evidence.pdf".to_string()),
+            file_size: 8,
+            file_type: mime::APPLICATION_PDF.to_string(),
+            provider_file_id: Some(helpers::get_file_key("merchant_1", file_id)),
+            file_upload_provider: Some(file_upload_provider),
+            available: true,
+            connector_label: None,
+            profile_id: None,
+            merchant_connector_id: None,
+            purpose: Some(api::FilePurpose::DisputeEvidence.to_string()),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_file_download_link_serves_stored_file() {
+        let state = state_with_in_memory_storage().await;
+        let file_metadata = state
+            .store
+            .insert_file_metadata(file_metadata_new(
+                "file_1",
+                diesel_models::enums::FileUploadProvider::Router,
+            ))
+            .await
+            .unwrap();
+        helpers::upload_file(
+            &state,
+            helpers::get_file_key("merchant_1", "file_1"),
+            b"evidence".to_vec(),
+        )
+        .await
+        .unwrap();
+
+        let (link, _) = helpers::create_file_download_link(&state, &file_metadata, 60)
+            .await
+            .unwrap();
+        let token = link
+            .strip_prefix(&format!("{}/files/download/", state.conf.server.base_url))
+            .unwrap()
+            .to_string();
+
+        let response = files_download_core(state.clone(), token).await.unwrap();
+        assert!(matches!(
+            response,
+            ApplicationResponse::FileData((ref file, ref content_type))
+                if file.as_slice() == b"evidence" && *content_type == mime::APPLICATION_PDF
+        ));
+
+        assert!(files_download_core(state, "download_unknown".to_string())
+            .await
+            .is_err());
+    }
+
+    #[actix_rt::test]
+    async fn test_file_download_link_rejects_files_stored_at_connector() {
+        let state = state_with_in_memory_storage().await;
+        let file_metadata = state
+            .store
+            .insert_file_metadata(file_metadata_new(
+                "file_2",
+                diesel_models::enums::FileUploadProvider::Stripe,
+            ))
+            .await
+            .unwrap();
+
+        let error = helpers::create_file_download_link(&state, &file_metadata, 60)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FileProviderNotSupported { .. }
+        ));
+    }
+}
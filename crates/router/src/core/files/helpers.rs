@@ -4,6 +4,7 @@ use error_stack::{IntoReport, ResultExt};
 use futures::TryStreamExt;
 
 use crate::{
+    consts,
     core::{
         errors::{self, StorageErrorExt},
         files, payments, utils,
@@ -13,6 +14,14 @@ use crate::{
     types::{self, api, domain, transformers::ForeignTryFrom},
 };
 
+const FILE_DOWNLOAD_TOKEN_PREFIX: &str = "file_download";
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct FileDownloadTokenData {
+    merchant_id: String,
+    file_id: String,
+}
+
 pub async fn read_string(field: &mut Field) -> Option<String> {
     let bytes = field.try_next().await;
     if let Ok(Some(bytes)) = bytes {
@@ -26,39 +35,170 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("payout_batch") => Some(api::FilePurpose::PayoutBatch),
+        Some("blocklist_import") => Some(api::FilePurpose::BlocklistImport),
         _ => None,
     }
 }
 
+/// Key under which a file is stored in the file storage
+pub fn get_file_key(merchant_id: &str, file_id: &str) -> String {
+    format!("{merchant_id}/{file_id}")
+}
+
 pub async fn upload_file(
-    #[cfg(feature = "aws_s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
     file: Vec<u8>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    #[cfg(feature = "aws_s3")]
-    return files::s3_utils::upload_file_to_s3(state, file_key, file).await;
-    #[cfg(not(feature = "aws_s3"))]
-    return files::fs_utils::save_file_to_fs(file_key, file);
+    state.file_storage_client.upload_file(&file_key, file).await
+}
+
+pub async fn upload_file_from_path(
+    state: &AppState,
+    file_key: String,
+    path: &std::path::Path,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    state
+        .file_storage_client
+        .upload_file_from_path(&file_key, path)
+        .await
 }
 
 pub async fn delete_file(
-    #[cfg(feature = "aws_s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    #[cfg(feature = "aws_s3")]
-    return files::s3_utils::delete_file_from_s3(state, file_key).await;
-    #[cfg(not(feature = "aws_s3"))]
-    return files::fs_utils::delete_file_from_fs(file_key);
+    state.file_storage_client.delete_file(&file_key).await
 }
 
 pub async fn retrieve_file(
-    #[cfg(feature = "aws_s3")] state: &AppState,
+    state: &AppState,
     file_key: String,
 ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
-    #[cfg(feature = "aws_s3")]
-    return files::s3_utils::retrieve_file_from_s3(state, file_key).await;
-    #[cfg(not(feature = "aws_s3"))]
-    return files::fs_utils::retrieve_file_from_fs(file_key);
+    state.file_storage_client.retrieve_file(&file_key).await
+}
+
+/// Retrieves a file stored by the router which was uploaded for the given purpose, files uploaded
+/// for any other purpose are rejected
+pub async fn retrieve_file_for_purpose(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    file_id: &str,
+    purpose: api::FilePurpose,
+) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)?;
+
+    let expected_purpose = purpose.to_string();
+    if file_metadata.purpose.as_deref() != Some(expected_purpose.as_str()) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("file {file_id} was not uploaded for {expected_purpose}"),
+        })?
+    }
+
+    match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id,
+        file_metadata.available,
+    ) {
+        (Some(diesel_models::enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            retrieve_file(state, provider_file_id).await
+        }
+        _ => Err(errors::ApiErrorResponse::FileNotAvailable)
+            .into_report()
+            .attach_printable("File not available"),
+    }
+}
+
+/// Creates a link which downloads a file stored by the router, without authentication, until it
+/// expires. The file storage signs the link when it can, the router serves the file otherwise
+pub async fn create_file_download_link(
+    state: &AppState,
+    file_metadata: &diesel_models::file::FileMetadata,
+    expiry: i64,
+) -> CustomResult<(String, time::PrimitiveDateTime), errors::ApiErrorResponse> {
+    let file_key = match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id.as_ref(),
+        file_metadata.available,
+    ) {
+        (Some(diesel_models::enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            provider_file_id
+        }
+        (Some(_), Some(_), true) => Err(errors::ApiErrorResponse::FileProviderNotSupported {
+            message: "Download links are only supported for files stored by the router".to_string(),
+        })?,
+        _ => Err(errors::ApiErrorResponse::FileNotAvailable)
+            .into_report()
+            .attach_printable("File not available")?,
+    };
+    let expires_at = common_utils::date_time::now() + time::Duration::seconds(expiry);
+    let expires_in = std::time::Duration::from_secs(u64::try_from(expiry).unwrap_or_default());
+
+    if let Some(presigned_url) = state
+        .file_storage_client
+        .get_presigned_url(file_key, expires_in)
+        .await?
+    {
+        return Ok((presigned_url, expires_at));
+    }
+
+    let token = common_utils::generate_id(consts::ID_LENGTH, "download");
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &get_file_download_token_key(&token),
+            FileDownloadTokenData {
+                merchant_id: file_metadata.merchant_id.clone(),
+                file_id: file_metadata.file_id.clone(),
+            },
+            expiry,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store file download token")?;
+
+    Ok((
+        format!("{}/files/download/{token}", state.conf.server.base_url),
+        expires_at,
+    ))
+}
+
+/// Finds the file a download link created by `create_file_download_link` points to
+pub async fn find_file_metadata_by_download_token(
+    state: &AppState,
+    token: &str,
+) -> CustomResult<diesel_models::file::FileMetadata, errors::ApiErrorResponse> {
+    let token_data = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<FileDownloadTokenData>(
+            &get_file_download_token_key(token),
+            "FileDownloadTokenData",
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("File download token is invalid or has expired")?;
+
+    state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&token_data.merchant_id, &token_data.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)
+        .attach_printable("Unable to retrieve file_metadata")
+}
+
+fn get_file_download_token_key(token: &str) -> String {
+    format!("{FILE_DOWNLOAD_TOKEN_PREFIX}_{token}")
 }
 
 pub async fn validate_file_upload(
@@ -66,6 +206,19 @@ pub async fn validate_file_upload(
     merchant_account: domain::MerchantAccount,
     create_file_request: api::CreateFileRequest,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
+    if !state
+        .conf
+        .file_storage
+        .is_content_type_allowed(&create_file_request.file_type)
+    {
+        Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: format!(
+                "file_type {} is not allowed",
+                create_file_request.file_type.essence_str()
+            ),
+        })?
+    }
+
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
@@ -108,6 +261,19 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::PayoutBatch | api::FilePurpose::BlocklistImport => {
+            if create_file_request.file_size > consts::MAX_CSV_FILE_SIZE {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_size exceeded the max file size of 5MB".to_owned(),
+                })?
+            }
+            if create_file_request.file_type != mime::TEXT_CSV {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV format".to_owned(),
+                })?
+            }
+            Ok(())
+        }
     }
 }
 
@@ -133,12 +299,7 @@ pub async fn delete_file_using_file_id(
     };
     match provider {
         diesel_models::enums::FileUploadProvider::Router => {
-            delete_file(
-                #[cfg(feature = "aws_s3")]
-                state,
-                provider_file_id,
-            )
-            .await
+            delete_file(state, provider_file_id).await
         }
         _ => Err(errors::ApiErrorResponse::FileProviderNotSupported {
             message: "Not Supported because provider is not Router".to_string(),
@@ -233,14 +394,7 @@ pub async fn retrieve_file_and_provider_file_id_from_file_id(
             };
             match provider {
                 diesel_models::enums::FileUploadProvider::Router => Ok((
-                    Some(
-                        retrieve_file(
-                            #[cfg(feature = "aws_s3")]
-                            state,
-                            provider_file_id.clone(),
-                        )
-                        .await?,
-                    ),
+                    Some(retrieve_file(state, provider_file_id.clone()).await?),
                     Some(provider_file_id),
                 )),
                 _ => {
@@ -263,7 +417,7 @@ pub async fn retrieve_file_and_provider_file_id_from_file_id(
     }
 }
 
-//Upload file to connector if it supports / store it in S3 and return file_upload_provider, provider_file_id accordingly
+//Upload file to connector if it supports / store it in the file storage and return file_upload_provider, provider_file_id accordingly
 pub async fn upload_and_get_provider_provider_file_id_profile_id(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
@@ -364,13 +518,7 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                     payment_attempt.merchant_connector_id,
                 ))
             } else {
-                upload_file(
-                    #[cfg(feature = "aws_s3")]
-                    state,
-                    file_key.clone(),
-                    create_file_request.file.clone(),
-                )
-                .await?;
+                upload_file(state, file_key.clone(), create_file_request.file.clone()).await?;
                 Ok((
                     file_key,
                     api_models::enums::FileUploadProvider::Router,
@@ -379,5 +527,16 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        // Payout batch and blocklist import files are only consumed by the router, so they are
+        // always stored locally
+        api::FilePurpose::PayoutBatch | api::FilePurpose::BlocklistImport => {
+            upload_file(state, file_key.clone(), create_file_request.file.clone()).await?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
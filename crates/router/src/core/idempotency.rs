@@ -0,0 +1,342 @@
+use std::future::Future;
+
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    events::{ApiEventMetric, ApiEventsType},
+    ext_traits::Encode,
+    fp_utils::when,
+};
+use error_stack::{report, ResultExt};
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::errors::{self, RouterResponse, RouterResult};
+use crate::{
+    consts, headers,
+    routes::{lock_utils, AppState},
+    services::ApplicationResponse,
+};
+
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// The idempotency key sent by the client, along with a hash of the request it was sent with.
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    pub idempotency_key: String,
+    pub request_hash: String,
+    pub api_identifier: lock_utils::ApiIdentifier,
+}
+
+impl IdempotencyInput {
+    /// Reads the `Idempotency-Key` header from the request, if present.
+    ///
+    /// The request hash is computed from the request body as received from the client, and hence
+    /// this must be called before any identifiers are generated for the request.
+    pub fn from_request<T: Serialize>(
+        request_headers: &actix_web::http::header::HeaderMap,
+        request: &T,
+        api_identifier: lock_utils::ApiIdentifier,
+    ) -> RouterResult<Option<Self>> {
+        let Some(idempotency_key) = request_headers.get(headers::IDEMPOTENCY_KEY) else {
+            return Ok(None);
+        };
+
+        let idempotency_key = idempotency_key
+            .to_str()
+            .map_err(|_| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`{}` header is invalid", headers::IDEMPOTENCY_KEY),
+            })?
+            .trim()
+            .to_string();
+
+        when(
+            idempotency_key.is_empty() || idempotency_key.len() > IDEMPOTENCY_KEY_MAX_LENGTH,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "`{}` header must be between 1 and {IDEMPOTENCY_KEY_MAX_LENGTH} characters long",
+                        headers::IDEMPOTENCY_KEY
+                    ),
+                })
+            },
+        )?;
+
+        let request_body = Encode::<T>::encode_to_vec(request)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize request for computing its hash")?;
+        let request_hash = crypto::Sha256
+            .generate_digest(&request_body)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to compute hash of the request")?;
+
+        Ok(Some(Self {
+            idempotency_key,
+            request_hash: hex::encode(request_hash),
+            api_identifier,
+        }))
+    }
+
+    fn get_redis_key(&self, merchant_id: &str) -> String {
+        format!(
+            "{}_{}_{}_{}",
+            IDEMPOTENCY_KEY_PREFIX, merchant_id, self.api_identifier, self.idempotency_key
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum IdempotencyRecord {
+    InProgress {
+        request_hash: String,
+    },
+    Completed {
+        request_hash: String,
+        response: serde_json::Value,
+        headers: Vec<(String, String)>,
+    },
+}
+
+impl IdempotencyRecord {
+    fn get_request_hash(&self) -> &str {
+        match self {
+            Self::InProgress { request_hash } | Self::Completed { request_hash, .. } => {
+                request_hash
+            }
+        }
+    }
+}
+
+/// Response of a request made with an idempotency key, which is either the response of the
+/// request that was just processed, or the response replayed from the first request made with
+/// the same key.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum IdempotentResponse<R> {
+    Processed(R),
+    Replayed(serde_json::Value),
+}
+
+impl<R: ApiEventMetric> ApiEventMetric for IdempotentResponse<R> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match self {
+            Self::Processed(response) => response.get_api_event_type(),
+            Self::Replayed(_) => None,
+        }
+    }
+}
+
+/// Processes the request only if no request was made earlier with the same idempotency key.
+///
+/// The first successful JSON response is stored in Redis and replayed for subsequent requests
+/// made with the same key and request body. If the request fails, the key is released so that
+/// the client can retry the request with the same key.
+#[instrument(skip_all)]
+pub async fn perform_idempotent_request<R, Fut>(
+    state: AppState,
+    idempotency_input: Option<IdempotencyInput>,
+    merchant_id: String,
+    request_future: Fut,
+) -> RouterResponse<IdempotentResponse<R>>
+where
+    Fut: Future<Output = RouterResponse<R>>,
+    R: Serialize,
+{
+    let Some(idempotency_input) = idempotency_input else {
+        return request_future
+            .await
+            .map(|response| response.map(IdempotentResponse::Processed));
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let redis_key = idempotency_input.get_redis_key(&merchant_id);
+
+    let setnx_reply = redis_conn
+        .serialize_and_set_key_if_not_exist(
+            &redis_key,
+            IdempotencyRecord::InProgress {
+                request_hash: idempotency_input.request_hash.clone(),
+            },
+            Some(state.conf.idempotency.in_progress_expiry_seconds),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store idempotency record in redis")?;
+
+    match setnx_reply {
+        SetnxReply::KeySet => {
+            let response = request_future.await;
+
+            let response_to_store = match &response {
+                Ok(ApplicationResponse::Json(body)) => Some((body, Vec::new())),
+                Ok(ApplicationResponse::JsonWithHeaders((body, headers))) => Some((
+                    body,
+                    headers
+                        .iter()
+                        .filter(|(name, _)| name != consts::X_HS_LATENCY)
+                        .cloned()
+                        .collect(),
+                )),
+                _ => None,
+            };
+
+            let store_result = match response_to_store {
+                Some((body, headers)) => {
+                    store_response(
+                        &redis_conn,
+                        &redis_key,
+                        idempotency_input.request_hash,
+                        body,
+                        headers,
+                        state.conf.idempotency.response_expiry_seconds,
+                    )
+                    .await
+                }
+                None => redis_conn
+                    .delete_key(&redis_key)
+                    .await
+                    .map(|_| ())
+                    .change_context(errors::ApiErrorResponse::InternalServerError),
+            };
+
+            if let Err(error) = store_result {
+                logger::error!(
+                    ?error,
+                    "Failed to update idempotency record for key {}",
+                    idempotency_input.idempotency_key
+                );
+            }
+
+            response.map(|response| response.map(IdempotentResponse::Processed))
+        }
+        SetnxReply::KeyNotSet => {
+            let record = redis_conn
+                .get_and_deserialize_key::<IdempotencyRecord>(&redis_key, "IdempotencyRecord")
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch idempotency record from redis")?;
+
+            when(
+                record.get_request_hash() != idempotency_input.request_hash,
+                || {
+                    Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused {
+                        idempotency_key: idempotency_input.idempotency_key.clone(),
+                    }))
+                },
+            )?;
+
+            match record {
+                IdempotencyRecord::InProgress { .. } => Err(report!(
+                    errors::ApiErrorResponse::IdempotentRequestInProgress {
+                        idempotency_key: idempotency_input.idempotency_key,
+                    }
+                )),
+                IdempotencyRecord::Completed {
+                    response,
+                    mut headers,
+                    ..
+                } => {
+                    logger::info!(
+                        "Replaying stored response for idempotency key {}",
+                        idempotency_input.idempotency_key
+                    );
+                    headers.push((headers::IDEMPOTENT_REPLAYED.to_string(), "true".to_string()));
+                    Ok(ApplicationResponse::JsonWithHeaders((
+                        IdempotentResponse::Replayed(response),
+                        headers,
+                    )))
+                }
+            }
+        }
+    }
+}
+
+async fn store_response<R: Serialize>(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    redis_key: &str,
+    request_hash: String,
+    body: &R,
+    headers: Vec<(String, String)>,
+    expiry_seconds: i64,
+) -> RouterResult<()> {
+    let response = Encode::<R>::encode_to_value(body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize response of idempotent request")?;
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            redis_key,
+            IdempotencyRecord::Completed {
+                request_hash,
+                response,
+                headers,
+            },
+            expiry_seconds,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store response of idempotent request in redis")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_request_hash_depends_on_request_body() {
+        let mut request_headers = actix_web::http::header::HeaderMap::new();
+        request_headers.insert(
+            actix_web::http::header::HeaderName::from_static("idempotency-key"),
+            actix_web::http::header::HeaderValue::from_static("key_1"),
+        );
+
+        let first = IdempotencyInput::from_request(
+            &request_headers,
+            &serde_json::json!({ "amount": 100 }),
+            lock_utils::ApiIdentifier::Payments,
+        )
+        .unwrap()
+        .unwrap();
+        let repeated = IdempotencyInput::from_request(
+            &request_headers,
+            &serde_json::json!({ "amount": 100 }),
+            lock_utils::ApiIdentifier::Payments,
+        )
+        .unwrap()
+        .unwrap();
+        let different = IdempotencyInput::from_request(
+            &request_headers,
+            &serde_json::json!({ "amount": 200 }),
+            lock_utils::ApiIdentifier::Payments,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(first.idempotency_key, "key_1");
+        assert_eq!(first.request_hash, repeated.request_hash);
+        assert_ne!(first.request_hash, different.request_hash);
+        assert_eq!(
+            first.get_redis_key("merchant_1"),
+            "IDEMPOTENCY_merchant_1_payments_key_1"
+        );
+    }
+
+    #[test]
+    fn test_missing_idempotency_key() {
+        let idempotency_input = IdempotencyInput::from_request(
+            &actix_web::http::header::HeaderMap::new(),
+            &serde_json::json!({ "amount": 100 }),
+            lock_utils::ApiIdentifier::Payments,
+        )
+        .unwrap();
+
+        assert!(idempotency_input.is_none());
+    }
+}
@@ -34,7 +34,7 @@ pub async fn rust_locker_migration(
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
     let domain_customers = db
-        .list_customers_by_merchant_id(merchant_id, &key_store)
+        .list_customers_by_merchant_id(merchant_id, &key_store, merchant_account.storage_scheme)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
@@ -43,7 +43,11 @@ pub async fn rust_locker_migration(
 
     for customer in domain_customers {
         let result = db
-            .find_payment_method_by_customer_id_merchant_id_list(&customer.customer_id, merchant_id)
+            .find_payment_method_by_customer_id_merchant_id_list(
+                &customer.customer_id,
+                merchant_id,
+                merchant_account.storage_scheme,
+            )
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .and_then(|pm| {
                 call_to_locker(
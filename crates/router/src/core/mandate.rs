@@ -39,11 +39,21 @@ pub async fn get_mandate(
     let mandate = state
         .store
         .as_ref()
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
     Ok(services::ApplicationResponse::Json(
-        mandates::MandateResponse::from_db_mandate(&state, key_store, mandate).await?,
+        mandates::MandateResponse::from_db_mandate(
+            &state,
+            key_store,
+            mandate,
+            merchant_account.storage_scheme,
+        )
+        .await?,
     ))
 }
 
@@ -56,7 +66,11 @@ pub async fn revoke_mandate(
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
     let db = state.store.as_ref();
     let mandate = db
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
 
@@ -135,6 +149,7 @@ pub async fn revoke_mandate(
                             storage::MandateUpdate::StatusUpdate {
                                 mandate_status: storage::enums::MandateStatus::Revoked,
                             },
+                            merchant_account.storage_scheme,
                         )
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
@@ -174,13 +189,14 @@ pub async fn update_connector_mandate_id(
     merchant_account: String,
     mandate_ids_opt: Option<api_models::payments::MandateIds>,
     resp: Result<types::PaymentsResponseData, types::ErrorResponse>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> RouterResponse<mandates::MandateResponse> {
     let connector_mandate_id = Option::foreign_try_from(resp)?;
     //Ignore updation if the payment_attempt mandate_id or connector_mandate_id is not present
     if let Some((mandate_ids, connector_id)) = mandate_ids_opt.zip(connector_mandate_id) {
         let mandate_id = &mandate_ids.mandate_id;
         let mandate = db
-            .find_mandate_by_merchant_id_mandate_id(&merchant_account, mandate_id)
+            .find_mandate_by_merchant_id_mandate_id(&merchant_account, mandate_id, storage_scheme)
             .await
             .change_context(errors::ApiErrorResponse::MandateNotFound)?;
         // only update the connector_mandate_id if existing is none
@@ -191,6 +207,7 @@ pub async fn update_connector_mandate_id(
                 storage::MandateUpdate::ConnectorReferenceUpdate {
                     connector_mandate_ids: Some(connector_id),
                 },
+                storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
@@ -208,7 +225,11 @@ pub async fn get_customer_mandates(
 ) -> RouterResponse<Vec<mandates::MandateResponse>> {
     let mandates = state
         .store
-        .find_mandate_by_merchant_id_customer_id(&merchant_account.merchant_id, &req.customer_id)
+        .find_mandate_by_merchant_id_customer_id(
+            &merchant_account.merchant_id,
+            &req.customer_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| {
@@ -224,8 +245,13 @@ pub async fn get_customer_mandates(
         let mut response_vec = Vec::with_capacity(mandates.len());
         for mandate in mandates {
             response_vec.push(
-                mandates::MandateResponse::from_db_mandate(&state, key_store.clone(), mandate)
-                    .await?,
+                mandates::MandateResponse::from_db_mandate(
+                    &state,
+                    key_store.clone(),
+                    mandate,
+                    merchant_account.storage_scheme,
+                )
+                .await?,
             );
         }
         Ok(services::ApplicationResponse::Json(response_vec))
@@ -244,12 +270,164 @@ where
     }
 }
 
+/// Records the debit made by a payment against its mandate, returns `false` if the payment attempt
+/// was already recorded
+async fn record_mandate_usage<F, FData>(
+    state: &AppState,
+    resp: &types::RouterData<F, FData, types::PaymentsResponseData>,
+    mandate_id: &str,
+) -> errors::RouterResult<bool>
+where
+    FData: MandateBehaviour,
+{
+    let mandate_usage = storage::MandateUsageNew {
+        mandate_id: mandate_id.to_owned(),
+        merchant_id: resp.merchant_id.clone(),
+        payment_id: resp.payment_id.clone(),
+        attempt_id: resp.attempt_id.clone(),
+        amount: resp.request.get_amount(),
+        currency: resp.request.get_currency(),
+        created_at: common_utils::date_time::now(),
+    };
+    match state.store.insert_mandate_usage(mandate_usage).await {
+        Ok(_) => Ok(true),
+        Err(err) if err.current_context().is_db_unique_violation() => {
+            logger::warn!(
+                "Mandate usage of attempt {} is already recorded",
+                resp.attempt_id
+            );
+            Ok(false)
+        }
+        Err(err) => Err(err
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the mandate usage")),
+    }
+}
+
+/// Adds the amount of a payment to the captured amount of a multi use mandate before the connector
+/// is called, so that concurrent payments cannot exceed the cumulative limit together. Returns
+/// `true` if an amount was reserved.
+pub async fn reserve_mandate_amount(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    amount: i64,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> errors::RouterResult<bool> {
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    if mandate.mandate_type != storage_enums::MandateType::MultiUse {
+        return Ok(false);
+    }
+
+    state
+        .store
+        .increment_mandate_amount_captured(merchant_id, mandate_id, amount, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::MandateCumulativeLimitExceeded {
+                remaining_amount: mandate.get_remaining_amount().unwrap_or(0),
+            })
+        })?;
+    Ok(true)
+}
+
+/// Gives back an amount reserved by [`reserve_mandate_amount`] for a payment which was not made
+pub async fn release_mandate_amount(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    amount: i64,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) {
+    match state
+        .store
+        .increment_mandate_amount_captured(merchant_id, mandate_id, -amount, storage_scheme)
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => logger::error!(
+            "The reserved amount of mandate {} could not be released",
+            mandate_id
+        ),
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to release the reserved amount of mandate {}",
+            mandate_id
+        ),
+    }
+}
+
+/// Whether an attempt failed without charging the customer, the amount reserved for a mandate
+/// payment is given back when its attempt fails
+pub fn is_mandate_payment_failed(status: storage_enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::AttemptStatus::Failure
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+    )
+}
+
+/// Gives back the amount reserved for a mandate payment which was still pending after the
+/// connector call, when it fails later through a sync, a webhook or the completion of the
+/// authorization
+pub async fn release_mandate_amount_of_failed_attempt(
+    state: &AppState,
+    previous_status: storage_enums::AttemptStatus,
+    payment_attempt: &storage::PaymentAttempt,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) {
+    let Some(mandate_id) = payment_attempt.mandate_id.as_ref() else {
+        return;
+    };
+    if is_mandate_payment_failed(previous_status)
+        || !is_mandate_payment_failed(payment_attempt.status)
+    {
+        return;
+    }
+
+    // An amount is only reserved for multi use mandates
+    match state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(
+            &payment_attempt.merchant_id,
+            mandate_id,
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(mandate) if mandate.mandate_type == storage_enums::MandateType::MultiUse => {
+            release_mandate_amount(
+                state,
+                &payment_attempt.merchant_id,
+                mandate_id,
+                payment_attempt.get_total_amount(),
+                storage_scheme,
+            )
+            .await
+        }
+        Ok(_) => {}
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to release the reserved amount of mandate {}",
+            mandate_id
+        ),
+    }
+}
+
 pub async fn mandate_procedure<F, FData>(
     state: &AppState,
     mut resp: types::RouterData<F, FData, types::PaymentsResponseData>,
     maybe_customer: &Option<domain::Customer>,
     pm_id: Option<String>,
     merchant_connector_id: Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> errors::RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
     FData: MandateBehaviour,
@@ -261,9 +439,14 @@ where
                 let mandate_id = &mandate_id.mandate_id;
                 let mandate = state
                     .store
-                    .find_mandate_by_merchant_id_mandate_id(resp.merchant_id.as_ref(), mandate_id)
+                    .find_mandate_by_merchant_id_mandate_id(
+                        resp.merchant_id.as_ref(),
+                        mandate_id,
+                        storage_scheme,
+                    )
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+                let is_new_usage = record_mandate_usage(state, &resp, mandate_id).await?;
                 let mandate = match mandate.mandate_type {
                     storage_enums::MandateType::SingleUse => state
                         .store
@@ -273,23 +456,25 @@ where
                             storage::MandateUpdate::StatusUpdate {
                                 mandate_status: storage_enums::MandateStatus::Revoked,
                             },
+                            storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
-                    storage_enums::MandateType::MultiUse => state
-                        .store
-                        .update_mandate_by_merchant_id_mandate_id(
-                            &resp.merchant_id,
-                            mandate_id,
-                            storage::MandateUpdate::CaptureAmountUpdate {
-                                amount_captured: Some(
-                                    mandate.amount_captured.unwrap_or(0)
-                                        + resp.request.get_amount(),
-                                ),
-                            },
-                        )
-                        .await
-                        .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
+                    // The amount was reserved before calling the connector, it is only counted
+                    // once for a payment attempt
+                    storage_enums::MandateType::MultiUse => {
+                        if !is_new_usage {
+                            release_mandate_amount(
+                                state,
+                                &resp.merchant_id,
+                                mandate_id,
+                                resp.request.get_amount(),
+                                storage_scheme,
+                            )
+                            .await;
+                        }
+                        Ok(mandate)
+                    }
                 }?;
                 metrics::SUBSEQUENT_MANDATE_PAYMENT.add(
                     &metrics::CONTEXT,
@@ -368,7 +553,7 @@ where
                         }));
                         state
                             .store
-                            .insert_mandate(new_mandate_data)
+                            .insert_mandate(new_mandate_data, storage_scheme)
                             .await
                             .to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate)?;
                         metrics::MANDATE_COUNT.add(
@@ -394,12 +579,21 @@ pub async fn retrieve_mandates_list(
     let mandates = state
         .store
         .as_ref()
-        .find_mandates_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .find_mandates_by_merchant_id(
+            &merchant_account.merchant_id,
+            constraints,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve mandates")?;
     let mandates_list = future::try_join_all(mandates.into_iter().map(|mandate| {
-        mandates::MandateResponse::from_db_mandate(&state, key_store.clone(), mandate)
+        mandates::MandateResponse::from_db_mandate(
+            &state,
+            key_store.clone(),
+            mandate,
+            merchant_account.storage_scheme,
+        )
     }))
     .await?;
     Ok(services::ApplicationResponse::Json(mandates_list))
@@ -431,6 +625,7 @@ impl ForeignTryFrom<Result<types::PaymentsResponseData, types::ErrorResponse>>
 
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
+    fn get_currency(&self) -> diesel_models::enums::Currency;
     fn get_setup_future_usage(&self) -> Option<diesel_models::enums::FutureUsage>;
     fn get_mandate_id(&self) -> Option<&api_models::payments::MandateIds>;
     fn set_mandate_id(&mut self, new_mandate_id: Option<api_models::payments::MandateIds>);
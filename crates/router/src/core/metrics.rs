@@ -72,6 +72,10 @@ counter_metric!(
     ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
     GLOBAL_METER
 );
+counter_metric!(ROUTING_SIMULATE_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
+counter_metric!(ROUTING_COVERAGE_CONFIG, GLOBAL_METER);
+counter_metric!(ROUTING_COVERAGE_CONFIG_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_RETRIEVE_CONFIG_FOR_PROFILE, GLOBAL_METER);
 counter_metric!(
     ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
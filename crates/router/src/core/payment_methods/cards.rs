@@ -80,24 +80,33 @@ pub async fn create_payment_method(
     pm_metadata: Option<serde_json::Value>,
     payment_method_data: Option<Encryption>,
     key_store: &domain::MerchantKeyStore,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::ApiErrorResponse> {
-    db.find_customer_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    db.find_customer_by_customer_id_merchant_id(
+        customer_id,
+        merchant_id,
+        key_store,
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
     let response = db
-        .insert_payment_method(storage::PaymentMethodNew {
-            customer_id: customer_id.to_string(),
-            merchant_id: merchant_id.to_string(),
-            payment_method_id: payment_method_id.to_string(),
-            payment_method: req.payment_method,
-            payment_method_type: req.payment_method_type,
-            payment_method_issuer: req.payment_method_issuer.clone(),
-            scheme: req.card_network.clone(),
-            metadata: pm_metadata.map(masking::Secret::new),
-            payment_method_data,
-            ..storage::PaymentMethodNew::default()
-        })
+        .insert_payment_method(
+            storage::PaymentMethodNew {
+                customer_id: customer_id.to_string(),
+                merchant_id: merchant_id.to_string(),
+                payment_method_id: payment_method_id.to_string(),
+                payment_method: req.payment_method,
+                payment_method_type: req.payment_method_type,
+                payment_method_issuer: req.payment_method_issuer.clone(),
+                scheme: req.card_network.clone(),
+                metadata: pm_metadata.map(masking::Secret::new),
+                payment_method_data,
+                ..storage::PaymentMethodNew::default()
+            },
+            storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to add payment method in db")?;
@@ -199,6 +208,7 @@ pub async fn add_payment_method(
             pm_metadata.cloned(),
             pm_data_encrypted,
             key_store,
+            merchant_account.storage_scheme,
         )
         .await?;
     }
@@ -219,6 +229,7 @@ pub async fn update_customer_payment_method(
         .delete_payment_method_by_merchant_id_payment_method_id(
             &merchant_account.merchant_id,
             payment_method_id,
+            merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -703,11 +714,12 @@ pub async fn update_payment_method(
     db: &dyn db::StorageInterface,
     pm: payment_method::PaymentMethod,
     pm_metadata: serde_json::Value,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> errors::CustomResult<(), errors::VaultError> {
     let pm_update = payment_method::PaymentMethodUpdate::MetadataUpdate {
         metadata: Some(pm_metadata),
     };
-    db.update_payment_method(pm, pm_update)
+    db.update_payment_method(pm, pm_update, storage_scheme)
         .await
         .change_context(errors::VaultError::UpdateInPaymentMethodDataTableFailed)?;
     Ok(())
@@ -1122,6 +1134,7 @@ pub async fn list_payment_methods(
                         cust.as_str(),
                         &pi.merchant_id,
                         &key_store,
+                        merchant_account.storage_scheme,
                     )
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
@@ -1838,6 +1851,7 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_transaction_amount: i.max_transaction_amount,
                         })
                     }
                     data_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -1847,6 +1861,7 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_transaction_amount: i.max_transaction_amount,
                         }))
                     }
                     data_models::mandates::MandateDataType::MultiUse(None) => {
@@ -2477,6 +2492,7 @@ pub async fn list_customer_payment_method(
         customer_id,
         &merchant_account.merchant_id,
         &key_store,
+        merchant_account.storage_scheme,
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
@@ -2498,6 +2514,7 @@ pub async fn list_customer_payment_method(
         .find_payment_method_by_customer_id_merchant_id_list(
             customer_id,
             &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -3019,10 +3036,11 @@ pub async fn retrieve_payment_method(
     state: routes::AppState,
     pm: api::PaymentMethodId,
     key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
 ) -> errors::RouterResponse<api::PaymentMethodResponse> {
     let db = state.store.as_ref();
     let pm = db
-        .find_payment_method(&pm.payment_method_id)
+        .find_payment_method(&pm.payment_method_id, merchant_account.storage_scheme)
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
@@ -3074,7 +3092,10 @@ pub async fn delete_payment_method(
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
     let db = state.store.as_ref();
     let key = db
-        .find_payment_method(pm_id.payment_method_id.as_str())
+        .find_payment_method(
+            pm_id.payment_method_id.as_str(),
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
@@ -3098,6 +3119,7 @@ pub async fn delete_payment_method(
     db.delete_payment_method_by_merchant_id_payment_method_id(
         &merchant_account.merchant_id,
         pm_id.payment_method_id.as_str(),
+        merchant_account.storage_scheme,
     )
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
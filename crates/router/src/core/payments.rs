@@ -139,6 +139,7 @@ where
             &mut payment_data,
             customer_details,
             &key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
@@ -316,31 +317,42 @@ where
                     #[cfg(feature = "retry")]
                     {
                         use crate::core::payments::retry::{self, GsmValidation};
-                        let config_bool = retry::config_should_call_gsm(
-                            &*state.store,
-                            &merchant_account.merchant_id,
-                        )
-                        .await;
-
-                        if config_bool && router_data.should_call_gsm() {
-                            router_data = retry::do_gsm_actions(
+                        if router_data.should_call_gsm() {
+                            // A configured retry policy enables the GSM actions on its own
+                            let retry_policy = retry::get_retry_policy(
                                 state,
-                                &mut payment_data,
-                                connectors,
-                                connector_data,
-                                router_data,
-                                &merchant_account,
-                                &key_store,
-                                &operation,
-                                &customer,
-                                &validate_result,
-                                schedule_time,
-                                #[cfg(feature = "frm")]
-                                frm_info.as_ref().and_then(|fi| fi.suggested_action),
-                                #[cfg(not(feature = "frm"))]
-                                None,
+                                &merchant_account.merchant_id,
+                                payment_data.payment_intent.profile_id.as_ref(),
                             )
-                            .await?;
+                            .await;
+                            let config_bool = retry_policy.is_some()
+                                || retry::config_should_call_gsm(
+                                    &*state.store,
+                                    &merchant_account.merchant_id,
+                                )
+                                .await;
+
+                            if config_bool {
+                                router_data = retry::do_gsm_actions(
+                                    state,
+                                    &mut payment_data,
+                                    connectors,
+                                    connector_data,
+                                    router_data,
+                                    &merchant_account,
+                                    &key_store,
+                                    &operation,
+                                    &customer,
+                                    &validate_result,
+                                    schedule_time,
+                                    retry_policy,
+                                    #[cfg(feature = "frm")]
+                                    frm_info.as_ref().and_then(|fi| fi.suggested_action),
+                                    #[cfg(not(feature = "frm"))]
+                                    None,
+                                )
+                                .await?;
+                            }
                         };
                     }
 
@@ -80,7 +80,19 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         if self.should_proceed_with_authorize() {
             self.decide_authentication_type();
             logger::debug!(auth_type=?self.auth_type);
-            let resp = services::execute_connector_processing_step(
+            let reserved_mandate_id = match self.request.mandate_id.as_ref() {
+                Some(mandate_ids) => mandate::reserve_mandate_amount(
+                    state,
+                    &self.merchant_id,
+                    &mandate_ids.mandate_id,
+                    self.request.amount,
+                    merchant_account.storage_scheme,
+                )
+                .await?
+                .then(|| mandate_ids.mandate_id.clone()),
+                None => None,
+            };
+            let resp = match services::execute_connector_processing_step(
                 state,
                 connector_integration,
                 &self,
@@ -88,7 +100,37 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                 connector_request,
             )
             .await
-            .to_payment_failed_response()?;
+            {
+                Ok(resp) => resp,
+                Err(error) => {
+                    if let Some(mandate_id) = reserved_mandate_id.as_ref() {
+                        mandate::release_mandate_amount(
+                            state,
+                            &self.merchant_id,
+                            mandate_id,
+                            self.request.amount,
+                            merchant_account.storage_scheme,
+                        )
+                        .await;
+                    }
+                    return Err(error).to_payment_failed_response();
+                }
+            };
+
+            // Payments which are still pending keep their reservation until they fail through a
+            // sync or a webhook
+            if let Some(mandate_id) = reserved_mandate_id.filter(|_| {
+                resp.response.is_err() || mandate::is_mandate_payment_failed(resp.status)
+            }) {
+                mandate::release_mandate_amount(
+                    state,
+                    &resp.merchant_id,
+                    &mandate_id,
+                    resp.request.amount,
+                    merchant_account.storage_scheme,
+                )
+                .await;
+            }
 
             metrics::PAYMENT_COUNT.add(&metrics::CONTEXT, 1, &[]); // Metrics
 
@@ -111,6 +153,7 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                     maybe_customer,
                     payment_method_id,
                     connector.merchant_connector_id.clone(),
+                    merchant_account.storage_scheme,
                 )
                 .await?)
             } else {
@@ -274,6 +317,9 @@ impl mandate::MandateBehaviour for types::PaymentsAuthorizeData {
     fn get_amount(&self) -> i64 {
         self.amount
     }
+    fn get_currency(&self) -> diesel_models::enums::Currency {
+        self.currency
+    }
     fn get_mandate_id(&self) -> Option<&api_models::payments::MandateIds> {
         self.mandate_id.as_ref()
     }
@@ -92,6 +92,7 @@ impl Feature<api::SetupMandate, types::SetupMandateRequestData> for types::Setup
             maybe_customer,
             pm_id,
             connector.merchant_connector_id.clone(),
+            merchant_account.storage_scheme,
         )
         .await
     }
@@ -226,6 +227,7 @@ impl types::SetupMandateRouterData {
                     maybe_customer,
                     pm_id,
                     connector.merchant_connector_id.clone(),
+                    merchant_account.storage_scheme,
                 )
                 .await?)
             }
@@ -239,6 +241,10 @@ impl mandate::MandateBehaviour for types::SetupMandateRequestData {
         0
     }
 
+    fn get_currency(&self) -> diesel_models::enums::Currency {
+        self.currency
+    }
+
     fn get_setup_future_usage(&self) -> Option<diesel_models::enums::FutureUsage> {
         self.setup_future_usage
     }
@@ -475,7 +475,11 @@ pub async fn get_token_for_recurring_mandate(
     let mandate_id = req.mandate_id.clone().get_required_value("mandate_id")?;
 
     let mandate = db
-        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, mandate_id.as_str())
+        .find_mandate_by_merchant_id_mandate_id(
+            &merchant_account.merchant_id,
+            mandate_id.as_str(),
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
 
@@ -522,7 +526,7 @@ pub async fn get_token_for_recurring_mandate(
     )?;
 
     let payment_method = db
-        .find_payment_method(payment_method_id.as_str())
+        .find_payment_method(payment_method_id.as_str(), merchant_account.storage_scheme)
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
@@ -842,7 +846,7 @@ fn validate_new_mandate_request(
         Some(api_models::payments::MandateType::MultiUse(details)) => details,
         None => None,
     };
-    mandate_details.and_then(|md| md.start_date.zip(md.end_date)).map(|(start_date, end_date)|
+    mandate_details.as_ref().and_then(|md| md.start_date.zip(md.end_date)).map(|(start_date, end_date)|
         utils::when (start_date >= end_date, || {
         Err(report!(errors::ApiErrorResponse::PreconditionFailed {
             message: "`mandate_data.mandate_type.{multi_use|single_use}.start_date` should be greater than  \
@@ -851,6 +855,24 @@ fn validate_new_mandate_request(
         }))
     })).transpose()?;
 
+    if let Some(md) = mandate_details {
+        utils::when(
+            md.max_transaction_amount
+                .map_or(false, |max_transaction_amount| {
+                    max_transaction_amount <= 0 || max_transaction_amount > md.amount
+                }),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message:
+                        "`mandate_data.mandate_type.{multi_use|single_use}.max_transaction_amount` \
+                              should be greater than 0 and not greater than \
+                              `mandate_data.mandate_type.{multi_use|single_use}.amount`"
+                            .into()
+                }))
+            },
+        )?;
+    }
+
     Ok(())
 }
 
@@ -942,32 +964,44 @@ pub fn verify_mandate_details(
     request_currency: api_enums::Currency,
     mandate: storage::Mandate,
 ) -> RouterResult<()> {
-    match mandate.mandate_type {
-        storage_enums::MandateType::SingleUse => utils::when(
-            mandate
-                .mandate_amount
-                .map(|mandate_amount| request_amount > mandate_amount)
-                .unwrap_or(true),
-            || {
-                Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
-                    reason: "request amount is greater than mandate amount".to_string()
-                }))
-            },
-        ),
-        storage::enums::MandateType::MultiUse => utils::when(
-            mandate
-                .mandate_amount
-                .map(|mandate_amount| {
-                    (mandate.amount_captured.unwrap_or(0) + request_amount) > mandate_amount
-                })
-                .unwrap_or(false),
-            || {
-                Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
-                    reason: "request amount is greater than mandate amount".to_string()
-                }))
-            },
-        ),
-    }?;
+    let now = common_utils::date_time::now();
+    utils::when(
+        mandate
+            .start_date
+            .map_or(false, |start_date| now < start_date),
+        || Err(report!(errors::ApiErrorResponse::MandateNotYetValid)),
+    )?;
+    utils::when(
+        mandate.end_date.map_or(false, |end_date| now > end_date),
+        || Err(report!(errors::ApiErrorResponse::MandateExpired)),
+    )?;
+
+    utils::when(
+        mandate.mandate_type == storage_enums::MandateType::SingleUse
+            && mandate.mandate_amount.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "single use mandate does not have an amount".to_string()
+            }))
+        },
+    )?;
+    if let Some(limit) = mandate
+        .get_transaction_limit()
+        .filter(|limit| request_amount > *limit)
+    {
+        Err(report!(
+            errors::ApiErrorResponse::MandateTransactionLimitExceeded { limit }
+        ))?
+    }
+    if let Some(remaining_amount) = mandate
+        .get_remaining_amount()
+        .filter(|remaining_amount| request_amount > *remaining_amount)
+    {
+        Err(report!(
+            errors::ApiErrorResponse::MandateCumulativeLimitExceeded { remaining_amount }
+        ))?
+    }
+
     utils::when(
         mandate
             .mandate_currency
@@ -1140,6 +1174,7 @@ pub async fn get_customer_from_details<F: Clone>(
     merchant_id: &str,
     payment_data: &mut PaymentData<F>,
     merchant_key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
 ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
     match customer_id {
         None => Ok(None),
@@ -1149,6 +1184,7 @@ pub async fn get_customer_from_details<F: Clone>(
                     &c_id,
                     merchant_id,
                     merchant_key_store,
+                    storage_scheme,
                 )
                 .await?;
             payment_data.email = payment_data.email.clone().or_else(|| {
@@ -1287,6 +1323,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
     req: Option<CustomerDetails>,
     merchant_id: &str,
     key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
 ) -> CustomResult<(BoxedOperation<'a, F, R, Ctx>, Option<domain::Customer>), errors::StorageError> {
     let request_customer_details = req
         .get_required_value("customer")
@@ -1303,6 +1340,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                     &customer_id,
                     merchant_id,
                     key_store,
+                    storage_scheme,
                 )
                 .await?;
 
@@ -1354,6 +1392,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                             merchant_id.to_string(),
                             customer_update,
                             key_store,
+                            storage_scheme,
                         )
                         .await
                     } else {
@@ -1396,7 +1435,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                     .change_context(errors::StorageError::SerializationFailed)
                     .attach_printable("Failed while encrypting Customer while insert")?;
                     metrics::CUSTOMER_CREATED.add(&metrics::CONTEXT, 1, &[]);
-                    db.insert_customer(new_customer, key_store).await
+                    db.insert_customer(new_customer, key_store, storage_scheme)
+                        .await
                 }
             })
         }
@@ -1407,6 +1447,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                     customer_id,
                     merchant_id,
                     key_store,
+                    storage_scheme,
                 )
                 .await?
                 .map(Ok),
@@ -2410,6 +2451,8 @@ pub fn generate_mandate(
                     data_models::mandates::MandateDataType::SingleUse(data) => new_mandate
                         .set_mandate_amount(Some(data.amount))
                         .set_mandate_currency(Some(data.currency))
+                        .set_start_date(data.start_date)
+                        .set_end_date(data.end_date)
                         .set_mandate_type(storage_enums::MandateType::SingleUse)
                         .to_owned(),
 
@@ -2418,7 +2461,8 @@ pub fn generate_mandate(
                             .set_mandate_amount(Some(data.amount))
                             .set_mandate_currency(Some(data.currency))
                             .set_start_date(data.start_date)
-                            .set_end_date(data.end_date),
+                            .set_end_date(data.end_date)
+                            .set_max_transaction_amount(data.max_transaction_amount),
                         // .set_metadata(data.metadata),
                         // we are storing PaymentMethodData in metadata of mandate
                         None => &mut new_mandate,
@@ -2783,6 +2827,86 @@ mod tests {
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    fn get_multi_use_mandate() -> storage::Mandate {
+        storage::Mandate {
+            id: 1,
+            mandate_id: "man_1".to_string(),
+            customer_id: "cus_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            payment_method_id: "pm_1".to_string(),
+            mandate_status: storage_enums::MandateStatus::Active,
+            mandate_type: storage_enums::MandateType::MultiUse,
+            customer_accepted_at: None,
+            customer_ip_address: None,
+            customer_user_agent: None,
+            network_transaction_id: None,
+            previous_attempt_id: None,
+            created_at: common_utils::date_time::now(),
+            mandate_amount: Some(1000),
+            mandate_currency: Some(storage_enums::Currency::USD),
+            amount_captured: Some(700),
+            connector: "stripe".to_string(),
+            connector_mandate_id: None,
+            start_date: None,
+            end_date: None,
+            metadata: None,
+            connector_mandate_ids: None,
+            original_payment_id: None,
+            merchant_connector_id: None,
+            max_transaction_amount: Some(200),
+        }
+    }
+
+    #[test]
+    fn test_verify_mandate_details_within_limits() {
+        let mandate = get_multi_use_mandate();
+        assert!(verify_mandate_details(200, api_enums::Currency::USD, mandate).is_ok())
+    }
+
+    #[test]
+    fn test_verify_mandate_details_transaction_limit_exceeded() {
+        let mandate = get_multi_use_mandate();
+        let result = verify_mandate_details(250, api_enums::Currency::USD, mandate);
+        assert!(matches!(
+            result.map_err(|err| err.current_context().clone()),
+            Err(errors::ApiErrorResponse::MandateTransactionLimitExceeded { limit: 200 })
+        ))
+    }
+
+    #[test]
+    fn test_verify_mandate_details_cumulative_limit_exceeded() {
+        let mut mandate = get_multi_use_mandate();
+        mandate.amount_captured = Some(900);
+        let result = verify_mandate_details(150, api_enums::Currency::USD, mandate);
+        assert!(matches!(
+            result.map_err(|err| err.current_context().clone()),
+            Err(errors::ApiErrorResponse::MandateCumulativeLimitExceeded {
+                remaining_amount: 100
+            })
+        ))
+    }
+
+    #[test]
+    fn test_verify_mandate_details_outside_validity_dates() {
+        let mut mandate = get_multi_use_mandate();
+        mandate.start_date =
+            Some(common_utils::date_time::now().saturating_add(time::Duration::days(1)));
+        let result = verify_mandate_details(100, api_enums::Currency::USD, mandate.clone());
+        assert!(matches!(
+            result.map_err(|err| err.current_context().clone()),
+            Err(errors::ApiErrorResponse::MandateNotYetValid)
+        ));
+
+        mandate.start_date = None;
+        mandate.end_date =
+            Some(common_utils::date_time::now().saturating_sub(time::Duration::days(1)));
+        let result = verify_mandate_details(100, api_enums::Currency::USD, mandate);
+        assert!(matches!(
+            result.map_err(|err| err.current_context().clone()),
+            Err(errors::ApiErrorResponse::MandateExpired)
+        ))
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3197,6 +3321,8 @@ impl AttemptType {
             unified_code: None,
             unified_message: None,
             net_amount: old_payment_attempt.amount,
+            retry_decision: None,
+            retry_reason: None,
         }
     }
 
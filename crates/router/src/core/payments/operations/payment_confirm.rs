@@ -10,6 +10,7 @@ use error_stack::{report, IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms;
 use futures::FutureExt;
+use masking::PeekInterface;
 use router_derive::PaymentOperation;
 use router_env::{instrument, logger, tracing};
 use tracing_futures::Instrument;
@@ -510,6 +511,7 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest, Ctx>,
@@ -524,6 +526,7 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
             request,
             &key_store.merchant_id,
             key_store,
+            storage_scheme,
         )
         .await
     }
@@ -747,88 +750,95 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             })
             .map(hex::encode);
 
-        // Hashed Cardbin to check whether or not this payment should be blocked.
-        let card_bin_fingerprint = payment_data
-            .payment_method_data
+        let card_number =
+            payment_data
+                .payment_method_data
+                .as_ref()
+                .and_then(|pm_data| match pm_data {
+                    api_models::payments::PaymentMethodData::Card(card) => {
+                        Some(card.card_number.clone())
+                    }
+                    _ => None,
+                });
+
+        let email = payment_data
+            .email
             .as_ref()
-            .and_then(|pm_data| match pm_data {
-                api_models::payments::PaymentMethodData::Card(card) => {
-                    crypto::HmacSha512::sign_message(
-                        &crypto::HmacSha512,
-                        merchant_fingerprint_secret.as_bytes(),
-                        card.card_number.clone().get_card_isin().as_bytes(),
-                    )
-                    .attach_printable("error in card bin hash creation")
-                    .map_or_else(
-                        |err| {
-                            logger::error!(error=?err);
-                            None
-                        },
-                        Some,
-                    )
-                }
-                _ => None,
-            })
-            .map(hex::encode);
+            .map(|email| email.peek().to_string())
+            .or_else(|| {
+                customer
+                    .as_ref()
+                    .and_then(|customer| customer.email.as_ref())
+                    .map(|email| email.get_inner().peek().to_string())
+            });
 
-        // Hashed Extended Cardbin to check whether or not this payment should be blocked.
-        let extended_card_bin_fingerprint = payment_data
-            .payment_method_data
+        let phone_number = customer
             .as_ref()
-            .and_then(|pm_data| match pm_data {
-                api_models::payments::PaymentMethodData::Card(card) => {
-                    crypto::HmacSha512::sign_message(
-                        &crypto::HmacSha512,
-                        merchant_fingerprint_secret.as_bytes(),
-                        card.card_number.clone().get_extended_card_bin().as_bytes(),
-                    )
-                    .attach_printable("error in extended card bin hash creation")
-                    .map_or_else(
-                        |err| {
-                            logger::error!(error=?err);
-                            None
-                        },
-                        Some,
+            .and_then(|customer| {
+                customer.phone.as_ref().map(|phone| {
+                    blocklist_utils::phone_number_with_country_code(
+                        customer.phone_country_code.as_deref(),
+                        phone.get_inner().peek(),
                     )
-                }
-                _ => None,
+                })
             })
-            .map(hex::encode);
-
-        let mut fingerprint_id = None;
-
-        //validating the payment method.
-        let mut is_pm_blocklisted = false;
-
-        let mut blocklist_futures = Vec::new();
-        if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
-            blocklist_futures.push(db.find_blocklist_lookup_entry_by_merchant_id_fingerprint(
-                &merchant_id,
-                card_number_fingerprint,
-            ));
-        }
-
-        if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
-            blocklist_futures.push(db.find_blocklist_lookup_entry_by_merchant_id_fingerprint(
-                &merchant_id,
-                card_bin_fingerprint,
-            ));
-        }
+            .or_else(|| {
+                payment_data
+                    .address
+                    .billing
+                    .as_ref()
+                    .and_then(|billing| billing.phone.as_ref())
+                    .and_then(|phone| {
+                        phone.number.as_ref().map(|number| {
+                            blocklist_utils::phone_number_with_country_code(
+                                phone.country_code.as_deref(),
+                                number.peek(),
+                            )
+                        })
+                    })
+            });
 
-        if let Some(extended_card_bin_fingerprint) = extended_card_bin_fingerprint.as_ref() {
-            blocklist_futures.push(db.find_blocklist_lookup_entry_by_merchant_id_fingerprint(
-                &merchant_id,
-                extended_card_bin_fingerprint,
-            ));
-        }
+        let ip_address = browser_info
+            .clone()
+            .and_then(|browser_info| {
+                serde_json::from_value::<types::BrowserInformation>(browser_info).ok()
+            })
+            .and_then(|browser_info| browser_info.ip_address);
 
-        let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
+        let mut fingerprint_id = None;
 
-        if blocklist_lookups.iter().any(|x| x.is_ok()) {
-            intent_status = storage_enums::IntentStatus::Failed;
-            attempt_status = storage_enums::AttemptStatus::Failure;
-            is_pm_blocklisted = true;
-        }
+        //validating the payment method and the customer details.
+        let blocklist_match = blocklist_utils::find_blocklist_match(
+            state,
+            &merchant_id,
+            &merchant_fingerprint_secret,
+            blocklist_utils::BlocklistCheckData {
+                card_number_fingerprint: card_number_fingerprint.clone(),
+                card_number,
+                email,
+                phone_number,
+                ip_address,
+            },
+        )
+        .await;
+
+        let (m_error_code, m_error_message, m_error_reason) = match blocklist_match.as_ref() {
+            Some(blocklist_match) => {
+                logger::info!(
+                    data_kind = %blocklist_match.data_kind,
+                    blocklist_entry_id = ?blocklist_match.blocklist_entry_id,
+                    "payment blocked by blocklist"
+                );
+                intent_status = storage_enums::IntentStatus::Failed;
+                attempt_status = storage_enums::AttemptStatus::Failure;
+                (
+                    Some(Some(consts::BLOCKLIST_ERROR_CODE.to_string())),
+                    Some(Some(blocklist_match.reason())),
+                    Some(blocklist_match.error_reason()),
+                )
+            }
+            None => (m_error_code, m_error_message, None),
+        };
 
         if let Some(encoded_hash) = card_number_fingerprint {
             #[cfg(feature = "kms")]
@@ -898,6 +908,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         straight_through_algorithm: m_straight_through_algorithm,
                         error_code: m_error_code,
                         error_message: m_error_message,
+                        error_reason: m_error_reason,
                         amount_capturable: Some(authorized_amount),
                         updated_by: storage_scheme.to_string(),
                         merchant_connector_id,
@@ -974,6 +985,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                             m_customer_merchant_id,
                             m_updated_customer,
                             &m_key_store,
+                            storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1000,7 +1012,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         payment_data.payment_attempt = payment_attempt;
 
         // Block the payment if the entry was present in the Blocklist
-        if is_pm_blocklisted {
+        if blocklist_match.is_some() {
             return Err(errors::ApiErrorResponse::PaymentBlocked.into());
         }
 
@@ -6,7 +6,7 @@ use data_models::payments::payment_attempt::PaymentAttempt;
 use error_stack::{report, IntoReport, ResultExt};
 use futures::FutureExt;
 use router_derive;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
@@ -17,7 +17,7 @@ use crate::{
         errors::{self, RouterResult, StorageErrorExt},
         mandate,
         payment_methods::PaymentMethodRetrieve,
-        payments::{helpers as payments_helpers, types::MultipleCaptureData, PaymentData},
+        payments::{helpers as payments_helpers, routing, types::MultipleCaptureData, PaymentData},
         utils as core_utils,
     },
     routes::{metrics, AppState},
@@ -60,6 +60,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthorizeData
         payment_data.mandate_id = payment_data
             .mandate_id
             .or_else(|| router_data.request.mandate_id.clone());
+        let previous_status = payment_data.payment_attempt.status;
 
         payment_data = Box::pin(payment_response_update_tracker(
             db,
@@ -70,6 +71,8 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthorizeData
         ))
         .await?;
 
+        update_connector_success_rate(db, previous_status, &payment_data).await;
+
         Ok(payment_data)
     }
 }
@@ -219,14 +222,28 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
     where
         F: 'b + Send,
     {
-        Box::pin(payment_response_update_tracker(
+        // Payments pending at the connector, whether synced or notified through a webhook, reach
+        // their outcome here
+        let previous_status = payment_data.payment_attempt.status;
+        let payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_id,
             payment_data,
             router_data,
             storage_scheme,
         ))
-        .await
+        .await?;
+
+        update_connector_success_rate(db, previous_status, &payment_data).await;
+        mandate::release_mandate_amount_of_failed_attempt(
+            db,
+            previous_status,
+            &payment_data.payment_attempt,
+            storage_scheme,
+        )
+        .await;
+
+        Ok(payment_data)
     }
 }
 
@@ -422,17 +439,42 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::CompleteAuthorizeData
     where
         F: 'b + Send,
     {
-        Box::pin(payment_response_update_tracker(
+        let previous_status = payment_data.payment_attempt.status;
+        let payment_data = Box::pin(payment_response_update_tracker(
             db,
             payment_id,
             payment_data,
             response,
             storage_scheme,
         ))
-        .await
+        .await?;
+
+        update_connector_success_rate(db, previous_status, &payment_data).await;
+        mandate::release_mandate_amount_of_failed_attempt(
+            db,
+            previous_status,
+            &payment_data.payment_attempt,
+            storage_scheme,
+        )
+        .await;
+
+        Ok(payment_data)
     }
 }
 
+/// The success rate counters are only used for routing, so a failure to update them doesn't fail
+/// the payment.
+async fn update_connector_success_rate<F: Clone>(
+    state: &AppState,
+    previous_status: enums::AttemptStatus,
+    payment_data: &PaymentData<F>,
+) {
+    routing::success_rate::update_success_rate_window(state, previous_status, payment_data)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to update success rate of connector"))
+        .ok();
+}
+
 #[instrument(skip_all)]
 async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     state: &AppState,
@@ -831,6 +873,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 m_router_data_merchant_id,
                 m_payment_data_mandate_id,
                 m_router_data_response,
+                storage_scheme,
             )
             .await
         }
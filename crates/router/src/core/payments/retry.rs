@@ -1,5 +1,7 @@
 use std::{str::FromStr, vec::IntoIter};
 
+use api_models::gsm as gsm_api;
+use common_utils::ext_traits::StringExt;
 use diesel_models::enums as storage_enums;
 use error_stack::{IntoReport, ResultExt};
 use router_env::{
@@ -40,6 +42,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData, Ctx>(
     customer: &Option<domain::Customer>,
     validate_result: &operations::ValidateResult<'_>,
     schedule_time: Option<time::PrimitiveDateTime>,
+    retry_policy: Option<gsm_api::RetryPolicy>,
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
@@ -56,6 +59,8 @@ where
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
+    let first_attempt_created_at = payment_data.payment_attempt.created_at;
+
     let mut initial_gsm = get_gsm(state, &router_data).await?;
 
     //Check if step-up to threeDS is possible and merchant has enabled
@@ -79,6 +84,15 @@ where
     };
 
     if should_step_up {
+        store_retry_decision(
+            state,
+            payment_data,
+            gsm_api::RetryDecision::StepUp,
+            gsm_api::RetryReason::StepUpPossible,
+            merchant_account.storage_scheme,
+        )
+        .await;
+
         router_data = do_retry(
             &state.clone(),
             original_connector_data,
@@ -103,45 +117,13 @@ where
                 Some(gsm) => Some(gsm.clone()),
                 None => get_gsm(state, &router_data).await?,
             };
+            let error_category = gsm
+                .as_ref()
+                .and_then(|gsm| gsm.error_category.as_deref())
+                .and_then(|category| gsm_api::GsmErrorCategory::from_str(category).ok());
 
-            match get_gsm_decision(gsm) {
-                api_models::gsm::GsmDecision::Retry => {
-                    retries = get_retries(state, retries, &merchant_account.merchant_id).await;
-
-                    if retries.is_none() || retries == Some(0) {
-                        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
-                        logger::info!("retries exhausted for auto_retry payment");
-                        break;
-                    }
-
-                    if connectors.len() == 0 {
-                        logger::info!("connectors exhausted for auto_retry payment");
-                        metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
-                        break;
-                    }
-
-                    let connector = super::get_connector_data(&mut connectors)?;
-
-                    router_data = do_retry(
-                        &state.clone(),
-                        connector,
-                        operation,
-                        customer,
-                        merchant_account,
-                        key_store,
-                        payment_data,
-                        router_data,
-                        validate_result,
-                        schedule_time,
-                        //this is an auto retry payment, but not step-up
-                        false,
-                        frm_suggestion,
-                    )
-                    .await?;
-
-                    retries = retries.map(|i| i - 1);
-                }
-                api_models::gsm::GsmDecision::Requeue => {
+            let (decision, reason) = match (get_gsm_decision(gsm), retry_policy.as_ref()) {
+                (gsm_api::GsmDecision::Requeue, _) => {
                     Err(errors::ApiErrorResponse::NotImplemented {
                         message: errors::api_error_response::NotImplementedMessage::Reason(
                             "Requeue not implemented".to_string(),
@@ -149,14 +131,206 @@ where
                     })
                     .into_report()?
                 }
-                api_models::gsm::GsmDecision::DoDefault => break,
+                (gsm_decision, Some(policy)) => get_retry_decision_for_policy(
+                    policy,
+                    gsm_decision,
+                    error_category,
+                    payment_data.payment_intent.attempt_count,
+                    common_utils::date_time::now() - first_attempt_created_at,
+                ),
+                (gsm_api::GsmDecision::Retry, None) => {
+                    retries = get_retries(state, retries, &merchant_account.merchant_id).await;
+
+                    if retries.is_none() || retries == Some(0) {
+                        (
+                            gsm_api::RetryDecision::DoNotRetry,
+                            gsm_api::RetryReason::RetriesExhausted,
+                        )
+                    } else {
+                        (
+                            gsm_api::RetryDecision::Retry,
+                            gsm_api::RetryReason::GsmRetry,
+                        )
+                    }
+                }
+                (gsm_api::GsmDecision::DoDefault, None) => (
+                    gsm_api::RetryDecision::DoNotRetry,
+                    gsm_api::RetryReason::GsmDoDefault,
+                ),
+            };
+
+            let (decision, reason) =
+                if decision == gsm_api::RetryDecision::Retry && connectors.len() == 0 {
+                    (
+                        gsm_api::RetryDecision::DoNotRetry,
+                        gsm_api::RetryReason::ConnectorsExhausted,
+                    )
+                } else {
+                    (decision, reason)
+                };
+
+            store_retry_decision(
+                state,
+                payment_data,
+                decision,
+                reason,
+                merchant_account.storage_scheme,
+            )
+            .await;
+
+            if decision != gsm_api::RetryDecision::Retry {
+                if matches!(
+                    reason,
+                    gsm_api::RetryReason::RetriesExhausted
+                        | gsm_api::RetryReason::ConnectorsExhausted
+                        | gsm_api::RetryReason::MaxAttemptsReached
+                        | gsm_api::RetryReason::LatencyBudgetExceeded
+                ) {
+                    metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                }
+                logger::info!(retry_reason = %reason, "not retrying auto_retry payment");
+                break;
             }
+
+            let connector = super::get_connector_data(&mut connectors)?;
+
+            router_data = do_retry(
+                &state.clone(),
+                connector,
+                operation,
+                customer,
+                merchant_account,
+                key_store,
+                payment_data,
+                router_data,
+                validate_result,
+                schedule_time,
+                //this is an auto retry payment, but not step-up
+                false,
+                frm_suggestion,
+            )
+            .await?;
+
+            retries = retries.map(|i| i - 1);
             initial_gsm = None;
         }
     }
     Ok(router_data)
 }
 
+/// Fetches the retry policy of the business profile of the payment, falling back to the retry
+/// policy of the merchant.
+#[instrument(skip_all)]
+pub async fn get_retry_policy(
+    state: &app::AppState,
+    merchant_id: &str,
+    profile_id: Option<&String>,
+) -> Option<gsm_api::RetryPolicy> {
+    let db = &*state.store;
+    let keys = profile_id
+        .map(|profile_id| format!("retry_policy_{merchant_id}_{profile_id}"))
+        .into_iter()
+        .chain(std::iter::once(format!("retry_policy_{merchant_id}")));
+
+    for key in keys {
+        match db.find_config_by_key(key.as_str()).await {
+            Ok(config) => {
+                return config
+                    .config
+                    .parse_struct::<gsm_api::RetryPolicy>("RetryPolicy")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Retry policy parsing failed")
+                    .map_err(|err| {
+                        logger::error!(retry_policy_error=?err);
+                    })
+                    .ok();
+            }
+            Err(err) if err.current_context().is_db_not_found() => continue,
+            Err(err) => {
+                logger::error!(retry_policy_error=?err);
+                return None;
+            }
+        }
+    }
+
+    None
+}
+
+/// Decides whether a failed attempt is retried as per the retry policy. `attempt_count` is the
+/// number of attempts made for the payment so far, and `elapsed` is the time since the attempt
+/// that failed first was created.
+pub fn get_retry_decision_for_policy(
+    policy: &gsm_api::RetryPolicy,
+    gsm_decision: gsm_api::GsmDecision,
+    error_category: Option<gsm_api::GsmErrorCategory>,
+    attempt_count: i16,
+    elapsed: time::Duration,
+) -> (gsm_api::RetryDecision, gsm_api::RetryReason) {
+    let do_not_retry = |reason| (gsm_api::RetryDecision::DoNotRetry, reason);
+
+    if policy.soft_declines_only && error_category != Some(gsm_api::GsmErrorCategory::SoftDecline) {
+        return do_not_retry(gsm_api::RetryReason::NotSoftDecline);
+    }
+
+    let reason = match policy.allowed_error_categories.as_ref() {
+        Some(allowed_categories) => {
+            if !error_category.map_or(false, |category| allowed_categories.contains(&category)) {
+                return do_not_retry(gsm_api::RetryReason::ErrorCategoryNotAllowed);
+            }
+            gsm_api::RetryReason::ErrorCategoryAllowed
+        }
+        None => {
+            if gsm_decision != gsm_api::GsmDecision::Retry {
+                return do_not_retry(gsm_api::RetryReason::GsmDoDefault);
+            }
+            gsm_api::RetryReason::GsmRetry
+        }
+    };
+
+    if i32::from(attempt_count) >= i32::from(policy.max_attempts) {
+        return do_not_retry(gsm_api::RetryReason::MaxAttemptsReached);
+    }
+
+    if policy.latency_budget_ms.map_or(false, |budget| {
+        elapsed.whole_milliseconds() >= i128::from(budget)
+    }) {
+        return do_not_retry(gsm_api::RetryReason::LatencyBudgetExceeded);
+    }
+
+    (gsm_api::RetryDecision::Retry, reason)
+}
+
+/// Stores the retry decision taken for the current attempt of the payment, so that retries can
+/// be audited. The decision is only kept for auditing, so failing to store it does not fail the
+/// payment.
+#[instrument(skip_all)]
+async fn store_retry_decision<F>(
+    state: &app::AppState,
+    payment_data: &mut payments::PaymentData<F>,
+    decision: gsm_api::RetryDecision,
+    reason: gsm_api::RetryReason,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) where
+    F: Clone + Send,
+{
+    match state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::RetryDecisionUpdate {
+                retry_decision: decision.to_string(),
+                retry_reason: reason.to_string(),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_attempt) => payment_data.payment_attempt = payment_attempt,
+        Err(error) => logger::error!(?error, %decision, %reason, "Failed to store retry decision"),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn is_step_up_enabled_for_merchant_connector(
     state: &app::AppState,
@@ -559,3 +733,127 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> gsm_api::RetryPolicy {
+        gsm_api::RetryPolicy {
+            max_attempts: 3,
+            allowed_error_categories: None,
+            soft_declines_only: false,
+            latency_budget_ms: Some(10_000),
+        }
+    }
+
+    #[test]
+    fn test_retry_decision_follows_gsm_without_allowed_categories() {
+        let elapsed = time::Duration::milliseconds(100);
+
+        assert_eq!(
+            get_retry_decision_for_policy(&policy(), gsm_api::GsmDecision::Retry, None, 1, elapsed),
+            (
+                gsm_api::RetryDecision::Retry,
+                gsm_api::RetryReason::GsmRetry
+            )
+        );
+        assert_eq!(
+            get_retry_decision_for_policy(
+                &policy(),
+                gsm_api::GsmDecision::DoDefault,
+                None,
+                1,
+                elapsed
+            ),
+            (
+                gsm_api::RetryDecision::DoNotRetry,
+                gsm_api::RetryReason::GsmDoDefault
+            )
+        );
+    }
+
+    #[test]
+    fn test_retry_decision_with_error_categories() {
+        let elapsed = time::Duration::milliseconds(100);
+        let policy = gsm_api::RetryPolicy {
+            allowed_error_categories: Some(vec![gsm_api::GsmErrorCategory::ProcessorDowntime]),
+            ..policy()
+        };
+
+        assert_eq!(
+            get_retry_decision_for_policy(
+                &policy,
+                gsm_api::GsmDecision::DoDefault,
+                Some(gsm_api::GsmErrorCategory::ProcessorDowntime),
+                1,
+                elapsed
+            ),
+            (
+                gsm_api::RetryDecision::Retry,
+                gsm_api::RetryReason::ErrorCategoryAllowed
+            )
+        );
+        assert_eq!(
+            get_retry_decision_for_policy(
+                &policy,
+                gsm_api::GsmDecision::Retry,
+                Some(gsm_api::GsmErrorCategory::HardDecline),
+                1,
+                elapsed
+            ),
+            (
+                gsm_api::RetryDecision::DoNotRetry,
+                gsm_api::RetryReason::ErrorCategoryNotAllowed
+            )
+        );
+
+        let policy = gsm_api::RetryPolicy {
+            soft_declines_only: true,
+            ..policy
+        };
+        assert_eq!(
+            get_retry_decision_for_policy(
+                &policy,
+                gsm_api::GsmDecision::Retry,
+                Some(gsm_api::GsmErrorCategory::ProcessorDowntime),
+                1,
+                elapsed
+            ),
+            (
+                gsm_api::RetryDecision::DoNotRetry,
+                gsm_api::RetryReason::NotSoftDecline
+            )
+        );
+    }
+
+    #[test]
+    fn test_retry_decision_respects_limits() {
+        assert_eq!(
+            get_retry_decision_for_policy(
+                &policy(),
+                gsm_api::GsmDecision::Retry,
+                None,
+                3,
+                time::Duration::milliseconds(100)
+            ),
+            (
+                gsm_api::RetryDecision::DoNotRetry,
+                gsm_api::RetryReason::MaxAttemptsReached
+            )
+        );
+        assert_eq!(
+            get_retry_decision_for_policy(
+                &policy(),
+                gsm_api::GsmDecision::Retry,
+                None,
+                1,
+                time::Duration::seconds(11)
+            ),
+            (
+                gsm_api::RetryDecision::DoNotRetry,
+                gsm_api::RetryReason::LatencyBudgetExceeded
+            )
+        );
+    }
+}
@@ -1,3 +1,5 @@
+pub mod simulation;
+pub mod success_rate;
 mod transformers;
 
 use std::{
@@ -52,6 +54,7 @@ pub(super) enum CachedAlgorithm {
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    SuccessRate(routing_types::SuccessRateRoutingConfig),
 }
 
 pub struct SessionFlowRoutingInput<'a> {
@@ -179,6 +182,13 @@ where
             .map(api_enums::Country::from_alpha2),
         business_label: payment_data.payment_intent.business_label.clone(),
         setup_future_usage: payment_data.payment_intent.setup_future_usage,
+        transaction_time: Some(
+            payment_data
+                .payment_intent
+                .created_at
+                .assume_utc()
+                .unix_timestamp(),
+        ),
     };
 
     let metadata = payment_data
@@ -256,7 +266,14 @@ pub async fn perform_static_routing_v1<F: Clone>(
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = make_dsl_input(payment_data)?;
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            execute_dsl_and_get_connector_v1(backend_input, interpreter, None)?
+        }
+
+        CachedAlgorithm::SuccessRate(config) => {
+            let backend_input = make_dsl_input(payment_data)?;
+
+            success_rate::perform_success_rate_routing(state, merchant_id, config, &backend_input)
+                .await
         }
     })
 }
@@ -334,6 +351,7 @@ pub fn perform_straight_through_routing<F: Clone>(
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    rng_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let routing_output: routing_types::RoutingAlgorithm = interpreter
         .execute(backend_input)
@@ -344,8 +362,10 @@ fn execute_dsl_and_get_connector_v1(
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits, rng_seed)
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?
+        }
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .into_report()
@@ -407,6 +427,9 @@ pub async fn refresh_routing_cache_v1(
 
             CachedAlgorithm::Advanced(interpreter)
         }
+        routing_types::RoutingAlgorithm::SuccessRate(config) => {
+            CachedAlgorithm::SuccessRate(config)
+        }
     };
 
     ROUTING_CACHE
@@ -795,6 +818,13 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        transaction_time: Some(
+            session_input
+                .payment_intent
+                .created_at
+                .assume_utc()
+                .unix_timestamp(),
+        ),
     };
 
     let metadata = session_input
@@ -908,7 +938,17 @@ async fn perform_session_routing_for_pm_type(
                     CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                         session_pm_input.backend_input.clone(),
                         interpreter,
+                        None,
                     )?,
+                    CachedAlgorithm::SuccessRate(config) => {
+                        success_rate::perform_success_rate_routing(
+                            session_pm_input.state,
+                            merchant_id,
+                            config,
+                            &session_pm_input.backend_input,
+                        )
+                        .await
+                    }
                 }
             } else {
                 routing_helpers::get_merchant_default_config(
@@ -1031,6 +1071,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        transaction_time: Some(payment_intent.created_at.assume_utc().unix_timestamp()),
     };
     let metadata = payment_intent
         .metadata
@@ -0,0 +1,256 @@
+//! Replaying of historical payments through a candidate routing algorithm.
+//!
+//! The stored attempt and intent of every payment are converted back into the input of the
+//! routing algorithm, and the connector that the algorithm would have selected is compared with
+//! the connector that actually processed the payment.
+
+use std::collections::BTreeMap;
+
+use api_models::payments::AdditionalPaymentData;
+use error_stack::{IntoReport, ResultExt};
+use euclid::{backend, backend::inputs as dsl_inputs, enums as euclid_enums};
+
+use super::{CachedAlgorithm, RoutingResult};
+use crate::{
+    core::errors,
+    logger,
+    types::{api::routing as routing_types, storage as oss_storage},
+    utils::ValueExt,
+};
+
+/// Prepares the candidate algorithm for evaluation. Success rate based routing depends on the
+/// live success rate counters rather than on the payment, and so cannot be simulated.
+fn prepare_algorithm(algorithm: routing_types::RoutingAlgorithm) -> RoutingResult<CachedAlgorithm> {
+    Ok(match algorithm {
+        routing_types::RoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
+        routing_types::RoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            CachedAlgorithm::VolumeSplit(splits)
+        }
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            let interpreter = backend::VirInterpreterBackend::with_program(program)
+                .into_report()
+                .change_context(errors::RoutingError::DslBackendInitError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+
+            CachedAlgorithm::Advanced(interpreter)
+        }
+        routing_types::RoutingAlgorithm::SuccessRate(_) => {
+            Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
+                .into_report()
+                .attach_printable("Success rate based routing cannot be simulated")?
+        }
+    })
+}
+
+/// Builds the input of the routing algorithm from a payment that has already been processed.
+///
+/// The billing address of the payment isn't looked up, so rules on the billing country never
+/// match a replayed payment.
+pub fn make_dsl_input_for_simulation(
+    payment_attempt: &oss_storage::PaymentAttempt,
+    payment_intent: &oss_storage::PaymentIntent,
+) -> RoutingResult<dsl_inputs::BackendInput> {
+    let mut backend_input =
+        super::make_dsl_input_for_surcharge(payment_attempt, payment_intent, None)?;
+
+    let card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|value| value.parse_value::<AdditionalPaymentData>("AdditionalPaymentData"))
+        .transpose()
+        .change_context(errors::RoutingError::PaymentMethodDataParsingError)
+        .attach_printable("Unable to parse payment method data of payment_attempt")
+        .unwrap_or_else(|err| {
+            logger::error!(error=?err);
+            None
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+
+    backend_input.payment_method = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        card_network: card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_network.clone()),
+    };
+    backend_input.payment.card_bin = card_info.and_then(|card_info| card_info.card_isin);
+    backend_input.mandate = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        mandate_type: payment_attempt.mandate_details.as_ref().map(
+            |mandate_type| match mandate_type {
+                data_models::mandates::MandateDataType::SingleUse(_) => {
+                    euclid_enums::MandateType::SingleUse
+                }
+                data_models::mandates::MandateDataType::MultiUse(_) => {
+                    euclid_enums::MandateType::MultiUse
+                }
+            },
+        ),
+        payment_type: Some(payment_attempt.mandate_details.as_ref().map_or_else(
+            || euclid_enums::PaymentType::NonMandate,
+            |_| euclid_enums::PaymentType::SetupMandate,
+        )),
+    };
+
+    Ok(backend_input)
+}
+
+/// Evaluates the candidate algorithm for a replayed payment. Volume splits are seeded with the
+/// attempt ID, so that a payment is always routed to the same connector across simulations.
+fn perform_simulated_routing(
+    algorithm: &CachedAlgorithm,
+    payment_attempt: &oss_storage::PaymentAttempt,
+    payment_intent: &oss_storage::PaymentIntent,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    Ok(match algorithm {
+        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+
+        CachedAlgorithm::Priority(plist) => plist.clone(),
+
+        CachedAlgorithm::VolumeSplit(splits) => {
+            super::perform_volume_split(splits.to_vec(), Some(&payment_attempt.attempt_id))
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+        }
+
+        CachedAlgorithm::Advanced(interpreter) => {
+            let backend_input = make_dsl_input_for_simulation(payment_attempt, payment_intent)?;
+
+            super::execute_dsl_and_get_connector_v1(
+                backend_input,
+                interpreter,
+                Some(&payment_attempt.attempt_id),
+            )?
+        }
+
+        CachedAlgorithm::SuccessRate(_) => {
+            Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
+                .into_report()
+                .attach_printable("Success rate based routing cannot be simulated")?
+        }
+    })
+}
+
+/// Counts of the payments replayed through the candidate algorithm.
+#[derive(Debug, Default)]
+struct SimulationTally {
+    total_payments: usize,
+    changed_payments: usize,
+    skipped_payments: usize,
+    connectors: BTreeMap<String, routing_types::RoutingSimulationConnectorDistribution>,
+}
+
+impl SimulationTally {
+    fn record(&mut self, actual_connector: &str, simulated_connector: &str) {
+        self.total_payments += 1;
+        if actual_connector != simulated_connector {
+            self.changed_payments += 1;
+        }
+
+        self.get_distribution(actual_connector).actual_count += 1;
+        self.get_distribution(simulated_connector).simulated_count += 1;
+    }
+
+    fn record_skipped(&mut self) {
+        self.total_payments += 1;
+        self.skipped_payments += 1;
+    }
+
+    fn get_distribution(
+        &mut self,
+        connector: &str,
+    ) -> &mut routing_types::RoutingSimulationConnectorDistribution {
+        self.connectors
+            .entry(connector.to_string())
+            .or_insert_with(|| routing_types::RoutingSimulationConnectorDistribution {
+                connector: connector.to_string(),
+                ..Default::default()
+            })
+    }
+}
+
+impl From<SimulationTally> for routing_types::RoutingSimulationResponse {
+    fn from(tally: SimulationTally) -> Self {
+        Self {
+            total_payments: tally.total_payments,
+            changed_payments: tally.changed_payments,
+            skipped_payments: tally.skipped_payments,
+            connectors: tally.connectors.into_values().collect(),
+        }
+    }
+}
+
+/// Replays the payments through the candidate algorithm, and reports the distribution of the
+/// payments across connectors that the algorithm would have produced against the actual one.
+pub fn simulate_routing(
+    algorithm: routing_types::RoutingAlgorithm,
+    payments: &[(oss_storage::PaymentIntent, oss_storage::PaymentAttempt)],
+) -> RoutingResult<routing_types::RoutingSimulationResponse> {
+    let algorithm = prepare_algorithm(algorithm)?;
+    let mut tally = SimulationTally::default();
+
+    for (payment_intent, payment_attempt) in payments {
+        // Payments that were never routed to a connector have nothing to compare against
+        let Some(actual_connector) = payment_attempt.connector.as_deref() else {
+            tally.record_skipped();
+            continue;
+        };
+
+        match perform_simulated_routing(&algorithm, payment_attempt, payment_intent) {
+            Ok(connectors) => match connectors.first() {
+                Some(simulated_connector) => {
+                    tally.record(actual_connector, &simulated_connector.connector.to_string())
+                }
+                None => tally.record_skipped(),
+            },
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    payment_id = %payment_attempt.payment_id,
+                    "Failed to replay payment through the candidate routing algorithm"
+                );
+                tally.record_skipped();
+            }
+        }
+    }
+
+    Ok(tally.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulation_tally() {
+        let mut tally = SimulationTally::default();
+        tally.record("stripe", "stripe");
+        tally.record("stripe", "adyen");
+        tally.record("checkout", "adyen");
+        tally.record_skipped();
+
+        let response = routing_types::RoutingSimulationResponse::from(tally);
+        assert_eq!(response.total_payments, 4);
+        assert_eq!(response.changed_payments, 2);
+        assert_eq!(response.skipped_payments, 1);
+
+        let distribution = response
+            .connectors
+            .iter()
+            .map(|distribution| {
+                (
+                    distribution.connector.as_str(),
+                    distribution.actual_count,
+                    distribution.simulated_count,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            distribution,
+            vec![("adyen", 0, 2), ("checkout", 1, 0), ("stripe", 2, 1)]
+        );
+    }
+}
@@ -0,0 +1,386 @@
+//! Ranking of connectors by their recent authorization success rate.
+//!
+//! The outcome of every authorization is counted in Redis, in fixed size buckets of time. The
+//! success rate of a connector is computed over the buckets that make up the sliding window
+//! configured in the algorithm.
+
+use std::cmp::Ordering;
+
+use error_stack::ResultExt;
+use euclid::backend::inputs as dsl_inputs;
+use rand::Rng;
+
+use super::RoutingResult;
+use crate::{
+    core::{errors, payments as payments_oss},
+    logger,
+    types::{api::routing as routing_types, storage::enums as storage_enums},
+    AppState,
+};
+
+const SUCCESS_RATE_KEY_PREFIX: &str = "SUCCESS_RATE";
+
+/// The payments over which the success rate of a connector is computed.
+struct SuccessRateScope<'a> {
+    merchant_id: &'a str,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    card_network: Option<storage_enums::CardNetwork>,
+    currency: storage_enums::Currency,
+}
+
+impl<'a> SuccessRateScope<'a> {
+    fn new(merchant_id: &'a str, backend_input: &dsl_inputs::BackendInput) -> Self {
+        Self {
+            merchant_id,
+            payment_method: backend_input.payment_method.payment_method,
+            card_network: backend_input.payment_method.card_network.clone(),
+            currency: backend_input.payment.currency,
+        }
+    }
+
+    fn get_counter_key(&self, connector: &str, bucket: i64, counter: Counter) -> String {
+        let payment_method = self
+            .payment_method
+            .map(|payment_method| payment_method.to_string())
+            .unwrap_or_else(|| "none".to_string());
+        let card_network = self
+            .card_network
+            .as_ref()
+            .map(|card_network| card_network.to_string())
+            .unwrap_or_else(|| "none".to_string());
+
+        format!(
+            "{}_{}_{}_{}_{}_{}_{}_{}",
+            SUCCESS_RATE_KEY_PREFIX,
+            self.merchant_id,
+            connector,
+            payment_method,
+            card_network,
+            self.currency,
+            bucket,
+            counter,
+        )
+    }
+}
+
+#[derive(Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum Counter {
+    Total,
+    Success,
+}
+
+/// Number of authorizations processed by a connector in the window.
+#[derive(Clone, Copy, Debug, Default)]
+struct WindowCounts {
+    total: u64,
+    success: u64,
+}
+
+impl WindowCounts {
+    fn has_min_volume(&self, min_volume: u32) -> bool {
+        self.total > 0 && self.total >= u64::from(min_volume)
+    }
+
+    /// Compares the success rates without dividing, by cross multiplying the counts.
+    fn cmp_success_rate(&self, other: &Self) -> Ordering {
+        (u128::from(self.success) * u128::from(other.total))
+            .cmp(&(u128::from(other.success) * u128::from(self.total)))
+    }
+}
+
+/// Identifies a connector in the counters, in the same way as
+/// [`routing_types::RoutableConnectorChoice`] does.
+fn get_connector_identifier(connector: &str, label: Option<&str>) -> String {
+    match label {
+        Some(label) => format!("{connector}_{label}"),
+        None => connector.to_string(),
+    }
+}
+
+fn get_current_bucket() -> i64 {
+    common_utils::date_time::now_unix_timestamp()
+        / i64::from(routing_types::SuccessRateRoutingConfig::BUCKET_SIZE_IN_SECONDS)
+}
+
+/// Orders the connectors by their success rate, with connectors that haven't processed enough
+/// payments in the window placed last, in the order in which they were configured.
+pub async fn perform_success_rate_routing(
+    state: &AppState,
+    merchant_id: &str,
+    config: &routing_types::SuccessRateRoutingConfig,
+    backend_input: &dsl_inputs::BackendInput,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let scope = SuccessRateScope::new(merchant_id, backend_input);
+
+    let window_counts = match fetch_window_counts(state, &scope, config).await {
+        Ok(window_counts) => window_counts,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch success rates of connectors, falling back to the configured order"
+            );
+            return config.connectors.clone();
+        }
+    };
+
+    let mut connectors =
+        rank_by_success_rate(&config.connectors, &window_counts, config.min_volume);
+
+    let mut rng = rand::thread_rng();
+    if connectors.len() > 1 && rng.gen_range(0..100) < config.exploration_percent {
+        let explored_connector = connectors.remove(rng.gen_range(0..connectors.len()));
+        connectors.insert(0, explored_connector);
+    }
+
+    connectors
+}
+
+async fn fetch_window_counts(
+    state: &AppState,
+    scope: &SuccessRateScope<'_>,
+    config: &routing_types::SuccessRateRoutingConfig,
+) -> RoutingResult<Vec<WindowCounts>> {
+    let current_bucket = get_current_bucket();
+    let bucket_size = routing_types::SuccessRateRoutingConfig::BUCKET_SIZE_IN_SECONDS;
+    let window_buckets = i64::from((config.window_in_seconds + bucket_size - 1) / bucket_size);
+
+    let keys = config
+        .connectors
+        .iter()
+        .flat_map(|choice| {
+            let connector = get_connector_identifier(
+                &choice.connector.to_string(),
+                #[cfg(feature = "connector_choice_mca_id")]
+                None,
+                #[cfg(not(feature = "connector_choice_mca_id"))]
+                choice.sub_label.as_deref(),
+            );
+
+            ((current_bucket - window_buckets + 1)..=current_bucket).flat_map(move |bucket| {
+                [
+                    scope.get_counter_key(&connector, bucket, Counter::Total),
+                    scope.get_counter_key(&connector, bucket, Counter::Success),
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let values = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::SuccessRateFetchFailed)
+        .attach_printable("Failed to get redis connection")?
+        .get_multiple_keys::<_, i64>(keys)
+        .await
+        .change_context(errors::RoutingError::SuccessRateFetchFailed)
+        .attach_printable("Failed to fetch success rate counters from redis")?;
+
+    // The values are ordered by connector, and then by bucket with the total and success counters
+    // of each bucket next to each other
+    Ok(values
+        .chunks(
+            usize::try_from(window_buckets * 2)
+                .unwrap_or_default()
+                .max(1),
+        )
+        .map(|connector_values| {
+            connector_values
+                .chunks(2)
+                .fold(WindowCounts::default(), |counts, bucket_values| {
+                    let get_value = |index: usize| {
+                        bucket_values
+                            .get(index)
+                            .copied()
+                            .flatten()
+                            .and_then(|value| u64::try_from(value).ok())
+                            .unwrap_or_default()
+                    };
+
+                    WindowCounts {
+                        total: counts.total + get_value(0),
+                        success: counts.success + get_value(1),
+                    }
+                })
+        })
+        .collect())
+}
+
+fn rank_by_success_rate<T: Clone>(
+    connectors: &[T],
+    window_counts: &[WindowCounts],
+    min_volume: u32,
+) -> Vec<T> {
+    let mut ranked_connectors = connectors
+        .iter()
+        .enumerate()
+        .map(|(index, connector)| {
+            let counts = window_counts
+                .get(index)
+                .filter(|counts| counts.has_min_volume(min_volume));
+
+            (counts, connector)
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, so connectors with equal success rates retain the configured order
+    ranked_connectors.sort_by(|(first, _), (second, _)| match (first, second) {
+        (Some(first), Some(second)) => second.cmp_success_rate(first),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+
+    ranked_connectors
+        .into_iter()
+        .map(|(_, connector)| connector.clone())
+        .collect()
+}
+
+/// Whether the authorization of an attempt in `status` succeeded, `None` while the outcome of the
+/// authorization is not known yet.
+fn get_authorization_outcome(status: storage_enums::AttemptStatus) -> Option<bool> {
+    match status {
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable => Some(true),
+        storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::Failure => Some(false),
+        _ => None,
+    }
+}
+
+/// Records the outcome of the authorization of a payment attempt in the success rate counters of
+/// the connector, once the attempt moves from `previous_status` to a terminal status. Attempts
+/// whose outcome was already known before the update are not counted again.
+pub async fn update_success_rate_window<F: Clone>(
+    state: &AppState,
+    previous_status: storage_enums::AttemptStatus,
+    payment_data: &payments_oss::PaymentData<F>,
+) -> RoutingResult<()> {
+    let payment_attempt = &payment_data.payment_attempt;
+
+    if get_authorization_outcome(previous_status).is_some() {
+        return Ok(());
+    }
+    let Some(is_authorized) = get_authorization_outcome(payment_attempt.status) else {
+        return Ok(());
+    };
+
+    let Some(connector) = payment_attempt.connector.as_deref() else {
+        return Ok(());
+    };
+
+    let backend_input = super::make_dsl_input(payment_data)?;
+    let scope = SuccessRateScope::new(&payment_attempt.merchant_id, &backend_input);
+    let connector = get_connector_identifier(
+        connector,
+        #[cfg(feature = "connector_choice_mca_id")]
+        None,
+        #[cfg(not(feature = "connector_choice_mca_id"))]
+        payment_attempt.business_sub_label.as_deref(),
+    );
+    let current_bucket = get_current_bucket();
+
+    let counters = if is_authorized {
+        vec![Counter::Total, Counter::Success]
+    } else {
+        vec![Counter::Total]
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::RoutingError::SuccessRateUpdateFailed)
+        .attach_printable("Failed to get redis connection")?;
+
+    for counter in counters {
+        let key = scope.get_counter_key(&connector, current_bucket, counter);
+
+        let count = redis_conn
+            .increment_key(&key)
+            .await
+            .change_context(errors::RoutingError::SuccessRateUpdateFailed)
+            .attach_printable("Failed to increment success rate counter")?;
+
+        if count == 1 {
+            redis_conn
+                .set_expiry(
+                    &key,
+                    i64::from(
+                        routing_types::SuccessRateRoutingConfig::MAX_WINDOW_IN_SECONDS
+                            + routing_types::SuccessRateRoutingConfig::BUCKET_SIZE_IN_SECONDS,
+                    ),
+                )
+                .await
+                .change_context(errors::RoutingError::SuccessRateUpdateFailed)
+                .attach_printable("Failed to set expiry for success rate counter")?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_by_success_rate() {
+        let connectors = ["stripe", "adyen", "checkout", "paypal"];
+        let window_counts = [
+            WindowCounts {
+                total: 100,
+                success: 70,
+            },
+            WindowCounts {
+                total: 100,
+                success: 90,
+            },
+            // Not enough payments for the success rate to be considered
+            WindowCounts {
+                total: 5,
+                success: 5,
+            },
+            WindowCounts {
+                total: 50,
+                success: 45,
+            },
+        ];
+
+        assert_eq!(
+            rank_by_success_rate(&connectors, &window_counts, 10),
+            vec!["adyen", "paypal", "stripe", "checkout"]
+        );
+        assert_eq!(
+            rank_by_success_rate(&connectors, &[], 10),
+            connectors.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_get_authorization_outcome() {
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Charged),
+            Some(true)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Authorized),
+            Some(true)
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Failure),
+            Some(false)
+        );
+        // Attempts pending at the connector are counted once a sync or webhook settles them
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::Pending),
+            None
+        );
+        assert_eq!(
+            get_authorization_outcome(storage_enums::AttemptStatus::AuthenticationPending),
+            None
+        );
+    }
+}
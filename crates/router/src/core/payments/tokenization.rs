@@ -106,7 +106,10 @@ where
 
                 if is_duplicate {
                     let existing_pm = db
-                        .find_payment_method(&locker_response.0.payment_method_id)
+                        .find_payment_method(
+                            &locker_response.0.payment_method_id,
+                            merchant_account.storage_scheme,
+                        )
                         .await;
                     match existing_pm {
                         Ok(pm) => {
@@ -115,10 +118,15 @@ where
                                 connector_token,
                             )?;
                             if let Some(metadata) = pm_metadata {
-                                payment_methods::cards::update_payment_method(db, pm, metadata)
-                                    .await
-                                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                                    .attach_printable("Failed to add payment method in db")?;
+                                payment_methods::cards::update_payment_method(
+                                    db,
+                                    pm,
+                                    metadata,
+                                    merchant_account.storage_scheme,
+                                )
+                                .await
+                                .change_context(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable("Failed to add payment method in db")?;
                             };
                         }
                         Err(error) => {
@@ -138,6 +146,7 @@ where
                                             pm_metadata,
                                             pm_data_encrypted,
                                             key_store,
+                                            merchant_account.storage_scheme,
                                         )
                                         .await
                                     }
@@ -164,6 +173,7 @@ where
                         pm_metadata,
                         pm_data_encrypted,
                         key_store,
+                        merchant_account.storage_scheme,
                     )
                     .await?;
                 };
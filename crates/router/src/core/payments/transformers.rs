@@ -619,6 +619,7 @@ where
                                             start_date: i.start_date,
                                             end_date: i.end_date,
                                             metadata: i.metadata,
+                                            max_transaction_amount: i.max_transaction_amount,
                                         }))
                                     }
                                     data_models::mandates::MandateDataType::SingleUse(i) => {
@@ -629,6 +630,7 @@ where
                                                 start_date: i.start_date,
                                                 end_date: i.end_date,
                                                 metadata: i.metadata,
+                                                max_transaction_amount: i.max_transaction_amount,
                                             },
                                         )
                                     }
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod helpers;
 pub mod validator;
 
@@ -5,7 +6,8 @@ use api_models::enums as api_enums;
 use common_utils::{crypto::Encryptable, ext_traits::ValueExt};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, errors as sch_errors, utils as pt_utils};
 use serde_json;
 
 use super::errors::{ConnectorErrorExt, StorageErrorExt};
@@ -15,14 +17,17 @@ use crate::{
         payments::{self, helpers as payment_helpers},
         utils as core_utils,
     },
+    db::StorageInterface,
     routes::AppState,
     services,
     types::{
         self,
         api::{self, payouts},
         domain, storage,
+        transformers::ForeignFrom,
     },
     utils::{self, OptionExt},
+    workflows,
 };
 
 // ********************************************** TYPES **********************************************
@@ -254,7 +259,7 @@ pub async fn payouts_retrieve_core(
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutRetrieveRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
-    let payout_data = make_payout_data(
+    let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
@@ -262,6 +267,33 @@ pub async fn payouts_retrieve_core(
     )
     .await?;
 
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    if req.force_sync.unwrap_or(false)
+        && helpers::is_eligible_for_payout_sync(payout_attempt.status)
+        && helpers::is_payout_sync_supported(&payout_attempt.connector)
+        && !payout_attempt.connector_payout_id.is_empty()
+    {
+        // Form connector data
+        let connector_data = get_connector_data(
+            &state,
+            &merchant_account,
+            Some(payout_attempt.connector),
+            None,
+        )
+        .await?;
+
+        payout_data = sync_payout(
+            &state,
+            &merchant_account,
+            &key_store,
+            &payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned()),
+            &connector_data,
+            &mut payout_data,
+        )
+        .await
+        .attach_printable("Payout sync failed for given Payout request")?;
+    }
+
     response_handler(
         &state,
         &merchant_account,
@@ -320,6 +352,7 @@ pub async fn payouts_cancel_core(
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error updating payout_attempt in db")?;
+        trigger_payout_status_update_tasks(&state, &merchant_account, &payout_data).await;
 
     // Trigger connector's cancellation
     } else {
@@ -436,6 +469,87 @@ pub async fn payouts_fulfill_core(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: payouts::PayoutListConstraints,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    validator::validate_payout_list_request(&constraints)?;
+    let db = &*state.store;
+    let payouts = db
+        .filter_payouts_and_attempts(&merchant_account.merchant_id, &constraints.into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let data: Vec<payouts::PayoutCreateResponse> =
+        payouts.into_iter().map(ForeignFrom::foreign_from).collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_filtered_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    filters: payouts::PayoutListFilterConstraints,
+) -> RouterResponse<payouts::PayoutListResponseV2> {
+    validator::validate_payout_list_request_for_joins(filters.limit)?;
+    let db = &*state.store;
+    let fetch_constraints: storage::PayoutFetchConstraints = filters.clone().into();
+    let list = db
+        .filter_payouts_and_attempts(&merchant_account.merchant_id, &fetch_constraints)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let data: Vec<payouts::PayoutCreateResponse> =
+        list.into_iter().map(ForeignFrom::foreign_from).collect();
+
+    let active_payout_ids = data
+        .iter()
+        .map(|payout| payout.payout_id.to_owned())
+        .collect::<Vec<String>>();
+
+    let total_count = db
+        .get_total_count_of_filtered_payouts(&merchant_account.merchant_id, &fetch_constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to fetch total count of filtered payouts for {active_payout_ids:?}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponseV2 {
+            count: data.len(),
+            total_count,
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_list_available_filters_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    time_range: api_models::payments::TimeRange,
+) -> RouterResponse<payouts::PayoutListFilters> {
+    let db = &*state.store;
+    let filters = db
+        .filter_payouts_by_meta_constraints(&merchant_account.merchant_id, &time_range)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(filters))
+}
+
 // ********************************************** HELPERS **********************************************
 #[cfg(feature = "payouts")]
 pub async fn call_connector_payout(
@@ -619,6 +733,7 @@ pub async fn create_recipient(
                             merchant_id,
                             updated_customer,
                             key_store,
+                            merchant_account.storage_scheme,
                         )
                         .await
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -701,6 +816,7 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -728,6 +844,7 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
         }
     };
 
@@ -807,6 +924,7 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -834,6 +952,7 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
         }
     };
 
@@ -905,7 +1024,8 @@ pub async fn cancel_payout(
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error updating payout_attempt in db")?
+                .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
         }
         Err(err) => {
             let updated_payouts_create =
@@ -925,7 +1045,8 @@ pub async fn cancel_payout(
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error updating payout_attempt in db")?
+                .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
         }
     };
 
@@ -1011,6 +1132,7 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1036,7 +1158,92 @@ pub async fn fulfill_payout(
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Error updating payout_attempt in db")?
+                .attach_printable("Error updating payout_attempt in db")?;
+            trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
+        }
+    };
+
+    Ok(payout_data.clone())
+}
+
+#[cfg(feature = "payouts")]
+pub async fn sync_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutRequest,
+    connector_data: &api::PayoutConnectorData,
+    payout_data: &mut PayoutData,
+) -> RouterResult<PayoutData> {
+    // 1. Form Router data
+    let router_data = core_utils::construct_payout_router_data(
+        state,
+        &connector_data.connector_name.to_string(),
+        merchant_account,
+        key_store,
+        req,
+        payout_data,
+    )
+    .await?;
+
+    // 2. Fetch connector integration details
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PoSync,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // 3. Call connector service
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payout_failed_response()?;
+
+    // 4. Process data returned by the connector
+    match router_data_resp.response {
+        Ok(payout_response_data) => {
+            let payout_attempt = &payout_data.payout_attempt;
+            let status = payout_response_data
+                .status
+                .unwrap_or(payout_attempt.status.to_owned());
+            if status != payout_attempt.status {
+                let updated_payout_attempt =
+                    storage::payout_attempt::PayoutAttemptUpdate::StatusUpdate {
+                        connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+                        status,
+                        error_code: None,
+                        error_message: None,
+                        is_eligible: payout_attempt.is_eligible,
+                        last_modified_at: Some(common_utils::date_time::now()),
+                    };
+                payout_data.payout_attempt = state
+                    .store
+                    .update_payout_attempt_by_merchant_id_payout_id(
+                        &merchant_account.merchant_id,
+                        &payout_attempt.payout_id.to_owned(),
+                        updated_payout_attempt,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Error updating payout_attempt in db")?;
+                trigger_payout_status_update_tasks(state, merchant_account, payout_data).await;
+            }
+        }
+        // A failed status lookup says nothing about the payout itself, so the last known status
+        // is retained and the sync is retried later
+        Err(err) => {
+            logger::warn!(
+                payout_id = %payout_data.payout_attempt.payout_id,
+                error_code = %err.code,
+                error_message = %err.message,
+                "Failed to sync payout status with the connector"
+            );
         }
     };
 
@@ -1050,6 +1257,17 @@ pub async fn response_handler(
     _req: &payouts::PayoutRequest,
     payout_data: &PayoutData,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    Ok(services::ApplicationResponse::Json(make_payout_response(
+        merchant_account,
+        payout_data,
+    )))
+}
+
+#[cfg(feature = "payouts")]
+pub fn make_payout_response(
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) -> payouts::PayoutCreateResponse {
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let payouts = payout_data.payouts.to_owned();
     let billing_address = payout_data.billing_address.to_owned();
@@ -1083,7 +1301,7 @@ pub async fn response_handler(
         }
     });
 
-    let response = api::PayoutCreateResponse {
+    api::PayoutCreateResponse {
         payout_id: payouts.payout_id.to_owned(),
         merchant_id: merchant_account.merchant_id.to_owned(),
         amount: payouts.amount.to_owned(),
@@ -1109,8 +1327,81 @@ pub async fn response_handler(
         error_message: payout_attempt.error_message.to_owned(),
         error_code: payout_attempt.error_code,
         profile_id: payout_attempt.profile_id,
+    }
+}
+
+/// Notifies the merchant of the latest payout status and, while the connector is still processing
+/// the payout, schedules a status sync with connectors which can be polled for it.
+#[cfg(feature = "payouts")]
+async fn trigger_payout_status_update_tasks(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) {
+    utils::trigger_payouts_webhook(state, merchant_account, payout_data)
+        .await
+        .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+        .ok();
+
+    let payout_attempt = &payout_data.payout_attempt;
+    if payout_attempt.status == storage_enums::PayoutStatus::Pending
+        && helpers::is_payout_sync_supported(&payout_attempt.connector)
+    {
+        let db = &*state.store;
+        let schedule_time = workflows::payment_sync::get_sync_process_schedule_time(
+            db,
+            &payout_attempt.connector,
+            &payout_attempt.merchant_id,
+            0,
+        )
+        .await
+        .map_err(|error| logger::warn!(payouts_sync_schedule_time_error=?error))
+        .ok()
+        .flatten();
+
+        if let Some(schedule_time) = schedule_time {
+            add_payout_sync_task(db, payout_attempt, schedule_time)
+                .await
+                .map_err(|error| logger::warn!(payouts_sync_task_error=?error))
+                .ok();
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+pub async fn add_payout_sync_task(
+    db: &dyn StorageInterface,
+    payout_attempt: &storage::PayoutAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_attempt.payout_id.to_owned(),
+        force_sync: Some(true),
+        merchant_id: Some(payout_attempt.merchant_id.to_owned()),
     };
-    Ok(services::ApplicationResponse::Json(response))
+    let runner = "PAYOUTS_SYNC_WORKFLOW";
+    let task = "PAYOUTS_SYNC";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_attempt.payout_attempt_id,
+        &payout_attempt.merchant_id,
+    );
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The payout may move to pending more than once (e.g. on creation and again on
+        // fulfillment), in which case the already scheduled sync task is reused
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.into()),
+    }
 }
 
 // DB entries
@@ -1306,6 +1597,7 @@ pub async fn make_payout_data(
             &payouts.customer_id.to_owned(),
             merchant_id,
             key_store,
+            merchant_account.storage_scheme,
         )
         .await
         .map_or(None, |c| c);
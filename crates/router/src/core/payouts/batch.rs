@@ -0,0 +1,674 @@
+use api_models::{enums as api_enums, payments};
+use common_utils::{errors::ErrorSwitch, ext_traits::ValueExt};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, IntoReport, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, errors as sch_errors, utils as pt_utils};
+
+use super::validator;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files,
+    },
+    routes::AppState,
+    services,
+    types::{api, api::payouts, domain, storage},
+    utils::{self, OptionExt},
+};
+
+/// Number of pending items of a batch which are loaded at once by the batch workflow
+const PAYOUT_BATCH_ITEM_FETCH_LIMIT: i64 = 1000;
+
+/// A single row of a payout batch CSV file
+#[derive(Debug, serde::Deserialize)]
+struct PayoutBatchCsvRecord {
+    payout_id: Option<String>,
+    amount: Option<i64>,
+    currency: Option<api_enums::Currency>,
+    customer_id: Option<String>,
+    email: Option<common_utils::pii::Email>,
+    name: Option<Secret<String>>,
+    phone: Option<Secret<String>>,
+    phone_country_code: Option<String>,
+    payout_type: Option<api_enums::PayoutType>,
+    payout_token: Option<String>,
+    connector: Option<api_enums::Connector>,
+    description: Option<String>,
+    entity_type: Option<api_enums::PayoutEntityType>,
+    recurring: Option<bool>,
+    auto_fulfill: Option<bool>,
+    confirm: Option<bool>,
+    business_country: Option<api_enums::CountryAlpha2>,
+    business_label: Option<String>,
+    profile_id: Option<String>,
+    return_url: Option<String>,
+    card_number: Option<cards::CardNumber>,
+    expiry_month: Option<Secret<String>>,
+    expiry_year: Option<Secret<String>>,
+    card_holder_name: Option<Secret<String>>,
+    bank_name: Option<String>,
+    bank_country_code: Option<api_enums::CountryAlpha2>,
+    bank_city: Option<String>,
+    bank_account_number: Option<Secret<String>>,
+    bank_routing_number: Option<Secret<String>>,
+    bank_sort_code: Option<Secret<String>>,
+    iban: Option<Secret<String>>,
+    bic: Option<Secret<String>>,
+    billing_line1: Option<Secret<String>>,
+    billing_line2: Option<Secret<String>>,
+    billing_city: Option<String>,
+    billing_state: Option<Secret<String>>,
+    billing_zip: Option<Secret<String>>,
+    billing_country: Option<api_enums::CountryAlpha2>,
+    billing_first_name: Option<Secret<String>>,
+    billing_last_name: Option<Secret<String>>,
+}
+
+/// Returns the value of a column which is required by the payout method of the row
+fn get_required_column<T>(
+    value: Option<T>,
+    column: &str,
+    payout_method: &str,
+) -> Result<T, String> {
+    value.ok_or_else(|| format!("{column} is required for a {payout_method} payout"))
+}
+
+impl PayoutBatchCsvRecord {
+    fn get_payout_method_data(&mut self) -> Result<Option<payouts::PayoutMethodData>, String> {
+        if let Some(card_number) = self.card_number.take() {
+            return Ok(Some(payouts::PayoutMethodData::Card(payouts::Card {
+                card_number,
+                expiry_month: get_required_column(
+                    self.expiry_month.take(),
+                    "expiry_month",
+                    "card",
+                )?,
+                expiry_year: get_required_column(self.expiry_year.take(), "expiry_year", "card")?,
+                card_holder_name: self.card_holder_name.take(),
+            })));
+        }
+        let bank = if let Some(iban) = self.iban.take() {
+            payouts::Bank::Sepa(payouts::SepaBankTransfer {
+                bank_name: self.bank_name.take(),
+                bank_country_code: self.bank_country_code,
+                bank_city: self.bank_city.take(),
+                iban,
+                bic: self.bic.take(),
+            })
+        } else if let Some(bank_sort_code) = self.bank_sort_code.take() {
+            payouts::Bank::Bacs(payouts::BacsBankTransfer {
+                bank_name: self.bank_name.take(),
+                bank_country_code: self.bank_country_code,
+                bank_city: self.bank_city.take(),
+                bank_account_number: get_required_column(
+                    self.bank_account_number.take(),
+                    "bank_account_number",
+                    "bacs",
+                )?,
+                bank_sort_code,
+            })
+        } else if let Some(bank_routing_number) = self.bank_routing_number.take() {
+            payouts::Bank::Ach(payouts::AchBankTransfer {
+                bank_name: self.bank_name.take(),
+                bank_country_code: self.bank_country_code,
+                bank_city: self.bank_city.take(),
+                bank_account_number: get_required_column(
+                    self.bank_account_number.take(),
+                    "bank_account_number",
+                    "ach",
+                )?,
+                bank_routing_number,
+            })
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(payouts::PayoutMethodData::Bank(bank)))
+    }
+}
+
+impl TryFrom<PayoutBatchCsvRecord> for payouts::PayoutCreateRequest {
+    type Error = String;
+
+    fn try_from(mut record: PayoutBatchCsvRecord) -> Result<Self, Self::Error> {
+        let payout_method_data = record.get_payout_method_data()?;
+        let billing = payments::Address {
+            address: Some(payments::AddressDetails {
+                city: record.billing_city,
+                country: record.billing_country,
+                line1: record.billing_line1,
+                line2: record.billing_line2,
+                line3: None,
+                zip: record.billing_zip,
+                state: record.billing_state,
+                first_name: record.billing_first_name,
+                last_name: record.billing_last_name,
+            }),
+            phone: None,
+        };
+        Ok(Self {
+            payout_id: record.payout_id,
+            amount: record.amount.map(payments::Amount::from),
+            currency: record.currency,
+            connector: record.connector.map(|connector| vec![connector]),
+            confirm: record.confirm,
+            payout_type: record.payout_type,
+            payout_method_data,
+            billing: Some(billing),
+            customer_id: record.customer_id,
+            auto_fulfill: record.auto_fulfill,
+            email: record.email,
+            name: record.name,
+            phone: record.phone,
+            phone_country_code: record.phone_country_code,
+            return_url: record.return_url,
+            business_country: record.business_country,
+            business_label: record.business_label,
+            description: record.description,
+            entity_type: record.entity_type,
+            recurring: record.recurring,
+            payout_token: record.payout_token,
+            profile_id: record.profile_id,
+            ..Default::default()
+        })
+    }
+}
+
+fn parse_payout_batch_file(file: &[u8]) -> RouterResult<Vec<payouts::PayoutCreateRequest>> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(file)
+        .deserialize::<PayoutBatchCsvRecord>()
+        .enumerate()
+        .map(|(index, record)| {
+            record
+                .map_err(|error| error.to_string())
+                .and_then(payouts::PayoutCreateRequest::try_from)
+                .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                    // The header is the first line of the file
+                    message: format!(
+                        "failed to parse line {} of the payout batch file: {error}",
+                        index + 2
+                    ),
+                })
+                .into_report()
+        })
+        .collect()
+}
+
+/// The request is stored as JSON so that it can be processed by `payouts_create_core` later
+fn encode_payout_batch_item(
+    item: &payouts::PayoutCreateRequest,
+) -> RouterResult<serde_json::Value> {
+    serde_json::to_value(item)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize payout batch item")
+}
+
+pub fn get_payout_batch_status(
+    total_count: i32,
+    succeeded_count: i32,
+    failed_count: i32,
+) -> storage_enums::PayoutBatchStatus {
+    if succeeded_count + failed_count < total_count {
+        storage_enums::PayoutBatchStatus::Processing
+    } else if failed_count == 0 {
+        storage_enums::PayoutBatchStatus::Completed
+    } else if succeeded_count == 0 {
+        storage_enums::PayoutBatchStatus::Failed
+    } else {
+        storage_enums::PayoutBatchStatus::PartiallyCompleted
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn payouts_batch_create_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBatchCreateRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    let mut items = match (req.payouts, req.file_id.as_ref()) {
+        (Some(payouts), None) => payouts,
+        (None, Some(file_id)) => {
+            let file = files::helpers::retrieve_file_for_purpose(
+                &state,
+                &merchant_account,
+                file_id,
+                api::FilePurpose::PayoutBatch,
+            )
+            .await?;
+            parse_payout_batch_file(&file)?
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "exactly one of payouts or file_id should be passed".to_string(),
+        }))?,
+    };
+
+    let payout_ids = validator::validate_batch_create_request(merchant_id, &items)?;
+    validator::validate_uniqueness_of_batch_payout_ids(db, merchant_id, &payout_ids).await?;
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "payout_batch");
+    let key = key_store.key.get_inner().peek();
+    let now = common_utils::date_time::now();
+    let mut batch_items = Vec::with_capacity(items.len());
+    for (index, (item, payout_id)) in items.iter_mut().zip(payout_ids).enumerate() {
+        item.payout_id = Some(payout_id);
+        if item.profile_id.is_none() {
+            item.profile_id = req.profile_id.clone();
+        }
+        let request = domain::types::encrypt(
+            Secret::<_, masking::WithType>::new(encode_payout_batch_item(item)?),
+            key,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt payout batch item")?;
+        batch_items.push(storage::PayoutBatchItemNew {
+            batch_id: batch_id.clone(),
+            item_index: i32::try_from(index)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+            merchant_id: merchant_id.to_owned(),
+            payout_id: item.payout_id.clone(),
+            status: storage_enums::PayoutBatchItemStatus::Pending,
+            payout_status: None,
+            request: request.into(),
+            error_code: None,
+            error_message: None,
+            created_at: now,
+            last_modified_at: now,
+        });
+    }
+
+    let total_count = i32::try_from(batch_items.len())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let process_tracker_entry = make_payout_batch_task(&batch_id, merchant_id, now)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct payout batch task")?;
+    let batch = db
+        .insert_payout_batch_with_items(
+            storage::PayoutBatchNew {
+                batch_id: batch_id.clone(),
+                merchant_id: merchant_id.to_owned(),
+                profile_id: req.profile_id,
+                status: storage_enums::PayoutBatchStatus::Pending,
+                total_count,
+                succeeded_count: 0,
+                failed_count: 0,
+                file_id: req.file_id,
+                created_at: now,
+                last_modified_at: now,
+            },
+            batch_items,
+            process_tracker_entry,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    let items = db
+        .find_payout_batch_items_by_merchant_id_batch_id(merchant_id, &batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?;
+
+    Ok(services::ApplicationResponse::Json(
+        make_payout_batch_response(batch, items),
+    ))
+}
+
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn payouts_batch_retrieve_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutBatchRetrieveRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let batch = db
+        .find_payout_batch_by_merchant_id_batch_id(merchant_id, &req.batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutBatchNotFound)?;
+    let items = db
+        .find_payout_batch_items_by_merchant_id_batch_id(merchant_id, &req.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?;
+
+    Ok(services::ApplicationResponse::Json(
+        make_payout_batch_response(batch, items),
+    ))
+}
+
+#[cfg(feature = "payouts")]
+pub fn make_payout_batch_response(
+    batch: storage::PayoutBatch,
+    items: Vec<storage::PayoutBatchItem>,
+) -> payouts::PayoutBatchResponse {
+    payouts::PayoutBatchResponse {
+        batch_id: batch.batch_id,
+        merchant_id: batch.merchant_id,
+        profile_id: batch.profile_id,
+        status: batch.status,
+        total_count: batch.total_count,
+        succeeded_count: batch.succeeded_count,
+        failed_count: batch.failed_count,
+        file_id: batch.file_id,
+        created_at: batch.created_at,
+        items: items
+            .into_iter()
+            .map(|item| payouts::PayoutBatchItemResponse {
+                item_index: item.item_index,
+                payout_id: item.payout_id,
+                status: item.status,
+                payout_status: item.payout_status,
+                error_code: item.error_code,
+                error_message: item.error_message,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(feature = "payouts")]
+pub fn make_payout_batch_task(
+    batch_id: &str,
+    merchant_id: &str,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<storage::ProcessTrackerNew, sch_errors::ProcessTrackerError> {
+    let tracking_data = storage::PayoutBatchWorkflow {
+        batch_id: batch_id.to_owned(),
+        merchant_id: merchant_id.to_owned(),
+    };
+    let runner = "PAYOUT_BATCH_WORKFLOW";
+    let task = "PAYOUT_BATCH";
+    let process_tracker_id = pt_utils::get_process_tracker_id(runner, task, batch_id, merchant_id);
+    <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        schedule_time,
+    )
+}
+
+/// Creates the payouts of all the pending items of a batch, at most `batch_item_concurrency` at
+/// a time, and updates the aggregate status of the batch once every item has been processed
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn process_payout_batch(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let concurrency = state.conf.payouts.batch_item_concurrency.max(1);
+
+    db.update_payout_batch_by_merchant_id_batch_id(
+        merchant_id,
+        &batch.batch_id,
+        storage::PayoutBatchUpdate::StatusUpdate {
+            status: storage_enums::PayoutBatchStatus::Processing,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch status")?;
+
+    loop {
+        // Processed items are no longer pending, so every iteration picks up the next set
+        let pending_items = db
+            .find_payout_batch_items_by_merchant_id_batch_id_status(
+                merchant_id,
+                &batch.batch_id,
+                storage_enums::PayoutBatchItemStatus::Pending,
+                PAYOUT_BATCH_ITEM_FETCH_LIMIT,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch pending payout batch items")?;
+        if pending_items.is_empty() {
+            break;
+        }
+
+        futures::stream::iter(pending_items)
+            .map(|item| async move {
+                let item_update =
+                    process_payout_batch_item(state, merchant_account, key_store, &item).await;
+                db.update_payout_batch_item(&item.batch_id, item.item_index, item_update)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update payout batch item")
+            })
+            .buffer_unordered(concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+    }
+
+    let items = db
+        .find_payout_batch_items_by_merchant_id_batch_id(merchant_id, &batch.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?;
+    let count_items = |status| {
+        i32::try_from(items.iter().filter(|item| item.status == status).count())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    };
+    let succeeded_count = count_items(storage_enums::PayoutBatchItemStatus::Succeeded)?;
+    let failed_count = count_items(storage_enums::PayoutBatchItemStatus::Failed)?;
+
+    db.update_payout_batch_by_merchant_id_batch_id(
+        merchant_id,
+        &batch.batch_id,
+        storage::PayoutBatchUpdate::ResultUpdate {
+            status: get_payout_batch_status(batch.total_count, succeeded_count, failed_count),
+            succeeded_count,
+            failed_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update payout batch result")
+}
+
+/// Creates the payout of a single batch item. Errors are recorded against the item instead of
+/// being returned, so that one bad item does not hold back the rest of the batch
+#[cfg(feature = "payouts")]
+async fn process_payout_batch_item(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    item: &storage::PayoutBatchItem,
+) -> storage::PayoutBatchItemUpdate {
+    let result: RouterResult<payouts::PayoutCreateResponse> = async {
+        let request = domain::types::decrypt::<serde_json::Value, masking::WithType>(
+            Some(item.request.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt payout batch item")?
+        .get_required_value("request")?
+        .into_inner()
+        .expose()
+        .parse_value::<payouts::PayoutCreateRequest>("PayoutCreateRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        match super::payouts_create_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            request,
+        )
+        .await?
+        {
+            services::ApplicationResponse::Json(response) => Ok(response),
+            _ => Err(report!(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unexpected response from payouts create")),
+        }
+    }
+    .await;
+
+    match result {
+        Ok(response) => storage::PayoutBatchItemUpdate {
+            payout_id: Some(response.payout_id),
+            status: storage_enums::PayoutBatchItemStatus::Succeeded,
+            payout_status: Some(response.status),
+            error_code: response.error_code,
+            error_message: response.error_message,
+        },
+        Err(error) => {
+            // The item may have been processed in an earlier run of the workflow which failed
+            // before the item could be updated, in which case the existing payout is reported.
+            // The payout_ids of a batch are checked against existing payouts when the batch is
+            // created, so a payout which is older than the item was not created by the batch
+            if let errors::ApiErrorResponse::DuplicatePayout { payout_id } = error.current_context()
+            {
+                let payout_attempt = state
+                    .store
+                    .find_payout_attempt_by_merchant_id_payout_id(
+                        &merchant_account.merchant_id,
+                        payout_id,
+                    )
+                    .await
+                    .ok()
+                    .filter(|payout_attempt| payout_attempt.created_at >= item.created_at);
+                if let Some(payout_attempt) = payout_attempt {
+                    return storage::PayoutBatchItemUpdate {
+                        payout_id: Some(payout_attempt.payout_id),
+                        status: storage_enums::PayoutBatchItemStatus::Succeeded,
+                        payout_status: Some(payout_attempt.status),
+                        error_code: payout_attempt.error_code,
+                        error_message: payout_attempt.error_message,
+                    };
+                }
+            }
+            logger::warn!(payout_batch_item_error=?error);
+            let mut api_error: api_models::errors::types::ApiErrorResponse =
+                error.current_context().switch();
+            let error_info = api_error.get_internal_error_mut();
+            storage::PayoutBatchItemUpdate {
+                payout_id: item.payout_id.clone(),
+                status: storage_enums::PayoutBatchItemStatus::Failed,
+                payout_status: None,
+                error_code: Some(format!(
+                    "{}_{:02}",
+                    error_info.sub_code, error_info.error_identifier
+                )),
+                error_message: Some(error_info.error_message.clone()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::panic)]
+
+    use super::*;
+
+    #[test]
+    fn test_payout_batch_status() {
+        assert_eq!(
+            get_payout_batch_status(3, 1, 1),
+            storage_enums::PayoutBatchStatus::Processing
+        );
+        assert_eq!(
+            get_payout_batch_status(2, 2, 0),
+            storage_enums::PayoutBatchStatus::Completed
+        );
+        assert_eq!(
+            get_payout_batch_status(2, 0, 2),
+            storage_enums::PayoutBatchStatus::Failed
+        );
+        assert_eq!(
+            get_payout_batch_status(3, 2, 1),
+            storage_enums::PayoutBatchStatus::PartiallyCompleted
+        );
+    }
+
+    #[test]
+    fn test_parse_payout_batch_file() {
+        let file = "payout_id,amount,currency,customer_id,card_number,expiry_month,expiry_year,iban,bank_name,billing_city\n\
+                    payout_1,1000,USD,cus_1,4242424242424242,12,2030,,,Berlin\n\
+                    payout_2, 250 ,EUR,cus_2,,,,DE89370400440532013000,Bank,\n";
+
+        let items = parse_payout_batch_file(file.as_bytes()).unwrap();
+        assert_eq!(items.len(), 2);
+
+        let card_payout = &items[0];
+        assert_eq!(card_payout.payout_id.as_deref(), Some("payout_1"));
+        assert_eq!(card_payout.amount, Some(payments::Amount::from(1000)));
+        assert_eq!(card_payout.currency, Some(api_enums::Currency::USD));
+        assert_eq!(card_payout.customer_id.as_deref(), Some("cus_1"));
+        let Some(payouts::PayoutMethodData::Card(card)) = &card_payout.payout_method_data else {
+            panic!("expected card payout method data");
+        };
+        assert_eq!(card.expiry_month.peek(), "12");
+        assert_eq!(card.expiry_year.peek(), "2030");
+        let billing_city = card_payout
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.city.as_deref());
+        assert_eq!(billing_city, Some("Berlin"));
+
+        let bank_payout = &items[1];
+        assert_eq!(bank_payout.amount, Some(payments::Amount::from(250)));
+        let Some(payouts::PayoutMethodData::Bank(payouts::Bank::Sepa(sepa))) =
+            &bank_payout.payout_method_data
+        else {
+            panic!("expected sepa payout method data");
+        };
+        assert_eq!(sepa.iban.peek(), "DE89370400440532013000");
+        assert_eq!(sepa.bank_name.as_deref(), Some("Bank"));
+    }
+
+    #[test]
+    fn test_parse_payout_batch_file_rejects_card_without_expiry() {
+        let file = "payout_id,amount,currency,card_number,expiry_year\n\
+                    payout_1,1000,USD,4242424242424242,2030\n";
+
+        let error = parse_payout_batch_file(file.as_bytes()).unwrap_err();
+        let errors::ApiErrorResponse::InvalidRequestData { message } = error.current_context()
+        else {
+            panic!("expected invalid request data error");
+        };
+        assert!(message.contains("line 2"));
+        assert!(message.contains("expiry_month is required for a card payout"));
+    }
+
+    #[test]
+    fn test_encoded_payout_batch_item_is_a_valid_request() {
+        let item = payouts::PayoutCreateRequest {
+            payout_id: Some("payout_1".to_string()),
+            amount: Some(payments::Amount::from(1000)),
+            currency: Some(api_enums::Currency::USD),
+            ..Default::default()
+        };
+
+        let value = encode_payout_batch_item(&item).unwrap();
+        assert_eq!(value.get("amount"), Some(&serde_json::json!(1000)));
+
+        let decoded = value
+            .parse_value::<payouts::PayoutCreateRequest>("PayoutCreateRequest")
+            .unwrap();
+        assert_eq!(decoded.payout_id, item.payout_id);
+        assert_eq!(decoded.amount, item.amount);
+        assert_eq!(decoded.currency, item.currency);
+    }
+}
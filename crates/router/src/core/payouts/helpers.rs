@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use common_utils::{
     errors::CustomResult,
     ext_traits::{AsyncExt, StringExt, ValueExt},
@@ -357,6 +359,7 @@ pub async fn save_payout_data_to_locker(
         None,
         card_details_encrypted,
         key_store,
+        merchant_account.storage_scheme,
     )
     .await?;
 
@@ -377,7 +380,12 @@ pub async fn get_or_create_customer_details(
     let key = key_store.key.get_inner().peek();
 
     match db
-        .find_customer_optional_by_customer_id_merchant_id(&customer_id, merchant_id, key_store)
+        .find_customer_optional_by_customer_id_merchant_id(
+            &customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?
     {
@@ -409,7 +417,7 @@ pub async fn get_or_create_customer_details(
             };
 
             Ok(Some(
-                db.insert_customer(customer, key_store)
+                db.insert_customer(customer, key_store, merchant_account.storage_scheme)
                     .await
                     .change_context(errors::ApiErrorResponse::InternalServerError)?,
             ))
@@ -539,6 +547,13 @@ pub fn is_payout_initiated(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+/// Whether the status of a payout made through the connector can be synced with the connector
+pub fn is_payout_sync_supported(connector: &str) -> bool {
+    api_enums::PayoutConnectors::from_str(connector)
+        .map(|connector| connector.supports_payout_sync())
+        .unwrap_or(false)
+}
+
 pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
     !matches!(
         status,
@@ -558,6 +573,14 @@ pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
     )
 }
 
+pub fn is_eligible_for_payout_sync(status: api_enums::PayoutStatus) -> bool {
+    // Successful payouts are included as they can still be reversed by the connector
+    matches!(
+        status,
+        api_enums::PayoutStatus::Pending | api_enums::PayoutStatus::Success
+    )
+}
+
 pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus) -> bool {
     matches!(
         status,
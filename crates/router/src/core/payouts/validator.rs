@@ -1,8 +1,11 @@
+use std::collections::HashSet;
+
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::helpers;
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult},
         utils as core_utils,
@@ -121,3 +124,175 @@ pub async fn validate_create_request(
 
     Ok((payout_id, payout_method_data, profile_id))
 }
+
+#[cfg(all(feature = "payouts", feature = "olap"))]
+pub(super) fn validate_payout_list_request(
+    req: &payouts::PayoutListConstraints,
+) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_V1;
+
+    utils::when(
+        req.limit > PAYOUTS_LIST_MAX_LIMIT_V1 || req.limit < 1,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    PAYOUTS_LIST_MAX_LIMIT_V1
+                ),
+            }))
+        },
+    )
+}
+
+#[cfg(all(feature = "payouts", feature = "olap"))]
+pub(super) fn validate_payout_list_request_for_joins(limit: u32) -> RouterResult<()> {
+    use common_utils::consts::PAYOUTS_LIST_MAX_LIMIT_V2;
+
+    utils::when(!(1..=PAYOUTS_LIST_MAX_LIMIT_V2).contains(&limit), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be in between 1 and {}",
+                PAYOUTS_LIST_MAX_LIMIT_V2
+            ),
+        }))
+    })
+}
+
+/// Validates that none of the payout_ids of a batch belongs to an existing payout of the merchant,
+/// so that a payout which already exists is never reported as the payout of a batch item
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn validate_uniqueness_of_batch_payout_ids(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payout_ids: &[String],
+) -> RouterResult<()> {
+    for chunk in payout_ids.chunks(consts::PAYOUT_BATCH_ITEM_INSERT_CHUNK_SIZE) {
+        let existing_payouts = db
+            .find_payouts_by_merchant_id_payout_ids(merchant_id, chunk.to_vec())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while checking the payout_ids of the batch")?;
+        if let Some(payout) = existing_payouts.into_iter().next() {
+            Err(report!(errors::ApiErrorResponse::DuplicatePayout {
+                payout_id: payout.payout_id
+            }))?
+        }
+    }
+    Ok(())
+}
+
+/// Validates the items of a payout batch on below checks
+/// - batch is neither empty nor larger than the max batch size
+/// - merchant_id passed in each item is same as the one in merchant_account table
+/// - payout_id of each item is unique within the batch
+///
+/// Returns the payout_id assigned to each item. The payout_ids are checked against existing
+/// payouts by `validate_uniqueness_of_batch_payout_ids`
+#[cfg(feature = "payouts")]
+pub fn validate_batch_create_request(
+    merchant_id: &str,
+    items: &[payouts::PayoutCreateRequest],
+) -> RouterResult<Vec<String>> {
+    utils::when(
+        items.is_empty() || items.len() > consts::MAX_PAYOUT_BATCH_SIZE,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "number of payouts in a batch should be in between 1 and {}",
+                    consts::MAX_PAYOUT_BATCH_SIZE
+                ),
+            }))
+        },
+    )?;
+
+    let mut payout_ids = HashSet::with_capacity(items.len());
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let predicate = item.merchant_id.as_ref().map(|mid| mid != merchant_id);
+            utils::when(predicate.unwrap_or(false), || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: format!("payouts[{index}].merchant_id"),
+                    expected_format: "merchant_id from merchant account".to_string(),
+                }))
+            })?;
+
+            let payout_id = core_utils::get_or_generate_uuid("payout_id", item.payout_id.as_ref())?;
+            utils::when(!payout_ids.insert(payout_id.clone()), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("payout_id {payout_id} is repeated in the batch"),
+                }))
+            })?;
+            Ok(payout_id)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn payout_create_request(
+        payout_id: Option<&str>,
+        merchant_id: Option<&str>,
+    ) -> payouts::PayoutCreateRequest {
+        payouts::PayoutCreateRequest {
+            payout_id: payout_id.map(str::to_string),
+            merchant_id: merchant_id.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_batch_payout_ids_are_kept_or_generated() {
+        let items = [
+            payout_create_request(Some("payout_1"), Some("merchant_1")),
+            payout_create_request(None, None),
+        ];
+
+        let payout_ids = validate_batch_create_request("merchant_1", &items).unwrap();
+        assert_eq!(payout_ids.len(), 2);
+        assert_eq!(payout_ids[0], "payout_1");
+        assert!(!payout_ids[1].is_empty());
+    }
+
+    #[test]
+    fn test_batch_with_repeated_payout_id_is_rejected() {
+        let items = [
+            payout_create_request(Some("payout_1"), None),
+            payout_create_request(Some("payout_1"), None),
+        ];
+
+        let error = validate_batch_create_request("merchant_1", &items).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "payout_id payout_1 is repeated in the batch"
+        ));
+    }
+
+    #[test]
+    fn test_batch_of_another_merchant_is_rejected() {
+        let items = [payout_create_request(None, Some("merchant_2"))];
+
+        let error = validate_batch_create_request("merchant_1", &items).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidDataFormat { field_name, .. }
+                if field_name == "payouts[0].merchant_id"
+        ));
+    }
+
+    #[test]
+    fn test_empty_batch_is_rejected() {
+        let error = validate_batch_create_request("merchant_1", &[]).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+}
@@ -301,6 +301,7 @@ async fn store_bank_details_in_payment_methods(
         .find_payment_method_by_customer_id_merchant_id_list(
             &customer_id,
             &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
         )
         .await
         .change_context(ApiErrorResponse::InternalServerError)?;
@@ -458,7 +459,13 @@ async fn store_bank_details_in_payment_methods(
         };
     }
 
-    store_in_db(update_entries, new_entries, db).await?;
+    store_in_db(
+        update_entries,
+        new_entries,
+        db,
+        merchant_account.storage_scheme,
+    )
+    .await?;
 
     Ok(())
 }
@@ -467,15 +474,16 @@ async fn store_in_db(
     update_entries: Vec<(storage::PaymentMethod, storage::PaymentMethodUpdate)>,
     new_entries: Vec<storage::PaymentMethodNew>,
     db: &dyn StorageInterface,
+    storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<()> {
     let update_entries_futures = update_entries
         .into_iter()
-        .map(|(pm, pm_update)| db.update_payment_method(pm, pm_update))
+        .map(|(pm, pm_update)| db.update_payment_method(pm, pm_update, storage_scheme))
         .collect::<Vec<_>>();
 
     let new_entries_futures = new_entries
         .into_iter()
-        .map(|pm_new| db.insert_payment_method(pm_new))
+        .map(|pm_new| db.insert_payment_method(pm_new, storage_scheme))
         .collect::<Vec<_>>();
 
     let update_futures = futures::future::join_all(update_entries_futures);
@@ -6,13 +6,20 @@ use api_models::routing::{self as routing_types, RoutingAlgorithmId};
 use api_models::routing::{RoutingRetrieveLinkQuery, RoutingRetrieveQuery};
 #[cfg(not(feature = "business_profile_routing"))]
 use common_utils::ext_traits::{Encode, StringExt};
+#[cfg(feature = "olap")]
+use data_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
 #[cfg(not(feature = "business_profile_routing"))]
 use diesel_models::configs;
 #[cfg(feature = "business_profile_routing")]
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::{IntoReport, ResultExt};
+use euclid::frontend::ast::printer;
 use rustc_hash::FxHashSet;
 
+#[cfg(feature = "olap")]
+use crate::core::payments::routing as payments_routing;
 #[cfg(feature = "business_profile_routing")]
 use crate::types::transformers::{ForeignInto, ForeignTryInto};
 use crate::{
@@ -338,10 +345,11 @@ pub async fn link_routing_config(
 pub async fn retrieve_routing_config(
     state: AppState,
     merchant_account: domain::MerchantAccount,
-    algorithm_id: RoutingAlgorithmId,
+    request: routing_types::RoutingRetrieveConfigRequest,
 ) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
     metrics::ROUTING_RETRIEVE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
+    let algorithm_id = request.algorithm_id;
     #[cfg(feature = "business_profile_routing")]
     {
         let routing_algorithm = db
@@ -367,7 +375,7 @@ pub async fn retrieve_routing_config(
             .attach_printable("unable to parse routing algorithm")?;
 
         metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-        Ok(service_api::ApplicationResponse::Json(response))
+        get_routing_config_response(response, request.format)
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
@@ -405,7 +413,30 @@ pub async fn retrieve_routing_config(
         };
 
         metrics::ROUTING_RETRIEVE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
-        Ok(service_api::ApplicationResponse::Json(response))
+        get_routing_config_response(response, request.format)
+    }
+}
+
+fn get_routing_config_response(
+    response: routing_types::MerchantRoutingAlgorithm,
+    format: routing_types::RoutingAlgorithmFormat,
+) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
+    match format {
+        routing_types::RoutingAlgorithmFormat::Json => {
+            Ok(service_api::ApplicationResponse::Json(response))
+        }
+        routing_types::RoutingAlgorithmFormat::Text => match &response.algorithm {
+            routing_types::RoutingAlgorithm::Advanced(program) => printer::print_program(program)
+                .map(service_api::ApplicationResponse::TextPlain)
+                .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("routing algorithm cannot be represented as text: {error}"),
+                })
+                .into_report(),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "only advanced routing algorithms can be represented as text".to_string(),
+            })
+            .into_report(),
+        },
     }
 }
 pub async fn unlink_routing_config(
@@ -879,3 +910,151 @@ pub async fn update_default_routing_config_for_profile(
         },
     ))
 }
+
+#[cfg(feature = "olap")]
+pub async fn simulate_routing_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    metrics::ROUTING_SIMULATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+
+    utils::when(
+        matches!(
+            request.algorithm,
+            routing_types::RoutingAlgorithm::SuccessRate(_)
+        ),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "success rate based routing cannot be simulated".to_string(),
+            })
+        },
+    )?;
+
+    let limit = request
+        .limit
+        .unwrap_or(routing_types::ROUTING_SIMULATION_MAX_LIMIT);
+    utils::when(
+        !(1..=routing_types::ROUTING_SIMULATION_MAX_LIMIT).contains(&limit),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    routing_types::ROUTING_SIMULATION_MAX_LIMIT
+                ),
+            })
+        },
+    )?;
+
+    core_utils::validate_and_get_business_profile(
+        db,
+        request.profile_id.as_ref(),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: Some(request.time_range.start_time),
+        ending_at: request.time_range.end_time,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        profile_id: request.profile_id,
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(limit),
+    }));
+
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            &merchant_account.merchant_id,
+            &constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let response = payments_routing::simulation::simulate_routing(request.algorithm, &payments)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to simulate the routing algorithm on historical payments")?;
+
+    metrics::ROUTING_SIMULATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[cfg(feature = "olap")]
+pub async fn analyze_routing_config_coverage(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingCoverageRequest,
+) -> RouterResponse<routing_types::RoutingCoverageResponse> {
+    metrics::ROUTING_COVERAGE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+
+    let routing_types::RoutingAlgorithm::Advanced(program) = request.algorithm else {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "coverage can only be analyzed for advanced routing algorithms".to_string(),
+        })
+        .into_report();
+    };
+
+    #[cfg(feature = "business_profile_routing")]
+    let profile_id = Some(
+        request
+            .profile_id
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "profile_id",
+            })
+            .attach_printable("Profile_id not provided")?,
+    );
+    #[cfg(not(feature = "business_profile_routing"))]
+    let profile_id = request.profile_id;
+
+    core_utils::validate_and_get_business_profile(
+        db,
+        profile_id.as_ref(),
+        &merchant_account.merchant_id,
+    )
+    .await?;
+
+    let mca_graph = payments_routing::get_merchant_kgraph(
+        &state,
+        &key_store,
+        merchant_account.modified_at.assume_utc().unix_timestamp(),
+        #[cfg(feature = "business_profile_routing")]
+        profile_id.clone(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the knowledge graph of the merchant connector accounts")?;
+
+    let knowledge_graph = euclid::dssa::graph::KnowledgeGraph::combine(
+        &mca_graph,
+        &euclid::dssa::truth::ANALYSIS_GRAPH,
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to combine the merchant knowledge graph with the truth graph")?;
+
+    let coverage = euclid::dssa::coverage::analyze_coverage(program, Some(&knowledge_graph))
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("invalid routing program: {error}"),
+        })
+        .into_report()?;
+
+    metrics::ROUTING_COVERAGE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingCoverageResponse {
+            profile_id,
+            coverage,
+        },
+    ))
+}
@@ -424,6 +424,12 @@ pub async fn validate_connectors_in_routing_config(
             }
         }
 
+        routing_types::RoutingAlgorithm::SuccessRate(config) => {
+            for choice in &config.connectors {
+                check_connector_choice(choice)?;
+            }
+        }
+
         routing_types::RoutingAlgorithm::Advanced(program) => {
             let check_connector_selection =
                 |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
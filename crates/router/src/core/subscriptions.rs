@@ -0,0 +1,764 @@
+use api_models::{payments as payment_types, subscriptions, webhooks};
+use common_utils::ext_traits::StringExt;
+use diesel_models::enums as storage_enums;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing, Instrument};
+use scheduler::{
+    consumer::types::process_data, db::process_tracker::ProcessTrackerExt, errors as sch_errors,
+    utils as pt_utils,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::Oss,
+        payments, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{api, domain, storage},
+    utils,
+};
+
+const SUBSCRIPTION_WORKFLOW_RUNNER: &str = "SUBSCRIPTION_WORKFLOW";
+const SUBSCRIPTION_WORKFLOW_TASK: &str = "SUBSCRIPTION_RENEWAL";
+
+#[instrument(skip_all)]
+pub async fn create_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: subscriptions::SubscriptionCreateRequest,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    if req.amount <= 0 {
+        return Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })
+        .into_report();
+    }
+    if req.interval_count == 0 {
+        return Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "interval_count",
+        })
+        .into_report();
+    }
+
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &req.mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+    validate_subscription_mandate(&req, &mandate)?;
+
+    let now = common_utils::date_time::now();
+    let anchor_date = req.anchor_date.unwrap_or(now);
+    if anchor_date < now {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "anchor_date should not be in the past".to_string(),
+        })
+        .into_report();
+    }
+
+    let (end_after_cycles, ends_at) = match req.end_condition {
+        subscriptions::SubscriptionEndCondition::Never => (None, None),
+        subscriptions::SubscriptionEndCondition::AfterCycles { cycles } => (
+            Some(
+                i32::try_from(cycles)
+                    .ok()
+                    .filter(|cycles| *cycles > 0)
+                    .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "end_condition.cycles",
+                    })?,
+            ),
+            None,
+        ),
+        subscriptions::SubscriptionEndCondition::OnDate { date } => (None, Some(date)),
+    };
+
+    let interval_count = i32::from(req.interval_count);
+    let trial_days = i32::from(req.trial_days);
+    let first_billing_at =
+        get_billing_date(anchor_date, trial_days, req.interval, interval_count, 0).ok_or(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "anchor_date",
+            },
+        )?;
+    if ends_at.map_or(false, |ends_at| ends_at < first_billing_at) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "the subscription ends before its first billing cycle".to_string(),
+        })
+        .into_report();
+    }
+
+    let status = if trial_days > 0 {
+        storage_enums::SubscriptionStatus::Trialing
+    } else {
+        storage_enums::SubscriptionStatus::Active
+    };
+    let subscription = db
+        .insert_subscription(storage::SubscriptionNew {
+            subscription_id: utils::generate_id(consts::ID_LENGTH, "sub"),
+            merchant_id: merchant_id.to_owned(),
+            customer_id: req.customer_id,
+            mandate_id: req.mandate_id,
+            profile_id: req.profile_id,
+            status,
+            amount: req.amount,
+            currency: req.currency,
+            interval: req.interval,
+            interval_count,
+            anchor_date,
+            trial_days,
+            end_after_cycles,
+            ends_at,
+            completed_cycles: 0,
+            failed_attempts: 0,
+            next_billing_at: Some(first_billing_at),
+            last_payment_id: None,
+            description: req.description,
+            metadata: req.metadata,
+            cancelled_at: None,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    add_subscription_task(db, &subscription, first_billing_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the subscription workflow")?;
+
+    Ok(services::ApplicationResponse::Json(
+        make_subscription_response(subscription)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscriptions::SubscriptionId,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let subscription = state
+        .store
+        .find_subscription_by_merchant_id_subscription_id(
+            &merchant_account.merchant_id,
+            &req.subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        make_subscription_response(subscription)?,
+    ))
+}
+
+/// Cancels the subscription, the scheduled renewal is dropped the next time the subscription
+/// workflow picks it up
+#[instrument(skip_all)]
+pub async fn cancel_subscription(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: subscriptions::SubscriptionId,
+) -> RouterResponse<subscriptions::SubscriptionResponse> {
+    let db = &*state.store;
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(
+            &merchant_account.merchant_id,
+            &req.subscription_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SubscriptionNotFound)?;
+
+    if is_subscription_ended(subscription.status) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "subscription cannot be cancelled because it is {}",
+                subscription.status
+            ),
+        })
+        .into_report();
+    }
+
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            &merchant_account.merchant_id,
+            &req.subscription_id,
+            storage::SubscriptionUpdate::Cancel {
+                cancelled_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        // The last billing cycle completed the subscription after it was fetched
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "subscription cannot be cancelled because it has ended".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        make_subscription_response(subscription)?,
+    ))
+}
+
+fn validate_subscription_mandate(
+    req: &subscriptions::SubscriptionCreateRequest,
+    mandate: &storage::Mandate,
+) -> RouterResult<()> {
+    let reason = if mandate.customer_id != req.customer_id {
+        Some("mandate does not belong to the customer")
+    } else if mandate.mandate_status != storage_enums::MandateStatus::Active {
+        Some("mandate is not active")
+    } else if mandate.mandate_type != storage_enums::MandateType::MultiUse {
+        Some("mandate should be a multi use mandate")
+    } else if mandate
+        .mandate_currency
+        .map_or(false, |currency| currency != req.currency)
+    {
+        Some("currency does not match the currency of the mandate")
+    } else if mandate
+        .get_transaction_limit()
+        .map_or(false, |limit| req.amount > limit)
+    {
+        Some("amount exceeds the per transaction limit of the mandate")
+    } else if mandate
+        .get_remaining_amount()
+        .map_or(false, |remaining_amount| req.amount > remaining_amount)
+    {
+        Some("amount exceeds the remaining allowance of the mandate")
+    } else {
+        None
+    };
+
+    match reason {
+        Some(reason) => Err(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: reason.to_string(),
+        })
+        .into_report(),
+        None => Ok(()),
+    }
+}
+
+pub fn is_subscription_ended(status: storage_enums::SubscriptionStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::SubscriptionStatus::Cancelled | storage_enums::SubscriptionStatus::Completed
+    )
+}
+
+/// Returns the date on which the given billing cycle is charged, cycles are counted from the
+/// end of the trial period so that monthly cycles do not drift when a month is shorter than
+/// the anchor day
+pub fn get_billing_date(
+    anchor_date: PrimitiveDateTime,
+    trial_days: i32,
+    interval: storage_enums::SubscriptionInterval,
+    interval_count: i32,
+    cycle: i32,
+) -> Option<PrimitiveDateTime> {
+    let start = anchor_date.checked_add(time::Duration::days(i64::from(trial_days)))?;
+    let intervals = i64::from(interval_count).checked_mul(i64::from(cycle))?;
+    match interval {
+        storage_enums::SubscriptionInterval::Day => {
+            start.checked_add(time::Duration::days(intervals))
+        }
+        storage_enums::SubscriptionInterval::Week => {
+            start.checked_add(time::Duration::weeks(intervals))
+        }
+        storage_enums::SubscriptionInterval::Month => add_months(start, intervals),
+        storage_enums::SubscriptionInterval::Year => add_months(start, intervals.checked_mul(12)?),
+    }
+}
+
+/// Adds months to the date, using the last day of the month when the day does not exist in it
+fn add_months(date: PrimitiveDateTime, months: i64) -> Option<PrimitiveDateTime> {
+    let month_index = i64::from(date.year())
+        .checked_mul(12)?
+        .checked_add(i64::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = i32::try_from(month_index.div_euclid(12)).ok()?;
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+    let date_in_month = time::Date::from_calendar_date(year, month, day).ok()?;
+    Some(PrimitiveDateTime::new(date_in_month, date.time()))
+}
+
+async fn add_subscription_task(
+    db: &dyn StorageInterface,
+    subscription: &storage::Subscription,
+    schedule_time: PrimitiveDateTime,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let tracking_data = storage::SubscriptionWorkflow {
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        SUBSCRIPTION_WORKFLOW_RUNNER,
+        SUBSCRIPTION_WORKFLOW_TASK,
+        &subscription.subscription_id,
+        &subscription.merchant_id,
+    );
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        SUBSCRIPTION_WORKFLOW_TASK,
+        SUBSCRIPTION_WORKFLOW_RUNNER,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Get the delay before the next attempt of a failed billing cycle
+///
+/// The dunning schedule can be configured in configs by this key
+/// `pt_mapping_subscription_dunning`
+/// ```json
+/// {
+///     "default_mapping": {
+///         "start_after": 86400,
+///         "frequency": [172800, 345600],
+///         "count": [1, 1]
+///     },
+///     "custom_merchant_mapping": {}
+/// }
+/// ```
+///
+/// This config represents
+///
+/// `start_after`: The first failed payment of a cycle is retried after a day
+///
+/// `frequency` and `count`: The next 2 retries happen 2 and 4 days after the previous one,
+/// after which the subscription is cancelled
+///
+async fn get_dunning_retry_delay(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<i32> {
+    let mapping: common_utils::errors::CustomResult<
+        process_data::SubscriptionDunningProcessTrackerMapping,
+        errors::StorageError,
+    > = db
+        .find_config_by_key("pt_mapping_subscription_dunning")
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("SubscriptionDunningProcessTrackerMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = match mapping {
+        Ok(mapping) => mapping,
+        Err(error) => {
+            logger::info!("Subscription dunning mapping error: {}", error);
+            process_data::SubscriptionDunningProcessTrackerMapping::default()
+        }
+    };
+
+    pt_utils::get_subscription_dunning_schedule_time(mapping, merchant_id, retry_count)
+}
+
+/// Charges the mandate of the subscription for its current billing cycle and moves the
+/// subscription to its next billing date, or to the next dunning attempt when the payment
+/// failed
+#[instrument(skip_all, fields(subscription_id = %subscription.subscription_id))]
+pub async fn process_subscription_cycle(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: storage::Subscription,
+) -> RouterResult<storage::Subscription> {
+    let db = &*state.store;
+    // The payment id is derived from the cycle and the attempt, so that a task which is picked
+    // up again after a crash does not charge the customer twice
+    let payment_id = format!(
+        "{}_{}_{}",
+        subscription.subscription_id, subscription.completed_cycles, subscription.failed_attempts
+    );
+
+    let (payment_status, payment_profile_id) = make_subscription_payment(
+        state,
+        merchant_account,
+        key_store,
+        &subscription,
+        payment_id.clone(),
+    )
+    .await?
+    .map_or((None, None), |(status, profile_id)| {
+        (Some(status), profile_id)
+    });
+    let is_paid = payment_status.map_or(false, |status| {
+        matches!(
+            status,
+            storage_enums::IntentStatus::Succeeded
+                | storage_enums::IntentStatus::Processing
+                | storage_enums::IntentStatus::RequiresCapture
+                | storage_enums::IntentStatus::PartiallyCaptured
+                | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+        )
+    });
+
+    let (subscription_update, event_type) = if is_paid {
+        let completed_cycles = subscription.completed_cycles + 1;
+        let next_billing_at = get_billing_date(
+            subscription.anchor_date,
+            subscription.trial_days,
+            subscription.interval,
+            subscription.interval_count,
+            completed_cycles,
+        )
+        .filter(|next_billing_at| {
+            subscription
+                .end_after_cycles
+                .map_or(true, |cycles| completed_cycles < cycles)
+                && subscription
+                    .ends_at
+                    .map_or(true, |ends_at| *next_billing_at <= ends_at)
+        });
+        let status = if next_billing_at.is_some() {
+            storage_enums::SubscriptionStatus::Active
+        } else {
+            storage_enums::SubscriptionStatus::Completed
+        };
+        (
+            storage::SubscriptionUpdate::CyclePaid {
+                status,
+                completed_cycles,
+                next_billing_at,
+                last_payment_id: payment_id.clone(),
+            },
+            storage_enums::EventType::SubscriptionRenewed,
+        )
+    } else {
+        let failed_attempts = subscription.failed_attempts + 1;
+        let next_billing_at = pt_utils::get_time_from_delta(
+            get_dunning_retry_delay(db, &subscription.merchant_id, failed_attempts - 1).await,
+        );
+        let (status, cancelled_at) = match next_billing_at {
+            Some(_) => (storage_enums::SubscriptionStatus::PastDue, None),
+            None => (
+                storage_enums::SubscriptionStatus::Cancelled,
+                Some(common_utils::date_time::now()),
+            ),
+        };
+        (
+            storage::SubscriptionUpdate::CycleFailed {
+                status,
+                failed_attempts,
+                next_billing_at,
+                last_payment_id: payment_id.clone(),
+                cancelled_at,
+            },
+            storage_enums::EventType::SubscriptionRenewalFailed,
+        )
+    };
+
+    // Subscriptions which have ended are not updated, a subscription which was cancelled while
+    // it was being renewed stays cancelled and is not billed again
+    let subscription = match db
+        .update_subscription_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            subscription_update,
+        )
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::warn!(%payment_id, "Subscription ended while it was being renewed");
+            return db
+                .find_subscription_by_merchant_id_subscription_id(
+                    &subscription.merchant_id,
+                    &subscription.subscription_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch subscription");
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update subscription")?,
+    };
+
+    // The customer has been charged and the subscription updated at this point, failing the
+    // cycle because of the webhook would charge the customer again
+    trigger_subscription_webhook(
+        state,
+        merchant_account,
+        &subscription,
+        payment_id,
+        payment_profile_id,
+        event_type,
+    )
+    .await
+    .map_err(|error| logger::error!(subscription_webhook_error=?error))
+    .ok();
+
+    Ok(subscription)
+}
+
+/// Creates and confirms the off-session payment of a billing cycle, returns the status and the
+/// business profile of the payment, or `None` when the payment could not be created
+async fn make_subscription_payment(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    subscription: &storage::Subscription,
+    payment_id: String,
+) -> RouterResult<Option<(storage_enums::IntentStatus, Option<String>)>> {
+    let request = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        amount: Some(payment_types::Amount::from(subscription.amount)),
+        currency: Some(subscription.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        mandate_id: Some(subscription.mandate_id.clone()),
+        off_session: Some(true),
+        confirm: Some(true),
+        profile_id: subscription.profile_id.clone(),
+        description: subscription.description.clone(),
+        metadata: subscription.metadata.clone(),
+        ..Default::default()
+    };
+
+    let response = payments::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _, Oss>(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payments::PaymentCreate,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    )
+    .await;
+
+    let error = match response {
+        Ok(services::ApplicationResponse::JsonWithHeaders((response, _)))
+        | Ok(services::ApplicationResponse::Json(response)) => {
+            return Ok(Some((response.status, response.profile_id)))
+        }
+        Ok(_) => report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payments create"),
+        Err(error) => error,
+    };
+
+    // The customer may have been charged even though an error was returned, e.g. when the payment
+    // could not be updated after the connector call, or by an earlier run of the workflow which
+    // failed before the subscription could be updated. The status of the payment is used when it
+    // exists, so that the next dunning attempt does not charge the customer again
+    match state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => {
+            logger::warn!(subscription_payment_error=?error, %payment_id, status = %payment_intent.status);
+            Ok(Some((payment_intent.status, payment_intent.profile_id)))
+        }
+        Err(db_error) if db_error.current_context().is_db_not_found() => {
+            logger::warn!(subscription_payment_error=?error, %payment_id);
+            Ok(None)
+        }
+        Err(db_error) => Err(db_error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment of the subscription"),
+    }
+}
+
+async fn trigger_subscription_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    subscription: &storage::Subscription,
+    payment_id: String,
+    payment_profile_id: Option<String>,
+    event_type: storage_enums::EventType,
+) -> RouterResult<()> {
+    let Some(profile_id) = payment_profile_id.or_else(|| subscription.profile_id.clone()) else {
+        logger::warn!("Outgoing webhook not sent because the subscription has no business profile");
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    let subscription_response = make_subscription_response(subscription.clone())?;
+    let m_state = state.clone();
+    let merchant_account = merchant_account.clone();
+    // Every attempt of a billing cycle is a separate payment, so the event is keyed by the
+    // payment to notify the merchant of each renewal instead of only the first one
+    tokio::spawn(
+        async move {
+            Box::pin(
+                webhooks_core::create_event_and_trigger_appropriate_outgoing_webhook(
+                    m_state,
+                    merchant_account,
+                    business_profile,
+                    event_type,
+                    storage_enums::EventClass::Subscriptions,
+                    None,
+                    payment_id,
+                    storage_enums::EventObjectType::SubscriptionDetails,
+                    webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(
+                        subscription_response,
+                    )),
+                ),
+            )
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+pub fn make_subscription_response(
+    subscription: storage::Subscription,
+) -> RouterResult<subscriptions::SubscriptionResponse> {
+    let end_condition = match (subscription.end_after_cycles, subscription.ends_at) {
+        (Some(cycles), _) => subscriptions::SubscriptionEndCondition::AfterCycles {
+            cycles: to_unsigned(cycles, "end_after_cycles")?,
+        },
+        (None, Some(date)) => subscriptions::SubscriptionEndCondition::OnDate { date },
+        (None, None) => subscriptions::SubscriptionEndCondition::Never,
+    };
+
+    Ok(subscriptions::SubscriptionResponse {
+        subscription_id: subscription.subscription_id,
+        merchant_id: subscription.merchant_id,
+        customer_id: subscription.customer_id,
+        mandate_id: subscription.mandate_id,
+        profile_id: subscription.profile_id,
+        status: subscription.status,
+        amount: subscription.amount,
+        currency: subscription.currency,
+        interval: subscription.interval,
+        interval_count: to_unsigned(subscription.interval_count, "interval_count")?,
+        anchor_date: subscription.anchor_date,
+        trial_days: to_unsigned(subscription.trial_days, "trial_days")?,
+        end_condition,
+        completed_cycles: to_unsigned(subscription.completed_cycles, "completed_cycles")?,
+        failed_attempts: to_unsigned(subscription.failed_attempts, "failed_attempts")?,
+        next_billing_at: subscription.next_billing_at,
+        last_payment_id: subscription.last_payment_id,
+        description: subscription.description,
+        metadata: subscription.metadata,
+        cancelled_at: subscription.cancelled_at,
+        created_at: subscription.created_at,
+    })
+}
+
+fn to_unsigned<T: TryFrom<i32>>(value: i32, field: &str) -> RouterResult<T> {
+    T::try_from(value)
+        .ok()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable_lazy(|| format!("Invalid {field} {value} in subscription"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_monthly_billing_dates_are_clamped_to_the_end_of_the_month() {
+        let billing_dates = (0..4)
+            .map(|cycle| {
+                get_billing_date(
+                    datetime!(2024-01-31 10:00),
+                    0,
+                    storage_enums::SubscriptionInterval::Month,
+                    1,
+                    cycle,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            billing_dates,
+            vec![
+                datetime!(2024-01-31 10:00),
+                datetime!(2024-02-29 10:00),
+                datetime!(2024-03-31 10:00),
+                datetime!(2024-04-30 10:00),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_billing_dates_start_after_the_trial() {
+        assert_eq!(
+            get_billing_date(
+                datetime!(2024-01-01 00:00),
+                14,
+                storage_enums::SubscriptionInterval::Week,
+                2,
+                1,
+            ),
+            Some(datetime!(2024-01-29 00:00))
+        );
+        assert_eq!(
+            get_billing_date(
+                datetime!(2024-02-29 00:00),
+                0,
+                storage_enums::SubscriptionInterval::Year,
+                1,
+                1,
+            ),
+            Some(datetime!(2025-02-28 00:00))
+        );
+    }
+
+    #[test]
+    fn test_default_dunning_schedule() {
+        let retry_delays = (0..4)
+            .map(|retry_count| {
+                pt_utils::get_subscription_dunning_schedule_time(
+                    process_data::SubscriptionDunningProcessTrackerMapping::default(),
+                    "-",
+                    retry_count,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            retry_delays,
+            vec![Some(86400), Some(172800), Some(345600), None]
+        );
+    }
+}
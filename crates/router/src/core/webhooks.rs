@@ -1,5 +1,7 @@
 pub mod types;
 pub mod utils;
+#[cfg(feature = "olap")]
+pub mod webhook_events;
 
 use std::{str::FromStr, time::Instant};
 
@@ -8,9 +10,11 @@ use api_models::{
     payments::HeaderPayload,
     webhooks::{self, WebhookResponseTracker},
 };
-use common_utils::{errors::ReportSwitchExt, events::ApiEventsType, request::RequestContent};
+use common_utils::{
+    errors::ReportSwitchExt, events::ApiEventsType, ext_traits::Encode, request::RequestContent,
+};
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, Mask};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId};
 
 use super::{errors::StorageErrorExt, metrics};
@@ -30,18 +34,25 @@ use crate::{
         outgoing_webhook_logs::{OutgoingWebhookEvent, OutgoingWebhookEventMetric},
     },
     logger,
-    routes::{app::AppStateInfo, lock_utils, metrics::request::add_attributes, AppState},
+    routes::{
+        app::AppStateInfo,
+        lock_utils,
+        metrics::{self as router_metrics, request::add_attributes},
+        AppState,
+    },
     services::{self, authentication as auth},
     types::{
         api::{self, mandates::MandateResponseExt},
         domain,
-        storage::{self, enums},
+        storage::{self, enums, ProcessTrackerExt},
         transformers::{ForeignInto, ForeignTryInto},
     },
     utils::{self as helper_utils, generate_id, OptionExt, ValueExt},
+    workflows,
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+const OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_LENGTH: usize = 1024;
 const MERCHANT_ID: &str = "merchant_id";
 
 pub async fn payments_incoming_webhook_flow<
@@ -435,6 +446,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 .find_mandate_by_merchant_id_mandate_id(
                     &merchant_account.merchant_id,
                     mandate_id.as_str(),
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -444,6 +456,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 .find_mandate_by_merchant_id_connector_mandate_id(
                     &merchant_account.merchant_id,
                     connector_mandate_id.as_str(),
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -461,6 +474,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 &merchant_account.merchant_id,
                 &mandate.mandate_id,
                 storage::MandateUpdate::StatusUpdate { mandate_status },
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
@@ -469,6 +483,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 &state,
                 key_store,
                 updated_mandate.clone(),
+                merchant_account.storage_scheme,
             )
             .await?,
         );
@@ -717,6 +732,10 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        business_profile_id: Some(business_profile.profile_id.clone()),
+        initial_attempt_id: Some(event_id.clone()),
+        delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
     };
 
     let event_insert_result = state.store.insert_event(new_event).await;
@@ -750,7 +769,8 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         tokio::spawn(async move {
             let mut error = None;
             let result =
-                trigger_webhook_to_merchant::<W>(business_profile, outgoing_webhook, state).await;
+                trigger_webhook_to_merchant::<W>(business_profile, &event, outgoing_webhook, state)
+                    .await;
 
             if let Err(e) = result {
                 error.replace(
@@ -788,11 +808,83 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
 
 pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     business_profile: diesel_models::business_profile::BusinessProfile,
+    event: &storage::Event,
     webhook: api::OutgoingWebhook,
     state: AppState,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let transformed_outgoing_webhook = W::from(webhook);
+
+    let request_content = get_outgoing_webhook_request(
+        transformed_outgoing_webhook,
+        business_profile.payment_response_hash_key.clone(),
+    )?;
+
+    let delivery_result =
+        send_outgoing_webhook_request(&state, &business_profile, event, request_content).await;
+
+    if let Err(error) = &delivery_result {
+        if error
+            .current_context()
+            .is_webhook_delivery_retryable_error()
+        {
+            add_outgoing_webhook_retry_task(&*state.store, &business_profile, event)
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to schedule retry for outgoing webhook {}",
+                        event.event_id
+                    )
+                })
+                .ok();
+        }
+    }
+
+    delivery_result
+}
+
+/// Serializes and signs the outgoing webhook, so that the same request can be sent to the
+/// merchant again when the delivery has to be retried
+pub fn get_outgoing_webhook_request<W: types::OutgoingWebhookType>(
+    transformed_outgoing_webhook: W,
+    payment_response_hash_key: Option<String>,
+) -> CustomResult<api::OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    let body = Encode::<serde_json::Value>::encode_to_string_of_json(&transformed_outgoing_webhook)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("failed encoding outgoing webhook payload")?;
+
+    let outgoing_webhooks_signature =
+        transformed_outgoing_webhook.get_outgoing_webhooks_signature(payment_response_hash_key)?;
+
+    let mut header = vec![(
+        reqwest::header::CONTENT_TYPE.to_string(),
+        "application/json".into(),
+    )];
+
+    if let Some(signature) = outgoing_webhooks_signature {
+        W::add_webhook_header(&mut header, signature)
+    }
+
+    Ok(api::OutgoingWebhookRequestContent {
+        body: body.into(),
+        headers: header
+            .into_iter()
+            .map(|(name, value)| (name, value.into_inner().into()))
+            .collect(),
+    })
+}
+
+/// Sends the outgoing webhook request to the merchant and records the delivery details (request,
+/// response and latency) on the event
+pub async fn send_outgoing_webhook_request(
+    state: &AppState,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event: &storage::Event,
+    request_content: api::OutgoingWebhookRequestContent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = business_profile
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -807,34 +899,34 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
         .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
         .map(ExposeInterface::expose)?;
 
-    let outgoing_webhook_event_id = webhook.event_id.clone();
-
-    let transformed_outgoing_webhook = W::from(webhook);
-
-    let outgoing_webhooks_signature = transformed_outgoing_webhook
-        .get_outgoing_webhooks_signature(business_profile.payment_response_hash_key.clone())?;
-
-    let mut header = vec![(
-        reqwest::header::CONTENT_TYPE.to_string(),
-        "application/json".into(),
-    )];
+    let request_details =
+        Encode::<api::OutgoingWebhookRequestContent>::encode_to_value(&request_content)
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("failed encoding outgoing webhook request details")?;
 
-    if let Some(signature) = outgoing_webhooks_signature {
-        W::add_webhook_header(&mut header, signature)
-    }
+    let header = request_content
+        .headers
+        .into_iter()
+        .map(|(name, value)| (name, value.into_masked()))
+        .collect();
 
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
         .attach_default_headers()
         .headers(header)
-        .set_body(RequestContent::Json(Box::new(transformed_outgoing_webhook)))
+        .set_body(RequestContent::RawBytes(
+            request_content.body.expose().into_bytes(),
+        ))
         .build();
 
+    let delivery_start_time = Instant::now();
     let response = state
         .api_client
-        .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
+        .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    let delivery_latency_ms =
+        i64::try_from(delivery_start_time.elapsed().as_millis()).unwrap_or(i64::MAX);
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
@@ -846,13 +938,25 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
-    match response {
-        Err(e) => {
-            // [#217]: Schedule webhook for retry.
-            Err(e).change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
-        }
+    let (response_status_code, response_body, delivery_result) = match response {
+        Err(e) => (
+            None,
+            None,
+            Err(e).change_context(errors::WebhooksFlowError::CallToMerchantFailed),
+        ),
         Ok(res) => {
-            if res.status().is_success() {
+            let status_code = res.status();
+            let response_body = res
+                .text()
+                .await
+                .map(|body| {
+                    body.chars()
+                        .take(OUTGOING_WEBHOOK_RESPONSE_BODY_MAX_LENGTH)
+                        .collect::<String>()
+                })
+                .ok();
+
+            let delivery_result = if status_code.is_success() {
                 metrics::WEBHOOK_OUTGOING_RECEIVED_COUNT.add(
                     &metrics::CONTEXT,
                     1,
@@ -861,14 +965,7 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
                         business_profile.merchant_id.clone(),
                     )],
                 );
-                let update_event = storage::EventUpdate::UpdateWebhookNotified {
-                    is_webhook_notified: Some(true),
-                };
-                state
-                    .store
-                    .update_event(outgoing_webhook_event_id, update_event)
-                    .await
-                    .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+                Ok(())
             } else {
                 metrics::WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT.add(
                     &metrics::CONTEXT,
@@ -878,11 +975,91 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
                         business_profile.merchant_id.clone(),
                     )],
                 );
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
-            }
+                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()
+            };
+
+            (
+                i16::try_from(status_code.as_u16()).ok(),
+                response_body,
+                delivery_result,
+            )
         }
-    }
+    };
+
+    let update_event = storage::EventUpdate::UpdateDeliveryDetails {
+        is_webhook_notified: delivery_result.is_ok(),
+        request: request_details.into(),
+        response_status_code,
+        response_body,
+        delivery_latency_ms,
+    };
+    // The outcome of the delivery is returned even if it cannot be recorded, so that a failed
+    // delivery is still retried
+    state
+        .store
+        .update_event(event.event_id.clone(), update_event)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to record the delivery details of outgoing webhook {}",
+                event.event_id
+            )
+        })
+        .ok();
+
+    delivery_result
+}
+
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event: &storage::Event,
+) -> Result<(), errors::ProcessTrackerError> {
+    let initial_attempt_id = event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| event.event_id.clone());
+
+    let schedule_time =
+        workflows::outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(
+            db,
+            &business_profile.merchant_id,
+            0,
+        )
+        .await
+        .ok_or(errors::ProcessTrackerError::ConfigurationError)?;
+
+    let tracking_data = types::OutgoingWebhookTrackingData {
+        merchant_id: business_profile.merchant_id.clone(),
+        business_profile_id: business_profile.profile_id.clone(),
+        event_id: event.event_id.clone(),
+    };
+
+    let runner = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+    let task = "OUTGOING_WEBHOOK_RETRY";
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &initial_attempt_id,
+        &business_profile.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+
+    router_metrics::TASKS_ADDED_COUNT.add(
+        &router_metrics::CONTEXT,
+        1,
+        &[add_attributes("flow", "OutgoingWebhookRetry")],
+    );
 
     Ok(())
 }
@@ -1,7 +1,7 @@
 use api_models::webhooks;
 use common_utils::{crypto::SignMessage, ext_traits};
 use error_stack::ResultExt;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{core::errors, headers, services::request::Maskable};
 
@@ -43,3 +43,12 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
         header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
     }
 }
+
+/// Data required by the outgoing webhook retry workflow to deliver a webhook again. The request
+/// is not part of it, it is read from the event whose delivery failed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub business_profile_id: String,
+    pub event_id: String,
+}
@@ -0,0 +1,218 @@
+use api_models::webhook_events::{self as api_webhook_events, EventListConstraints};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    logger,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils::{self, ValueExt},
+};
+
+const EVENTS_LIST_DEFAULT_LIMIT: i64 = 10;
+const EVENTS_LIST_MAX_LIMIT: i64 = 100;
+
+#[instrument(skip_all)]
+pub async fn list_delivery_attempts(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+    constraints: EventListConstraints,
+) -> RouterResponse<Vec<api_webhook_events::EventResponse>> {
+    let store = state.store.as_ref();
+
+    let business_profile = store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?;
+
+    if business_profile.merchant_id != merchant_account.merchant_id {
+        Err(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.clone(),
+        })?
+    }
+
+    let created_after = constraints
+        .created_after
+        .unwrap_or(time::PrimitiveDateTime::new(
+            time::OffsetDateTime::UNIX_EPOCH.date(),
+            time::OffsetDateTime::UNIX_EPOCH.time(),
+        ));
+    let created_before = constraints
+        .created_before
+        .unwrap_or_else(common_utils::date_time::now);
+    let limit = constraints
+        .limit
+        .unwrap_or(EVENTS_LIST_DEFAULT_LIMIT)
+        .clamp(1, EVENTS_LIST_MAX_LIMIT);
+    if constraints.offset.map_or(false, |offset| offset < 0) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset must not be negative".to_string(),
+        })?
+    }
+
+    let events = store
+        .list_events_by_business_profile_id_constraints(
+            &profile_id,
+            created_after,
+            created_before,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events for business profile")?;
+
+    let events = events
+        .into_iter()
+        .map(get_event_response)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ApplicationResponse::Json(events))
+}
+
+#[instrument(skip_all)]
+pub async fn retry_delivery_attempt(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<api_webhook_events::EventResponse> {
+    let store = state.store.as_ref();
+    let event_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Event with id '{event_id}' does not exist in our records"),
+    };
+
+    let event_to_retry = store
+        .find_event_by_event_id(&event_id)
+        .await
+        .to_not_found_response(event_not_found())?;
+
+    if event_to_retry.merchant_id.as_deref() != Some(merchant_account.merchant_id.as_str()) {
+        Err(event_not_found())?
+    }
+
+    let business_profile_id = event_to_retry.business_profile_id.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "Event is not associated with a business profile".to_string(),
+        },
+    )?;
+
+    let request_content = event_to_retry
+        .request
+        .clone()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The request sent for this event is not available for redelivery".to_string(),
+        })?
+        .peek()
+        .clone()
+        .parse_value::<api::OutgoingWebhookRequestContent>("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the stored outgoing webhook request")?;
+
+    let business_profile = store
+        .find_business_profile_by_profile_id(&business_profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: business_profile_id.clone(),
+        })?;
+
+    let new_event = storage::EventNew {
+        event_id: utils::generate_id(consts::ID_LENGTH, "evt"),
+        event_type: event_to_retry.event_type,
+        event_class: event_to_retry.event_class,
+        is_webhook_notified: false,
+        intent_reference_id: event_to_retry.intent_reference_id.clone(),
+        primary_object_id: event_to_retry.primary_object_id.clone(),
+        primary_object_type: event_to_retry.primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        business_profile_id: Some(business_profile_id),
+        initial_attempt_id: Some(
+            event_to_retry
+                .initial_attempt_id
+                .clone()
+                .unwrap_or_else(|| event_to_retry.event_id.clone()),
+        ),
+        delivery_attempt: Some(enums::WebhookDeliveryAttempt::ManualRetry),
+    };
+
+    let event = store
+        .insert_event(new_event)
+        .await
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to insert event in events table")?;
+
+    // The outcome of the delivery is recorded on the event, and is returned to the merchant as
+    // part of the response
+    super::send_outgoing_webhook_request(&state, &business_profile, &event, request_content)
+        .await
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                "Manual redelivery of outgoing webhook {} failed",
+                event.event_id
+            )
+        })
+        .ok();
+
+    let updated_event = store
+        .find_event_by_event_id(&event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the redelivered event")?;
+
+    Ok(ApplicationResponse::Json(get_event_response(
+        updated_event,
+    )?))
+}
+
+fn get_event_response(
+    event: storage::Event,
+) -> errors::RouterResult<api_webhook_events::EventResponse> {
+    let request = event
+        .request
+        .map(|request| {
+            request
+                .peek()
+                .clone()
+                .parse_value::<api::OutgoingWebhookRequestContent>("OutgoingWebhookRequestContent")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the stored outgoing webhook request")?;
+
+    let response = event.delivery_latency_ms.map(|latency_ms| {
+        api_webhook_events::OutgoingWebhookResponseContent {
+            status_code: event
+                .response_status_code
+                .and_then(|status_code| u16::try_from(status_code).ok()),
+            body: event.response_body,
+            latency_ms: Some(latency_ms),
+        }
+    });
+
+    Ok(api_webhook_events::EventResponse {
+        initial_attempt_id: event
+            .initial_attempt_id
+            .unwrap_or_else(|| event.event_id.clone()),
+        event_id: event.event_id,
+        merchant_id: event.merchant_id.unwrap_or_default(),
+        profile_id: event.business_profile_id.unwrap_or_default(),
+        object_id: event.primary_object_id,
+        event_type: event.event_type,
+        event_class: event.event_class,
+        is_delivery_successful: event.is_webhook_notified,
+        delivery_attempt: event.delivery_attempt,
+        created: event.created_at,
+        request,
+        response,
+    })
+}
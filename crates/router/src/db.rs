@@ -15,12 +15,14 @@ pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
 mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
+pub mod mandate_usage;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
@@ -28,10 +30,12 @@ pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
@@ -80,9 +84,11 @@ pub trait StorageInterface:
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
     + file::FileMetadataInterface
+    + forex_rate_snapshot::ForexRateSnapshotInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
+    + mandate_usage::MandateUsageInterface
     + merchant_account::MerchantAccountInterface
     + merchant_connector_account::ConnectorAccessToken
     + merchant_connector_account::MerchantConnectorAccountInterface
@@ -93,8 +99,10 @@ pub trait StorageInterface:
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + scheduler::SchedulerInterface
     + payout_attempt::PayoutAttemptInterface
+    + payout_batch::PayoutBatchInterface
     + payouts::PayoutsInterface
     + refund::RefundInterface
+    + subscription::SubscriptionInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
@@ -1,8 +1,11 @@
 use error_stack::IntoReport;
 use router_env::{instrument, tracing};
-use storage_impl::MockDb;
+use storage_impl::{
+    redis::cache::{CacheKind, CONFIG_CACHE},
+    MockDb,
+};
 
-use super::Store;
+use super::{cache, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
@@ -10,6 +13,16 @@ use crate::{
     types::storage,
 };
 
+/// Kinds of the entries which are matched as ranges, rather than looked up by hash
+const RANGE_DATA_KINDS: [common_enums::BlocklistDataKind; 2] = [
+    common_enums::BlocklistDataKind::CardBinRange,
+    common_enums::BlocklistDataKind::IpRange,
+];
+
+fn get_blocklist_ranges_cache_key(merchant_id: &str) -> String {
+    format!("blocklist_ranges_{merchant_id}")
+}
+
 #[async_trait::async_trait]
 pub trait BlocklistInterface {
     async fn insert_blocklist_entry(
@@ -41,6 +54,12 @@ pub trait BlocklistInterface {
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    /// Lists the range entries of the merchant, which are checked on every confirmation
+    async fn list_blocklist_range_entries_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -50,12 +69,25 @@ impl BlocklistInterface for Store {
         &self,
         pm_blocklist: storage::BlocklistNew,
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        pm_blocklist
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let cache_key = RANGE_DATA_KINDS
+            .contains(&pm_blocklist.data_kind)
+            .then(|| get_blocklist_ranges_cache_key(&pm_blocklist.merchant_id));
+        let insert_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            pm_blocklist
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        match cache_key {
+            Some(cache_key) => {
+                cache::publish_and_redact(self, CacheKind::Config(cache_key.into()), insert_func)
+                    .await
+            }
+            None => insert_func().await,
+        }
     }
 
     async fn find_blocklist_entry_by_merchant_id_fingerprint_id(
@@ -101,16 +133,60 @@ impl BlocklistInterface for Store {
         .into_report()
     }
 
+    async fn list_blocklist_range_entries_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        // The ranges are only cached in memory, the id of an entry is not serialized and would be
+        // lost in redis
+        let cache_key = get_blocklist_ranges_cache_key(merchant_id);
+        if let Some(entries) = CONFIG_CACHE
+            .get_val::<Vec<storage::Blocklist>>(&cache_key)
+            .await
+        {
+            return Ok(entries);
+        }
+
+        let conn = connection::pg_connection_read(self).await?;
+        let entries = storage::Blocklist::list_by_merchant_id_data_kinds(
+            &conn,
+            merchant_id,
+            RANGE_DATA_KINDS.to_vec(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+
+        CONFIG_CACHE.push(cache_key, entries.clone()).await;
+        Ok(entries)
+    }
+
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
         merchant_id: &str,
         fingerprint_id: &str,
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
         let conn = connection::pg_connection_write(self).await?;
-        storage::Blocklist::delete_by_merchant_id_fingerprint_id(&conn, merchant_id, fingerprint_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let blocklist_entry = storage::Blocklist::delete_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+
+        if RANGE_DATA_KINDS.contains(&blocklist_entry.data_kind) {
+            cache::publish_into_redact_channel(
+                self,
+                [CacheKind::Config(
+                    get_blocklist_ranges_cache_key(merchant_id).into(),
+                )],
+            )
+            .await?;
+        }
+
+        Ok(blocklist_entry)
     }
 }
 
@@ -149,6 +225,13 @@ impl BlocklistInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_blocklist_range_entries_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
         _merchant_id: &str,
@@ -200,6 +283,15 @@ impl BlocklistInterface for KafkaStore {
             .await
     }
 
+    async fn list_blocklist_range_entries_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .list_blocklist_range_entries_by_merchant_id(merchant_id)
+            .await
+    }
+
     async fn list_blocklist_entries_by_merchant_id(
         &self,
         merchant_id: &str,
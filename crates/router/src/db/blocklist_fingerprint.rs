@@ -22,6 +22,12 @@ pub trait BlocklistFingerprintInterface {
         merchant_id: &str,
         fingerprint_id: &str,
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
+
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -54,6 +60,22 @@ impl BlocklistFingerprintInterface for Store {
         .map_err(Into::into)
         .into_report()
     }
+
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BlocklistFingerprint::delete_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -73,6 +95,14 @@ impl BlocklistFingerprintInterface for MockDb {
     ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        _merchant_id: &str,
+        _fingerprint_id: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -96,4 +126,14 @@ impl BlocklistFingerprintInterface for KafkaStore {
             .find_blocklist_fingerprint_by_merchant_id_fingerprint_id(merchant_id, fingerprint)
             .await
     }
+
+    async fn delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &str,
+        fingerprint: &str,
+    ) -> CustomResult<storage::BlocklistFingerprint, errors::StorageError> {
+        self.diesel_store
+            .delete_blocklist_fingerprint_by_merchant_id_fingerprint_id(merchant_id, fingerprint)
+            .await
+    }
 }
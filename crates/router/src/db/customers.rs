@@ -1,29 +1,26 @@
 use common_utils::ext_traits::AsyncExt;
-use error_stack::{IntoReport, ResultExt};
+use diesel_models::enums::MerchantStorageScheme;
+use error_stack::ResultExt;
 use futures::future::try_join_all;
-use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
-    core::{
-        customers::REDACTED,
-        errors::{self, CustomResult},
-    },
+    core::errors::{self, CustomResult},
     types::{
         domain::{
             self,
             behaviour::{Conversion, ReverseConversion},
         },
-        storage,
+        storage as storage_types,
     },
 };
 
 #[async_trait::async_trait]
 pub trait CustomerInterface
 where
-    domain::Customer: Conversion<DstType = storage::Customer, NewDstType = storage::CustomerNew>,
+    domain::Customer:
+        Conversion<DstType = storage_types::Customer, NewDstType = storage_types::CustomerNew>,
 {
     async fn delete_customer_by_customer_id_merchant_id(
         &self,
@@ -36,14 +33,16 @@ where
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, errors::StorageError>;
 
     async fn update_customer_by_customer_id_merchant_id(
         &self,
         customer_id: String,
         merchant_id: String,
-        customer: storage::CustomerUpdate,
+        customer: storage_types::CustomerUpdate,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
 
     async fn find_customer_by_customer_id_merchant_id(
@@ -51,93 +50,180 @@ where
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
 
     async fn list_customers_by_merchant_id(
         &self,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError>;
 
     async fn insert_customer(
         &self,
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl CustomerInterface for Store {
-    async fn find_customer_optional_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        let maybe_customer: Option<domain::Customer> =
-            storage::Customer::find_optional_by_customer_id_merchant_id(
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use common_utils::ext_traits::AsyncExt;
+    use diesel_models::enums::MerchantStorageScheme;
+    use error_stack::{IntoReport, ResultExt};
+    use futures::future::try_join_all;
+    use masking::PeekInterface;
+    use router_env::{instrument, tracing};
+
+    use super::CustomerInterface;
+    use crate::{
+        connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage,
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let maybe_customer: Option<domain::Customer> =
+                storage::Customer::find_optional_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()?
+                .async_map(|c| async {
+                    c.convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+                .transpose()?;
+            maybe_customer.map_or(Ok(None), |customer| {
+                // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
+                // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+                match customer.name {
+                    Some(ref name) if name.peek() == REDACTED => {
+                        Err(errors::StorageError::CustomerRedacted)?
+                    }
+                    _ => Ok(Some(customer)),
+                }
+            })
+        }
+
+        #[instrument(skip_all)]
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage::CustomerUpdate,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Customer::update_by_customer_id_merchant_id(
                 &conn,
                 customer_id,
                 merchant_id,
+                customer.into(),
             )
             .await
             .map_err(Into::into)
-            .into_report()?
-            .async_map(|c| async {
+            .into_report()
+            .async_and_then(|c| async {
                 c.convert(key_store.key.get_inner())
                     .await
                     .change_context(errors::StorageError::DecryptionError)
             })
             .await
-            .transpose()?;
-        maybe_customer.map_or(Ok(None), |customer| {
-            // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
-            // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+        }
+
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let customer: domain::Customer =
+                storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                    .async_and_then(|c| async {
+                        c.convert(key_store.key.get_inner())
+                            .await
+                            .change_context(errors::StorageError::DecryptionError)
+                    })
+                    .await?;
             match customer.name {
                 Some(ref name) if name.peek() == REDACTED => {
                     Err(errors::StorageError::CustomerRedacted)?
                 }
-                _ => Ok(Some(customer)),
+                _ => Ok(customer),
             }
-        })
-    }
+        }
 
-    #[instrument(skip_all)]
-    async fn update_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: String,
-        merchant_id: String,
-        customer: storage::CustomerUpdate,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Customer, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Customer::update_by_customer_id_merchant_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            customer.into(),
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-        .async_and_then(|c| async {
-            c.convert(key_store.key.get_inner())
+        async fn list_customers_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+
+            let encrypted_customers = storage::Customer::list_by_merchant_id(&conn, merchant_id)
                 .await
-                .change_context(errors::StorageError::DecryptionError)
-        })
-        .await
-    }
+                .map_err(Into::into)
+                .into_report()?;
 
-    async fn find_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Customer, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        let customer: domain::Customer =
-            storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+            let customers = try_join_all(encrypted_customers.into_iter().map(
+                |encrypted_customer| async {
+                    encrypted_customer
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                },
+            ))
+            .await?;
+
+            Ok(customers)
+        }
+
+        async fn insert_customer(
+            &self,
+            customer_data: domain::Customer,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            customer_data
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?
+                .insert(&conn)
                 .await
                 .map_err(Into::into)
                 .into_report()
@@ -146,72 +232,379 @@ impl CustomerInterface for Store {
                         .await
                         .change_context(errors::StorageError::DecryptionError)
                 })
-                .await?;
-        match customer.name {
-            Some(ref name) if name.peek() == REDACTED => {
-                Err(errors::StorageError::CustomerRedacted)?
-            }
-            _ => Ok(customer),
+                .await
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
         }
     }
+}
 
-    async fn list_customers_by_merchant_id(
-        &self,
-        merchant_id: &str,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::ext_traits::AsyncExt;
+    use diesel_models::enums::MerchantStorageScheme;
+    use error_stack::{IntoReport, ResultExt};
+    use futures::future::try_join_all;
+    use masking::PeekInterface;
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{self, kv_wrapper, KvOperation, RedisConnInterface};
 
-        let encrypted_customers = storage::Customer::list_by_merchant_id(&conn, merchant_id)
-            .await
-            .map_err(Into::into)
-            .into_report()?;
+    use super::CustomerInterface;
+    use crate::{
+        connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, utils::RedisErrorExt, CustomResult},
+        },
+        services::Store,
+        types::{
+            domain::{
+                self,
+                behaviour::{Conversion, ReverseConversion},
+            },
+            storage::{self, kv},
+        },
+        utils::db_utils,
+    };
 
-        let customers = try_join_all(encrypted_customers.into_iter().map(
-            |encrypted_customer| async {
-                encrypted_customer
-                    .convert(key_store.key.get_inner())
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Customer::find_optional_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            let maybe_customer = match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_cust_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<storage::Customer>::HGet(&field), key)
+                                .await?
+                                .try_into_hget()
+                                .map(Some)
+                        },
+                        database_call,
+                    ))
                     .await
-                    .change_context(errors::StorageError::DecryptionError)
-            },
-        ))
-        .await?;
+                }
+            }?;
+            let maybe_customer: Option<domain::Customer> = maybe_customer
+                .async_map(|c| async {
+                    c.convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+                .transpose()?;
+            maybe_customer.map_or(Ok(None), |customer| {
+                // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
+                // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+                match customer.name {
+                    Some(ref name) if name.peek() == REDACTED => {
+                        Err(errors::StorageError::CustomerRedacted)?
+                    }
+                    _ => Ok(Some(customer)),
+                }
+            })
+        }
 
-        Ok(customers)
-    }
+        #[instrument(skip_all)]
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage::CustomerUpdate,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let updated_customer = match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage::Customer::update_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                        customer.into(),
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_cust_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    let database_call = || async {
+                        let conn = connection::pg_connection_read(self).await?;
+                        storage::Customer::find_by_customer_id_merchant_id(
+                            &conn,
+                            &customer_id,
+                            &merchant_id,
+                        )
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                    };
+                    let origin_customer = Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<storage::Customer>::HGet(&field), &key)
+                                .await?
+                                .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await?;
 
-    async fn insert_customer(
-        &self,
-        customer_data: domain::Customer,
-        key_store: &domain::MerchantKeyStore,
-    ) -> CustomResult<domain::Customer, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        customer_data
-            .construct_new()
-            .await
-            .change_context(errors::StorageError::EncryptionError)?
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
-            .into_report()
-            .async_and_then(|c| async {
-                c.convert(key_store.key.get_inner())
+                    let customer_update = storage::CustomerUpdateInternal::from(customer);
+                    let updated_customer = customer_update
+                        .clone()
+                        .apply_changeset(origin_customer.clone());
+
+                    let redis_value = serde_json::to_string(&updated_customer)
+                        .into_report()
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::CustomerUpdate(kv::CustomerUpdateMems {
+                                orig: origin_customer,
+                                update_data: customer_update,
+                            }),
+                        },
+                    };
+
+                    kv_store::add_merchant_customer_key(self, merchant_id, &key)
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage::Customer>((&field, redis_value), redis_entry),
+                        &key,
+                    )
                     .await
-                    .change_context(errors::StorageError::DecryptionError)
-            })
-            .await
-    }
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
 
-    async fn delete_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<bool, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+                    Ok(updated_customer)
+                }
+            }?;
+
+            updated_customer
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            let customer = match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_cust_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(self, KvOperation::<storage::Customer>::HGet(&field), key)
+                                .await?
+                                .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }?;
+            let customer: domain::Customer = customer
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            match customer.name {
+                Some(ref name) if name.peek() == REDACTED => {
+                    Err(errors::StorageError::CustomerRedacted)?
+                }
+                _ => Ok(customer),
+            }
+        }
+
+        async fn list_customers_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Customer::list_by_merchant_id(&conn, merchant_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            let encrypted_customers = match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let redis_conn = self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+                    let mut encrypted_customers =
+                        Box::pin(db_utils::find_all_combined_kv_database(
+                            db_utils::find_all_kv_entries_of_merchant(
+                                &redis_conn,
+                                merchant_id,
+                                "cust_*",
+                            ),
+                            database_call,
+                            |customer: &storage::Customer| customer.customer_id.as_str(),
+                        ))
+                        .await?;
+                    encrypted_customers.sort_by_key(|customer| customer.created_at);
+                    Ok(encrypted_customers)
+                }
+            }?;
+
+            let customers = try_join_all(encrypted_customers.into_iter().map(
+                |encrypted_customer| async {
+                    encrypted_customer
+                        .convert(key_store.key.get_inner())
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                },
+            ))
+            .await?;
+
+            Ok(customers)
+        }
+
+        async fn insert_customer(
+            &self,
+            customer_data: domain::Customer,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::Customer, errors::StorageError> {
+            let new_customer = customer_data
+                .construct_new()
+                .await
+                .change_context(errors::StorageError::EncryptionError)?;
+            let created_customer = match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    new_customer
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!(
+                        "mid_{}_cust_{}",
+                        new_customer.merchant_id, new_customer.customer_id
+                    );
+                    let field = format!("cust_{}", new_customer.customer_id);
+                    let created_customer = storage::Customer {
+                        id: 0i32,
+                        customer_id: new_customer.customer_id.clone(),
+                        merchant_id: new_customer.merchant_id.clone(),
+                        name: new_customer.name.clone(),
+                        email: new_customer.email.clone(),
+                        phone: new_customer.phone.clone(),
+                        phone_country_code: new_customer.phone_country_code.clone(),
+                        description: new_customer.description.clone(),
+                        created_at: new_customer.created_at,
+                        metadata: new_customer.metadata.clone(),
+                        connector_customer: new_customer.connector_customer.clone(),
+                        modified_at: new_customer.modified_at,
+                        address_id: new_customer.address_id.clone(),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Customer(new_customer),
+                        },
+                    };
+
+                    kv_store::add_merchant_customer_key(self, &new_customer.merchant_id, &key)
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                    match kv_wrapper::<storage::Customer, _, _>(
+                        self,
+                        KvOperation::HSetNx::<storage::Customer>(
+                            &field,
+                            &created_customer,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "customer",
+                            key: Some(created_customer.customer_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_customer),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }?;
+
+            created_customer
+                .convert(key_store.key.get_inner())
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
 }
 
@@ -223,6 +616,7 @@ impl CustomerInterface for MockDb {
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
         let customers = self.customers.lock().await;
         let customer = customers
@@ -245,6 +639,7 @@ impl CustomerInterface for MockDb {
         &self,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
         let customers = self.customers.lock().await;
 
@@ -270,8 +665,9 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: String,
         _merchant_id: String,
-        _customer: storage::CustomerUpdate,
+        _customer: storage_types::CustomerUpdate,
         _key_store: &domain::MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -282,6 +678,7 @@ impl CustomerInterface for MockDb {
         _customer_id: &str,
         _merchant_id: &str,
         _key_store: &domain::MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -292,6 +689,7 @@ impl CustomerInterface for MockDb {
         &self,
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         let mut customers = self.customers.lock().await;
 
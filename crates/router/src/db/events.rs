@@ -18,6 +18,18 @@ pub trait EventInterface {
         event_id: String,
         event: storage::EventUpdate,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn list_events_by_business_profile_id_constraints(
+        &self,
+        business_profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -40,6 +52,37 @@ impl EventInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn list_events_by_business_profile_id_constraints(
+        &self,
+        business_profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_by_business_profile_id_constraints(
+            &conn,
+            business_profile_id,
+            created_after,
+            created_before,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -65,6 +108,14 @@ impl EventInterface for MockDb {
             primary_object_id: event.primary_object_id,
             primary_object_type: event.primary_object_type,
             created_at: now,
+            merchant_id: event.merchant_id,
+            business_profile_id: event.business_profile_id,
+            initial_attempt_id: event.initial_attempt_id,
+            delivery_attempt: event.delivery_attempt,
+            request: None,
+            response_status_code: None,
+            response_body: None,
+            delivery_latency_ms: None,
         };
 
         locked_events.push(stored_event.clone());
@@ -90,10 +141,73 @@ impl EventInterface for MockDb {
                     event_to_update.is_webhook_notified = is_webhook_notified;
                 }
             }
+            storage::EventUpdate::UpdateDeliveryDetails {
+                is_webhook_notified,
+                request,
+                response_status_code,
+                response_body,
+                delivery_latency_ms,
+            } => {
+                event_to_update.is_webhook_notified = is_webhook_notified;
+                event_to_update.request = Some(request);
+                event_to_update.response_status_code = response_status_code;
+                event_to_update.response_body = response_body;
+                event_to_update.delivery_latency_ms = Some(delivery_latency_ms);
+            }
         }
 
         Ok(event_to_update.clone())
     }
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        locked_events
+            .iter()
+            .find(|event| event.event_id == event_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No event available with event_id = {event_id}"
+                ))
+                .into(),
+            )
+    }
+    async fn list_events_by_business_profile_id_constraints(
+        &self,
+        business_profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let offset = offset
+            .unwrap_or(0)
+            .try_into()
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?;
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.business_profile_id.as_deref() == Some(business_profile_id)
+                    && event.created_at >= created_after
+                    && event.created_at <= created_before
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(events.into_iter().skip(offset).take(limit).collect())
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +236,10 @@ mod tests {
                 intent_reference_id: Some("test".into()),
                 primary_object_id: "primary_object_tet".into(),
                 primary_object_type: enums::EventObjectType::PaymentDetails,
+                merchant_id: Some("merchant_1".into()),
+                business_profile_id: Some("profile_1".into()),
+                initial_attempt_id: Some("test_event_id".into()),
+                delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
             })
             .await
             .unwrap();
@@ -141,5 +259,26 @@ mod tests {
         assert!(updated_event.is_webhook_notified);
         assert_eq!(updated_event.primary_object_id, "primary_object_tet");
         assert_eq!(updated_event.id, 0);
+
+        let found_event = mockdb
+            .find_event_by_event_id("test_event_id")
+            .await
+            .unwrap();
+
+        assert_eq!(found_event.event_id, "test_event_id");
+
+        let listed_events = mockdb
+            .list_events_by_business_profile_id_constraints(
+                "profile_1",
+                time::PrimitiveDateTime::MIN,
+                common_utils::date_time::now(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(listed_events.len(), 1);
+        assert_eq!(listed_events[0].event_id, "test_event_id");
     }
 }
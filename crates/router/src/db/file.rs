@@ -84,19 +84,46 @@ impl FileMetadataInterface for Store {
 impl FileMetadataInterface for MockDb {
     async fn insert_file_metadata(
         &self,
-        _file: storage::FileMetadataNew,
+        file: storage::FileMetadataNew,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let file_metadata = storage::FileMetadata {
+            file_id: file.file_id,
+            merchant_id: file.merchant_id,
+            file_name: file.file_name,
+            file_size: file.file_size,
+            file_type: file.file_type,
+            provider_file_id: file.provider_file_id,
+            file_upload_provider: file.file_upload_provider,
+            available: file.available,
+            created_at: common_utils::date_time::now(),
+            connector_label: file.connector_label,
+            profile_id: file.profile_id,
+            merchant_connector_id: file.merchant_connector_id,
+            purpose: file.purpose,
+        };
+        self.file_metadata.lock().await.push(file_metadata.clone());
+        Ok(file_metadata)
     }
 
     async fn find_file_metadata_by_merchant_id_file_id(
         &self,
-        _merchant_id: &str,
-        _file_id: &str,
+        merchant_id: &str,
+        file_id: &str,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.file_metadata
+            .lock()
+            .await
+            .iter()
+            .find(|file_metadata| {
+                file_metadata.merchant_id == merchant_id && file_metadata.file_id == file_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No file metadata found for merchant_id = {merchant_id} and file_id = {file_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn delete_file_metadata_by_merchant_id_file_id(
@@ -0,0 +1,92 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ForexRateSnapshotInterface {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+
+    async fn find_forex_rate_snapshot_on_or_before(
+        &self,
+        provider: &str,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        snapshot
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_forex_rate_snapshot_on_or_before(
+        &self,
+        provider: &str,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ForexRateSnapshot::find_latest_on_or_before(&conn, provider, rate_date)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for MockDb {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        _snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_forex_rate_snapshot_on_or_before(
+        &self,
+        _provider: &str,
+        _rate_date: time::Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for KafkaStore {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store.insert_forex_rate_snapshot(snapshot).await
+    }
+
+    async fn find_forex_rate_snapshot_on_or_before(
+        &self,
+        provider: &str,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store
+            .find_forex_rate_snapshot_on_or_before(provider, rate_date)
+            .await
+    }
+}
@@ -316,9 +316,15 @@ impl CustomerInterface for KafkaStore {
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<domain::Customer>, errors::StorageError> {
         self.diesel_store
-            .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
+            .find_customer_optional_by_customer_id_merchant_id(
+                customer_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
             .await
     }
 
@@ -328,6 +334,7 @@ impl CustomerInterface for KafkaStore {
         merchant_id: String,
         customer: storage::CustomerUpdate,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         self.diesel_store
             .update_customer_by_customer_id_merchant_id(
@@ -335,6 +342,7 @@ impl CustomerInterface for KafkaStore {
                 merchant_id,
                 customer,
                 key_store,
+                storage_scheme,
             )
             .await
     }
@@ -343,9 +351,10 @@ impl CustomerInterface for KafkaStore {
         &self,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
         self.diesel_store
-            .list_customers_by_merchant_id(merchant_id, key_store)
+            .list_customers_by_merchant_id(merchant_id, key_store, storage_scheme)
             .await
     }
 
@@ -354,9 +363,15 @@ impl CustomerInterface for KafkaStore {
         customer_id: &str,
         merchant_id: &str,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         self.diesel_store
-            .find_customer_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
+            .find_customer_by_customer_id_merchant_id(
+                customer_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
             .await
     }
 
@@ -364,9 +379,10 @@ impl CustomerInterface for KafkaStore {
         &self,
         customer_data: domain::Customer,
         key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
         self.diesel_store
-            .insert_customer(customer_data, key_store)
+            .insert_customer(customer_data, key_store, storage_scheme)
             .await
     }
 }
@@ -377,7 +393,12 @@ impl DisputeInterface for KafkaStore {
         &self,
         dispute: storage::DisputeNew,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        self.diesel_store.insert_dispute(dispute).await
+        let dispute = self.diesel_store.insert_dispute(dispute).await?;
+
+        if let Err(er) = self.kafka_producer.log_dispute(&dispute, None).await {
+            logger::error!(message="Failed to insert analytics event for Dispute Create {dispute?}", error_message=?er);
+        }
+        Ok(dispute)
     }
 
     async fn find_by_merchant_id_payment_id_connector_dispute_id(
@@ -420,7 +441,15 @@ impl DisputeInterface for KafkaStore {
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        self.diesel_store.update_dispute(this, dispute).await
+        let dispute = self
+            .diesel_store
+            .update_dispute(this.clone(), dispute)
+            .await?;
+
+        if let Err(er) = self.kafka_producer.log_dispute(&dispute, Some(this)).await {
+            logger::error!(message="Failed to insert analytics event for Dispute Update {dispute?}", error_message=?er);
+        }
+        Ok(dispute)
     }
 
     async fn find_disputes_by_merchant_id_payment_id(
@@ -473,6 +502,32 @@ impl EventInterface for KafkaStore {
     ) -> CustomResult<storage::Event, errors::StorageError> {
         self.diesel_store.update_event(event_id, event).await
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        self.diesel_store.find_event_by_event_id(event_id).await
+    }
+
+    async fn list_events_by_business_profile_id_constraints(
+        &self,
+        business_profile_id: &str,
+        created_after: time::PrimitiveDateTime,
+        created_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_events_by_business_profile_id_constraints(
+                business_profile_id,
+                created_after,
+                created_before,
+                limit,
+                offset,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -505,9 +560,10 @@ impl MandateInterface for KafkaStore {
         &self,
         merchant_id: &str,
         mandate_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
         self.diesel_store
-            .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+            .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
             .await
     }
 
@@ -515,9 +571,14 @@ impl MandateInterface for KafkaStore {
         &self,
         merchant_id: &str,
         connector_mandate_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
         self.diesel_store
-            .find_mandate_by_merchant_id_connector_mandate_id(merchant_id, connector_mandate_id)
+            .find_mandate_by_merchant_id_connector_mandate_id(
+                merchant_id,
+                connector_mandate_id,
+                storage_scheme,
+            )
             .await
     }
 
@@ -525,9 +586,10 @@ impl MandateInterface for KafkaStore {
         &self,
         merchant_id: &str,
         customer_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
         self.diesel_store
-            .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+            .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id, storage_scheme)
             .await
     }
 
@@ -536,9 +598,27 @@ impl MandateInterface for KafkaStore {
         merchant_id: &str,
         mandate_id: &str,
         mandate: storage::MandateUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
         self.diesel_store
-            .update_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, mandate)
+            .update_mandate_by_merchant_id_mandate_id(
+                merchant_id,
+                mandate_id,
+                mandate,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn increment_mandate_amount_captured(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .increment_mandate_amount_captured(merchant_id, mandate_id, amount, storage_scheme)
             .await
     }
 
@@ -546,17 +626,21 @@ impl MandateInterface for KafkaStore {
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
         self.diesel_store
-            .find_mandates_by_merchant_id(merchant_id, mandate_constraints)
+            .find_mandates_by_merchant_id(merchant_id, mandate_constraints, storage_scheme)
             .await
     }
 
     async fn insert_mandate(
         &self,
         mandate: storage::MandateNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        self.diesel_store.insert_mandate(mandate).await
+        self.diesel_store
+            .insert_mandate(mandate, storage_scheme)
+            .await
     }
 }
 
@@ -1236,9 +1320,10 @@ impl PaymentMethodInterface for KafkaStore {
     async fn find_payment_method(
         &self,
         payment_method_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
         self.diesel_store
-            .find_payment_method(payment_method_id)
+            .find_payment_method(payment_method_id, storage_scheme)
             .await
     }
 
@@ -1246,26 +1331,35 @@ impl PaymentMethodInterface for KafkaStore {
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
         self.diesel_store
-            .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id)
+            .find_payment_method_by_customer_id_merchant_id_list(
+                customer_id,
+                merchant_id,
+                storage_scheme,
+            )
             .await
     }
 
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        self.diesel_store.insert_payment_method(m).await
+        self.diesel_store
+            .insert_payment_method(m, storage_scheme)
+            .await
     }
 
     async fn update_payment_method(
         &self,
         payment_method: storage::PaymentMethod,
         payment_method_update: storage::PaymentMethodUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
         self.diesel_store
-            .update_payment_method(payment_method, payment_method_update)
+            .update_payment_method(payment_method, payment_method_update, storage_scheme)
             .await
     }
 
@@ -1273,9 +1367,14 @@ impl PaymentMethodInterface for KafkaStore {
         &self,
         merchant_id: &str,
         payment_method_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
         self.diesel_store
-            .delete_payment_method_by_merchant_id_payment_method_id(merchant_id, payment_method_id)
+            .delete_payment_method_by_merchant_id_payment_method_id(
+                merchant_id,
+                payment_method_id,
+                storage_scheme,
+            )
             .await
     }
 }
@@ -1323,6 +1422,16 @@ impl PayoutsInterface for KafkaStore {
             .await
     }
 
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &str,
+        payout_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::StorageError> {
+        self.diesel_store
+            .find_payouts_by_merchant_id_payout_ids(merchant_id, payout_ids)
+            .await
+    }
+
     async fn update_payout_by_merchant_id_payout_id(
         &self,
         merchant_id: &str,
@@ -1340,6 +1449,39 @@ impl PayoutsInterface for KafkaStore {
     ) -> CustomResult<storage::Payouts, errors::StorageError> {
         self.diesel_store.insert_payout(payout).await
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        self.diesel_store
+            .filter_payouts_and_attempts(merchant_id, constraints)
+            .await
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_total_count_of_filtered_payouts(merchant_id, constraints)
+            .await
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        self.diesel_store
+            .filter_payouts_by_meta_constraints(merchant_id, time_range)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
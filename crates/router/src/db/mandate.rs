@@ -1,10 +1,10 @@
+use diesel_models::enums::MerchantStorageScheme;
 use error_stack::{IntoReport, ResultExt};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::{errors, errors::CustomResult},
-    types::storage::{self, MandateDbExt},
+    types::storage as storage_types,
 };
 
 #[async_trait::async_trait]
@@ -13,116 +13,656 @@ pub trait MandateInterface {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
     async fn find_mandate_by_merchant_id_connector_mandate_id(
         &self,
         merchant_id: &str,
         connector_mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 
     async fn find_mandate_by_merchant_id_customer_id(
         &self,
         merchant_id: &str,
         customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
     async fn update_mandate_by_merchant_id_mandate_id(
         &self,
         merchant_id: &str,
         mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        mandate: storage_types::MandateUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
+
+    /// Adds `amount` (which may be negative) to the captured amount of the mandate in the
+    /// database in a single statement. Returns `None` if the captured amount would exceed the
+    /// mandate amount.
+    async fn increment_mandate_amount_captured(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Mandate>, errors::StorageError>;
 
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError>;
 
     async fn insert_mandate(
         &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError>;
+        mandate: storage_types::MandateNew,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl MandateInterface for Store {
-    async fn find_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+/// Whether the mandate matches the filters of a mandate list request, the limit is not applied
+fn matches_list_constraints(
+    mandate: &storage_types::Mandate,
+    mandate_constraints: &api_models::mandates::MandateListConstraints,
+) -> bool {
+    let mut checker = true;
+    if let Some(created_time) = mandate_constraints.created_time {
+        checker &= mandate.created_at == created_time;
     }
-
-    async fn find_mandate_by_merchant_id_connector_mandate_id(
-        &self,
-        merchant_id: &str,
-        connector_mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_connector_mandate_id(
-            &conn,
-            merchant_id,
-            connector_mandate_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+    if let Some(created_time_lt) = mandate_constraints.created_time_lt {
+        checker &= mandate.created_at < created_time_lt;
     }
+    if let Some(created_time_gt) = mandate_constraints.created_time_gt {
+        checker &= mandate.created_at > created_time_gt;
+    }
+    if let Some(created_time_lte) = mandate_constraints.created_time_lte {
+        checker &= mandate.created_at <= created_time_lte;
+    }
+    if let Some(created_time_gte) = mandate_constraints.created_time_gte {
+        checker &= mandate.created_at >= created_time_gte;
+    }
+    if let Some(connector) = &mandate_constraints.connector {
+        checker &= mandate.connector == *connector;
+    }
+    if let Some(mandate_status) = mandate_constraints.mandate_status {
+        checker &= mandate.mandate_status == mandate_status;
+    }
+    checker
+}
 
-    async fn find_mandate_by_merchant_id_customer_id(
-        &self,
-        merchant_id: &str,
-        customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use diesel_models::enums::MerchantStorageScheme;
+    use error_stack::IntoReport;
+
+    use super::MandateInterface;
+    use crate::{
+        connection,
+        core::{errors, errors::CustomResult},
+        services::Store,
+        types::storage::{self, MandateDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_mandate_by_merchant_id_connector_mandate_id(
+            &self,
+            merchant_id: &str,
+            connector_mandate_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Mandate::find_by_merchant_id_connector_mandate_id(
+                &conn,
+                merchant_id,
+                connector_mandate_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn update_mandate_by_merchant_id_mandate_id(
-        &self,
-        merchant_id: &str,
-        mandate_id: &str,
-        mandate: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::Mandate::update_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id, mandate)
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate: storage::MandateUpdate,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Mandate::update_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                mandate,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_mandates_by_merchant_id(
-        &self,
-        merchant_id: &str,
-        mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+        async fn increment_mandate_amount_captured(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            amount: i64,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Option<storage::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Mandate::increment_amount_captured_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
+
+        async fn find_mandates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage::MandateNew,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            mandate
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn insert_mandate(
-        &self,
-        mandate: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        mandate
-            .insert(&conn)
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::fallback_reverse_lookup_not_found;
+    use diesel_models::{enums::MerchantStorageScheme, mandate::MandateUpdateInternal};
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::{errors::RedisError, HsetnxReply};
+    use router_env::logger;
+    use storage_impl::redis::kv_store::{self, kv_wrapper, KvOperation, RedisConnInterface};
+
+    use super::MandateInterface;
+    use crate::{
+        connection,
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self, kv, MandateDbExt},
+        utils::db_utils,
+    };
+
+    #[async_trait::async_trait]
+    impl MandateInterface for Store {
+        async fn find_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!("mid_{merchant_id}_mandate_{mandate_id}");
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = &lookup.pk_id;
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage::Mandate>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        async fn find_mandate_by_merchant_id_connector_mandate_id(
+            &self,
+            merchant_id: &str,
+            connector_mandate_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Mandate::find_by_merchant_id_connector_mandate_id(
+                    &conn,
+                    merchant_id,
+                    connector_mandate_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let lookup_id =
+                        format!("mid_{merchant_id}_conn_mandate_{connector_mandate_id}");
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = &lookup.pk_id;
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage::Mandate>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        async fn find_mandate_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_cust_{customer_id}");
+                    Box::pin(db_utils::find_all_combined_kv_database(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage::Mandate>::Scan("mandate_*"),
+                                key,
+                            )
+                            .await?
+                            .try_into_scan()
+                        },
+                        database_call,
+                        |mandate: &storage::Mandate| mandate.mandate_id.as_str(),
+                    ))
+                    .await
+                }
+            }
+        }
+
+        async fn update_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            mandate_update: storage::MandateUpdate,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    storage::Mandate::update_by_merchant_id_mandate_id(
+                        &conn,
+                        merchant_id,
+                        mandate_id,
+                        mandate_update,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let mandate = self
+                        .find_mandate_by_merchant_id_mandate_id(
+                            merchant_id,
+                            mandate_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    let key = format!("mid_{}_cust_{}", merchant_id, mandate.customer_id);
+                    let field = format!("mandate_{}", mandate_id);
+
+                    db_utils::insert_reverse_lookup_if_not_exists(
+                        self,
+                        storage::ReverseLookupNew {
+                            lookup_id: format!("mid_{merchant_id}_mandate_{mandate_id}"),
+                            pk_id: key.clone(),
+                            sk_id: field.clone(),
+                            source: "mandate".to_string(),
+                            updated_by: storage_scheme.to_string(),
+                        },
+                        storage_scheme,
+                    )
+                    .await?;
+
+                    let updated_mandate = MandateUpdateInternal::from(mandate_update.clone())
+                        .apply_changeset(mandate.clone());
+
+                    let redis_value = serde_json::to_string(&updated_mandate)
+                        .into_report()
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::MandateUpdate(kv::MandateUpdateMems {
+                                orig: mandate,
+                                update_data: mandate_update,
+                            }),
+                        },
+                    };
+
+                    kv_store::add_merchant_customer_key(self, merchant_id, &key)
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage::Mandate>((&field, redis_value), redis_entry),
+                        &key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_mandate)
+                }
+            }
+        }
+
+        async fn increment_mandate_amount_captured(
+            &self,
+            merchant_id: &str,
+            mandate_id: &str,
+            amount: i64,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Option<storage::Mandate>, errors::StorageError> {
+            // The increment has to be atomic, so it is always applied on the database. Writing it
+            // through the drainer would replay an absolute value over concurrent increments.
+            let conn = connection::pg_connection_write(self).await?;
+            let updated_mandate =
+                storage::Mandate::increment_amount_captured_by_merchant_id_mandate_id(
+                    &conn,
+                    merchant_id,
+                    mandate_id,
+                    amount,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+
+            if let (MerchantStorageScheme::RedisKv, Some(updated_mandate)) =
+                (storage_scheme, updated_mandate.as_ref())
+            {
+                if let Err(error) = refresh_kv_amount_captured(self, updated_mandate).await {
+                    logger::error!(
+                        ?error,
+                        "Failed to refresh the captured amount of the mandate in redis"
+                    );
+                }
+            }
+
+            Ok(updated_mandate)
+        }
+
+        async fn find_mandates_by_merchant_id(
+            &self,
+            merchant_id: &str,
+            mandate_constraints: api_models::mandates::MandateListConstraints,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+            let limit = mandate_constraints.limit;
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::Mandate::filter_by_constraints(
+                    &conn,
+                    merchant_id,
+                    mandate_constraints.clone(),
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let redis_conn = self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+                    // The redis copies are filtered after merging, a copy which no longer
+                    // matches the constraints has to replace its database entry first
+                    let mut mandates = Box::pin(db_utils::find_all_combined_kv_database(
+                        db_utils::find_all_kv_entries_of_merchant(
+                            &redis_conn,
+                            merchant_id,
+                            "mandate_*",
+                        ),
+                        database_call,
+                        |mandate: &storage::Mandate| mandate.mandate_id.as_str(),
+                    ))
+                    .await?;
+                    mandates.retain(|mandate| {
+                        super::matches_list_constraints(mandate, &mandate_constraints)
+                    });
+                    mandates.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                    if let Some(limit) = limit {
+                        mandates.truncate(usize::try_from(limit).unwrap_or(0));
+                    }
+                    Ok(mandates)
+                }
+            }
+        }
+
+        async fn insert_mandate(
+            &self,
+            mandate: storage::MandateNew,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::Mandate, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    mandate
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{}_cust_{}", mandate.merchant_id, mandate.customer_id);
+                    let field = format!("mandate_{}", mandate.mandate_id);
+
+                    let mut reverse_lookups = vec![storage::ReverseLookupNew {
+                        lookup_id: format!(
+                            "mid_{}_mandate_{}",
+                            mandate.merchant_id, mandate.mandate_id
+                        ),
+                        pk_id: key.clone(),
+                        sk_id: field.clone(),
+                        source: "mandate".to_string(),
+                        updated_by: storage_scheme.to_string(),
+                    }];
+                    if let Some(connector_mandate_id) = &mandate.connector_mandate_id {
+                        reverse_lookups.push(storage::ReverseLookupNew {
+                            lookup_id: format!(
+                                "mid_{}_conn_mandate_{}",
+                                mandate.merchant_id, connector_mandate_id
+                            ),
+                            pk_id: key.clone(),
+                            sk_id: field.clone(),
+                            source: "mandate".to_string(),
+                            updated_by: storage_scheme.to_string(),
+                        });
+                    }
+                    let rev_look = reverse_lookups
+                        .into_iter()
+                        .map(|rev| self.insert_reverse_lookup(rev, storage_scheme));
+
+                    futures::future::try_join_all(rev_look).await?;
+
+                    let created_mandate = storage::Mandate {
+                        id: 0i32,
+                        mandate_id: mandate.mandate_id.clone(),
+                        customer_id: mandate.customer_id.clone(),
+                        merchant_id: mandate.merchant_id.clone(),
+                        payment_method_id: mandate.payment_method_id.clone(),
+                        mandate_status: mandate.mandate_status,
+                        mandate_type: mandate.mandate_type,
+                        customer_accepted_at: mandate.customer_accepted_at,
+                        customer_ip_address: mandate.customer_ip_address.clone(),
+                        customer_user_agent: mandate.customer_user_agent.clone(),
+                        network_transaction_id: mandate.network_transaction_id.clone(),
+                        previous_attempt_id: mandate.previous_attempt_id.clone(),
+                        created_at: mandate
+                            .created_at
+                            .unwrap_or_else(common_utils::date_time::now),
+                        mandate_amount: mandate.mandate_amount,
+                        mandate_currency: mandate.mandate_currency,
+                        amount_captured: mandate.amount_captured,
+                        connector: mandate.connector.clone(),
+                        connector_mandate_id: mandate.connector_mandate_id.clone(),
+                        start_date: mandate.start_date,
+                        end_date: mandate.end_date,
+                        metadata: mandate.metadata.clone(),
+                        connector_mandate_ids: mandate.connector_mandate_ids.clone(),
+                        original_payment_id: mandate.original_payment_id.clone(),
+                        merchant_connector_id: mandate.merchant_connector_id.clone(),
+                        max_transaction_amount: mandate.max_transaction_amount,
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Mandate(mandate),
+                        },
+                    };
+
+                    kv_store::add_merchant_customer_key(self, &created_mandate.merchant_id, &key)
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                    match kv_wrapper::<storage::Mandate, _, _>(
+                        self,
+                        KvOperation::HSetNx::<storage::Mandate>(
+                            &field,
+                            &created_mandate,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "mandate",
+                            key: Some(created_mandate.mandate_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_mandate),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Copies the captured amount of the mandate into its redis entry, if there is one, without
+    /// queueing an update for the drainer
+    async fn refresh_kv_amount_captured(
+        store: &Store,
+        mandate: &storage::Mandate,
+    ) -> CustomResult<(), errors::StorageError> {
+        let key = format!("mid_{}_cust_{}", mandate.merchant_id, mandate.customer_id);
+        let field = format!("mandate_{}", mandate.mandate_id);
+        let redis_conn = store
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+
+        let mut kv_mandate = match redis_conn
+            .get_hash_field_and_deserialize::<storage::Mandate>(&key, &field, "Mandate")
             .await
-            .map_err(Into::into)
+        {
+            Ok(kv_mandate) => kv_mandate,
+            Err(err) if matches!(err.current_context(), RedisError::NotFound) => return Ok(()),
+            Err(err) => return Err(err.to_redis_failed_response(&key)),
+        };
+        kv_mandate.amount_captured = mandate.amount_captured;
+
+        let redis_value = serde_json::to_string(&kv_mandate)
             .into_report()
+            .change_context(errors::StorageError::KVError)?;
+        redis_conn
+            .set_hash_fields(&key, (field.as_str(), redis_value), None)
+            .await
+            .map_err(|err| err.to_redis_failed_response(&key))
     }
 }
 
@@ -132,7 +672,8 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         self.mandates
             .lock()
             .await
@@ -147,7 +688,8 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         connector_mandate_id: &str,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         self.mandates
             .lock()
             .await
@@ -165,7 +707,8 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         customer_id: &str,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         return Ok(self
             .mandates
             .lock()
@@ -182,8 +725,9 @@ impl MandateInterface for MockDb {
         &self,
         merchant_id: &str,
         mandate_id: &str,
-        mandate_update: storage::MandateUpdate,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        mandate_update: storage_types::MandateUpdate,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         let mut mandates = self.mandates.lock().await;
         match mandates
             .iter_mut()
@@ -191,13 +735,13 @@ impl MandateInterface for MockDb {
         {
             Some(mandate) => {
                 match mandate_update {
-                    storage::MandateUpdate::StatusUpdate { mandate_status } => {
+                    storage_types::MandateUpdate::StatusUpdate { mandate_status } => {
                         mandate.mandate_status = mandate_status;
                     }
-                    storage::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
+                    storage_types::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
                         mandate.amount_captured = amount_captured;
                     }
-                    storage::MandateUpdate::ConnectorReferenceUpdate {
+                    storage_types::MandateUpdate::ConnectorReferenceUpdate {
                         connector_mandate_ids,
                     } => {
                         mandate.connector_mandate_ids = connector_mandate_ids;
@@ -211,39 +755,42 @@ impl MandateInterface for MockDb {
         }
     }
 
+    async fn increment_mandate_amount_captured(
+        &self,
+        merchant_id: &str,
+        mandate_id: &str,
+        amount: i64,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Mandate>, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        Ok(mandates
+            .iter_mut()
+            .find(|mandate| mandate.merchant_id == merchant_id && mandate.mandate_id == mandate_id)
+            .and_then(|mandate| {
+                let amount_captured = mandate.amount_captured.unwrap_or(0) + amount;
+                mandate
+                    .mandate_amount
+                    .map_or(true, |mandate_amount| amount_captured <= mandate_amount)
+                    .then(|| {
+                        mandate.amount_captured = Some(amount_captured);
+                        mandate.clone()
+                    })
+            }))
+    }
+
     async fn find_mandates_by_merchant_id(
         &self,
         merchant_id: &str,
         mandate_constraints: api_models::mandates::MandateListConstraints,
-    ) -> CustomResult<Vec<storage::Mandate>, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
         let mandates = self.mandates.lock().await;
         let mandates_iter = mandates.iter().filter(|mandate| {
-            let mut checker = mandate.merchant_id == merchant_id;
-            if let Some(created_time) = mandate_constraints.created_time {
-                checker &= mandate.created_at == created_time;
-            }
-            if let Some(created_time_lt) = mandate_constraints.created_time_lt {
-                checker &= mandate.created_at < created_time_lt;
-            }
-            if let Some(created_time_gt) = mandate_constraints.created_time_gt {
-                checker &= mandate.created_at > created_time_gt;
-            }
-            if let Some(created_time_lte) = mandate_constraints.created_time_lte {
-                checker &= mandate.created_at <= created_time_lte;
-            }
-            if let Some(created_time_gte) = mandate_constraints.created_time_gte {
-                checker &= mandate.created_at >= created_time_gte;
-            }
-            if let Some(connector) = &mandate_constraints.connector {
-                checker &= mandate.connector == *connector;
-            }
-            if let Some(mandate_status) = mandate_constraints.mandate_status {
-                checker &= mandate.mandate_status == mandate_status;
-            }
-            checker
+            mandate.merchant_id == merchant_id
+                && matches_list_constraints(mandate, &mandate_constraints)
         });
 
-        let mandates: Vec<storage::Mandate> = if let Some(limit) = mandate_constraints.limit {
+        let mandates: Vec<storage_types::Mandate> = if let Some(limit) = mandate_constraints.limit {
             #[allow(clippy::as_conversions)]
             mandates_iter
                 .take((if limit < 0 { 0 } else { limit }) as usize)
@@ -257,10 +804,11 @@ impl MandateInterface for MockDb {
 
     async fn insert_mandate(
         &self,
-        mandate_new: storage::MandateNew,
-    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        mandate_new: storage_types::MandateNew,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
         let mut mandates = self.mandates.lock().await;
-        let mandate = storage::Mandate {
+        let mandate = storage_types::Mandate {
             id: mandates
                 .len()
                 .try_into()
@@ -291,6 +839,7 @@ impl MandateInterface for MockDb {
             metadata: mandate_new.metadata,
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
+            max_transaction_amount: mandate_new.max_transaction_amount,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
@@ -0,0 +1,57 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MandateUsageInterface {
+    async fn insert_mandate_usage(
+        &self,
+        mandate_usage: storage::MandateUsageNew,
+    ) -> CustomResult<storage::MandateUsage, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MandateUsageInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_mandate_usage(
+        &self,
+        mandate_usage: storage::MandateUsageNew,
+    ) -> CustomResult<storage::MandateUsage, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        mandate_usage
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl MandateUsageInterface for MockDb {
+    #[instrument(skip_all)]
+    async fn insert_mandate_usage(
+        &self,
+        _mandate_usage: storage::MandateUsageNew,
+    ) -> CustomResult<storage::MandateUsage, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl MandateUsageInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_mandate_usage(
+        &self,
+        mandate_usage: storage::MandateUsageNew,
+    ) -> CustomResult<storage::MandateUsage, errors::StorageError> {
+        self.diesel_store.insert_mandate_usage(mandate_usage).await
+    }
+}
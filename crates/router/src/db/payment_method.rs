@@ -1,11 +1,10 @@
-use diesel_models::payment_method::PaymentMethodUpdateInternal;
+use diesel_models::{enums::MerchantStorageScheme, payment_method::PaymentMethodUpdateInternal};
 use error_stack::{IntoReport, ResultExt};
 
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage as storage_types,
 };
 
 #[async_trait::async_trait]
@@ -13,96 +12,460 @@ pub trait PaymentMethodInterface {
     async fn find_payment_method(
         &self,
         payment_method_id: &str,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
 
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
 
     async fn insert_payment_method(
         &self,
-        payment_method_new: storage::PaymentMethodNew,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+        payment_method_new: storage_types::PaymentMethodNew,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
 
     async fn update_payment_method(
         &self,
-        payment_method: storage::PaymentMethod,
-        payment_method_update: storage::PaymentMethodUpdate,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+        payment_method: storage_types::PaymentMethod,
+        payment_method_update: storage_types::PaymentMethodUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
 
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
         payment_method_id: &str,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl PaymentMethodInterface for Store {
-    async fn find_payment_method(
-        &self,
-        payment_method_id: &str,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::PaymentMethod::find_by_payment_method_id(&conn, payment_method_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use diesel_models::enums::MerchantStorageScheme;
+    use error_stack::IntoReport;
 
-    async fn insert_payment_method(
-        &self,
-        payment_method_new: storage::PaymentMethodNew,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        payment_method_new
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+    use super::PaymentMethodInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage,
+    };
 
-    async fn update_payment_method(
-        &self,
-        payment_method: storage::PaymentMethod,
-        payment_method_update: storage::PaymentMethodUpdate,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        payment_method
-            .update_with_payment_method_id(&conn, payment_method_update)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+    #[async_trait::async_trait]
+    impl PaymentMethodInterface for Store {
+        async fn find_payment_method(
+            &self,
+            payment_method_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::PaymentMethod::find_by_payment_method_id(&conn, payment_method_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-    async fn find_payment_method_by_customer_id_merchant_id_list(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::PaymentMethod::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+        async fn insert_payment_method(
+            &self,
+            payment_method_new: storage::PaymentMethodNew,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            payment_method_new
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_payment_method(
+            &self,
+            payment_method: storage::PaymentMethod,
+            payment_method_update: storage::PaymentMethodUpdate,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            payment_method
+                .update_with_payment_method_id(&conn, payment_method_update)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_payment_method_by_customer_id_merchant_id_list(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage::PaymentMethod::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn delete_payment_method_by_merchant_id_payment_method_id(
+            &self,
+            merchant_id: &str,
+            payment_method_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::PaymentMethod::delete_by_merchant_id_payment_method_id(
+                &conn,
+                merchant_id,
+                payment_method_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
     }
+}
 
-    async fn delete_payment_method_by_merchant_id_payment_method_id(
-        &self,
-        merchant_id: &str,
-        payment_method_id: &str,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        storage::PaymentMethod::delete_by_merchant_id_payment_method_id(
-            &conn,
-            merchant_id,
-            payment_method_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::fallback_reverse_lookup_not_found;
+    use diesel_models::{
+        enums::MerchantStorageScheme, payment_method::PaymentMethodUpdateInternal,
+    };
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+    use storage_impl::redis::kv_store::{
+        kv_wrapper, KvOperation, PartitionKey, RedisConnInterface,
+    };
+
+    use super::PaymentMethodInterface;
+    use crate::{
+        connection,
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self, kv},
+        utils::db_utils,
+    };
+
+    #[async_trait::async_trait]
+    impl PaymentMethodInterface for Store {
+        async fn find_payment_method(
+            &self,
+            payment_method_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::PaymentMethod::find_by_payment_method_id(&conn, payment_method_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let lookup_id = format!("payment_method_{payment_method_id}");
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = &lookup.pk_id;
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage::PaymentMethod>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        async fn insert_payment_method(
+            &self,
+            payment_method_new: storage::PaymentMethodNew,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    payment_method_new
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!(
+                        "mid_{}_cust_{}",
+                        payment_method_new.merchant_id, payment_method_new.customer_id
+                    );
+                    let field =
+                        format!("payment_method_id_{}", payment_method_new.payment_method_id);
+
+                    let reverse_lookup = storage::ReverseLookupNew {
+                        lookup_id: format!(
+                            "payment_method_{}",
+                            payment_method_new.payment_method_id
+                        ),
+                        pk_id: key.clone(),
+                        sk_id: field.clone(),
+                        source: "payment_method".to_string(),
+                        updated_by: storage_scheme.to_string(),
+                    };
+                    self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                        .await?;
+
+                    let created_payment_method = storage::PaymentMethod {
+                        id: 0i32,
+                        customer_id: payment_method_new.customer_id.clone(),
+                        merchant_id: payment_method_new.merchant_id.clone(),
+                        payment_method_id: payment_method_new.payment_method_id.clone(),
+                        accepted_currency: payment_method_new.accepted_currency.clone(),
+                        scheme: payment_method_new.scheme.clone(),
+                        token: payment_method_new.token.clone(),
+                        cardholder_name: payment_method_new.cardholder_name.clone(),
+                        issuer_name: payment_method_new.issuer_name.clone(),
+                        issuer_country: payment_method_new.issuer_country.clone(),
+                        payer_country: payment_method_new.payer_country.clone(),
+                        is_stored: payment_method_new.is_stored,
+                        swift_code: payment_method_new.swift_code.clone(),
+                        direct_debit_token: payment_method_new.direct_debit_token.clone(),
+                        created_at: payment_method_new.created_at,
+                        last_modified: payment_method_new.last_modified,
+                        payment_method: payment_method_new.payment_method,
+                        payment_method_type: payment_method_new.payment_method_type,
+                        payment_method_issuer: payment_method_new.payment_method_issuer.clone(),
+                        payment_method_issuer_code: payment_method_new.payment_method_issuer_code,
+                        metadata: payment_method_new.metadata.clone(),
+                        payment_method_data: payment_method_new.payment_method_data.clone(),
+                    };
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::PaymentMethod(payment_method_new),
+                        },
+                    };
+
+                    match kv_wrapper::<storage::PaymentMethod, _, _>(
+                        self,
+                        KvOperation::HSetNx::<storage::PaymentMethod>(
+                            &field,
+                            &created_payment_method,
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "payment_method",
+                            key: Some(created_payment_method.payment_method_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => Ok(created_payment_method),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn update_payment_method(
+            &self,
+            payment_method: storage::PaymentMethod,
+            payment_method_update: storage::PaymentMethodUpdate,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    payment_method
+                        .update_with_payment_method_id(&conn, payment_method_update)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!(
+                        "mid_{}_cust_{}",
+                        payment_method.merchant_id, payment_method.customer_id
+                    );
+                    let field = format!("payment_method_id_{}", payment_method.payment_method_id);
+
+                    db_utils::insert_reverse_lookup_if_not_exists(
+                        self,
+                        storage::ReverseLookupNew {
+                            lookup_id: format!(
+                                "payment_method_{}",
+                                payment_method.payment_method_id
+                            ),
+                            pk_id: key.clone(),
+                            sk_id: field.clone(),
+                            source: "payment_method".to_string(),
+                            updated_by: storage_scheme.to_string(),
+                        },
+                        storage_scheme,
+                    )
+                    .await?;
+
+                    let updated_payment_method =
+                        PaymentMethodUpdateInternal::from(payment_method_update.clone())
+                            .create_payment_method(payment_method.clone());
+
+                    let redis_value = serde_json::to_string(&updated_payment_method)
+                        .into_report()
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::PaymentMethodUpdate(
+                                kv::PaymentMethodUpdateMems {
+                                    orig: payment_method,
+                                    update_data: payment_method_update,
+                                },
+                            ),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::Hset::<storage::PaymentMethod>(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        &key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_payment_method)
+                }
+            }
+        }
+
+        async fn find_payment_method_by_customer_id_merchant_id_list(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read(self).await?;
+                storage::PaymentMethod::find_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let key = format!("mid_{merchant_id}_cust_{customer_id}");
+                    Box::pin(db_utils::find_all_combined_kv_database(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage::PaymentMethod>::Scan("payment_method_id_*"),
+                                key,
+                            )
+                            .await?
+                            .try_into_scan()
+                        },
+                        database_call,
+                        |payment_method: &storage::PaymentMethod| {
+                            payment_method.payment_method_id.as_str()
+                        },
+                    ))
+                    .await
+                }
+            }
+        }
+
+        async fn delete_payment_method_by_merchant_id_payment_method_id(
+            &self,
+            merchant_id: &str,
+            payment_method_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_write(self).await?;
+                storage::PaymentMethod::delete_by_merchant_id_payment_method_id(
+                    &conn,
+                    merchant_id,
+                    payment_method_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                MerchantStorageScheme::PostgresOnly => database_call().await,
+                MerchantStorageScheme::RedisKv => {
+                    let payment_method = self
+                        .find_payment_method(payment_method_id, storage_scheme)
+                        .await?;
+                    if payment_method.merchant_id != merchant_id {
+                        return Err(errors::StorageError::ValueNotFound(format!(
+                            "Payment method {payment_method_id} not found"
+                        ))
+                        .into());
+                    }
+
+                    // The redis copy is deleted first, it would otherwise be served until it
+                    // expires
+                    let key = format!(
+                        "mid_{}_cust_{}",
+                        payment_method.merchant_id, payment_method.customer_id
+                    );
+                    let field = format!("payment_method_id_{}", payment_method.payment_method_id);
+                    self.get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .delete_hash_field(&key, &field)
+                        .await
+                        .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                    match database_call().await {
+                        // The insert of the payment method is not drained yet, the delete is
+                        // queued behind it so that the drainer doesn't recreate the row
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Delete {
+                                    deletable: kv::Deletable::PaymentMethod(payment_method.clone()),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage::PaymentMethod>(
+                                redis_entry,
+                                PartitionKey::MerchantIdPaymentIdCombination { combination: &key },
+                            )
+                            .await
+                            .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                            Ok(payment_method)
+                        }
+                        result => result,
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -111,7 +474,8 @@ impl PaymentMethodInterface for MockDb {
     async fn find_payment_method(
         &self,
         payment_method_id: &str,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
         let payment_methods = self.payment_methods.lock().await;
         let payment_method = payment_methods
             .iter()
@@ -129,11 +493,12 @@ impl PaymentMethodInterface for MockDb {
 
     async fn insert_payment_method(
         &self,
-        payment_method_new: storage::PaymentMethodNew,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        payment_method_new: storage_types::PaymentMethodNew,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
         let mut payment_methods = self.payment_methods.lock().await;
 
-        let payment_method = storage::PaymentMethod {
+        let payment_method = storage_types::PaymentMethod {
             id: payment_methods
                 .len()
                 .try_into()
@@ -169,9 +534,10 @@ impl PaymentMethodInterface for MockDb {
         &self,
         customer_id: &str,
         merchant_id: &str,
-    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
         let payment_methods = self.payment_methods.lock().await;
-        let payment_methods_found: Vec<storage::PaymentMethod> = payment_methods
+        let payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods
             .iter()
             .filter(|pm| pm.customer_id == customer_id && pm.merchant_id == merchant_id)
             .cloned()
@@ -191,7 +557,8 @@ impl PaymentMethodInterface for MockDb {
         &self,
         merchant_id: &str,
         payment_method_id: &str,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
         let mut payment_methods = self.payment_methods.lock().await;
         match payment_methods.iter().position(|pm| {
             pm.merchant_id == merchant_id && pm.payment_method_id == payment_method_id
@@ -209,9 +576,10 @@ impl PaymentMethodInterface for MockDb {
 
     async fn update_payment_method(
         &self,
-        payment_method: storage::PaymentMethod,
-        payment_method_update: storage::PaymentMethodUpdate,
-    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        payment_method: storage_types::PaymentMethod,
+        payment_method_update: storage_types::PaymentMethodUpdate,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
         match self
             .payment_methods
             .lock()
@@ -0,0 +1,321 @@
+use async_bb8_diesel::AsyncConnection;
+use diesel_models::errors::DatabaseError;
+use error_stack::{report, IntoReport};
+use router_env::{instrument, logger, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection, consts,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    /// Inserts the batch, its items and the task which processes them in a single transaction,
+    /// so that a batch is never left without its items or without a task to drive it
+    async fn insert_payout_batch_with_items(
+        &self,
+        batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+        process: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+        batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn update_payout_batch_item(
+        &self,
+        batch_id: &str,
+        item_index: i32,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_with_items(
+        &self,
+        batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+        process: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // The transaction can only fail with an error which can be built from a diesel error, so
+        // the context of the failed query is logged and its database error returned
+        let into_database_error = |error: error_stack::Report<DatabaseError>| {
+            logger::error!(?error, "Failed to insert payout batch");
+            *error.current_context()
+        };
+
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                let batch = batch.insert(&conn).await.map_err(into_database_error)?;
+
+                let mut items = items.into_iter().peekable();
+                while items.peek().is_some() {
+                    let chunk = items
+                        .by_ref()
+                        .take(consts::PAYOUT_BATCH_ITEM_INSERT_CHUNK_SIZE)
+                        .collect();
+                    storage::PayoutBatchItem::insert_batch(&conn, chunk)
+                        .await
+                        .map_err(into_database_error)?;
+                }
+
+                process
+                    .insert_process(&conn)
+                    .await
+                    .map_err(into_database_error)?;
+
+                Ok::<_, DatabaseError>(batch)
+            })
+        })
+        .await
+        .map_err(|error| errors::StorageError::DatabaseError(report!(error)))
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+        batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            batch_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_items_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        // Items are picked up again after being processed, so the primary is read to avoid
+        // processing an item twice because of replication lag
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::find_by_merchant_id_batch_id_status(
+            &conn,
+            merchant_id,
+            batch_id,
+            status,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item(
+        &self,
+        batch_id: &str,
+        item_index: i32,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::update_by_batch_id_item_index(
+            &conn,
+            batch_id,
+            item_index,
+            item_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch_with_items(
+        &self,
+        _batch: storage::PayoutBatchNew,
+        _items: Vec<storage::PayoutBatchItemNew>,
+        _process: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &str,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &str,
+        _batch_id: &str,
+        _batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &str,
+        _batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id_status(
+        &self,
+        _merchant_id: &str,
+        _batch_id: &str,
+        _status: enums::PayoutBatchItemStatus,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        _batch_id: &str,
+        _item_index: i32,
+        _item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    async fn insert_payout_batch_with_items(
+        &self,
+        batch: storage::PayoutBatchNew,
+        items: Vec<storage::PayoutBatchItemNew>,
+        process: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_with_items(batch, items, process)
+            .await
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn update_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+        batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id, batch_update)
+            .await
+    }
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_items_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id_status(
+        &self,
+        merchant_id: &str,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_items_by_merchant_id_batch_id_status(
+                merchant_id,
+                batch_id,
+                status,
+                limit,
+            )
+            .await
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        batch_id: &str,
+        item_index: i32,
+        item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item(batch_id, item_index, item_update)
+            .await
+    }
+}
@@ -15,6 +15,12 @@ pub trait PayoutsInterface {
         _payout_id: &str,
     ) -> CustomResult<storage::Payouts, errors::StorageError>;
 
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        _merchant_id: &str,
+        _payout_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::StorageError>;
+
     async fn update_payout_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
@@ -26,6 +32,27 @@ pub trait PayoutsInterface {
         &self,
         _payout: storage::PayoutsNew,
     ) -> CustomResult<storage::Payouts, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -42,6 +69,18 @@ impl PayoutsInterface for Store {
             .into_report()
     }
 
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &str,
+        payout_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Payouts::find_by_merchant_id_payout_ids(&conn, merchant_id, payout_ids)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
     async fn update_payout_by_merchant_id_payout_id(
         &self,
         merchant_id: &str,
@@ -62,6 +101,57 @@ impl PayoutsInterface for Store {
         let conn = connection::pg_connection_write(self).await?;
         payout.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        merchant_id: &str,
+        constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::get_payouts_count(
+            &conn,
+            merchant_id,
+            constraints,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::filter_by_meta_constraints(
+            &conn,
+            merchant_id,
+            time_range,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -75,6 +165,15 @@ impl PayoutsInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_payouts_by_merchant_id_payout_ids(
+        &self,
+        _merchant_id: &str,
+        _payout_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_payout_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
@@ -92,4 +191,34 @@ impl PayoutsInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_and_attempts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_filtered_payouts(
+        &self,
+        _merchant_id: &str,
+        _constraints: &storage::PayoutFetchConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,146 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &str,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &str,
+        _subscription_id: &str,
+        _subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for KafkaStore {
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store.insert_subscription(subscription).await
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &str,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await
+    }
+}
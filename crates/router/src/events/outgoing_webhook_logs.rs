@@ -41,6 +41,14 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    Payout {
+        payout_id: String,
+        content: Value,
+    },
+    Subscription {
+        subscription_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_type(&self) -> Option<OutgoingWebhookEventContent>;
@@ -72,6 +80,18 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::PayoutDetails(payout_payload) => Some(OutgoingWebhookEventContent::Payout {
+                payout_id: payout_payload.payout_id.clone(),
+                content: masking::masked_serialize(&payout_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.subscription_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -53,6 +53,7 @@ pub mod headers {
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -91,7 +92,7 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit);
+    let mut server_app = get_application_builder(request_body_limit, state.clone());
 
     #[cfg(feature = "openapi")]
     {
@@ -132,6 +133,7 @@ pub fn mk_app(
             .service(routes::EphemeralKey::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()))
             .service(routes::PaymentMethods::server(state.clone()))
+            .service(routes::Subscriptions::server(state.clone()))
     }
 
     #[cfg(feature = "olap")]
@@ -149,6 +151,7 @@ pub fn mk_app(
             .service(routes::PaymentLink::server(state.clone()))
             .service(routes::User::server(state.clone()))
             .service(routes::ConnectorOnboarding::server(state.clone()))
+            .service(routes::WebhookEvents::server(state.clone()))
     }
 
     #[cfg(all(feature = "olap", feature = "kms"))]
@@ -240,6 +243,7 @@ impl Stop for mpsc::Sender<()> {
 
 pub fn get_application_builder(
     request_body_limit: usize,
+    state: AppState,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -264,6 +268,7 @@ pub fn get_application_builder(
             StatusCode::METHOD_NOT_ALLOWED,
             errors::error_handlers::custom_error_handlers,
         ))
+        .wrap(middleware::RateLimiter::new(state))
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
         .wrap(cors::cors())
@@ -149,3 +149,454 @@ where
         )
     }
 }
+
+const RATE_LIMIT_KEY_PREFIX: &str = "RATE_LIMIT";
+const RATE_LIMIT_IDENTITY_KEY_PREFIX: &str = "RATE_LIMIT_IDENTITY";
+/// Prefix of the key in the configs table that holds the rate limits of a merchant
+pub const RATE_LIMIT_MERCHANT_CONFIG_KEY_PREFIX: &str = "rate_limit";
+const STRIPE_COMPATIBLE_PATH_PREFIX: &str = "/vs/v1";
+
+/// Identity of the API key used for a request, recorded once the request has been authenticated
+/// so that the limits of the merchant can be applied to subsequent requests made with the key.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RateLimitIdentity {
+    Merchant {
+        merchant_id: String,
+    },
+    /// Requests made with the admin API key are not rate limited
+    Exempt,
+}
+
+impl RateLimitIdentity {
+    pub fn from_authentication_type(
+        auth_type: &crate::services::authentication::AuthenticationType,
+    ) -> Option<Self> {
+        use crate::services::authentication::AuthenticationType;
+
+        match auth_type {
+            AuthenticationType::ApiKey { merchant_id, .. }
+            | AuthenticationType::PublishableKey { merchant_id } => Some(Self::Merchant {
+                merchant_id: merchant_id.clone(),
+            }),
+            AuthenticationType::AdminApiKey => Some(Self::Exempt),
+            AuthenticationType::MerchantJwt { .. }
+            | AuthenticationType::UserJwt { .. }
+            | AuthenticationType::MerchantId { .. }
+            | AuthenticationType::WebhookAuth { .. }
+            | AuthenticationType::NoAuth => None,
+        }
+    }
+}
+
+/// Limits configured for a merchant in the configs table, which take precedence over the limits
+/// in the application configuration.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct MerchantRateLimits {
+    pub default_limit: Option<u64>,
+    pub route_group_limits: std::collections::HashMap<String, u64>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ResolvedRateLimits {
+    Merchant {
+        merchant_id: String,
+        limits: MerchantRateLimits,
+    },
+    Exempt,
+}
+
+/// Middleware to rate limit requests made with an API key.
+///
+/// Requests are counted per API key and route group (the first segment of the request path) over
+/// fixed windows, with the counters stored in Redis so that the limits hold across all instances
+/// of the application. Requests exceeding the limit are rejected with a `429` status code and a
+/// `Retry-After` header.
+pub struct RateLimiter {
+    state: crate::routes::AppState,
+}
+
+impl RateLimiter {
+    pub fn new(state: crate::routes::AppState) -> Self {
+        Self { state }
+    }
+}
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimiter
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Transform = RateLimiterMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimiterMiddleware {
+            service: std::rc::Rc::new(service),
+            state: self.state.clone(),
+        }))
+    }
+}
+
+pub struct RateLimiterMiddleware<S> {
+    service: std::rc::Rc<S>,
+    state: crate::routes::AppState,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimiterMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let service = std::rc::Rc::clone(&self.service);
+        let state = self.state.clone();
+
+        Box::pin(
+            async move {
+                let api_key_hash = req
+                    .headers()
+                    .get("api-key")
+                    .and_then(|api_key| api_key.to_str().ok())
+                    .map(str::trim)
+                    .filter(|api_key| !api_key.is_empty() && state.conf.rate_limit.enabled)
+                    .and_then(get_api_key_hash);
+
+                let Some(api_key_hash) = api_key_hash else {
+                    return service
+                        .call(req)
+                        .await
+                        .map(actix_web::dev::ServiceResponse::map_into_left_body);
+                };
+
+                let route_group = get_route_group(req.path());
+                let resolved_limits = get_resolved_rate_limits(&state, &api_key_hash)
+                    .await
+                    .map_err(|error| {
+                        router_env::logger::error!(?error, "Failed to fetch cached rate limits")
+                    })
+                    .ok()
+                    .flatten();
+
+                let limit = match &resolved_limits {
+                    Some(ResolvedRateLimits::Exempt) => None,
+                    Some(ResolvedRateLimits::Merchant { limits, .. }) => Some(get_rate_limit(
+                        &state.conf.rate_limit,
+                        Some(limits),
+                        &route_group,
+                    )),
+                    None => Some(get_rate_limit(&state.conf.rate_limit, None, &route_group)),
+                };
+
+                if let Some(limit) = limit {
+                    // Requests are allowed through if the counter could not be updated, so that
+                    // an unavailable Redis instance doesn't result in all requests being rejected
+                    match increment_request_count(&state, &api_key_hash, &route_group).await {
+                        Ok((request_count, retry_after_seconds)) if request_count > limit => {
+                            router_env::logger::warn!(
+                                ?route_group,
+                                limit,
+                                "Rate limit exceeded for API key"
+                            );
+                            return Ok(get_too_many_requests_response(req, retry_after_seconds));
+                        }
+                        Ok(_) => {}
+                        Err(error) => {
+                            router_env::logger::error!(
+                                ?error,
+                                "Failed to update rate limit counter"
+                            )
+                        }
+                    }
+                }
+
+                let response = service.call(req).await?;
+
+                if resolved_limits.is_none() {
+                    let identity = response
+                        .request()
+                        .extensions()
+                        .get::<RateLimitIdentity>()
+                        .cloned();
+
+                    if let Some(identity) = identity {
+                        cache_resolved_rate_limits(&state, &api_key_hash, identity)
+                            .await
+                            .map_err(|error| {
+                                router_env::logger::error!(
+                                    ?error,
+                                    "Failed to cache rate limits for API key"
+                                )
+                            })
+                            .ok();
+                    }
+                }
+
+                Ok(response.map_into_left_body())
+            }
+            .in_current_span(),
+        )
+    }
+}
+
+/// The API key is hashed so that it is never stored in Redis as is.
+fn get_api_key_hash(api_key: &str) -> Option<String> {
+    use common_utils::crypto::{GenerateDigest, Sha256};
+
+    Sha256
+        .generate_digest(api_key.as_bytes())
+        .map(hex::encode)
+        .ok()
+}
+
+/// Returns the first segment of the request path, ignoring the prefix of the Stripe compatible
+/// APIs.
+fn get_route_group(path: &str) -> String {
+    path.strip_prefix(STRIPE_COMPATIBLE_PATH_PREFIX)
+        .unwrap_or(path)
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn get_rate_limit(
+    rate_limit_config: &crate::configs::settings::RateLimitConfig,
+    merchant_limits: Option<&MerchantRateLimits>,
+    route_group: &str,
+) -> u64 {
+    merchant_limits
+        .and_then(|limits| {
+            limits
+                .route_group_limits
+                .get(route_group)
+                .copied()
+                .or(limits.default_limit)
+        })
+        .or_else(|| {
+            rate_limit_config
+                .route_group_limits
+                .get(route_group)
+                .copied()
+        })
+        .unwrap_or(rate_limit_config.default_limit)
+}
+
+async fn get_resolved_rate_limits(
+    state: &crate::routes::AppState,
+    api_key_hash: &str,
+) -> crate::core::errors::RouterResult<Option<ResolvedRateLimits>> {
+    use error_stack::ResultExt;
+
+    use crate::core::errors;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    match redis_conn
+        .get_and_deserialize_key::<ResolvedRateLimits>(
+            &format!("{RATE_LIMIT_IDENTITY_KEY_PREFIX}_{api_key_hash}"),
+            "ResolvedRateLimits",
+        )
+        .await
+    {
+        Ok(resolved_limits) => Ok(Some(resolved_limits)),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    }
+}
+
+async fn cache_resolved_rate_limits(
+    state: &crate::routes::AppState,
+    api_key_hash: &str,
+    identity: RateLimitIdentity,
+) -> crate::core::errors::RouterResult<()> {
+    use common_utils::ext_traits::StringExt;
+    use error_stack::ResultExt;
+
+    use crate::core::errors;
+
+    let resolved_limits = match identity {
+        RateLimitIdentity::Merchant { merchant_id } => {
+            let config_key = format!("{RATE_LIMIT_MERCHANT_CONFIG_KEY_PREFIX}_{merchant_id}");
+            let limits = match state.store.find_config_by_key(&config_key).await {
+                Ok(config) => config
+                    .config
+                    .parse_struct::<MerchantRateLimits>("MerchantRateLimits")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to parse rate limits configured for merchant")?,
+                Err(error) if error.current_context().is_db_not_found() => {
+                    MerchantRateLimits::default()
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch rate limits configured for merchant")?,
+            };
+
+            ResolvedRateLimits::Merchant {
+                merchant_id,
+                limits,
+            }
+        }
+        RateLimitIdentity::Exempt => ResolvedRateLimits::Exempt,
+    };
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &format!("{RATE_LIMIT_IDENTITY_KEY_PREFIX}_{api_key_hash}"),
+            resolved_limits,
+            state.conf.rate_limit.identity_cache_expiry_seconds,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cache rate limits in redis")
+}
+
+/// Increments the number of requests made in the current window, and returns the updated count
+/// along with the number of seconds after which the window ends.
+async fn increment_request_count(
+    state: &crate::routes::AppState,
+    api_key_hash: &str,
+    route_group: &str,
+) -> crate::core::errors::RouterResult<(u64, u64)> {
+    use error_stack::ResultExt;
+
+    use crate::core::errors;
+
+    let window_in_seconds = state.conf.rate_limit.window_in_seconds;
+    let now = common_utils::date_time::now_unix_timestamp();
+    let window_start = now - now.rem_euclid(window_in_seconds);
+    let counter_key =
+        format!("{RATE_LIMIT_KEY_PREFIX}_{api_key_hash}_{route_group}_{window_start}");
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let request_count = redis_conn
+        .increment_key(&counter_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment rate limit counter")?;
+
+    if request_count == 1 {
+        redis_conn
+            .set_expiry(&counter_key, window_in_seconds)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry for rate limit counter")?;
+    }
+
+    Ok((
+        u64::try_from(request_count).unwrap_or_default(),
+        u64::try_from(window_start + window_in_seconds - now).unwrap_or_default(),
+    ))
+}
+
+fn get_too_many_requests_response<B>(
+    req: actix_web::dev::ServiceRequest,
+    retry_after_seconds: u64,
+) -> actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>> {
+    let error = crate::core::errors::ApiErrorResponse::TooManyRequests {
+        retry_after_seconds,
+    };
+
+    #[cfg(feature = "stripe")]
+    let mut response = if req.path().starts_with(STRIPE_COMPATIBLE_PATH_PREFIX) {
+        actix_web::ResponseError::error_response(
+            &crate::compatibility::stripe::errors::StripeErrorCode::from(error),
+        )
+    } else {
+        actix_web::ResponseError::error_response(&error)
+    };
+    #[cfg(not(feature = "stripe"))]
+    let mut response = actix_web::ResponseError::error_response(&error);
+
+    response.headers_mut().insert(
+        actix_web::http::header::RETRY_AFTER,
+        actix_web::http::header::HeaderValue::from(retry_after_seconds),
+    );
+
+    req.into_response(response).map_into_right_body()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_route_group() {
+        assert_eq!(get_route_group("/payments"), "payments");
+        assert_eq!(get_route_group("/payments/pay_123/confirm"), "payments");
+        assert_eq!(
+            get_route_group("/vs/v1/payment_intents/pi_123"),
+            "payment_intents"
+        );
+        assert_eq!(get_route_group("/"), "");
+    }
+
+    #[test]
+    fn test_get_rate_limit() {
+        let rate_limit_config = crate::configs::settings::RateLimitConfig {
+            default_limit: 100,
+            route_group_limits: [("payments".to_string(), 50)].into_iter().collect(),
+            ..Default::default()
+        };
+
+        assert_eq!(get_rate_limit(&rate_limit_config, None, "refunds"), 100);
+        assert_eq!(get_rate_limit(&rate_limit_config, None, "payments"), 50);
+
+        let merchant_limits = MerchantRateLimits {
+            default_limit: Some(20),
+            route_group_limits: [("refunds".to_string(), 10)].into_iter().collect(),
+        };
+
+        assert_eq!(
+            get_rate_limit(&rate_limit_config, Some(&merchant_limits), "refunds"),
+            10
+        );
+        assert_eq!(
+            get_rate_limit(&rate_limit_config, Some(&merchant_limits), "payments"),
+            20
+        );
+    }
+}
@@ -56,12 +56,14 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Subscriptions", description = "Manage recurring billing built on mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
         // (name = "API Key", description = "Create and manage API Keys"),
         (name = "Payouts", description = "Create and manage payouts"),
         (name = "payment link", description = "Create payment link"),
+        (name = "Event", description = "Manage events"),
     ),
     paths(
         crate::routes::refunds::refunds_create,
@@ -80,6 +82,9 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::admin::payment_connector_delete,
         crate::routes::mandates::get_mandate,
         crate::routes::mandates::revoke_mandate,
+        crate::routes::subscriptions::subscriptions_create,
+        crate::routes::subscriptions::subscriptions_retrieve,
+        crate::routes::subscriptions::subscriptions_cancel,
         crate::routes::payments::payments_create,
     // crate::routes::payments::payments_start,
         crate::routes::payments::payments_retrieve,
@@ -114,6 +119,11 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payouts::payouts_fulfill,
         crate::routes::payouts::payouts_retrieve,
         crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_list,
+        crate::routes::payouts::payouts_list_by_filter,
+        crate::routes::payouts::payouts_list_available_filters,
+        crate::routes::payouts::payouts_batch_create,
+        crate::routes::payouts::payouts_batch_retrieve,
         crate::routes::payment_link::payment_link_retrieve,
         crate::routes::gsm::create_gsm_rule,
         crate::routes::gsm::get_gsm_rule,
@@ -121,7 +131,11 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::gsm::delete_gsm_rule,
         crate::routes::blocklist::add_entry_to_blocklist,
         crate::routes::blocklist::list_blocked_payment_methods,
-        crate::routes::blocklist::remove_entry_from_blocklist
+        crate::routes::blocklist::remove_entry_from_blocklist,
+        crate::routes::blocklist::import_blocklist,
+        crate::routes::blocklist::export_blocklist,
+        crate::routes::webhook_events::list_delivery_attempts,
+        crate::routes::webhook_events::retry_webhook_delivery_attempt
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -201,6 +215,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmErrorCategory,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -335,6 +350,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::subscriptions::SubscriptionCreateRequest,
+        api_models::subscriptions::SubscriptionEndCondition,
+        api_models::subscriptions::SubscriptionResponse,
+        api_models::enums::SubscriptionStatus,
+        api_models::enums::SubscriptionInterval,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
         api_models::payments::GiftCardData,
@@ -349,16 +369,32 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutRetrieveRequest,
         api_models::payouts::PayoutActionRequest,
+        api_models::payouts::PayoutListConstraints,
+        api_models::payouts::PayoutListFilterConstraints,
+        api_models::payouts::PayoutListFilters,
+        api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutListResponseV2,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchItemResponse,
         api_models::payouts::PayoutRequest,
         api_models::payouts::PayoutMethodData,
         api_models::payouts::Bank,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutStatus,
         api_models::enums::PayoutType,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
         api_models::payments::FrmMessage,
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventType,
+        api_models::enums::EventClass,
+        api_models::enums::WebhookDeliveryAttempt,
+        api_models::webhooks::OutgoingWebhookRequestContent,
+        api_models::webhook_events::EventListConstraints,
+        api_models::webhook_events::EventResponse,
+        api_models::webhook_events::OutgoingWebhookResponseContent,
         crate::types::api::admin::MerchantAccountResponse,
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
@@ -375,8 +411,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentLinkInitiateRequest,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::AddToBlocklistRequest,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
+        api_models::blocklist::BlocklistExportQuery,
+        api_models::blocklist::BlocklistImportRequest,
+        api_models::blocklist::BlocklistImportResponse,
+        api_models::blocklist::BlocklistImportError,
         common_enums::enums::BlocklistDataKind
     )),
     modifiers(&SecurityAddon)
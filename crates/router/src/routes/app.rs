@@ -28,12 +28,14 @@ use super::payouts::*;
 use super::pm_auth;
 #[cfg(feature = "olap")]
 use super::routing as cloud_routing;
+#[cfg(feature = "oltp")]
+use super::subscriptions::*;
 #[cfg(all(feature = "olap", feature = "kms"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
 use super::{
     admin::*, api_keys::*, connector_onboarding::*, disputes::*, files::*, gsm::*,
-    locker_migration, payment_link::*, user::*, user_role::*,
+    locker_migration, payment_link::*, user::*, user_role::*, webhook_events::*,
 };
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -46,6 +48,7 @@ use crate::routes::fraud_check as frm_routes;
 use crate::routes::recon as recon_routes;
 #[cfg(feature = "olap")]
 use crate::routes::verify_connector::payment_connector_verify;
+use crate::services::file_storage::FileStorage;
 pub use crate::{
     configs::settings,
     db::{StorageImpl, StorageInterface},
@@ -65,6 +68,7 @@ pub struct AppState {
     #[cfg(feature = "kms")]
     pub kms_secrets: Arc<settings::ActiveKmsSecrets>,
     pub api_client: Box<dyn crate::services::ApiClient>,
+    pub file_storage_client: Arc<dyn FileStorage>,
     #[cfg(feature = "olap")]
     pub pool: crate::analytics::AnalyticsProvider,
     pub request_id: Option<RequestId>,
@@ -266,6 +270,8 @@ impl AppState {
             #[cfg(feature = "email")]
             let email_client = Arc::new(create_email_client(&conf).await);
 
+            let file_storage_client = conf.file_storage.get_file_storage_client().await;
+
             Self {
                 flow_name: String::from("default"),
                 store,
@@ -275,6 +281,7 @@ impl AppState {
                 #[cfg(feature = "kms")]
                 kms_secrets: Arc::new(kms_secrets),
                 api_client,
+                file_storage_client,
                 event_handler,
                 #[cfg(feature = "olap")]
                 pool,
@@ -470,6 +477,14 @@ impl Routing {
                 web::resource("/deactivate")
                     .route(web::post().to(cloud_routing::routing_unlink_config)),
             )
+            .service(
+                web::resource("/simulate")
+                    .route(web::post().to(cloud_routing::routing_simulate_config)),
+            )
+            .service(
+                web::resource("/coverage")
+                    .route(web::post().to(cloud_routing::routing_coverage_config)),
+            )
             .service(
                 web::resource("/decision")
                     .route(web::put().to(cloud_routing::upsert_decision_manager_config))
@@ -580,9 +595,27 @@ pub struct Payouts;
 #[cfg(feature = "payouts")]
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
-        let route = web::scope("/payouts").app_data(web::Data::new(state));
-        route
+        let mut route = web::scope("/payouts").app_data(web::Data::new(state));
+        route = route
             .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(
+                web::resource("/batch/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            );
+
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(
+                    web::resource("/list")
+                        .route(web::get().to(payouts_list))
+                        .route(web::post().to(payouts_list_by_filter)),
+                )
+                .service(
+                    web::resource("/filter").route(web::post().to(payouts_list_available_filters)),
+                );
+        }
+        route
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
             .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)))
             .service(
@@ -650,6 +683,8 @@ impl Blocklist {
                     .route(web::post().to(blocklist::add_entry_to_blocklist))
                     .route(web::delete().to(blocklist::remove_entry_from_blocklist)),
             )
+            .service(web::resource("/import").route(web::post().to(blocklist::import_blocklist)))
+            .service(web::resource("/export").route(web::get().to(blocklist::export_blocklist)))
     }
 }
 
@@ -748,6 +783,24 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(feature = "oltp")]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(subscriptions_create)))
+            .service(
+                web::resource("/{subscription_id}").route(web::get().to(subscriptions_retrieve)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscriptions_cancel)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(feature = "oltp")]
@@ -855,11 +908,16 @@ impl Files {
         web::scope("/files")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(files_create)))
+            .service(web::resource("/download/{token}").route(web::get().to(files_download)))
             .service(
                 web::resource("/{file_id}")
                     .route(web::delete().to(files_delete))
                     .route(web::get().to(files_retrieve)),
             )
+            .service(
+                web::resource("/{file_id}/download_link")
+                    .route(web::post().to(files_download_link)),
+            )
     }
 }
 
@@ -911,6 +969,23 @@ impl BusinessProfile {
     }
 }
 
+pub struct WebhookEvents;
+
+#[cfg(feature = "olap")]
+impl WebhookEvents {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/profile/{profile_id}").route(web::get().to(list_delivery_attempts)),
+            )
+            .service(
+                web::resource("/{event_id}/retry")
+                    .route(web::post().to(retry_webhook_delivery_attempt)),
+            )
+    }
+}
+
 pub struct Gsm;
 
 #[cfg(feature = "olap")]
@@ -11,7 +11,7 @@ use crate::{
 #[utoipa::path(
     post,
     path = "/blocklist",
-    request_body = BlocklistRequest,
+    request_body = AddToBlocklistRequest,
     responses(
         (status = 200, description = "Fingerprint Blocked", body = BlocklistResponse),
         (status = 400, description = "Invalid Data")
@@ -32,7 +32,8 @@ pub async fn add_entry_to_blocklist(
         &req,
         json_payload.into_inner(),
         |state, auth: auth::AuthenticationData, body| {
-            blocklist::add_entry_to_blocklist(state, auth.merchant_account, body)
+            let created_by = auth::get_authenticated_actor_id(&req);
+            blocklist::add_entry_to_blocklist(state, auth.merchant_account, body, created_by)
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
@@ -117,3 +118,78 @@ pub async fn list_blocked_payment_methods(
     ))
     .await
 }
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/import",
+    request_body = BlocklistImportRequest,
+    responses(
+        (status = 200, description = "Blocklist file imported", body = BlocklistImportResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Import Blocklist entries from a CSV file",
+    security(("api_key" = []))
+)]
+pub async fn import_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BlocklistImportRequest>,
+) -> HttpResponse {
+    let flow = Flow::ImportBlocklist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body| {
+            let created_by = auth::get_authenticated_actor_id(&req);
+            blocklist::import_blocklist(state, auth.merchant_account, body, created_by)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/export",
+    params (
+        ("data_kind" = Option<BlocklistDataKind>, Query, description = "Kind of the entries to be exported"),
+    ),
+    responses(
+        (status = 200, description = "Blocklist entries as a CSV file, email, phone number and IP address entries are left out as only their hash is stored"),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Export Blocklist entries as a CSV file",
+    security(("api_key" = []))
+)]
+pub async fn export_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_blocklist::BlocklistExportQuery>,
+) -> HttpResponse {
+    let flow = Flow::ExportBlocklist;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, query| {
+            blocklist::export_blocklist(state, auth.merchant_account, query)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
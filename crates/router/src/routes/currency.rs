@@ -34,6 +34,9 @@ pub async fn convert_forex(
     let amount = &params.amount;
     let to_currency = &params.to_currency;
     let from_currency = &params.from_currency;
+    let transaction_date = params.transaction_date;
+    let rounding_mode = params.rounding_mode.unwrap_or_default();
+    let markup_basis_points = params.markup_basis_points;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
@@ -45,6 +48,9 @@ pub async fn convert_forex(
                 *amount,
                 to_currency.to_string(),
                 from_currency.to_string(),
+                transaction_date,
+                rounding_mode,
+                markup_basis_points,
             )
         },
         auth::auth_type(
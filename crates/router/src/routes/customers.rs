@@ -116,7 +116,7 @@ pub async fn customers_list(state: web::Data<AppState>, req: HttpRequest) -> Htt
         state,
         &req,
         (),
-        |state, auth, _| list_customers(state, auth.merchant_account.merchant_id, auth.key_store),
+        |state, auth, _| list_customers(state, auth.merchant_account, auth.key_store),
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::CustomerRead),
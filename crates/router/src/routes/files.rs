@@ -138,3 +138,65 @@ pub async fn files_retrieve(
     ))
     .await
 }
+
+/// Files - Create Download Link
+///
+/// To create a link which downloads a file without authentication until it expires
+#[utoipa::path(
+    post,
+    path = "/files/{file_id}/download_link",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "Download link created", body = FileDownloadLinkResponse),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Create a File Download Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateFileDownloadLink))]
+pub async fn files_download_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::CreateFileDownloadLink;
+    let file_id = files::FileId {
+        file_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        file_id,
+        |state, auth, req| files_download_link_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::FileRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DownloadFile))]
+pub async fn files_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DownloadFile;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, token| files_download_core(state, token),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -71,13 +71,23 @@ pub async fn get_create_file_request(
             .attach_printable("Missing / Invalid file in the request")?
     }
     // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .into_report()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    let file_type = match infer::get(&file) {
+        Some(kind) => kind
+            .mime_type()
+            .parse::<mime::Mime>()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::MissingFileContentType)
+            .attach_printable("File content type error")?,
+        // Plain text formats like CSV have no magic bytes to be inferred from
+        None if matches!(
+            purpose,
+            files::FilePurpose::PayoutBatch | files::FilePurpose::BlocklistImport
+        ) =>
+        {
+            mime::TEXT_CSV
+        }
+        None => Err(errors::ApiErrorResponse::MissingFileContentType).into_report()?,
+    };
     Ok(CreateFileRequest {
         file,
         file_name,
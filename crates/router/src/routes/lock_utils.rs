@@ -12,6 +12,7 @@ pub enum ApiIdentifier {
     Customers,
     Ephemeral,
     Mandates,
+    Subscriptions,
     PaymentMethods,
     PaymentMethodAuth,
     Payouts,
@@ -32,6 +33,7 @@ pub enum ApiIdentifier {
     UserRole,
     ConnectorOnboarding,
     Recon,
+    WebhookEvents,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -53,6 +55,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulateConfig
+            | Flow::RoutingCoverageConfig
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
@@ -62,6 +66,8 @@ impl From<Flow> for ApiIdentifier {
             Flow::AddToBlocklist => Self::Blocklist,
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
+            Flow::ImportBlocklist => Self::Blocklist,
+            Flow::ExportBlocklist => Self::Blocklist,
 
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
@@ -85,6 +91,10 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
+            Flow::SubscriptionsCreate | Flow::SubscriptionsRetrieve | Flow::SubscriptionsCancel => {
+                Self::Subscriptions
+            }
+
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
@@ -114,7 +124,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
-            | Flow::PayoutsAccounts => Self::Payouts,
+            | Flow::PayoutsAccounts
+            | Flow::PayoutsList
+            | Flow::PayoutsFilter
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsBatchRetrieve => Self::Payouts,
 
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
@@ -123,6 +137,10 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::FrmFulfillment | Flow::IncomingWebhookReceive => Self::Webhooks,
 
+            Flow::WebhookEventDeliveryAttemptList | Flow::WebhookEventDeliveryRetry => {
+                Self::WebhookEvents
+            }
+
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
@@ -137,7 +155,11 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CardsInfo => Self::CardsInfo,
 
-            Flow::CreateFile | Flow::DeleteFile | Flow::RetrieveFile => Self::Files,
+            Flow::CreateFile
+            | Flow::DeleteFile
+            | Flow::RetrieveFile
+            | Flow::CreateFileDownloadLink
+            | Flow::DownloadFile => Self::Files,
 
             Flow::CacheInvalidate => Self::Cache,
 
@@ -248,7 +248,9 @@ pub async fn payment_method_retrieve_api(
         state,
         &req,
         payload,
-        |state, auth, pm| cards::retrieve_payment_method(state, pm, auth.key_store),
+        |state, auth, pm| {
+            cards::retrieve_payment_method(state, pm, auth.key_store, auth.merchant_account)
+        },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     ))
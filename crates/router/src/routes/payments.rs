@@ -13,6 +13,7 @@ use crate::{
     self as app,
     core::{
         errors::{self, http_not_implemented},
+        idempotency,
         payment_methods::{Oss, PaymentMethodRetrieve},
         payments::{self, PaymentRedirectFlow},
         utils as core_utils,
@@ -86,9 +87,13 @@ use crate::{
         //     ),
         // )
     ),
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "A unique key to safely retry the request. Requests made with the same key and body replay the response of the first request"),
+    ),
     responses(
         (status = 200, description = "Payment created", body = PaymentsResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 409, description = "Another request with the same idempotency key is in progress")
     ),
     tag = "Payments",
     operation_id = "Create a Payment",
@@ -107,6 +112,16 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    // The request hash has to be computed before the payment_id is generated for the request
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        &payload,
+        lock_utils::ApiIdentifier::Payments,
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -130,14 +145,19 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth, req| {
-            authorize_verify_select::<_, Oss>(
-                payments::PaymentCreate,
-                state,
-                auth.merchant_account,
-                auth.key_store,
-                payment_types::HeaderPayload::default(),
-                req,
-                api::AuthFlow::Merchant,
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                idempotency_input.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                authorize_verify_select::<_, Oss>(
+                    payments::PaymentCreate,
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payment_types::HeaderPayload::default(),
+                    req,
+                    api::AuthFlow::Merchant,
+                ),
             )
         },
         match env::which() {
@@ -5,12 +5,19 @@ use actix_web::{
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "payouts")]
+use super::lock_utils;
+#[cfg(all(feature = "olap", feature = "payouts"))]
+use crate::services::authorization::permissions::Permission;
 use crate::{
     core::api_locking,
     services::{api, authentication as auth},
 };
 #[cfg(feature = "payouts")]
-use crate::{core::payouts::*, types::api::payouts as payout_types};
+use crate::{
+    core::{idempotency, payouts::*},
+    types::api::payouts as payout_types,
+};
 
 /// Payouts - Create
 #[cfg(feature = "payouts")]
@@ -18,9 +25,13 @@ use crate::{core::payouts::*, types::api::payouts as payout_types};
     post,
     path = "/payouts/create",
     request_body=PayoutCreateRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "A unique key to safely retry the request. Requests made with the same key and body replay the response of the first request"),
+    ),
     responses(
         (status = 200, description = "Payout created", body = PayoutCreateResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 409, description = "Another request with the same idempotency key is in progress")
     ),
     tag = "Payouts",
     operation_id = "Create a Payout",
@@ -33,12 +44,29 @@ pub async fn payouts_create(
     json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        &payload,
+        lock_utils::ApiIdentifier::Payouts,
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
-        |state, auth, req| payouts_create_core(state, auth.merchant_account, auth.key_store, req),
+        payload,
+        |state, auth, req| {
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                idempotency_input.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                payouts_create_core(state, auth.merchant_account, auth.key_store, req),
+            )
+        },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     ))
@@ -70,6 +98,7 @@ pub async fn payouts_retrieve(
     let payout_retrieve_request = payout_types::PayoutRetrieveRequest {
         payout_id: path.into_inner(),
         force_sync: query_params.force_sync,
+        merchant_id: None,
     };
     let flow = Flow::PayoutsRetrieve;
     Box::pin(api::server_wrap(
@@ -200,6 +229,220 @@ pub async fn payouts_fulfill(
     ))
     .await
 }
+/// Payouts - List
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[utoipa::path(
+    get,
+    path = "/payouts/list",
+    params(
+        ("customer_id" = String, Query, description = "The identifier for the customer"),
+        ("starting_after" = String, Query, description = "A cursor for use in pagination, fetch the next list after some object"),
+        ("ending_before" = String, Query, description = "A cursor for use in pagination, fetch the previous list before some object"),
+        ("limit" = i64, Query, description = "Limit on the number of objects to return"),
+        ("created" = PrimitiveDateTime, Query, description = "The time at which payout is created"),
+        ("created_lt" = PrimitiveDateTime, Query, description = "Time less than the payout created time"),
+        ("created_gt" = PrimitiveDateTime, Query, description = "Time greater than the payout created time"),
+        ("created_lte" = PrimitiveDateTime, Query, description = "Time less than or equals to the payout created time"),
+        ("created_gte" = PrimitiveDateTime, Query, description = "Time greater than or equals to the payout created time")
+    ),
+    responses(
+        (status = 200, description = "Payouts listed", body = PayoutListResponse),
+        (status = 404, description = "Payout not found")
+    ),
+    tag = "Payouts",
+    operation_id = "List payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+pub async fn payouts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<payout_types::PayoutListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsList;
+    let payload = query_params.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| payouts_list_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - List using filters
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[utoipa::path(
+    post,
+    path = "/payouts/list",
+    request_body=PayoutListFilterConstraints,
+    responses(
+        (status = 200, description = "Payouts filtered", body = PayoutListResponseV2),
+        (status = 404, description = "Payout not found")
+    ),
+    tag = "Payouts",
+    operation_id = "Filter payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+pub async fn payouts_list_by_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutListFilterConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsList;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| payouts_filtered_list_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Available filters
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[utoipa::path(
+    post,
+    path = "/payouts/filter",
+    request_body=TimeRange,
+    responses(
+        (status = 200, description = "Filters listed", body = PayoutListFilters)
+    ),
+    tag = "Payouts",
+    operation_id = "List available payout filters",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFilter))]
+pub async fn payouts_list_available_filters(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payments::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsFilter;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| payouts_list_available_filters_core(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Create batch
+#[cfg(feature = "payouts")]
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "A unique key to safely retry the request. Requests made with the same key and body replay the response of the first request"),
+    ),
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 409, description = "Another request with the same idempotency key is in progress")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        &payload,
+        lock_utils::ApiIdentifier::Payouts,
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                idempotency_input.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                batch::payouts_batch_create_core(state, auth.merchant_account, auth.key_store, req),
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Payouts - Retrieve batch
+#[cfg(feature = "payouts")]
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchRetrieve))]
+pub async fn payouts_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchRetrieve;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| batch::payouts_batch_retrieve_core(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
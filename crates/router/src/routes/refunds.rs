@@ -1,9 +1,9 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
-use super::app::AppState;
+use super::{app::AppState, lock_utils};
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{api_locking, idempotency, refunds::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -15,9 +15,13 @@ use crate::{
     post,
     path = "/refunds",
     request_body=RefundRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "A unique key to safely retry the request. Requests made with the same key and body replay the response of the first request"),
+    ),
     responses(
         (status = 200, description = "Refund created", body = RefundResponse),
-        (status = 400, description = "Missing Mandatory fields")
+        (status = 400, description = "Missing Mandatory fields"),
+        (status = 409, description = "Another request with the same idempotency key is in progress")
     ),
     tag = "Refunds",
     operation_id = "Create a Refund",
@@ -31,12 +35,29 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        &payload,
+        lock_utils::ApiIdentifier::Refunds,
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
-        |state, auth, req| refund_create_core(state, auth.merchant_account, auth.key_store, req),
+        payload,
+        |state, auth, req| {
+            idempotency::perform_idempotent_request(
+                state.clone(),
+                idempotency_input.clone(),
+                auth.merchant_account.merchant_id.clone(),
+                refund_create_core(state, auth.merchant_account, auth.key_store, req),
+            )
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::RefundWrite),
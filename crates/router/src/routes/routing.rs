@@ -46,6 +46,69 @@ pub async fn routing_create_config(
     .await
 }
 
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_simulate_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingSimulationRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingSimulateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload| {
+            routing::simulate_routing_config(state, auth.merchant_account, payload)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_coverage_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingCoverageRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingCoverageConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload| {
+            routing::analyze_routing_config_coverage(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::RoutingRead),
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth(Permission::RoutingRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn routing_link_config(
@@ -87,16 +150,20 @@ pub async fn routing_retrieve_config(
     state: web::Data<AppState>,
     req: HttpRequest,
     path: web::Path<routing_types::RoutingAlgorithmId>,
+    query: web::Query<routing_types::RoutingRetrieveFormatQuery>,
 ) -> impl Responder {
-    let algorithm_id = path.into_inner();
+    let payload = routing_types::RoutingRetrieveConfigRequest {
+        algorithm_id: path.into_inner(),
+        format: query.into_inner().format,
+    };
     let flow = Flow::RoutingRetrieveConfig;
     Box::pin(oss_api::server_wrap(
         flow,
         state,
         &req,
-        algorithm_id,
-        |state, auth: auth::AuthenticationData, algorithm_id| {
-            routing::retrieve_routing_config(state, auth.merchant_account, algorithm_id)
+        payload,
+        |state, auth: auth::AuthenticationData, payload| {
+            routing::retrieve_routing_config(state, auth.merchant_account, payload)
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
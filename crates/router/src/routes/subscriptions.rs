@@ -0,0 +1,136 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::subscriptions as subscription_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Subscriptions - Create
+///
+/// Create a subscription which charges a multi use mandate of the customer at every billing cycle
+#[utoipa::path(
+    post,
+    path = "/subscriptions",
+    request_body = SubscriptionCreateRequest,
+    responses(
+        (status = 200, description = "Subscription created", body = SubscriptionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCreate))]
+pub async fn subscriptions_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            subscriptions::create_subscription(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MandateWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieve a subscription
+#[utoipa::path(
+    get,
+    path = "/subscriptions/{subscription_id}",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "The subscription was retrieved successfully", body = SubscriptionResponse),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Retrieve a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsRetrieve))]
+pub async fn subscriptions_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsRetrieve;
+    let subscription_id = subscription_types::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        subscription_id,
+        |state, auth, req| subscriptions::retrieve_subscription(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MandateRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Cancel
+///
+/// Cancel a subscription, no further billing cycles are charged
+#[utoipa::path(
+    post,
+    path = "/subscriptions/{subscription_id}/cancel",
+    params(
+        ("subscription_id" = String, Path, description = "The identifier for subscription")
+    ),
+    responses(
+        (status = 200, description = "The subscription was cancelled successfully", body = SubscriptionResponse),
+        (status = 400, description = "The subscription has already ended"),
+        (status = 404, description = "Subscription does not exist in our records")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Cancel a Subscription",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionsCancel))]
+pub async fn subscriptions_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionsCancel;
+    let subscription_id = subscription_types::SubscriptionId {
+        subscription_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        subscription_id,
+        |state, auth, req| subscriptions::cancel_subscription(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MandateWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,120 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_events::{
+    EventListConstraints, EventListRequestInternal, WebhookDeliveryRetryRequestInternal,
+};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::webhook_events},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Events - List
+///
+/// List the outgoing webhook delivery attempts for a business profile, along with the request
+/// sent and the response received for each of them.
+#[utoipa::path(
+    get,
+    path = "/events/profile/{profile_id}",
+    params(
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include events created after the specified time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include events created before the specified time"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of events to skip when retrieving the list of events"),
+    ),
+    responses(
+        (status = 200, description = "List of webhook delivery attempts retrieved successfully", body = Vec<EventResponse>),
+        (status = 404, description = "Business profile does not exist in our records"),
+    ),
+    tag = "Event",
+    operation_id = "List all webhook delivery attempts for a business profile",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryAttemptList))]
+pub async fn list_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<EventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryAttemptList;
+    let request_internal = EventListRequestInternal {
+        profile_id: path.into_inner(),
+        constraints: query.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth, request_internal| {
+            webhook_events::list_delivery_attempts(
+                state,
+                auth.merchant_account,
+                request_internal.profile_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Events - Manual Retry
+///
+/// Redeliver the outgoing webhook for an event, using the same request that was sent in the
+/// original attempt. The redelivery is recorded as a new delivery attempt.
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/retry",
+    params(
+        ("event_id" = String, Path, description = "The unique identifier for the event"),
+    ),
+    responses(
+        (status = 200, description = "The webhook was redelivered and the attempt was recorded", body = EventResponse),
+        (status = 404, description = "Event does not exist in our records"),
+    ),
+    tag = "Event",
+    operation_id = "Manually retry the delivery of a webhook event",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryRetry))]
+pub async fn retry_webhook_delivery_attempt(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryRetry;
+    let request_internal = WebhookDeliveryRetryRequestInternal {
+        event_id: path.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth, request_internal| {
+            webhook_events::retry_delivery_attempt(
+                state,
+                auth.merchant_account,
+                request_internal.event_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
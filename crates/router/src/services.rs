@@ -2,6 +2,7 @@ pub mod api;
 pub mod authentication;
 pub mod authorization;
 pub mod encryption;
+pub mod file_storage;
 #[cfg(feature = "olap")]
 pub mod jwt;
 pub mod kafka;
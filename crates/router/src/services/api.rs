@@ -362,6 +362,7 @@ where
                                 .masked_serialize()
                                 .unwrap_or(json!({ "error": "failed to mask serialize"})),
                             RequestContent::FormData(_) => json!({"request_type": "FORM_DATA"}),
+                            RequestContent::RawBytes(_) => json!({"request_type": "RAW_BYTES"}),
                         },
                         None => serde_json::Value::Null,
                     };
@@ -568,6 +569,7 @@ pub async fn send_request(
                             .change_context(errors::ApiClientError::BodySerializationFailed)?;
                         client.body(body).header("Content-Type", "application/xml")
                     }
+                    Some(RequestContent::RawBytes(payload)) => client.body(payload),
                     None => client,
                 }
             }
@@ -583,6 +585,7 @@ pub async fn send_request(
                             .change_context(errors::ApiClientError::BodySerializationFailed)?;
                         client.body(body).header("Content-Type", "application/xml")
                     }
+                    Some(RequestContent::RawBytes(payload)) => client.body(payload),
                     None => client,
                 }
             }
@@ -598,6 +601,7 @@ pub async fn send_request(
                             .change_context(errors::ApiClientError::BodySerializationFailed)?;
                         client.body(body).header("Content-Type", "application/xml")
                     }
+                    Some(RequestContent::RawBytes(payload)) => client.body(payload),
                     None => client,
                 }
             }
@@ -738,6 +742,29 @@ pub enum ApplicationResponse<R> {
     JsonWithHeaders((R, Vec<(String, String)>)),
 }
 
+impl<R> ApplicationResponse<R> {
+    /// Transforms the response body, leaving the other kinds of responses unchanged
+    pub fn map<T, F>(self, f: F) -> ApplicationResponse<T>
+    where
+        F: FnOnce(R) -> T,
+    {
+        match self {
+            Self::Json(response) => ApplicationResponse::Json(f(response)),
+            Self::JsonWithHeaders((response, headers)) => {
+                ApplicationResponse::JsonWithHeaders((f(response), headers))
+            }
+            Self::StatusOk => ApplicationResponse::StatusOk,
+            Self::TextPlain(text) => ApplicationResponse::TextPlain(text),
+            Self::JsonForRedirection(response) => ApplicationResponse::JsonForRedirection(response),
+            Self::Form(redirection_data) => ApplicationResponse::Form(redirection_data),
+            Self::PaymenkLinkForm(payment_link_data) => {
+                ApplicationResponse::PaymenkLinkForm(payment_link_data)
+            }
+            Self::FileData(file_data) => ApplicationResponse::FileData(file_data),
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum PaymentLinkAction {
     PaymentLinkFormData(PaymentLinkFormData),
@@ -882,6 +909,16 @@ where
         .await
         .switch()?;
 
+    // Recorded for the rate limiter to resolve the limits applicable to the API key used
+    if let Some(rate_limit_identity) =
+        crate::middleware::RateLimitIdentity::from_authentication_type(&auth_type)
+    {
+        request.extensions_mut().insert(rate_limit_identity);
+    }
+
+    // Recorded for the handlers which need to know who made the request
+    request.extensions_mut().insert(auth_type.clone());
+
     let merchant_id = auth_type
         .get_merchant_id()
         .unwrap_or("MERCHANT_ID_NOT_FOUND")
@@ -91,6 +91,30 @@ impl AuthenticationType {
             Self::AdminApiKey | Self::UserJwt { .. } | Self::NoAuth => None,
         }
     }
+
+    /// Identifier of the API key or the user which made the request
+    pub fn get_actor_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiKey { key_id, .. } => Some(key_id.as_ref()),
+            Self::MerchantJwt { user_id, .. } => user_id.as_deref(),
+            Self::UserJwt { user_id } => Some(user_id.as_ref()),
+            Self::AdminApiKey
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
+}
+
+/// Identifier of the API key or the user which made the request, only available to the handler
+/// once the request has been authenticated by `server_wrap`
+pub fn get_authenticated_actor_id(request: &actix_web::HttpRequest) -> Option<String> {
+    request
+        .extensions()
+        .get::<AuthenticationType>()
+        .and_then(AuthenticationType::get_actor_id)
+        .map(ToString::to_string)
 }
 
 #[derive(Clone, Debug)]
@@ -587,6 +611,7 @@ where
 
 #[derive(serde::Deserialize)]
 struct JwtAuthPayloadFetchMerchantAccount {
+    user_id: String,
     merchant_id: String,
     role_id: String,
 }
@@ -632,7 +657,7 @@ where
             auth.clone(),
             AuthenticationType::MerchantJwt {
                 merchant_id: auth.merchant_account.merchant_id.clone(),
-                user_id: None,
+                user_id: Some(payload.user_id),
             },
         ))
     }
@@ -32,6 +32,12 @@ pub enum EmailBody {
         user_name: String,
         user_email: String,
     },
+    ReportDownload {
+        report_name: String,
+        merchant_id: String,
+        link: String,
+        expiry_in_hours: i64,
+    },
 }
 
 pub mod html {
@@ -85,6 +91,21 @@ Email         : {user_email}
 
 (note: This is an auto generated email. Use merchant email for any further communications)",
             ),
+            EmailBody::ReportDownload {
+                report_name,
+                merchant_id,
+                link,
+                expiry_in_hours,
+            } => format!(
+                "Hello,
+
+The {report_name} report for merchant {merchant_id} is ready.
+Download it here: {link}
+
+The link expires in {expiry_in_hours} hours.
+
+(note: This is an auto generated email, please do not reply)",
+            ),
         }
     }
 }
@@ -291,3 +312,31 @@ impl EmailData for ProFeatureRequest {
         })
     }
 }
+
+pub struct ReportDownload {
+    pub recipient_email: domain::UserEmail,
+    pub report_name: String,
+    pub merchant_id: String,
+    pub link: String,
+    pub expiry_in_hours: i64,
+    pub settings: std::sync::Arc<configs::settings::Settings>,
+    pub subject: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ReportDownload {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::ReportDownload {
+            report_name: self.report_name.clone(),
+            merchant_id: self.merchant_id.clone(),
+            link: self.link.clone(),
+            expiry_in_hours: self.expiry_in_hours,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
@@ -0,0 +1,138 @@
+//! Storage of the files uploaded to, or generated by, the router
+
+#[cfg(feature = "aws_s3")]
+pub mod aws_s3;
+pub mod file_system;
+pub mod in_memory;
+
+use std::{collections::HashSet, path::Path, sync::Arc, time::Duration};
+
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+
+use crate::core::errors;
+
+/// A store which holds the files of the router, like dispute evidence, payout batch files and
+/// analytics reports
+#[async_trait::async_trait]
+pub trait FileStorage: Sync + Send + std::fmt::Debug + dyn_clone::DynClone {
+    /// Stores the file under the given key, replacing any file stored under it
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::ApiErrorResponse>;
+
+    /// Stores the file at the given local path under the given key. Stores which can, read the
+    /// file as it is being stored instead of loading it into memory first
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        let file = tokio::fs::read(path)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read the file to upload")?;
+        self.upload_file(file_key, file).await
+    }
+
+    /// Deletes the file stored under the given key
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::ApiErrorResponse>;
+
+    /// Reads the file stored under the given key
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse>;
+
+    /// Creates a URL which downloads the file directly from the store until it expires.
+    /// Stores which cannot sign URLs return `None`, the router then serves the file itself
+    async fn get_presigned_url(
+        &self,
+        _file_key: &str,
+        _expires_in: Duration,
+    ) -> CustomResult<Option<String>, errors::ApiErrorResponse> {
+        Ok(None)
+    }
+}
+
+dyn_clone::clone_trait_object!(FileStorage);
+
+/// List of available file storage backends to choose from
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStorageBackend {
+    /// Files are stored on the local file system
+    #[default]
+    FileSystem,
+    /// Files are stored in an AWS S3 bucket
+    #[cfg(feature = "aws_s3")]
+    AwsS3,
+    /// Files are kept in memory, they are lost once the router stops
+    InMemory,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct FileStorageConfig {
+    /// The backend to store files with
+    pub backend: FileStorageBackend,
+    /// Configs of the file system backend
+    pub file_system: file_system::FileSystemConfig,
+    /// Configs of the AWS S3 backend
+    #[cfg(feature = "aws_s3")]
+    pub aws_s3: aws_s3::AwsS3Config,
+    /// Content types which can be uploaded through the files API
+    pub allowed_content_types: HashSet<String>,
+    /// Validity of file download links, in seconds
+    pub download_link_expiry: i64,
+}
+
+impl Default for FileStorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: FileStorageBackend::default(),
+            file_system: file_system::FileSystemConfig::default(),
+            #[cfg(feature = "aws_s3")]
+            aws_s3: aws_s3::AwsS3Config::default(),
+            // Covers the documents connectors accept as dispute evidence besides csv uploads
+            allowed_content_types: [
+                "text/csv",
+                "text/plain",
+                "image/jpeg",
+                "image/png",
+                "image/gif",
+                "image/tiff",
+                "application/pdf",
+                "application/msword",
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            ]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+            download_link_expiry: 3600,
+        }
+    }
+}
+
+impl FileStorageConfig {
+    pub async fn get_file_storage_client(&self) -> Arc<dyn FileStorage> {
+        match self.backend {
+            FileStorageBackend::FileSystem => Arc::new(file_system::FileSystemStorage::new(
+                self.file_system.clone(),
+            )),
+            #[cfg(feature = "aws_s3")]
+            FileStorageBackend::AwsS3 => {
+                Arc::new(aws_s3::AwsS3Storage::new(self.aws_s3.clone()).await)
+            }
+            FileStorageBackend::InMemory => Arc::new(in_memory::InMemoryStorage::default()),
+        }
+    }
+
+    pub fn is_content_type_allowed(&self, content_type: &mime::Mime) -> bool {
+        self.allowed_content_types
+            .contains(content_type.essence_str())
+    }
+}
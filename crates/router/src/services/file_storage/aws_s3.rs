@@ -0,0 +1,143 @@
+use std::{path::Path, time::Duration};
+
+use aws_config::{self, meta::region::RegionProviderChain};
+use aws_sdk_s3::{config::Region, presigning::PresigningConfig, primitives::ByteStream, Client};
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+use futures::TryStreamExt;
+
+use super::FileStorage;
+use crate::core::errors;
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct AwsS3Config {
+    /// The AWS region to send file uploads
+    pub region: String,
+    /// The AWS s3 bucket to send file uploads
+    pub bucket_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AwsS3Storage {
+    client: Client,
+    bucket_name: String,
+}
+
+impl AwsS3Storage {
+    pub async fn new(config: AwsS3Config) -> Self {
+        let region_provider = RegionProviderChain::first_try(Region::new(config.region));
+        let sdk_config = aws_config::from_env().region(region_provider).load().await;
+        Self {
+            client: Client::new(&sdk_config),
+            bucket_name: config.bucket_name,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorage for AwsS3Storage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .body(file.into())
+            .send()
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("File upload to S3 failed")?;
+        Ok(())
+    }
+
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        let body = ByteStream::from_path(path)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to read the file to upload to S3")?;
+        self.client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .body(body)
+            .send()
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("File upload to S3 failed")?;
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::ApiErrorResponse> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("File delete from S3 failed")?;
+        Ok(())
+    }
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+        let mut object = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("File retrieve from S3 failed")?;
+        let mut received_data: Vec<u8> = Vec::new();
+        while let Some(bytes) = object
+            .body
+            .try_next()
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid file data received from S3")?
+        {
+            received_data.extend_from_slice(&bytes); // Collect the bytes in the Vec
+        }
+        Ok(received_data)
+    }
+
+    async fn get_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<Option<String>, errors::ApiErrorResponse> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid S3 presigned URL expiry")?;
+        let presigned_request = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .presigned(presigning_config)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to presign S3 file download")?;
+        Ok(Some(presigned_request.uri().to_string()))
+    }
+}
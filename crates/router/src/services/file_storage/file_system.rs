@@ -0,0 +1,183 @@
+use std::{
+    fs::{self, File},
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+
+use super::FileStorage;
+use crate::{core::errors, env};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct FileSystemConfig {
+    /// Directory the files are stored in
+    pub path: PathBuf,
+}
+
+impl Default for FileSystemConfig {
+    fn default() -> Self {
+        let mut path = env::workspace_path();
+        path.push("files");
+        Self { path }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FileSystemStorage {
+    config: FileSystemConfig,
+}
+
+impl FileSystemStorage {
+    pub fn new(config: FileSystemConfig) -> Self {
+        Self { config }
+    }
+
+    fn get_file_path(&self, file_key: &str) -> PathBuf {
+        let mut file_path = self.config.path.clone();
+        file_path.push(file_key);
+        file_path
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorage for FileSystemStorage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file_data: Vec<u8>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        let file_path = self.get_file_path(file_key);
+        // File keys are scoped by merchant, so the merchant directory may not exist yet
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to create file directory")?;
+        }
+        let mut file = File::create(file_path)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to create file")?;
+        file.write_all(&file_data)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while writing into file")?;
+        Ok(())
+    }
+
+    async fn upload_file_from_path(
+        &self,
+        file_key: &str,
+        path: &Path,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        let file_path = self.get_file_path(file_key);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to create file directory")?;
+        }
+        fs::copy(path, file_path)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while copying the file")?;
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::ApiErrorResponse> {
+        fs::remove_file(self.get_file_path(file_key))
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting the file")?;
+        Ok(())
+    }
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+        let mut received_data: Vec<u8> = Vec::new();
+        let mut file = File::open(self.get_file_path(file_key))
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while opening the file")?;
+        file.read_to_end(&mut received_data)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while reading the file")?;
+        Ok(received_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn storage_in_temp_dir() -> FileSystemStorage {
+        let mut path = std::env::temp_dir();
+        path.push(format!("file_storage_{}", uuid::Uuid::new_v4()));
+        FileSystemStorage::new(FileSystemConfig { path })
+    }
+
+    #[tokio::test]
+    async fn test_file_system_storage_round_trip() {
+        let storage = storage_in_temp_dir();
+        storage
+            .upload_file("merchant_1/file_1", b"evidence".to_vec())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.retrieve_file("merchant_1/file_1").await.unwrap(),
+            b"evidence".to_vec()
+        );
+        assert!(storage
+            .get_presigned_url("merchant_1/file_1", std::time::Duration::from_secs(60))
+            .await
+            .unwrap()
+            .is_none());
+
+        storage
+            .upload_file("merchant_1/file_1", b"updated evidence".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.retrieve_file("merchant_1/file_1").await.unwrap(),
+            b"updated evidence".to_vec()
+        );
+
+        storage.delete_file("merchant_1/file_1").await.unwrap();
+        assert!(storage.retrieve_file("merchant_1/file_1").await.is_err());
+        assert!(storage.delete_file("merchant_1/file_1").await.is_err());
+
+        fs::remove_dir_all(&storage.config.path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_system_storage_upload_from_path() {
+        let storage = storage_in_temp_dir();
+        let mut source = std::env::temp_dir();
+        source.push(format!("report_{}", uuid::Uuid::new_v4()));
+        fs::write(&source, b"report").unwrap();
+
+        storage
+            .upload_file_from_path("merchant_1/report_1", &source)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.retrieve_file("merchant_1/report_1").await.unwrap(),
+            b"report".to_vec()
+        );
+        // The source is copied, so it can be cleaned up independently of the stored file
+        assert!(source.exists());
+
+        fs::remove_file(&source).unwrap();
+        fs::remove_dir_all(&storage.config.path).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use common_utils::errors::CustomResult;
+use error_stack::{report, ResultExt};
+
+use super::FileStorage;
+use crate::core::errors;
+
+/// Keeps the files in the memory of the router process, meant for tests and local setups
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStorage {
+    files: Arc<RwLock<HashMap<String, Vec<u8>>>>,
+}
+
+#[async_trait::async_trait]
+impl FileStorage for InMemoryStorage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        self.files
+            .write()
+            .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to acquire the file store lock")?
+            .insert(file_key.to_string(), file);
+        Ok(())
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), errors::ApiErrorResponse> {
+        self.files
+            .write()
+            .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to acquire the file store lock")?
+            .remove(file_key)
+            .map(|_| ())
+            .ok_or(report!(errors::ApiErrorResponse::FileNotAvailable))
+            .attach_printable("File not found in the in-memory store")
+    }
+
+    async fn retrieve_file(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+        self.files
+            .read()
+            .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to acquire the file store lock")?
+            .get(file_key)
+            .cloned()
+            .ok_or(report!(errors::ApiErrorResponse::FileNotAvailable))
+            .attach_printable("File not found in the in-memory store")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unwrap_used)]
+    #[tokio::test]
+    async fn test_in_memory_storage_round_trip() {
+        let storage = InMemoryStorage::default();
+        storage
+            .upload_file("merchant_1/file_1", b"evidence".to_vec())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.retrieve_file("merchant_1/file_1").await.unwrap(),
+            b"evidence".to_vec()
+        );
+        assert!(storage
+            .get_presigned_url("merchant_1/file_1", std::time::Duration::from_secs(60))
+            .await
+            .unwrap()
+            .is_none());
+
+        storage.delete_file("merchant_1/file_1").await.unwrap();
+        assert!(storage.retrieve_file("merchant_1/file_1").await.is_err());
+        assert!(storage.delete_file("merchant_1/file_1").await.is_err());
+    }
+}
@@ -39,6 +39,8 @@ pub struct KafkaPaymentAttempt<'a> {
     // TODO: These types should implement copy ideally
     pub payment_experience: Option<&'a storage_enums::PaymentExperience>,
     pub payment_method_type: Option<&'a storage_enums::PaymentMethodType>,
+    pub retry_decision: Option<&'a String>,
+    pub retry_reason: Option<&'a String>,
 }
 
 impl<'a> KafkaPaymentAttempt<'a> {
@@ -74,6 +76,8 @@ impl<'a> KafkaPaymentAttempt<'a> {
             connector_metadata: attempt.connector_metadata.as_ref().map(|v| v.to_string()),
             payment_experience: attempt.payment_experience.as_ref(),
             payment_method_type: attempt.payment_method_type.as_ref(),
+            retry_decision: attempt.retry_decision.as_ref(),
+            retry_reason: attempt.retry_reason.as_ref(),
         }
     }
 }
@@ -63,6 +63,8 @@ pub struct CreateFileRequest {
 #[strum(serialize_all = "snake_case")]
 pub enum FilePurpose {
     DisputeEvidence,
+    PayoutBatch,
+    BlocklistImport,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +28,7 @@ pub(crate) trait MandateResponseExt: Sized {
         state: &AppState,
         key_store: domain::MerchantKeyStore,
         mandate: storage::Mandate,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> RouterResult<Self>;
 }
 
@@ -37,10 +38,11 @@ impl MandateResponseExt for MandateResponse {
         state: &AppState,
         key_store: domain::MerchantKeyStore,
         mandate: storage::Mandate,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> RouterResult<Self> {
         let db = &*state.store;
         let payment_method = db
-            .find_payment_method(&mandate.payment_method_id)
+            .find_payment_method(&mandate.payment_method_id, storage_scheme)
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
@@ -74,6 +76,7 @@ impl MandateResponseExt for MandateResponse {
         let payment_method_type = payment_method
             .payment_method_type
             .map(|pmt| pmt.to_string());
+        let remaining_amount = mandate.get_remaining_amount();
         Ok(Self {
             mandate_id: mandate.mandate_id,
             customer_acceptance: Some(api::payments::CustomerAcceptance {
@@ -93,6 +96,7 @@ impl MandateResponseExt for MandateResponse {
             payment_method: payment_method.payment_method.to_string(),
             payment_method_type,
             payment_method_id: mandate.payment_method_id,
+            remaining_amount,
         })
     }
 }
@@ -1,7 +1,9 @@
 pub use api_models::payouts::{
     AchBankTransfer, BacsBankTransfer, Bank as BankPayout, Card as CardPayout, PayoutActionRequest,
-    PayoutCreateRequest, PayoutCreateResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody,
-    PayoutRetrieveRequest, SepaBankTransfer,
+    PayoutBatchCreateRequest, PayoutBatchItemResponse, PayoutBatchResponse,
+    PayoutBatchRetrieveRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutListConstraints,
+    PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse, PayoutListResponseV2,
+    PayoutMethodData, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest, SepaBankTransfer,
 };
 
 #[cfg(feature = "payouts")]
@@ -33,6 +35,10 @@ pub struct PoQuote;
 #[derive(Debug, Clone)]
 pub struct PoRecipient;
 
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone)]
+pub struct PoSync;
+
 #[cfg(feature = "payouts")]
 pub trait PayoutCancel:
     api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
@@ -69,6 +75,12 @@ pub trait PayoutRecipient:
 {
 }
 
+#[cfg(feature = "payouts")]
+pub trait PayoutSync:
+    api::ConnectorIntegration<PoSync, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
 #[cfg(feature = "payouts")]
 pub trait Payouts:
     ConnectorCommon
@@ -78,6 +90,7 @@ pub trait Payouts:
     + PayoutFulfill
     + PayoutQuote
     + PayoutRecipient
+    + PayoutSync
 {
 }
 #[cfg(not(feature = "payouts"))]
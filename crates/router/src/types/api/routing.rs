@@ -4,8 +4,10 @@ pub use api_models::{
     enums as api_enums,
     routing::{
         ConnectorVolumeSplit, DetailedConnectorChoice, RoutableConnectorChoice, RoutingAlgorithm,
-        RoutingAlgorithmKind, RoutingAlgorithmRef, RoutingConfigRequest, RoutingDictionary,
-        RoutingDictionaryRecord, StraightThroughAlgorithm,
+        RoutingAlgorithmKind, RoutingAlgorithmRef, RoutingConfigRequest, RoutingCoverageRequest,
+        RoutingCoverageResponse, RoutingDictionary, RoutingDictionaryRecord,
+        RoutingSimulationConnectorDistribution, RoutingSimulationRequest,
+        RoutingSimulationResponse, StraightThroughAlgorithm, SuccessRateRoutingConfig,
     },
 };
 
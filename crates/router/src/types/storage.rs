@@ -15,12 +15,14 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
 pub mod mandate;
+pub mod mandate_usage;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
@@ -28,10 +30,12 @@ pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod user;
 pub mod user_role;
 
@@ -48,10 +52,12 @@ pub use scheduler::db::process_tracker;
 pub use self::{
     address::*, api_keys::*, authorization::*, blocklist::*, blocklist_fingerprint::*,
     blocklist_lookup::*, capture::*, cards_info::*, configs::*, customers::*,
-    dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    gsm::*, locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    merchant_key_store::*, payment_link::*, payment_method::*, payout_attempt::*, payouts::*,
-    process_tracker::*, refund::*, reverse_lookup::*, routing_algorithm::*, user::*, user_role::*,
+    dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*, file::*,
+    forex_rate_snapshot::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*,
+    mandate_usage::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_link::*, payment_method::*, payout_attempt::*, payout_batch::*, payouts::*,
+    process_tracker::*, refund::*, reverse_lookup::*, routing_algorithm::*, subscription::*,
+    user::*, user_role::*,
 };
 use crate::types::api::routing;
 
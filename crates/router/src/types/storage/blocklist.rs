@@ -1 +1 @@
-pub use diesel_models::blocklist::{Blocklist, BlocklistNew};
+pub use diesel_models::blocklist::{Blocklist, BlocklistExpiryWorkflow, BlocklistNew};
@@ -0,0 +1 @@
+pub use diesel_models::forex_rate_snapshot::{ForexRateSnapshot, ForexRateSnapshotNew};
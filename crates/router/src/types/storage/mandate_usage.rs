@@ -0,0 +1 @@
+pub use diesel_models::mandate_usage::{MandateUsage, MandateUsageNew};
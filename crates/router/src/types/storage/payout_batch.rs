@@ -0,0 +1,5 @@
+pub use diesel_models::payout_batch::{
+    PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+    PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate, PayoutBatchUpdateInternal,
+    PayoutBatchWorkflow,
+};
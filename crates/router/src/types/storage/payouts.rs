@@ -1 +1,361 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::{
+    consts::{PAYOUTS_LIST_MAX_LIMIT_V1, PAYOUTS_LIST_MAX_LIMIT_V2},
+    errors::CustomResult,
+};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 pub use diesel_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
+use diesel_models::{
+    enums::{Currency, PayoutStatus, PayoutType},
+    errors,
+    payout_attempt::PayoutAttempt,
+    query::generics::db_metrics,
+    schema::{payout_attempt::dsl as pa_dsl, payouts::dsl as po_dsl},
+};
+use error_stack::{IntoReport, ResultExt};
+use time::PrimitiveDateTime;
+
+use crate::{connection::PgPooledConn, logger};
+
+#[derive(Clone, Debug)]
+pub enum PayoutFetchConstraints {
+    Single { payout_id: String },
+    List(Box<PayoutListParams>),
+}
+
+#[derive(Clone, Debug)]
+pub struct PayoutListParams {
+    pub offset: u32,
+    pub starting_at: Option<PrimitiveDateTime>,
+    pub ending_at: Option<PrimitiveDateTime>,
+    pub connector: Option<Vec<api_models::enums::PayoutConnectors>>,
+    pub currency: Option<Vec<Currency>>,
+    pub status: Option<Vec<PayoutStatus>>,
+    pub payout_method: Option<Vec<PayoutType>>,
+    pub entity_type: Option<Vec<api_models::enums::PayoutEntityType>>,
+    pub profile_id: Option<String>,
+    pub customer_id: Option<String>,
+    pub starting_after_id: Option<String>,
+    pub ending_before_id: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl From<api_models::payouts::PayoutListConstraints> for PayoutFetchConstraints {
+    fn from(value: api_models::payouts::PayoutListConstraints) -> Self {
+        Self::List(Box::new(PayoutListParams {
+            offset: 0,
+            starting_at: value.created_gte.or(value.created_gt).or(value.created),
+            ending_at: value.created_lte.or(value.created_lt).or(value.created),
+            connector: None,
+            currency: None,
+            status: None,
+            payout_method: None,
+            entity_type: None,
+            profile_id: None,
+            customer_id: value.customer_id,
+            starting_after_id: value.starting_after,
+            ending_before_id: value.ending_before,
+            limit: Some(std::cmp::min(value.limit, PAYOUTS_LIST_MAX_LIMIT_V1)),
+        }))
+    }
+}
+
+impl From<api_models::payouts::PayoutListFilterConstraints> for PayoutFetchConstraints {
+    fn from(value: api_models::payouts::PayoutListFilterConstraints) -> Self {
+        if let Some(payout_id) = value.payout_id {
+            Self::Single { payout_id }
+        } else {
+            Self::List(Box::new(PayoutListParams {
+                offset: value.offset.unwrap_or_default(),
+                starting_at: value.time_range.map(|t| t.start_time),
+                ending_at: value.time_range.and_then(|t| t.end_time),
+                connector: value.connector,
+                currency: value.currency,
+                status: value.status,
+                payout_method: value.payout_method,
+                entity_type: value.entity_type,
+                profile_id: value.profile_id,
+                customer_id: value.customer_id,
+                starting_after_id: None,
+                ending_before_id: None,
+                limit: Some(std::cmp::min(value.limit, PAYOUTS_LIST_MAX_LIMIT_V2)),
+            }))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait PayoutsDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError>;
+
+    async fn get_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &PayoutFetchConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError>;
+
+    async fn filter_by_meta_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutsDbExt for Payouts {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &PayoutFetchConstraints,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError> {
+        let mut query = <Self as HasTable>::table()
+            .inner_join(
+                diesel_models::schema::payout_attempt::table.on(pa_dsl::payout_id
+                    .eq(po_dsl::payout_id)
+                    .and(pa_dsl::merchant_id.eq(po_dsl::merchant_id))),
+            )
+            .filter(po_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(po_dsl::created_at.desc())
+            .into_boxed();
+
+        query = match constraints {
+            PayoutFetchConstraints::Single { payout_id } => {
+                query.filter(po_dsl::payout_id.eq(payout_id.to_owned()))
+            }
+            PayoutFetchConstraints::List(params) => {
+                if let Some(limit) = params.limit {
+                    query = query.limit(limit.into());
+                }
+
+                query = query.offset(params.offset.into());
+
+                if let Some(customer_id) = &params.customer_id {
+                    query = query.filter(po_dsl::customer_id.eq(customer_id.clone()));
+                }
+
+                if let Some(profile_id) = &params.profile_id {
+                    query = query.filter(pa_dsl::profile_id.eq(profile_id.clone()));
+                }
+
+                query = match (params.starting_at, &params.starting_after_id) {
+                    (Some(starting_at), _) => query.filter(po_dsl::created_at.ge(starting_at)),
+                    (None, Some(starting_after_id)) => {
+                        let starting_at = Self::find_by_merchant_id_payout_id(
+                            conn,
+                            merchant_id,
+                            starting_after_id,
+                        )
+                        .await?
+                        .created_at;
+                        query.filter(po_dsl::created_at.ge(starting_at))
+                    }
+                    (None, None) => query,
+                };
+
+                query = match (params.ending_at, &params.ending_before_id) {
+                    (Some(ending_at), _) => query.filter(po_dsl::created_at.le(ending_at)),
+                    (None, Some(ending_before_id)) => {
+                        let ending_at = Self::find_by_merchant_id_payout_id(
+                            conn,
+                            merchant_id,
+                            ending_before_id,
+                        )
+                        .await?
+                        .created_at;
+                        query.filter(po_dsl::created_at.le(ending_at))
+                    }
+                    (None, None) => query,
+                };
+
+                if let Some(currency) = &params.currency {
+                    query = query.filter(po_dsl::destination_currency.eq_any(currency.clone()));
+                }
+
+                if let Some(connector) = &params.connector {
+                    let connector = connector
+                        .iter()
+                        .map(|connector| connector.to_string())
+                        .collect::<Vec<_>>();
+                    query = query.filter(pa_dsl::connector.eq_any(connector));
+                }
+
+                if let Some(status) = &params.status {
+                    query = query.filter(pa_dsl::status.eq_any(status.clone()));
+                }
+
+                if let Some(payout_method) = &params.payout_method {
+                    query = query.filter(po_dsl::payout_type.eq_any(payout_method.clone()));
+                }
+
+                if let Some(entity_type) = &params.entity_type {
+                    query = query.filter(po_dsl::entity_type.eq_any(entity_type.clone()));
+                }
+
+                query
+            }
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(Self, PayoutAttempt)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering payout records by predicate")
+    }
+
+    async fn get_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &PayoutFetchConstraints,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        let mut query = <Self as HasTable>::table()
+            .inner_join(
+                diesel_models::schema::payout_attempt::table.on(pa_dsl::payout_id
+                    .eq(po_dsl::payout_id)
+                    .and(pa_dsl::merchant_id.eq(po_dsl::merchant_id))),
+            )
+            .count()
+            .filter(po_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        query = match constraints {
+            PayoutFetchConstraints::Single { payout_id } => {
+                query.filter(po_dsl::payout_id.eq(payout_id.to_owned()))
+            }
+            PayoutFetchConstraints::List(params) => {
+                if let Some(customer_id) = &params.customer_id {
+                    query = query.filter(po_dsl::customer_id.eq(customer_id.clone()));
+                }
+
+                if let Some(profile_id) = &params.profile_id {
+                    query = query.filter(pa_dsl::profile_id.eq(profile_id.clone()));
+                }
+
+                if let Some(starting_at) = params.starting_at {
+                    query = query.filter(po_dsl::created_at.ge(starting_at));
+                }
+
+                if let Some(ending_at) = params.ending_at {
+                    query = query.filter(po_dsl::created_at.le(ending_at));
+                }
+
+                if let Some(currency) = &params.currency {
+                    query = query.filter(po_dsl::destination_currency.eq_any(currency.clone()));
+                }
+
+                if let Some(connector) = &params.connector {
+                    let connector = connector
+                        .iter()
+                        .map(|connector| connector.to_string())
+                        .collect::<Vec<_>>();
+                    query = query.filter(pa_dsl::connector.eq_any(connector));
+                }
+
+                if let Some(status) = &params.status {
+                    query = query.filter(pa_dsl::status.eq_any(status.clone()));
+                }
+
+                if let Some(payout_method) = &params.payout_method {
+                    query = query.filter(po_dsl::payout_type.eq_any(payout_method.clone()));
+                }
+
+                if let Some(entity_type) = &params.entity_type {
+                    query = query.filter(po_dsl::entity_type.eq_any(entity_type.clone()));
+                }
+
+                query
+            }
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable_lazy(|| "Error counting payout records by predicate")
+    }
+
+    async fn filter_by_meta_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::payments::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError> {
+        let start_time = time_range.start_time;
+
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        let filter = <Self as HasTable>::table()
+            .inner_join(
+                diesel_models::schema::payout_attempt::table.on(pa_dsl::payout_id
+                    .eq(po_dsl::payout_id)
+                    .and(pa_dsl::merchant_id.eq(po_dsl::merchant_id))),
+            )
+            .filter(po_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(po_dsl::created_at.ge(start_time))
+            .filter(po_dsl::created_at.le(end_time));
+
+        let filter_connector: Vec<String> = filter
+            .clone()
+            .select(pa_dsl::connector)
+            .distinct()
+            .order_by(pa_dsl::connector.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by connector")?;
+
+        let filter_currency: Vec<Currency> = filter
+            .clone()
+            .select(po_dsl::destination_currency)
+            .distinct()
+            .order_by(po_dsl::destination_currency.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by currency")?;
+
+        let filter_status: Vec<PayoutStatus> = filter
+            .clone()
+            .select(pa_dsl::status)
+            .distinct()
+            .order_by(pa_dsl::status.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout status")?;
+
+        let filter_payout_method: Vec<PayoutType> = filter
+            .select(po_dsl::payout_type)
+            .distinct()
+            .order_by(po_dsl::payout_type.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout type")?;
+
+        Ok(api_models::payouts::PayoutListFilters {
+            connector: filter_connector,
+            currency: filter_currency,
+            status: filter_status,
+            payout_method: filter_payout_method,
+        })
+    }
+}
@@ -271,6 +271,7 @@ impl ForeignFrom<storage_enums::MandateAmountData> for api_models::payments::Man
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_transaction_amount: from.max_transaction_amount,
         }
     }
 }
@@ -305,6 +306,7 @@ impl ForeignFrom<api_models::payments::MandateData> for data_models::mandates::M
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_transaction_amount: i.max_transaction_amount,
                         },
                     ))
                 }
@@ -316,6 +318,7 @@ impl ForeignFrom<api_models::payments::MandateData> for data_models::mandates::M
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_transaction_amount: i.max_transaction_amount,
                         },
                     )
                 }
@@ -335,6 +338,7 @@ impl ForeignFrom<api_models::payments::MandateAmountData> for storage_enums::Man
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_transaction_amount: from.max_transaction_amount,
         }
     }
 }
@@ -521,6 +525,25 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::PayoutStatus) -> Self {
+        match value {
+            storage_enums::PayoutStatus::Success => Some(storage_enums::EventType::PayoutSuccess),
+            storage_enums::PayoutStatus::Failed | storage_enums::PayoutStatus::Ineligible => {
+                Some(storage_enums::EventType::PayoutFailed)
+            }
+            storage_enums::PayoutStatus::Pending => {
+                Some(storage_enums::EventType::PayoutProcessing)
+            }
+            storage_enums::PayoutStatus::Reversed => Some(storage_enums::EventType::PayoutReversed),
+            storage_enums::PayoutStatus::Cancelled
+            | storage_enums::PayoutStatus::RequiresCreation
+            | storage_enums::PayoutStatus::RequiresPayoutMethodData
+            | storage_enums::PayoutStatus::RequiresFulfillment => None,
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -858,6 +881,41 @@ impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
     }
 }
 
+impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt)>
+    for api_models::payouts::PayoutCreateResponse
+{
+    fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt)) -> Self {
+        let (payout, payout_attempt) = item;
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.destination_currency,
+            connector: Some(payout_attempt.connector),
+            payout_type: payout.payout_type,
+            billing: None,
+            customer_id: payout.customer_id,
+            auto_fulfill: payout.auto_fulfill,
+            email: None,
+            name: None,
+            phone: None,
+            phone_country_code: None,
+            client_secret: None,
+            return_url: payout.return_url,
+            business_country: payout_attempt.business_country,
+            business_label: payout_attempt.business_label,
+            description: payout.description,
+            entity_type: payout.entity_type,
+            recurring: payout.recurring,
+            metadata: payout.metadata,
+            status: payout_attempt.status,
+            error_message: payout_attempt.error_message,
+            error_code: payout_attempt.error_code,
+            profile_id: payout_attempt.profile_id,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::payouts::Bank> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::Bank) -> Self {
         match value {
@@ -1024,6 +1082,7 @@ impl ForeignFrom<gsm_api_types::GsmCreateRequest> for storage::GatewayStatusMapp
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            error_category: value.error_category.map(|category| category.to_string()),
         }
     }
 }
@@ -1042,6 +1101,9 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             step_up_possible: value.step_up_possible,
             unified_code: value.unified_code,
             unified_message: value.unified_message,
+            error_category: value
+                .error_category
+                .and_then(|category| category.parse_enum("GsmErrorCategory").ok()),
         }
     }
 }
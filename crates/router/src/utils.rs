@@ -302,6 +302,7 @@ pub async fn find_payment_intent_from_mandate_id_type(
             .find_mandate_by_merchant_id_mandate_id(
                 &merchant_account.merchant_id,
                 mandate_id.as_str(),
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -309,6 +310,7 @@ pub async fn find_payment_intent_from_mandate_id_type(
             .find_mandate_by_merchant_id_connector_mandate_id(
                 &merchant_account.merchant_id,
                 connector_mandate_id.as_str(),
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?,
@@ -770,6 +772,57 @@ where
     Ok(())
 }
 
+#[cfg(feature = "payouts")]
+pub async fn trigger_payouts_webhook(
+    state: &crate::routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &crate::core::payouts::PayoutData,
+) -> RouterResult<()> {
+    let status = payout_data.payout_attempt.status;
+    let event_type: Option<enums::EventType> = ForeignFrom::foreign_from(status);
+
+    let Some(event_type) = event_type else {
+        logger::debug!(payout_status = %status, "No outgoing webhook for payout status");
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&payout_data.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: payout_data.profile_id.to_owned(),
+        })?;
+
+    let payout_id = payout_data.payouts.payout_id.clone();
+    let payout_response = crate::core::payouts::make_payout_response(merchant_account, payout_data);
+    let m_state = state.clone();
+    let merchant_account = merchant_account.clone();
+    // Same as payments, the webhook is delivered from a detached task so that a slow or failing
+    // merchant endpoint does not hold up the payout flow.
+    tokio::spawn(
+        async move {
+            Box::pin(
+                webhooks_core::create_event_and_trigger_appropriate_outgoing_webhook(
+                    m_state,
+                    merchant_account,
+                    business_profile,
+                    event_type,
+                    diesel_models::enums::EventClass::Payouts,
+                    None,
+                    payout_id,
+                    diesel_models::enums::EventObjectType::PayoutDetails,
+                    webhooks::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+                ),
+            )
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
 type Handle<T> = tokio::task::JoinHandle<RouterResult<T>>;
 
 pub async fn flatten_join_error<T>(handle: Handle<T>) -> RouterResult<T> {
@@ -1,32 +1,32 @@
-use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc, time::Duration};
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
 
 use api_models::enums;
 use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
-use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use currency_conversion::types::{ConversionOptions, CurrencyFactors, ExchangeRates};
 use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "hashicorp-vault")]
 use external_services::hashicorp_vault::{self, decrypt::VaultFetch};
 #[cfg(feature = "kms")]
 use external_services::kms;
+#[cfg(feature = "kms")]
 use masking::PeekInterface;
+use masking::Secret;
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
-use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
 use tokio::{sync::RwLock, time::sleep};
 
+mod providers;
+
+pub use self::providers::{ForexProvider, ForexRates};
 use crate::{
+    db::StorageInterface,
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, AppState,
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexApi, ForexProviderConfig},
+    types::storage,
+    AppState,
 };
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
-const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
@@ -75,27 +75,14 @@ pub enum ForexCacheError {
     RedisWriteError,
     #[error("Not able to acquire write lock")]
     WriteLockNotAcquired,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
-#[serde(transparent)]
-struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
-
-impl Deref for FloatDecimal {
-    type Target = Decimal;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+    #[error("Forex rates not available for the requested date")]
+    HistoricalRatesNotFound,
+    #[error("Forex rates requested for a date in the future")]
+    FutureRateDate,
+    #[error("Error reading forex rate snapshot")]
+    SnapshotReadError,
+    #[error("Error writing forex rate snapshot")]
+    SnapshotWriteError,
 }
 
 impl FxExchangeRatesCacheEntry {
@@ -428,90 +415,20 @@ async fn handler_local_expired(
 async fn fetch_forex_rates(
     state: &AppState,
     #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
-
     #[cfg(feature = "hashicorp-vault")]
     hc_config: &external_services::hashicorp_vault::HashiCorpVaultConfig,
 ) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexCacheError>> {
-    let forex_api_key = async {
-        #[cfg(feature = "hashicorp-vault")]
-        let client = hashicorp_vault::get_hashicorp_client(hc_config)
-            .await
-            .change_context(ForexCacheError::KmsDecryptionFailed)?;
-
-        #[cfg(not(feature = "hashicorp-vault"))]
-        let output = state.conf.forex_api.api_key.clone();
+    let provider = get_forex_provider(
+        &state.conf.forex_api.provider,
+        &state.conf.forex_api.api_key,
+        #[cfg(feature = "kms")]
+        kms_config,
         #[cfg(feature = "hashicorp-vault")]
-        let output = state
-            .conf
-            .forex_api
-            .api_key
-            .clone()
-            .fetch_inner::<hashicorp_vault::Kv2>(client)
-            .await
-            .change_context(ForexCacheError::KmsDecryptionFailed)?;
-
-        Ok::<_, error_stack::Report<ForexCacheError>>(output)
-    }
+        hc_config,
+    )
     .await?;
-    #[cfg(feature = "kms")]
-    let forex_api_key = kms::get_kms_client(kms_config)
-        .await
-        .decrypt(forex_api_key.peek())
-        .await
-        .change_context(ForexCacheError::KmsDecryptionFailed)?;
-
-    #[cfg(not(feature = "kms"))]
-    let forex_api_key = forex_api_key.peek();
-
-    let forex_url: String = format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(?forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .into_report()
-        .change_context(ForexCacheError::ParsingError)?;
 
-    logger::info!("{:?}", forex_response);
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
-            }
-        };
-    }
-
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
+    fetch_and_snapshot_forex_rates(state, &state.conf.forex_api.provider, provider.as_ref()).await
 }
 
 pub async fn fallback_fetch_forex_rates(
@@ -520,98 +437,186 @@ pub async fn fallback_fetch_forex_rates(
     #[cfg(feature = "hashicorp-vault")]
     hc_config: &external_services::hashicorp_vault::HashiCorpVaultConfig,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
-    let fallback_api_key = async {
+    let provider = get_forex_provider(
+        &state.conf.forex_api.fallback_provider,
+        &state.conf.forex_api.fallback_api_key,
+        #[cfg(feature = "kms")]
+        kms_config,
         #[cfg(feature = "hashicorp-vault")]
+        hc_config,
+    )
+    .await?;
+
+    let rates = fetch_and_snapshot_forex_rates(
+        state,
+        &state.conf.forex_api.fallback_provider,
+        provider.as_ref(),
+    )
+    .await?;
+    match acquire_redis_lock(state).await {
+        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
+        Err(e) => {
+            logger::error!(?e);
+            Ok(rates)
+        }
+    }
+}
+
+async fn get_forex_provider(
+    provider_config: &ForexProviderConfig,
+    api_key: &Secret<String>,
+    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "hashicorp-vault")]
+    hc_config: &external_services::hashicorp_vault::HashiCorpVaultConfig,
+) -> CustomResult<Box<dyn ForexProvider>, ForexCacheError> {
+    Ok(match provider_config {
+        ForexProviderConfig::OpenExchangeRates => Box::new(providers::OpenExchangeRates {
+            api_key: get_forex_api_key(
+                api_key,
+                #[cfg(feature = "kms")]
+                kms_config,
+                #[cfg(feature = "hashicorp-vault")]
+                hc_config,
+            )
+            .await?,
+        }),
+        ForexProviderConfig::ApiLayer => Box::new(providers::ApiLayer {
+            api_key: get_forex_api_key(
+                api_key,
+                #[cfg(feature = "kms")]
+                kms_config,
+                #[cfg(feature = "hashicorp-vault")]
+                hc_config,
+            )
+            .await?,
+        }),
+        ForexProviderConfig::StaticFile { path } => {
+            Box::new(providers::StaticFile { path: path.clone() })
+        }
+        ForexProviderConfig::EcbXml { url } => Box::new(providers::EcbXml { url: url.clone() }),
+    })
+}
+
+async fn get_forex_api_key(
+    api_key: &Secret<String>,
+    #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    #[cfg(feature = "hashicorp-vault")]
+    hc_config: &external_services::hashicorp_vault::HashiCorpVaultConfig,
+) -> CustomResult<Secret<String>, ForexCacheError> {
+    #[cfg(feature = "hashicorp-vault")]
+    let api_key = {
         let client = hashicorp_vault::get_hashicorp_client(hc_config)
             .await
             .change_context(ForexCacheError::KmsDecryptionFailed)?;
 
-        #[cfg(not(feature = "hashicorp-vault"))]
-        let output = state.conf.forex_api.fallback_api_key.clone();
-        #[cfg(feature = "hashicorp-vault")]
-        let output = state
-            .conf
-            .forex_api
-            .fallback_api_key
+        api_key
             .clone()
             .fetch_inner::<hashicorp_vault::Kv2>(client)
             .await
-            .change_context(ForexCacheError::KmsDecryptionFailed)?;
+            .change_context(ForexCacheError::KmsDecryptionFailed)?
+    };
 
-        Ok::<_, error_stack::Report<ForexCacheError>>(output)
-    }
-    .await?;
     #[cfg(feature = "kms")]
-    let fallback_forex_api_key = kms::get_kms_client(kms_config)
+    let api_key = kms::get_kms_client(kms_config)
         .await
-        .decrypt(fallback_api_key.peek())
-        .await
-        .change_context(ForexCacheError::KmsDecryptionFailed)?;
-
-    #[cfg(not(feature = "kms"))]
-    let fallback_forex_api_key = fallback_api_key.peek();
-
-    let fallback_forex_url: String =
-        format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key,);
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(?fallback_forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
+        .decrypt(api_key.peek())
         .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .into_report()
-        .change_context(ForexCacheError::ParsingError)?;
+        .change_context(ForexCacheError::KmsDecryptionFailed)?
+        .into();
 
-    logger::info!("{:?}", fallback_forex_response);
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
-            )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
-            }
-        };
+    #[cfg(not(any(feature = "kms", feature = "hashicorp-vault")))]
+    let api_key = api_key.clone();
+
+    Ok(api_key)
+}
+
+async fn fetch_and_snapshot_forex_rates(
+    state: &AppState,
+    provider_config: &ForexProviderConfig,
+    provider: &dyn ForexProvider,
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    let rates = provider.fetch_rates(state).await?;
+
+    if let Err(error) = save_forex_snapshot(&*state.store, provider_config.name(), &rates).await {
+        logger::error!(?error, "Failed to store forex rate snapshot");
     }
 
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-        Err(e) => {
-            logger::error!(?e);
-            Ok(rates)
+    Ok(FxExchangeRatesCacheEntry::new(rates.exchange_rates))
+}
+
+/// Persists the rates a provider published for a day. The first rates stored for a day are kept
+/// as that provider's rates for the day, so that conversions against a date always resolve to
+/// the same rates.
+async fn save_forex_snapshot(
+    db: &dyn StorageInterface,
+    provider_name: &str,
+    rates: &ForexRates,
+) -> CustomResult<(), ForexCacheError> {
+    let snapshot = storage::ForexRateSnapshotNew {
+        rate_date: rates.rate_date,
+        provider: provider_name.to_string(),
+        base_currency: rates.exchange_rates.base_currency,
+        rates: serde_json::to_value(&rates.exchange_rates.conversion)
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?,
+        created_at: date_time::now(),
+    };
+
+    match db.insert_forex_rate_snapshot(snapshot).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.change_context(ForexCacheError::SnapshotWriteError)),
+    }
+}
+
+/// Finds the rates that applied on `rate_date`. Snapshots of the primary provider are preferred,
+/// those of the fallback provider are used for the days the primary provider could not be reached.
+/// Snapshots older than `max_snapshot_age_days` before `rate_date` are not used.
+async fn get_historical_forex_rates(
+    db: &dyn StorageInterface,
+    forex_api: &ForexApi,
+    rate_date: time::Date,
+) -> CustomResult<(ExchangeRates, time::Date), ForexCacheError> {
+    if rate_date > date_time::now().date() {
+        Err(ForexCacheError::FutureRateDate).into_report()?
+    }
+
+    let mut latest_snapshot: Option<storage::ForexRateSnapshot> = None;
+    for provider in [
+        forex_api.provider.name(),
+        forex_api.fallback_provider.name(),
+    ] {
+        match db
+            .find_forex_rate_snapshot_on_or_before(provider, rate_date)
+            .await
+        {
+            Ok(snapshot) => {
+                if latest_snapshot
+                    .as_ref()
+                    .map_or(true, |latest| snapshot.rate_date > latest.rate_date)
+                {
+                    latest_snapshot = Some(snapshot);
+                }
+            }
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => return Err(error.change_context(ForexCacheError::SnapshotReadError)),
         }
     }
+    let oldest_rate_date = rate_date
+        .checked_sub(time::Duration::days(forex_api.max_snapshot_age_days.into()))
+        .unwrap_or(time::Date::MIN);
+    let snapshot = latest_snapshot
+        .filter(|snapshot| snapshot.rate_date >= oldest_rate_date)
+        .ok_or(ForexCacheError::HistoricalRatesNotFound)
+        .into_report()?;
+    let conversion = serde_json::from_value(snapshot.rates)
+        .into_report()
+        .change_context(ForexCacheError::ParsingError)?;
+
+    Ok((
+        ExchangeRates::new(snapshot.base_currency, conversion),
+        snapshot.rate_date,
+    ))
 }
 
 async fn release_redis_lock(
@@ -686,27 +691,46 @@ async fn is_redis_expired(
     })
 }
 
+/// Converts `amount` using the latest rates, or the rates that applied on `transaction_date`
+/// when one is provided. The result is settled into the minor unit of `to_currency` as per
+/// `options`.
 pub async fn convert_currency(
     state: AppState,
     amount: i64,
     to_currency: String,
     from_currency: String,
+    transaction_date: Option<time::PrimitiveDateTime>,
+    options: ConversionOptions,
     #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
     #[cfg(feature = "hashicorp-vault")]
     hc_config: &external_services::hashicorp_vault::HashiCorpVaultConfig,
 ) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexCacheError> {
-    let rates = get_forex_rates(
-        &state,
-        state.conf.forex_api.call_delay,
-        state.conf.forex_api.local_fetch_retry_delay,
-        state.conf.forex_api.local_fetch_retry_count,
-        #[cfg(feature = "kms")]
-        kms_config,
-        #[cfg(feature = "hashicorp-vault")]
-        hc_config,
-    )
-    .await
-    .change_context(ForexCacheError::ApiError)?;
+    let (rates, rate_date) = match transaction_date {
+        Some(transaction_date) => {
+            let (rates, rate_date) = get_historical_forex_rates(
+                &*state.store,
+                &state.conf.forex_api,
+                transaction_date.date(),
+            )
+            .await?;
+            (Arc::new(rates), Some(rate_date))
+        }
+        None => {
+            let rates = get_forex_rates(
+                &state,
+                state.conf.forex_api.call_delay,
+                state.conf.forex_api.local_fetch_retry_delay,
+                state.conf.forex_api.local_fetch_retry_count,
+                #[cfg(feature = "kms")]
+                kms_config,
+                #[cfg(feature = "hashicorp-vault")]
+                hc_config,
+            )
+            .await
+            .change_context(ForexCacheError::ApiError)?;
+            (rates.data, None)
+        }
+    };
 
     let to_currency = api_models::enums::Currency::from_str(to_currency.as_str())
         .into_report()
@@ -716,13 +740,19 @@ pub async fn convert_currency(
         .into_report()
         .change_context(ForexCacheError::CurrencyNotAcceptable)?;
 
-    let converted_amount =
-        currency_conversion::conversion::convert(&rates.data, from_currency, to_currency, amount)
-            .into_report()
-            .change_context(ForexCacheError::ConversionError)?;
+    let converted_amount = currency_conversion::conversion::convert(
+        &rates,
+        from_currency,
+        to_currency,
+        amount,
+        &options,
+    )
+    .into_report()
+    .change_context(ForexCacheError::ConversionError)?;
 
     Ok(api_models::currency::CurrencyConversionResponse {
         converted_amount: converted_amount.to_string(),
         currency: to_currency.to_string(),
+        rate_date: rate_date.map(|date| date.to_string()),
     })
 }
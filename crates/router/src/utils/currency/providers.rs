@@ -0,0 +1,416 @@
+use std::{collections::HashMap, ops::Deref, str::FromStr};
+
+use api_models::enums;
+use common_utils::errors::CustomResult;
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+use time::{Date, OffsetDateTime};
+
+use super::ForexCacheError;
+use crate::{logger, routes::app::settings::DefaultExchangeRates, services, AppState};
+
+const FOREX_API_TIMEOUT: u64 = 5;
+const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const FOREX_BASE_CURRENCY: &str = "&base=USD";
+const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+const ECB_BASE_CURRENCY: enums::Currency = enums::Currency::EUR;
+
+/// Rates returned by a provider along with the day they were published for.
+#[derive(Debug, Clone)]
+pub struct ForexRates {
+    pub exchange_rates: ExchangeRates,
+    pub rate_date: Date,
+}
+
+#[async_trait::async_trait]
+pub trait ForexProvider: Send + Sync {
+    async fn fetch_rates(&self, state: &AppState) -> CustomResult<ForexRates, ForexCacheError>;
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ForexResponse {
+    pub rates: HashMap<String, FloatDecimal>,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FallbackForexResponse {
+    pub quotes: HashMap<String, FloatDecimal>,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
+
+impl Deref for FloatDecimal {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EcbEnvelope {
+    #[serde(rename = "Cube")]
+    cube: EcbCube,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EcbCube {
+    #[serde(rename = "Cube", default)]
+    days: Vec<EcbDailyCube>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EcbDailyCube {
+    #[serde(rename = "@time")]
+    time: String,
+    #[serde(rename = "Cube", default)]
+    rates: Vec<EcbRate>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EcbRate {
+    #[serde(rename = "@currency")]
+    currency: String,
+    #[serde(rename = "@rate", with = "rust_decimal::serde::str")]
+    rate: Decimal,
+}
+
+/// The latest rates from openexchangerates.org, quoted against USD.
+#[derive(Debug)]
+pub struct OpenExchangeRates {
+    pub api_key: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for OpenExchangeRates {
+    async fn fetch_rates(&self, state: &AppState) -> CustomResult<ForexRates, ForexCacheError> {
+        let forex_url = format!(
+            "{}{}{}",
+            FOREX_BASE_URL,
+            self.api_key.peek(),
+            FOREX_BASE_CURRENCY
+        );
+        let response = send_forex_request(state, &forex_url).await?;
+        let forex_response = response
+            .json::<ForexResponse>()
+            .await
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", forex_response);
+
+        Ok(ForexRates {
+            exchange_rates: get_exchange_rates(enums::Currency::USD, |currency| {
+                forex_response
+                    .rates
+                    .get(&currency.to_string())
+                    .map(|rate| **rate)
+            }),
+            rate_date: get_date_from_timestamp(forex_response.timestamp)?,
+        })
+    }
+}
+
+/// The latest rates from apilayer.net, quoted against USD.
+#[derive(Debug)]
+pub struct ApiLayer {
+    pub api_key: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for ApiLayer {
+    async fn fetch_rates(&self, state: &AppState) -> CustomResult<ForexRates, ForexCacheError> {
+        let fallback_forex_url = format!("{}{}", FALLBACK_FOREX_BASE_URL, self.api_key.peek());
+        let response = send_forex_request(state, &fallback_forex_url).await?;
+        let fallback_forex_response = response
+            .json::<FallbackForexResponse>()
+            .await
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", fallback_forex_response);
+
+        Ok(ForexRates {
+            exchange_rates: get_exchange_rates(enums::Currency::USD, |currency| {
+                fallback_forex_response
+                    .quotes
+                    .get(format!("{FALLBACK_FOREX_API_CURRENCY_PREFIX}{currency}").as_str())
+                    .map(|rate| **rate)
+            }),
+            rate_date: get_date_from_timestamp(fallback_forex_response.timestamp)?,
+        })
+    }
+}
+
+/// Rates read from a JSON file on disk, useful for environments without outbound access.
+#[derive(Debug)]
+pub struct StaticFile {
+    pub path: String,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for StaticFile {
+    async fn fetch_rates(&self, _state: &AppState) -> CustomResult<ForexRates, ForexCacheError> {
+        let contents = tokio::fs::read_to_string(&self.path)
+            .await
+            .into_report()
+            .change_context(ForexCacheError::LocalReadError)
+            .attach_printable_lazy(|| format!("Unable to read forex rates from {}", self.path))?;
+        parse_static_file_rates(&contents)
+    }
+}
+
+fn parse_static_file_rates(contents: &str) -> CustomResult<ForexRates, ForexCacheError> {
+    let default_rates = serde_json::from_str::<DefaultExchangeRates>(contents)
+        .into_report()
+        .change_context(ForexCacheError::ParsingError)?;
+    let rate_date = get_date_from_timestamp(default_rates.timestamp)?;
+
+    Ok(ForexRates {
+        exchange_rates: ExchangeRates::try_from(default_rates)?,
+        rate_date,
+    })
+}
+
+/// Daily reference rates in the ECB `eurofxref` XML format, quoted against EUR.
+///
+/// When the feed carries several days (as the historical feeds do), the most recent day is used.
+#[derive(Debug)]
+pub struct EcbXml {
+    pub url: String,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for EcbXml {
+    async fn fetch_rates(&self, state: &AppState) -> CustomResult<ForexRates, ForexCacheError> {
+        let response = send_forex_request(state, &self.url).await?;
+        let body = response
+            .text()
+            .await
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+        parse_ecb_rates(&body)
+    }
+}
+
+fn parse_ecb_rates(body: &str) -> CustomResult<ForexRates, ForexCacheError> {
+    let envelope = quick_xml::de::from_str::<EcbEnvelope>(body)
+        .into_report()
+        .change_context(ForexCacheError::ParsingError)?;
+
+    let date_format = time::format_description::parse("[year]-[month]-[day]")
+        .into_report()
+        .change_context(ForexCacheError::ParsingError)?;
+    let (rate_date, latest) = envelope
+        .cube
+        .days
+        .into_iter()
+        .map(|day| {
+            Date::parse(&day.time, &date_format)
+                .into_report()
+                .change_context(ForexCacheError::ParsingError)
+                .map(|date| (date, day.rates))
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .max_by_key(|(date, _)| *date)
+        .ok_or(ForexCacheError::ParsingError)
+        .into_report()
+        .attach_printable("No rates present in the ECB feed")?;
+
+    let rates = latest
+        .into_iter()
+        .filter_map(|rate| {
+            enums::Currency::from_str(&rate.currency)
+                .ok()
+                .map(|currency| (currency, rate.rate))
+        })
+        .chain(std::iter::once((ECB_BASE_CURRENCY, Decimal::ONE)))
+        .collect::<HashMap<_, _>>();
+
+    Ok(ForexRates {
+        exchange_rates: get_exchange_rates(ECB_BASE_CURRENCY, |currency| {
+            rates.get(&currency).copied()
+        }),
+        rate_date,
+    })
+}
+
+async fn send_forex_request(
+    state: &AppState,
+    url: &str,
+) -> CustomResult<reqwest::Response, ForexCacheError> {
+    let forex_request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(url)
+        .build();
+
+    logger::info!(?forex_request);
+    state
+        .api_client
+        .send_request(
+            &state.clone(),
+            forex_request,
+            Some(FOREX_API_TIMEOUT),
+            false,
+        )
+        .await
+        .change_context(ForexCacheError::ApiUnresponsive)
+}
+
+/// Builds the conversion factors for every supported currency from rates quoted against
+/// `base_currency`, skipping currencies the provider did not return.
+fn get_exchange_rates(
+    base_currency: enums::Currency,
+    get_rate: impl Fn(enums::Currency) -> Option<Decimal>,
+) -> ExchangeRates {
+    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+    for enum_curr in enums::Currency::iter() {
+        match get_rate(enum_curr) {
+            Some(rate) => {
+                let from_factor = match Decimal::new(1, 0).checked_div(rate) {
+                    Some(rate) => rate,
+                    None => {
+                        logger::error!("Rates for {} not received from API", &enum_curr);
+                        continue;
+                    }
+                };
+                let currency_factors = CurrencyFactors::new(rate, from_factor);
+                conversions.insert(enum_curr, currency_factors);
+            }
+            None => {
+                logger::error!("Rates for {} not received from API", &enum_curr);
+            }
+        };
+    }
+
+    ExchangeRates::new(base_currency, conversions)
+}
+
+fn get_date_from_timestamp(timestamp: i64) -> CustomResult<Date, ForexCacheError> {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .into_report()
+        .change_context(ForexCacheError::ParsingError)
+        .map(OffsetDateTime::date)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    const ECB_FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+    <gesmes:subject>Reference rates</gesmes:subject>
+    <gesmes:Sender>
+        <gesmes:name>European Central Bank</gesmes:name>
+    </gesmes:Sender>
+    <Cube>
+        <Cube time="2024-01-18">
+            <Cube currency="USD" rate="1.0875"/>
+            <Cube currency="JPY" rate="160.89"/>
+        </Cube>
+        <Cube time="2024-01-19">
+            <Cube currency="USD" rate="1.0887"/>
+            <Cube currency="JPY" rate="161.17"/>
+            <Cube currency="ZZZ" rate="2.5"/>
+        </Cube>
+    </Cube>
+</gesmes:Envelope>"#;
+
+    const STATIC_FILE_RATES: &str = r#"{
+        "base_currency": "USD",
+        "conversion": {
+            "EUR": { "to_factor": "0.9185", "from_factor": "1.0887" },
+            "INR": { "to_factor": "83.12", "from_factor": "0.01203" }
+        },
+        "timestamp": 1705622400
+    }"#;
+
+    fn decimal(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_ecb_rates_are_taken_from_the_latest_day() {
+        let rates = parse_ecb_rates(ECB_FEED).unwrap();
+
+        assert_eq!(rates.rate_date.to_string(), "2024-01-19");
+        assert_eq!(rates.exchange_rates.base_currency, enums::Currency::EUR);
+        let usd = rates
+            .exchange_rates
+            .conversion
+            .get(&enums::Currency::USD)
+            .unwrap();
+        assert_eq!(usd.to_factor, decimal("1.0887"));
+        assert_eq!(
+            usd.from_factor,
+            Decimal::ONE.checked_div(decimal("1.0887")).unwrap()
+        );
+        assert_eq!(
+            rates
+                .exchange_rates
+                .conversion
+                .get(&enums::Currency::JPY)
+                .unwrap()
+                .to_factor,
+            decimal("161.17")
+        );
+    }
+
+    #[test]
+    fn test_ecb_rates_include_the_base_currency_and_skip_unknown_currencies() {
+        let rates = parse_ecb_rates(ECB_FEED).unwrap();
+
+        let eur = rates
+            .exchange_rates
+            .conversion
+            .get(&enums::Currency::EUR)
+            .unwrap();
+        assert_eq!(eur.to_factor, Decimal::ONE);
+        assert_eq!(eur.from_factor, Decimal::ONE);
+        // Only the base currency and the known currencies of the latest day are present
+        assert_eq!(rates.exchange_rates.conversion.len(), 3);
+    }
+
+    #[test]
+    fn test_ecb_rates_reject_feeds_without_rates() {
+        let feed = r#"<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01">
+            <Cube></Cube>
+        </gesmes:Envelope>"#;
+
+        assert!(parse_ecb_rates(feed).is_err());
+        assert!(parse_ecb_rates("not xml").is_err());
+    }
+
+    #[test]
+    fn test_static_file_rates() {
+        let rates = parse_static_file_rates(STATIC_FILE_RATES).unwrap();
+
+        assert_eq!(rates.rate_date.to_string(), "2024-01-19");
+        assert_eq!(rates.exchange_rates.base_currency, enums::Currency::USD);
+        let eur = rates
+            .exchange_rates
+            .conversion
+            .get(&enums::Currency::EUR)
+            .unwrap();
+        assert_eq!(eur.to_factor, decimal("0.9185"));
+        assert_eq!(eur.from_factor, decimal("1.0887"));
+        assert_eq!(rates.exchange_rates.conversion.len(), 2);
+    }
+
+    #[test]
+    fn test_static_file_rates_reject_unknown_currencies() {
+        let contents = STATIC_FILE_RATES.replace("INR", "ZZZ");
+
+        assert!(parse_static_file_rates(&contents).is_err());
+    }
+}
@@ -1,6 +1,10 @@
+use storage_impl::redis::kv_store;
+
 use crate::{
     core::errors::{self, utils::RedisErrorExt},
+    db::reverse_lookup::ReverseLookupInterface,
     routes::metrics,
+    types::storage::{enums, ReverseLookupNew},
 };
 
 /// Generates hscan field pattern. Suppose the field is pa_1234_ref_1211 it will generate
@@ -36,3 +40,96 @@ where
         },
     }
 }
+
+/// Fetches the entries of a list from redis as well as from the database. Entries which are not
+/// drained yet are only present in redis while entries written before the merchant moved to KV
+/// are only present in the database, the redis copy is kept when an entry is present in both.
+pub async fn find_all_combined_kv_database<F, RFut, DFut, T, K>(
+    redis_fut: RFut,
+    database_call_closure: F,
+    unique_key: K,
+) -> error_stack::Result<Vec<T>, errors::StorageError>
+where
+    F: FnOnce() -> DFut,
+    RFut:
+        futures::Future<Output = error_stack::Result<Vec<T>, redis_interface::errors::RedisError>>,
+    DFut: futures::Future<Output = error_stack::Result<Vec<T>, errors::StorageError>>,
+    K: Fn(&T) -> &str,
+{
+    let mut redis_entries = match redis_fut.await {
+        Ok(entries) => entries,
+        Err(redis_error) => match redis_error.current_context() {
+            redis_interface::errors::RedisError::NotFound => Vec::new(),
+            // Keeping the key empty here since the error would never go here.
+            _ => Err(redis_error.to_redis_failed_response(""))?,
+        },
+    };
+    let database_entries = database_call_closure().await?;
+
+    let mut entries: Vec<T> = database_entries
+        .into_iter()
+        .map(|database_entry| {
+            match redis_entries
+                .iter()
+                .position(|redis_entry| unique_key(redis_entry) == unique_key(&database_entry))
+            {
+                Some(index) => redis_entries.swap_remove(index),
+                None => database_entry,
+            }
+        })
+        .collect();
+    entries.extend(redis_entries);
+    Ok(entries)
+}
+
+/// Fetches the entries whose field matches `field_pattern` from the redis hashes of all the
+/// customers of a merchant, for listing the entries of a merchant in KV. The hashes are read from
+/// the set of customer keys the merchant has written in KV.
+pub async fn find_all_kv_entries_of_merchant<T>(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    merchant_id: &str,
+    field_pattern: &str,
+) -> error_stack::Result<Vec<T>, redis_interface::errors::RedisError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let keys = redis_conn
+        .get_set_members(&kv_store::merchant_customer_keys_set(merchant_id))
+        .await?;
+    let entries = futures::future::try_join_all(
+        keys.iter()
+            .map(|key| redis_conn.hscan_and_deserialize::<T>(key, field_pattern, None)),
+    )
+    .await?;
+    Ok(entries.into_iter().flatten().collect())
+}
+
+/// Inserts the reverse lookup unless it is already present. Entries written before the merchant
+/// moved to KV have no reverse lookup, the reads would otherwise skip the redis copy of the entry
+/// once it is updated in KV.
+pub async fn insert_reverse_lookup_if_not_exists<T>(
+    db: &T,
+    new: ReverseLookupNew,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> error_stack::Result<(), errors::StorageError>
+where
+    T: ReverseLookupInterface + ?Sized,
+{
+    match db
+        .get_lookup_by_lookup_id(&new.lookup_id, storage_scheme)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err)
+            if matches!(
+                err.current_context(),
+                errors::StorageError::ValueNotFound(_)
+            ) || err.current_context().is_db_not_found() =>
+        {
+            db.insert_reverse_lookup(new, storage_scheme)
+                .await
+                .map(|_| ())
+        }
+        Err(err) => Err(err),
+    }
+}
@@ -1,3 +1,12 @@
+pub mod blocklist_expiry;
+pub mod outgoing_webhook_retry;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
+#[cfg(feature = "payouts")]
+pub mod payout_sync;
 pub mod refund_router;
+#[cfg(feature = "olap")]
+pub mod report;
+pub mod subscription;
 pub mod tokenized_data;
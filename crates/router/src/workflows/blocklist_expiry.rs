@@ -0,0 +1,69 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::blocklist::utils as blocklist_utils, db::StorageInterface, errors, routes::AppState,
+    types::storage,
+};
+
+pub struct BlocklistExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for BlocklistExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::BlocklistExpiryWorkflow = process
+            .tracking_data
+            .clone()
+            .parse_value("BlocklistExpiryWorkflow")?;
+
+        let blocklist_entry = db
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(
+                &tracking_data.merchant_id,
+                &tracking_data.fingerprint_id,
+            )
+            .await;
+
+        // The entry may have been removed, or removed and blocked again with a different expiry
+        // which is then handled by a task of its own
+        match blocklist_entry {
+            Ok(blocklist_entry)
+                if blocklist_utils::is_blocklist_entry_expired(
+                    &blocklist_entry,
+                    common_utils::date_time::now(),
+                ) =>
+            {
+                blocklist_utils::purge_expired_blocklist_entry(state, &blocklist_entry).await?;
+            }
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error)?,
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(
+                state.get_db().as_scheduler(),
+                format!("COMPLETED_BY_PT_{id}"),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,267 @@
+use common_utils::ext_traits::{StringExt, ValueExt};
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, types::process_data, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, utils as scheduler_utils, SchedulerAppState,
+};
+
+use crate::{
+    consts,
+    core::{
+        errors::StorageErrorExt,
+        webhooks::{self as webhooks_core, types::OutgoingWebhookTrackingData},
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::{
+        api,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+pub struct OutgoingWebhookRetryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(&tracking_data.business_profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: tracking_data.business_profile_id.clone(),
+            })?;
+
+        // The request is redelivered as it was stored on the event whose delivery failed
+        let failed_event = db
+            .find_event_by_event_id(&tracking_data.event_id)
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to fetch the event to redeliver")?;
+        let initial_attempt_id = failed_event
+            .initial_attempt_id
+            .clone()
+            .unwrap_or_else(|| failed_event.event_id.clone());
+        let request_content = failed_event
+            .request
+            .clone()
+            .ok_or(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .into_report()
+            .attach_printable("The request sent for the event is not stored")?
+            .peek()
+            .clone()
+            .parse_value::<api::OutgoingWebhookRequestContent>("OutgoingWebhookRequestContent")
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to parse the stored outgoing webhook request")?;
+
+        // Every retry is recorded as a separate event, linked to the initial attempt
+        let new_event = storage::EventNew {
+            event_id: utils::generate_id(consts::ID_LENGTH, "evt"),
+            event_type: failed_event.event_type,
+            event_class: failed_event.event_class,
+            is_webhook_notified: false,
+            intent_reference_id: failed_event.intent_reference_id.clone(),
+            primary_object_id: failed_event.primary_object_id.clone(),
+            primary_object_type: failed_event.primary_object_type,
+            merchant_id: Some(tracking_data.merchant_id.clone()),
+            business_profile_id: Some(tracking_data.business_profile_id.clone()),
+            initial_attempt_id: Some(initial_attempt_id.clone()),
+            delivery_attempt: Some(enums::WebhookDeliveryAttempt::AutomaticRetry),
+        };
+
+        let event = db
+            .insert_event(new_event)
+            .await
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to insert event in events table")?;
+
+        let delivery_result = webhooks_core::send_outgoing_webhook_request(
+            state,
+            &business_profile,
+            &event,
+            request_content,
+        )
+        .await;
+
+        match delivery_result {
+            Ok(()) => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(
+                        state.get_db().as_scheduler(),
+                        format!("COMPLETED_BY_PT_{id}"),
+                    )
+                    .await?;
+            }
+            Err(error)
+                if error
+                    .current_context()
+                    .is_webhook_delivery_retryable_error() =>
+            {
+                logger::warn!(
+                    ?error,
+                    "Outgoing webhook retry {} for event {} failed",
+                    process.retry_count + 1,
+                    initial_attempt_id
+                );
+                retry_webhook_delivery_task(db, &tracking_data.merchant_id, process).await?;
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Outgoing webhook retry for event {} cannot be delivered",
+                    initial_attempt_id
+                );
+                process
+                    .finish_with_status(
+                        state.get_db().as_scheduler(),
+                        "WEBHOOK_DELIVERY_ABORTED".to_string(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Get the next schedule time
+///
+/// The schedule time can be configured in configs by this key `pt_mapping_outgoing_webhooks`
+/// ```json
+/// {
+///     "defaultMapping": {
+///         "start_after": 60,
+///         "frequency": [300, 600, 1200],
+///         "count": [1, 1, 1]
+///     },
+///     "customMerchantMapping": {}
+/// }
+/// ```
+///
+/// This config represents
+///
+/// `start_after`: The first retry should happen after 60 seconds
+///
+/// `frequency` and `count`: The next 3 retries should happen after 300, 600 and 1200 seconds
+/// respectively, after which the delivery is abandoned
+///
+/// Without this config, the default mapping retries the delivery 9 times: after 60 seconds and
+/// then at intervals doubling from 300 to 38400 seconds.
+///
+pub async fn get_webhook_delivery_retry_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let mapping: common_utils::errors::CustomResult<
+        process_data::OutgoingWebhookRetryProcessTrackerMapping,
+        errors::StorageError,
+    > = db
+        .find_config_by_key("pt_mapping_outgoing_webhooks")
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("OutgoingWebhookRetryProcessTrackerMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = match mapping {
+        Ok(x) => x,
+        Err(err) => {
+            logger::info!("Redis Mapping Error: {}", err);
+            process_data::OutgoingWebhookRetryProcessTrackerMapping::default()
+        }
+    };
+
+    let time_delta = scheduler_utils::get_outgoing_webhook_retry_schedule_time(
+        mapping,
+        merchant_id,
+        retry_count,
+    );
+
+    scheduler_utils::get_time_from_delta(time_delta)
+}
+
+/// Schedule the task for retry
+///
+/// Returns bool which indicates whether this was the last retry or not
+pub async fn retry_webhook_delivery_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    process: storage::ProcessTracker,
+) -> Result<bool, sch_errors::ProcessTrackerError> {
+    let schedule_time =
+        get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
+
+    match schedule_time {
+        Some(schedule_time) => {
+            process.retry(db.as_scheduler(), schedule_time).await?;
+            Ok(false)
+        }
+        None => {
+            process
+                .finish_with_status(db.as_scheduler(), "RETRIES_EXCEEDED".to_string())
+                .await?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_default_outgoing_webhook_retry_schedule_time() {
+        let schedule_time_deltas = (0..10)
+            .map(|retry_count| {
+                scheduler_utils::get_outgoing_webhook_retry_schedule_time(
+                    process_data::OutgoingWebhookRetryProcessTrackerMapping::default(),
+                    "-",
+                    retry_count,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            schedule_time_deltas,
+            vec![
+                Some(60),
+                Some(300),
+                Some(600),
+                Some(1200),
+                Some(2400),
+                Some(4800),
+                Some(9600),
+                Some(19200),
+                Some(38400),
+                None
+            ]
+        );
+    }
+}
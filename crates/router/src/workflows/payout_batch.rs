@@ -0,0 +1,113 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::workflows::ProcessTrackerWorkflow, db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::payouts::batch,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+    workflows::payment_sync,
+};
+
+/// The retries of a failed batch can be configured in configs by the key
+/// `pt_mapping_payout_batch`, in the same format as the connector sync mappings
+const PAYOUT_BATCH_RETRY_MAPPING: &str = "payout_batch";
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PayoutBatchWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PayoutBatchWorkflow = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchWorkflow")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payout_batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+
+        // A task which failed is retried by the error handler. Items which were processed in an
+        // earlier attempt are not pending anymore, so a retried task only creates the payouts of
+        // the remaining items
+        if payout_batch.status == enums::PayoutBatchStatus::Pending
+            || payout_batch.status == enums::PayoutBatchStatus::Processing
+        {
+            batch::process_payout_batch(state, &merchant_account, &key_store, payout_batch).await?;
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(
+                state.get_db().as_scheduler(),
+                format!("COMPLETED_BY_PT_{id}"),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(pt.id = %process.id, ?error, "Failed while processing payout batch");
+
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PayoutBatchWorkflow = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchWorkflow")
+            .change_context(sch_errors::ProcessTrackerError::DeserializationFailed)?;
+
+        let retries_exhausted = payment_sync::retry_sync_task(
+            db,
+            PAYOUT_BATCH_RETRY_MAPPING.to_string(),
+            tracking_data.merchant_id.clone(),
+            process,
+        )
+        .await?;
+
+        // The items which are still pending won't be processed anymore, the batch is failed
+        // rather than being left in processing
+        if retries_exhausted {
+            db.update_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+                storage::PayoutBatchUpdate::StatusUpdate {
+                    status: enums::PayoutBatchStatus::Failed,
+                },
+            )
+            .await
+            .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::payouts,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::{api, storage},
+    workflows::payment_sync,
+};
+
+pub struct PayoutsSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PayoutsSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+        let merchant_id = tracking_data
+            .merchant_id
+            .as_ref()
+            .get_required_value("merchant_id")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+            .await?;
+
+        let request = api::PayoutRequest::PayoutRetrieveRequest(tracking_data.clone());
+        let mut payout_data =
+            payouts::make_payout_data(state, &merchant_account, &key_store, &request).await?;
+
+        // Tasks of connectors which can't be polled are finished without syncing
+        let is_sync_supported =
+            payouts::helpers::is_payout_sync_supported(&payout_data.payout_attempt.connector);
+        if payouts::helpers::is_eligible_for_payout_sync(payout_data.payout_attempt.status)
+            && is_sync_supported
+            && !payout_data.payout_attempt.connector_payout_id.is_empty()
+        {
+            let connector_data = api::PayoutConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &payout_data.payout_attempt.connector,
+                api::GetToken::Connector,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get the connector data")?;
+
+            payout_data = payouts::sync_payout(
+                state,
+                &merchant_account,
+                &key_store,
+                &request,
+                &connector_data,
+                &mut payout_data,
+            )
+            .await?;
+        }
+
+        let payout_attempt = payout_data.payout_attempt;
+        if payouts::helpers::is_payout_terminal_state(payout_attempt.status) || !is_sync_supported {
+            let id = process.id.clone();
+            process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await?;
+        } else {
+            payment_sync::retry_sync_task(
+                db,
+                payout_attempt.connector,
+                payout_attempt.merchant_id,
+                process,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,59 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    analytics::reports::{self, ReportTrackingData},
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct ReportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ReportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let tracking_data: ReportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReportTrackingData")?;
+
+        if let Err(error) = reports::generate_and_send_report(state, &tracking_data).await {
+            logger::error!(
+                ?error,
+                "Failed to generate {} report",
+                tracking_data.report_type
+            );
+            Err(sch_errors::ProcessTrackerError::FlowExecutionError {
+                flow: "GenerateReport",
+            })?
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(
+                state.get_db().as_scheduler(),
+                format!("COMPLETED_BY_PT_{id}"),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,159 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::workflows::ProcessTrackerWorkflow, db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::subscriptions,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+/// Delay before a renewal which failed with an error is run again, doubled on every consecutive
+/// error up to a day. The renewal is never given up so that billing doesn't silently stop
+const ERROR_RETRY_BASE_DELAY_SECONDS: i64 = 5 * 60;
+const ERROR_RETRY_MAX_DELAY_SECONDS: i64 = 24 * 60 * 60;
+
+pub struct SubscriptionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for SubscriptionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::SubscriptionWorkflow = process
+            .tracking_data
+            .clone()
+            .parse_value("SubscriptionWorkflow")?;
+
+        let subscription = db
+            .find_subscription_by_merchant_id_subscription_id(
+                &tracking_data.merchant_id,
+                &tracking_data.subscription_id,
+            )
+            .await?;
+
+        // Cancelled subscriptions keep their task until it is due, it is dropped here
+        if subscriptions::is_subscription_ended(subscription.status) {
+            let id = process.id.clone();
+            return process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await;
+        }
+
+        // A run which failed after the subscription was moved to its next billing date is only
+        // rescheduled, the next cycle must not be charged early
+        if subscription
+            .next_billing_at
+            .map_or(false, |next_billing_at| {
+                next_billing_at > common_utils::date_time::now()
+            })
+        {
+            return schedule_next_run(state, process, &subscription).await;
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let subscription = subscriptions::process_subscription_cycle(
+            state,
+            &merchant_account,
+            &key_store,
+            subscription,
+        )
+        .await?;
+
+        schedule_next_run(state, process, &subscription).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        let delay = get_error_retry_delay(process.retry_count);
+        logger::error!(
+            pt.id = %process.id,
+            ?error,
+            retry_after_seconds = delay,
+            "Subscription renewal failed, it will be retried"
+        );
+
+        let schedule_time =
+            common_utils::date_time::now().saturating_add(time::Duration::seconds(delay));
+        process
+            .retry(state.get_db().as_scheduler(), schedule_time)
+            .await?;
+        Ok(())
+    }
+}
+
+/// The same task is rescheduled for every billing cycle, dunning attempts of a cycle are counted
+/// as retries of the task
+async fn schedule_next_run(
+    state: &AppState,
+    process: storage::ProcessTracker,
+    subscription: &storage::Subscription,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    match subscription.next_billing_at {
+        Some(next_billing_at) if subscription.status == enums::SubscriptionStatus::PastDue => {
+            process
+                .retry(state.get_db().as_scheduler(), next_billing_at)
+                .await
+        }
+        Some(next_billing_at) => {
+            process
+                .reset(state.get_db().as_scheduler(), next_billing_at)
+                .await
+        }
+        None => {
+            let id = process.id.clone();
+            process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await
+        }
+    }
+}
+
+fn get_error_retry_delay(retry_count: i32) -> i64 {
+    let exponent = u32::try_from(retry_count.clamp(0, 16)).unwrap_or(0);
+    ERROR_RETRY_BASE_DELAY_SECONDS
+        .saturating_mul(2_i64.saturating_pow(exponent))
+        .min(ERROR_RETRY_MAX_DELAY_SECONDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_retry_delay_is_doubled_up_to_a_day() {
+        let delays = (0..10).map(get_error_retry_delay).collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            vec![300, 600, 1200, 2400, 4800, 9600, 19200, 38400, 76800, 86400]
+        );
+    }
+}
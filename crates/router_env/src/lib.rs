@@ -41,9 +41,11 @@ pub enum AnalyticsFlow {
     GetInfo,
     GetPaymentMetrics,
     GetRefundsMetrics,
+    GetDisputeMetrics,
     GetSdkMetrics,
     GetPaymentFilters,
     GetRefundFilters,
+    GetDisputeFilters,
     GetSdkEventFilters,
     GetApiEvents,
     GetSdkEvents,
@@ -23,6 +23,8 @@ pub struct Log {
     pub console: LogConsole,
     /// Telemetry / tracing.
     pub telemetry: LogTelemetry,
+    /// How secrets are masked in logs.
+    pub masking: masking::MaskingPolicy,
 }
 
 /// Logging to a file.
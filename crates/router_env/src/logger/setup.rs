@@ -38,6 +38,9 @@ pub fn setup(
 ) -> TelemetryGuard {
     let mut guards = Vec::new();
 
+    // Secrets may be formatted as soon as logging starts, so the masking policy goes first
+    masking::set_masking_policy(config.masking.clone());
+
     // Setup OpenTelemetry traces and metrics
     let traces_layer = if config.telemetry.traces_enabled {
         setup_tracing_pipeline(&config.telemetry, service_name)
@@ -100,6 +100,12 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Subscriptions create flow.
+    SubscriptionsCreate,
+    /// Subscriptions retrieve flow.
+    SubscriptionsRetrieve,
+    /// Subscriptions cancel flow.
+    SubscriptionsCancel,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods list flow.
@@ -153,6 +159,14 @@ pub enum Flow {
     PayoutsFulfill,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payouts list flow.
+    PayoutsList,
+    /// Payouts filter flow.
+    PayoutsFilter,
+    /// Payouts batch create flow.
+    PayoutsBatchCreate,
+    /// Payouts batch retrieve flow.
+    PayoutsBatchRetrieve,
     /// Payments Redirect flow.
     PaymentsRedirect,
     /// Refunds create flow.
@@ -193,12 +207,20 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing simulate config
+    RoutingSimulateConfig,
+    /// Routing coverage config
+    RoutingCoverageConfig,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
     DeleteFromBlocklist,
     /// List entries from blocklist
     ListBlocklist,
+    /// Import blocklist entries from a CSV file
+    ImportBlocklist,
+    /// Export blocklist entries as a CSV file
+    ExportBlocklist,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Validate payment method flow
@@ -225,6 +247,10 @@ pub enum Flow {
     DeleteFile,
     /// Retrieve File flow
     RetrieveFile,
+    /// Create File Download Link flow
+    CreateFileDownloadLink,
+    /// Download File flow
+    DownloadFile,
     /// Dispute Evidence submission flow
     DisputesEvidenceSubmit,
     /// Create Config Key flow
@@ -341,6 +367,10 @@ pub enum Flow {
     UpdateUserAccountDetails,
     /// Accept user invitation
     AcceptInvitation,
+    /// List the outgoing webhook delivery attempts for a business profile
+    WebhookEventDeliveryAttemptList,
+    /// Manually retry the delivery for a webhook event
+    WebhookEventDeliveryRetry,
 }
 
 ///
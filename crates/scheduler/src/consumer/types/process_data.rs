@@ -53,3 +53,47 @@ impl Default for PaymentMethodsPTMapping {
         }
     }
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingWebhookRetryProcessTrackerMapping {
+    pub default_mapping: RetryMapping,
+    pub custom_merchant_mapping: HashMap<String, RetryMapping>,
+}
+
+impl Default for OutgoingWebhookRetryProcessTrackerMapping {
+    fn default() -> Self {
+        Self {
+            custom_merchant_mapping: HashMap::new(),
+            // The interval between retries doubles every time, starting at 5 minutes and
+            // ending at about 10 hours, which gives up on the webhook after 9 retries (~21 hours)
+            default_mapping: RetryMapping {
+                start_after: 60,
+                frequency: vec![300, 600, 1200, 2400, 4800, 9600, 19200, 38400],
+                count: vec![1, 1, 1, 1, 1, 1, 1, 1],
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionDunningProcessTrackerMapping {
+    pub default_mapping: RetryMapping,
+    pub custom_merchant_mapping: HashMap<String, RetryMapping>,
+}
+
+impl Default for SubscriptionDunningProcessTrackerMapping {
+    fn default() -> Self {
+        Self {
+            custom_merchant_mapping: HashMap::new(),
+            // A failed billing cycle is retried after 1, 3 and 7 days, after which the
+            // subscription is cancelled
+            default_mapping: RetryMapping {
+                start_after: 86400,
+                frequency: vec![172800, 345600],
+                count: vec![1, 1],
+            },
+        }
+    }
+}
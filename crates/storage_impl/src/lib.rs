@@ -8,11 +8,14 @@ use redis::{kv_store::RedisConnInterface, RedisStore};
 mod address;
 pub mod config;
 pub mod connection;
+mod customers;
 pub mod database;
 pub mod errors;
 mod lookup;
+mod mandate;
 pub mod metrics;
 pub mod mock_db;
+mod payment_method;
 pub mod payments;
 pub mod redis;
 pub mod refund;
@@ -33,6 +33,7 @@ pub struct MockDb {
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
+    pub file_metadata: Arc<Mutex<Vec<store::file::FileMetadata>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<crate::store::capture::Capture>>>,
@@ -70,6 +71,7 @@ impl MockDb {
             cards_info: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
+            file_metadata: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
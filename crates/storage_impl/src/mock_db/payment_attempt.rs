@@ -147,6 +147,8 @@ impl PaymentAttemptInterface for MockDb {
             merchant_connector_id: payment_attempt.merchant_connector_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            retry_decision: payment_attempt.retry_decision,
+            retry_reason: payment_attempt.retry_reason,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
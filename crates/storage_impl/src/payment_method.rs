@@ -0,0 +1,5 @@
+use diesel_models::payment_method::PaymentMethod;
+
+use crate::redis::kv_store::KvStorePartition;
+
+impl KvStorePartition for PaymentMethod {}
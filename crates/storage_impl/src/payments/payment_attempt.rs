@@ -390,6 +390,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
                     unified_code: payment_attempt.unified_code.clone(),
                     unified_message: payment_attempt.unified_message.clone(),
+                    retry_decision: payment_attempt.retry_decision.clone(),
+                    retry_reason: payment_attempt.retry_reason.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -986,6 +988,7 @@ impl DataModelExt for MandateAmountData {
             start_date: self.start_date,
             end_date: self.end_date,
             metadata: self.metadata,
+            max_transaction_amount: self.max_transaction_amount,
         }
     }
 
@@ -996,6 +999,7 @@ impl DataModelExt for MandateAmountData {
             start_date: storage_model.start_date,
             end_date: storage_model.end_date,
             metadata: storage_model.metadata,
+            max_transaction_amount: storage_model.max_transaction_amount,
         }
     }
 }
@@ -1079,6 +1083,8 @@ impl DataModelExt for PaymentAttempt {
             merchant_connector_id: self.merchant_connector_id,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            retry_decision: self.retry_decision,
+            retry_reason: self.retry_reason,
         }
     }
 
@@ -1134,6 +1140,8 @@ impl DataModelExt for PaymentAttempt {
             merchant_connector_id: storage_model.merchant_connector_id,
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
+            retry_decision: storage_model.retry_decision,
+            retry_reason: storage_model.retry_reason,
         }
     }
 }
@@ -1189,6 +1197,8 @@ impl DataModelExt for PaymentAttemptNew {
             merchant_connector_id: self.merchant_connector_id,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            retry_decision: self.retry_decision,
+            retry_reason: self.retry_reason,
         }
     }
 
@@ -1242,6 +1252,8 @@ impl DataModelExt for PaymentAttemptNew {
             merchant_connector_id: storage_model.merchant_connector_id,
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
+            retry_decision: storage_model.retry_decision,
+            retry_reason: storage_model.retry_reason,
         }
     }
 }
@@ -1326,6 +1338,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 straight_through_algorithm,
                 error_code,
                 error_message,
+                error_reason,
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
@@ -1347,6 +1360,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 straight_through_algorithm,
                 error_code,
                 error_message,
+                error_reason,
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
@@ -1514,6 +1528,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount,
                 amount_capturable,
             },
+            Self::RetryDecisionUpdate {
+                retry_decision,
+                retry_reason,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::RetryDecisionUpdate {
+                retry_decision,
+                retry_reason,
+                updated_by,
+            },
         }
     }
 
@@ -1594,6 +1617,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 straight_through_algorithm,
                 error_code,
                 error_message,
+                error_reason,
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
@@ -1615,6 +1639,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 straight_through_algorithm,
                 error_code,
                 error_message,
+                error_reason,
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
@@ -1782,6 +1807,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount,
                 amount_capturable,
             },
+            DieselPaymentAttemptUpdate::RetryDecisionUpdate {
+                retry_decision,
+                retry_reason,
+                updated_by,
+            } => Self::RetryDecisionUpdate {
+                retry_decision,
+                retry_reason,
+                updated_by,
+            },
         }
     }
 }
@@ -193,3 +193,29 @@ where
             err
         })
 }
+
+/// The redis set holding the keys of the customer hashes written in KV for a merchant
+pub fn merchant_customer_keys_set(merchant_id: &str) -> String {
+    format!("mid_{merchant_id}_cust_keys")
+}
+
+/// Adds the key of a customer hash to the set of keys of its merchant, so the entries of a
+/// merchant can be listed without scanning the keyspace. The set lives as long as the most
+/// recently written hash, keys of expired hashes are skipped when reading their fields.
+pub async fn add_merchant_customer_key<D>(
+    store: &KVRouterStore<D>,
+    merchant_id: &str,
+    key: &str,
+) -> CustomResult<(), RedisError>
+where
+    D: crate::database::store::DatabaseStore,
+{
+    store
+        .get_redis_conn()?
+        .add_set_members_with_expiry(
+            &merchant_customer_keys_set(merchant_id),
+            key,
+            store.ttl_for_kv.into(),
+        )
+        .await
+}
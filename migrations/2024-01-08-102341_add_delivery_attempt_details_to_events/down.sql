@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE events
+DROP COLUMN IF EXISTS merchant_id,
+DROP COLUMN IF EXISTS business_profile_id,
+DROP COLUMN IF EXISTS initial_attempt_id,
+DROP COLUMN IF EXISTS delivery_attempt;
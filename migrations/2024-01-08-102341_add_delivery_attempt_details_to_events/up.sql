@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS merchant_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS business_profile_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS initial_attempt_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS delivery_attempt VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_business_profile_id_created_at_index;
+
+ALTER TABLE events
+DROP COLUMN IF EXISTS request,
+DROP COLUMN IF EXISTS response_status_code,
+DROP COLUMN IF EXISTS response_body,
+DROP COLUMN IF EXISTS delivery_latency_ms;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS request JSONB DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS response_status_code SMALLINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS response_body TEXT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS delivery_latency_ms BIGINT DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS events_business_profile_id_created_at_index ON events (business_profile_id, created_at);
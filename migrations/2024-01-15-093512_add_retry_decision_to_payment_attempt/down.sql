@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE gateway_status_map DROP COLUMN IF EXISTS error_category;
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS retry_decision;
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS retry_reason;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE gateway_status_map ADD COLUMN IF NOT EXISTS error_category VARCHAR(64);
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS retry_decision VARCHAR(32);
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS retry_reason VARCHAR(64);
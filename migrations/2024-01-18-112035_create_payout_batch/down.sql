@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payout_batch_item_merchant_id_batch_id_status_index;
+
+DROP TABLE IF EXISTS payout_batch_item;
+
+DROP INDEX IF EXISTS payout_batch_merchant_id_batch_id_index;
+
+DROP TABLE IF EXISTS payout_batch;
+
+DROP TYPE IF EXISTS "PayoutBatchItemStatus";
+
+DROP TYPE IF EXISTS "PayoutBatchStatus";
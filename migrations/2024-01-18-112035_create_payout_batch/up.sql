@@ -0,0 +1,42 @@
+-- Your SQL goes here
+CREATE TYPE "PayoutBatchStatus" AS ENUM (
+    'pending',
+    'processing',
+    'completed',
+    'partially_completed',
+    'failed'
+);
+
+CREATE TYPE "PayoutBatchItemStatus" AS ENUM ('pending', 'succeeded', 'failed');
+
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status "PayoutBatchStatus" NOT NULL,
+    total_count INTEGER NOT NULL,
+    succeeded_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    file_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS payout_batch_merchant_id_batch_id_index ON payout_batch (merchant_id, batch_id);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    item_index INTEGER NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payout_id VARCHAR(64),
+    status "PayoutBatchItemStatus" NOT NULL,
+    payout_status "PayoutStatus",
+    request BYTEA NOT NULL,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (batch_id, item_index)
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_merchant_id_batch_id_status_index ON payout_batch_item (merchant_id, batch_id, status);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS forex_rate_snapshot (
+    rate_date DATE NOT NULL,
+    provider VARCHAR(64) NOT NULL,
+    base_currency "Currency" NOT NULL,
+    rates JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (provider, rate_date)
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+-- `fingerprint_id` is not shrunk back to 64 characters, rows holding longer hashed values and
+-- ranges would make the column change fail
+SELECT 1;
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email_domain';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_address';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_range';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'phone_number';
+
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'card_bin_range';
+
+-- Hashed values and ranges do not fit in the 64 characters used for fingerprint ids
+ALTER TABLE blocklist ALTER COLUMN fingerprint_id TYPE VARCHAR(255);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE blocklist DROP COLUMN IF EXISTS created_by;
+
+ALTER TABLE blocklist DROP COLUMN IF EXISTS reason;
+
+ALTER TABLE blocklist DROP COLUMN IF EXISTS expires_at;
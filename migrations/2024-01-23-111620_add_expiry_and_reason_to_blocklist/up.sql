@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP;
+
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS reason VARCHAR(255);
+
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS created_by VARCHAR(64);
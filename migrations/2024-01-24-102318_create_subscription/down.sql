@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS subscription_merchant_id_customer_id_index;
+
+DROP INDEX IF EXISTS subscription_merchant_id_subscription_id_index;
+
+DROP TABLE IF EXISTS subscription;
+
+DROP TYPE IF EXISTS "SubscriptionInterval";
+
+DROP TYPE IF EXISTS "SubscriptionStatus";
@@ -0,0 +1,48 @@
+-- Your SQL goes here
+CREATE TYPE "SubscriptionStatus" AS ENUM (
+    'trialing',
+    'active',
+    'past_due',
+    'cancelled',
+    'completed'
+);
+
+CREATE TYPE "SubscriptionInterval" AS ENUM ('day', 'week', 'month', 'year');
+
+ALTER TYPE "EventClass" ADD VALUE 'subscriptions';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'subscription_details';
+
+ALTER TYPE "EventType" ADD VALUE 'subscription_renewed';
+
+ALTER TYPE "EventType" ADD VALUE 'subscription_renewal_failed';
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    status "SubscriptionStatus" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    interval "SubscriptionInterval" NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    anchor_date TIMESTAMP NOT NULL,
+    trial_days INTEGER NOT NULL DEFAULT 0,
+    end_after_cycles INTEGER,
+    ends_at TIMESTAMP,
+    completed_cycles INTEGER NOT NULL DEFAULT 0,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    next_billing_at TIMESTAMP,
+    last_payment_id VARCHAR(64),
+    description VARCHAR(255),
+    metadata JSONB,
+    cancelled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS subscription_merchant_id_subscription_id_index ON subscription (merchant_id, subscription_id);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS mandate_usage_merchant_id_mandate_id_index;
+
+DROP INDEX IF EXISTS mandate_usage_merchant_id_attempt_id_index;
+
+DROP TABLE IF EXISTS mandate_usage;
+
+ALTER TABLE mandate DROP COLUMN IF EXISTS max_transaction_amount;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+ALTER TABLE mandate ADD COLUMN IF NOT EXISTS max_transaction_amount BIGINT;
+
+CREATE TABLE IF NOT EXISTS mandate_usage (
+    id SERIAL PRIMARY KEY,
+    mandate_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS mandate_usage_merchant_id_attempt_id_index ON mandate_usage (merchant_id, attempt_id);
+
+CREATE INDEX IF NOT EXISTS mandate_usage_merchant_id_mandate_id_index ON mandate_usage (merchant_id, mandate_id);
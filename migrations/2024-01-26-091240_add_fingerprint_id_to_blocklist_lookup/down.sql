@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE blocklist_lookup DROP COLUMN IF EXISTS fingerprint_id;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE blocklist_lookup ADD COLUMN IF NOT EXISTS fingerprint_id VARCHAR(255);
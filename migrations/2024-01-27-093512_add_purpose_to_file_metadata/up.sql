@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE file_metadata ADD COLUMN IF NOT EXISTS purpose VARCHAR(64);